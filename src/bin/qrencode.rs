@@ -1,8 +1,55 @@
+use qrcode::payload::{CalendarEvent, Email, Geo, Payload, Sms, Tel, Wifi, WifiAuthentication};
+use qrcode::QrCode;
 use std::env;
 
+/// Parses the payload from the command line, e.g. `tel +15550100`, or returns
+/// `None` if the arguments should be encoded as plain text.
+///
+/// The supported payloads are:
+///
+/// * `tel <number>`
+/// * `sms <number> [<message>]`
+/// * `mailto <address> [<subject>] [<body>]`
+/// * `geo <latitude> <longitude>`
+/// * `event <summary> <start> [<end>]`, with times like `20240301T090000Z`
+/// * `wifi <ssid> [<password>]`
+fn parse_payload(args: &[String]) -> Option<Box<dyn Payload>> {
+    let (kind, args) = args.split_first()?;
+    let arg = |i: usize| args.get(i).cloned().unwrap_or_default();
+    let payload: Box<dyn Payload> = match kind.as_str() {
+        _ if args.is_empty() => return None,
+        "tel" => Box::new(Tel { number: arg(0) }),
+        "sms" => Box::new(Sms { number: arg(0), message: arg(1), ..Sms::default() }),
+        "mailto" => Box::new(Email { to: arg(0), subject: arg(1), body: arg(2), ..Email::default() }),
+        "geo" => Box::new(Geo {
+            latitude: arg(0).parse().expect("invalid latitude"),
+            longitude: arg(1).parse().expect("invalid longitude"),
+            ..Geo::default()
+        }),
+        "event" => Box::new(CalendarEvent {
+            summary: arg(0),
+            start: arg(1).parse().expect("invalid start time"),
+            end: args.get(2).map(|end| end.parse().expect("invalid end time")),
+            location: String::new(),
+            description: String::new(),
+        }),
+        "wifi" => Box::new(Wifi {
+            ssid: arg(0),
+            password: arg(1),
+            authentication: if args.len() > 1 { WifiAuthentication::Wpa } else { WifiAuthentication::NoPass },
+            hidden: false,
+        }),
+        _ => return None,
+    };
+    Some(payload)
+}
+
 pub fn main() {
-    let arg = env::args().nth(1).unwrap();
-    let code = qrcode::QrCode::new(arg.as_bytes()).unwrap();
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let code = match parse_payload(&args) {
+        Some(payload) => QrCode::from_payload(&*payload).unwrap(),
+        None => QrCode::new(args[0].as_bytes()).unwrap(),
+    };
 
     print!("{}", code.render().dark_color("\x1b[7m  \x1b[0m").light_color("\x1b[49m  \x1b[0m").build());
 }
@@ -7,23 +7,81 @@ use core::cmp::min;
 extern crate test;
 
 use crate::cast::{As, Truncate};
-use crate::optimize::{total_encoded_len, Optimizer, Parser, Segment};
-use crate::types::{EcLevel, Mode, QrError, QrResult, Version};
+use crate::hanzi::{encode_gb2312, to_gb2312};
+use crate::kanji::{encode_shift_jis, to_shift_jis};
+use crate::optimize::{
+    optimize_segments, optimize_str_segments, parse_hanzi_str, parse_non_kanji, parse_str, total_encoded_len, Parser,
+    Segment,
+};
+use crate::types::{EcLevel, Mode, Overflow, QrError, QrResult, Version};
 
 //------------------------------------------------------------------------------
 //{{{ Bits
 
+/// The kind of a segment pushed to the bits.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SegmentKind {
+    /// An ECI designator.
+    Eci(u32),
+
+    /// The FNC-1 mode indicator in the first position.
+    Fnc1First,
+
+    /// The FNC-1 mode indicator in the second position, with the application
+    /// indicator.
+    Fnc1Second(u8),
+
+    /// The header of a symbol in a structured append sequence.
+    StructuredAppend {
+        /// The 0-based position of the symbol.
+        index: u8,
+
+        /// The number of symbols in the sequence.
+        total: u8,
+
+        /// The parity of the whole data.
+        parity: u8,
+    },
+
+    /// Data encoded in a mode. The byte range indexes the concatenation of
+    /// all data pushed, e.g. the Shift JIS bytes for text pushed by
+    /// `push_kanji_str()`.
+    Data(Segment),
+}
+
+/// A segment pushed to the bits, with the number of bits it takes.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct EncodedSegment {
+    /// The kind of the segment.
+    pub kind: SegmentKind,
+
+    /// The number of bits, including the mode indicator and the character
+    /// count indicator.
+    pub bits_count: usize,
+}
+
 /// The `Bits` structure stores the encoded data for a QR code.
+#[derive(Clone)]
 pub struct Bits {
     data: Vec<u8>,
     bit_offset: usize,
     version: Version,
+    segments: Vec<EncodedSegment>,
+    terminator_bits_count: usize,
+    padding_bits_count: usize,
 }
 
 impl Bits {
     /// Constructs a new, empty bits structure.
     pub const fn new(version: Version) -> Self {
-        Self { data: Vec::new(), bit_offset: 0, version }
+        Self {
+            data: Vec::new(),
+            bit_offset: 0,
+            version,
+            segments: Vec::new(),
+            terminator_bits_count: 0,
+            padding_bits_count: 0,
+        }
     }
 
     /// Pushes an N-bit big-endian integer to the end of the bits.
@@ -63,14 +121,13 @@ impl Bits {
     /// Pushes an N-bit big-endian integer to the end of the bits, and check
     /// that the number does not overflow the bits.
     ///
-    /// Returns `Err(QrError::DataTooLong)` on overflow.
-    fn push_number_checked(&mut self, n: usize, number: usize) -> QrResult<()> {
-        if n > 16 || number >= (1 << n) {
-            Err(QrError::DataTooLong)
-        } else {
+    /// Returns `false` without pushing anything on overflow.
+    fn push_number_checked(&mut self, n: usize, number: usize) -> bool {
+        let fits = n <= 16 && number < (1 << n);
+        if fits {
             self.push_number(n, number.as_u16());
-            Ok(())
         }
+        fits
     }
 
     /// Reserves `n` extra bits of space for pushing.
@@ -114,6 +171,49 @@ impl Bits {
     pub const fn version(&self) -> Version {
         self.version
     }
+
+    /// The segments pushed so far, in order.
+    ///
+    /// ```
+    /// use qrcode::bits::{Bits, EncodedSegment, SegmentKind};
+    /// use qrcode::optimize::Segment;
+    /// use qrcode::types::{Mode, Version};
+    ///
+    /// let mut bits = Bits::new(Version::Normal(1));
+    /// bits.push_eci_designator(26).unwrap();
+    /// bits.push_byte_data(b"caf\xc3\xa9").unwrap();
+    /// assert_eq!(
+    ///     bits.segments(),
+    ///     &[
+    ///         EncodedSegment { kind: SegmentKind::Eci(26), bits_count: 12 },
+    ///         EncodedSegment {
+    ///             kind: SegmentKind::Data(Segment { mode: Mode::Byte, begin: 0, end: 5 }),
+    ///             bits_count: 52,
+    ///         },
+    ///     ]
+    /// );
+    /// ```
+    pub fn segments(&self) -> &[EncodedSegment] {
+        &self.segments
+    }
+
+    /// The number of bits of the terminator, or 0 if `push_terminator()` has
+    /// not been called.
+    pub const fn terminator_bits_count(&self) -> usize {
+        self.terminator_bits_count
+    }
+
+    /// The number of bits of padding after the terminator, or 0 if
+    /// `push_terminator()` has not been called.
+    pub const fn padding_bits_count(&self) -> usize {
+        self.padding_bits_count
+    }
+
+    /// Records a header segment pushed since the bits had `start` bits.
+    fn push_header_segment(&mut self, kind: SegmentKind, start: usize) {
+        let bits_count = self.len() - start;
+        self.segments.push(EncodedSegment { kind, bits_count });
+    }
 }
 
 #[test]
@@ -200,17 +300,32 @@ impl Bits {
             (Version::Micro(_), ExtendedMode::Data(Mode::Byte)) => 0b10,
             (Version::Micro(_), ExtendedMode::Data(Mode::Kanji)) => 0b11,
             (Version::Micro(_), _) => return Err(QrError::UnsupportedCharacterSet),
+            (Version::RectMicro(..), ExtendedMode::Data(Mode::Numeric)) => 0b001,
+            (Version::RectMicro(..), ExtendedMode::Data(Mode::Alphanumeric)) => 0b010,
+            (Version::RectMicro(..), ExtendedMode::Data(Mode::Byte)) => 0b011,
+            (Version::RectMicro(..), ExtendedMode::Data(Mode::Kanji)) => 0b100,
+            (Version::RectMicro(..), ExtendedMode::Fnc1First) => 0b101,
+            (Version::RectMicro(..), ExtendedMode::Fnc1Second) => 0b110,
+            (Version::RectMicro(..), ExtendedMode::Eci) => 0b111,
+            (Version::RectMicro(..), ExtendedMode::StructuredAppend | ExtendedMode::Data(Mode::Hanzi)) => {
+                return Err(QrError::UnsupportedCharacterSet)
+            }
             (_, ExtendedMode::Data(Mode::Numeric)) => 0b0001,
             (_, ExtendedMode::Data(Mode::Alphanumeric)) => 0b0010,
             (_, ExtendedMode::Data(Mode::Byte)) => 0b0100,
             (_, ExtendedMode::Data(Mode::Kanji)) => 0b1000,
+            (_, ExtendedMode::Data(Mode::Hanzi)) => 0b1101,
             (_, ExtendedMode::Eci) => 0b0111,
             (_, ExtendedMode::Fnc1First) => 0b0101,
             (_, ExtendedMode::Fnc1Second) => 0b1001,
             (_, ExtendedMode::StructuredAppend) => 0b0011,
         };
         let bits = self.version.mode_bits_count();
-        self.push_number_checked(bits, number).or(Err(QrError::UnsupportedCharacterSet))
+        if self.push_number_checked(bits, number) {
+            Ok(())
+        } else {
+            Err(QrError::UnsupportedCharacterSet)
+        }
     }
 }
 
@@ -258,6 +373,7 @@ impl Bits {
     /// If the designator is outside of the expected range, this method will
     /// return `Err(QrError::InvalidECIDesignator)`.
     pub fn push_eci_designator(&mut self, eci_designator: u32) -> QrResult<()> {
+        let start = self.len();
         self.reserve(12); // assume the common case that eci_designator <= 127.
         self.push_mode_indicator(ExtendedMode::Eci)?;
         match eci_designator {
@@ -275,6 +391,7 @@ impl Bits {
             }
             _ => return Err(QrError::InvalidEciDesignator),
         }
+        self.push_header_segment(SegmentKind::Eci(eci_designator), start);
         Ok(())
     }
 }
@@ -325,10 +442,45 @@ mod eci_tests {
 
 impl Bits {
     fn push_header(&mut self, mode: Mode, raw_data_len: usize) -> QrResult<()> {
+        let byte_size = if matches!(mode, Mode::Kanji | Mode::Hanzi) { raw_data_len * 2 } else { raw_data_len };
+        self.push_header_with_source_len(mode, raw_data_len, byte_size)
+    }
+
+    /// Pushes the header like `push_header()`, recording the data segment as
+    /// `source_len` bytes of the source data.
+    fn push_header_with_source_len(&mut self, mode: Mode, raw_data_len: usize, source_len: usize) -> QrResult<()> {
+        let start = self.len();
         let length_bits = mode.length_bits_count(self.version);
-        self.reserve(length_bits + 4 + mode.data_bits_count(raw_data_len));
+        self.reserve(length_bits + 8 + mode.data_bits_count(raw_data_len));
         self.push_mode_indicator(ExtendedMode::Data(mode))?;
-        self.push_number_checked(length_bits, raw_data_len)?;
+        if mode == Mode::Hanzi {
+            // The subset indicator of GB 2312.
+            self.push_number(4, 0b0001);
+        }
+
+        let begin = self
+            .segments
+            .iter()
+            .rev()
+            .find_map(|s| match s.kind {
+                SegmentKind::Data(segment) => Some(segment.end),
+                _ => None,
+            })
+            .unwrap_or(0);
+        let segment = Segment { mode, begin, end: begin + source_len };
+        let data_begin = self.len() + length_bits;
+        let max_data_len = (1 << length_bits) - 1;
+        if raw_data_len > max_data_len {
+            return Err(QrError::DataTooLong(Overflow {
+                bits_needed: data_begin + mode.data_bits_count(raw_data_len),
+                bits_available: data_begin + mode.data_bits_count(max_data_len),
+                segment: Some(segment),
+            }));
+        }
+        self.push_number(length_bits, raw_data_len.as_u16());
+        // The data bits are pushed by the caller right after the header.
+        let bits_count = data_begin + mode.data_bits_count(raw_data_len) - start;
+        self.segments.push(EncodedSegment { kind: SegmentKind::Data(segment), bits_count });
         Ok(())
     }
 
@@ -353,7 +505,8 @@ impl Bits {
 #[cfg(test)]
 mod numeric_tests {
     use crate::bits::Bits;
-    use crate::types::{QrError, Version};
+    use crate::optimize::Segment;
+    use crate::types::{Mode, Overflow, QrError, Version};
     use alloc::vec;
 
     #[test]
@@ -408,7 +561,14 @@ mod numeric_tests {
     #[test]
     fn test_data_too_long_error() {
         let mut bits = Bits::new(Version::Micro(1));
-        assert_eq!(bits.push_numeric_data(b"12345678"), Err(QrError::DataTooLong));
+        assert_eq!(
+            bits.push_numeric_data(b"12345678"),
+            Err(QrError::DataTooLong(Overflow {
+                bits_needed: 30,
+                bits_available: 27,
+                segment: Some(Segment { mode: Mode::Numeric, begin: 0, end: 8 })
+            }))
+        );
     }
 }
 
@@ -449,7 +609,14 @@ impl Bits {
     ///
     /// Returns `Err(QrError::DataTooLong)` on overflow.
     pub fn push_alphanumeric_data(&mut self, data: &[u8]) -> QrResult<()> {
-        self.push_header(Mode::Alphanumeric, data.len())?;
+        self.push_escaped_alphanumeric_data(data, data.len())
+    }
+
+    /// Encodes alphanumeric data escaped from `source_len` bytes of the source
+    /// data, e.g. a GS1 element string where `%%` represents `%`. The data
+    /// segment is recorded against the source data.
+    pub(crate) fn push_escaped_alphanumeric_data(&mut self, data: &[u8], source_len: usize) -> QrResult<()> {
+        self.push_header_with_source_len(Mode::Alphanumeric, data.len(), source_len)?;
         for chunk in data.chunks(2) {
             let number = chunk.iter().map(|b| alphanumeric_digit(*b)).fold(0, |a, b| a * 45 + b);
             let length = chunk.len() * 5 + 1;
@@ -462,7 +629,8 @@ impl Bits {
 #[cfg(test)]
 mod alphanumeric_tests {
     use crate::bits::Bits;
-    use crate::types::{QrError, Version};
+    use crate::optimize::Segment;
+    use crate::types::{Mode, Overflow, QrError, Version};
     use alloc::vec;
 
     #[test]
@@ -484,7 +652,14 @@ mod alphanumeric_tests {
     #[test]
     fn test_data_too_long() {
         let mut bits = Bits::new(Version::Micro(2));
-        assert_eq!(bits.push_alphanumeric_data(b"ABCDEFGH"), Err(QrError::DataTooLong));
+        assert_eq!(
+            bits.push_alphanumeric_data(b"ABCDEFGH"),
+            Err(QrError::DataTooLong(Overflow {
+                bits_needed: 48,
+                bits_available: 43,
+                segment: Some(Segment { mode: Mode::Alphanumeric, begin: 0, end: 8 })
+            }))
+        );
     }
 }
 
@@ -510,7 +685,8 @@ impl Bits {
 #[cfg(test)]
 mod byte_tests {
     use crate::bits::Bits;
-    use crate::types::{QrError, Version};
+    use crate::optimize::Segment;
+    use crate::types::{Mode, Overflow, QrError, Version};
     use alloc::vec;
 
     #[test]
@@ -543,7 +719,14 @@ mod byte_tests {
     #[test]
     fn test_data_too_long() {
         let mut bits = Bits::new(Version::Micro(3));
-        assert_eq!(bits.push_byte_data(b"0123456701234567"), Err(QrError::DataTooLong));
+        assert_eq!(
+            bits.push_byte_data(b"0123456701234567"),
+            Err(QrError::DataTooLong(Overflow {
+                bits_needed: 134,
+                bits_available: 126,
+                segment: Some(Segment { mode: Mode::Byte, begin: 0, end: 16 })
+            }))
+        );
     }
 }
 
@@ -573,12 +756,27 @@ impl Bits {
         }
         Ok(())
     }
+
+    /// Encodes a string of JIS X 0208 characters to the bits, converting them
+    /// to Shift JIS.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::DataTooLong)` on overflow.
+    ///
+    /// Returns `Err(QrError::InvalidCharacter)` if any character is not in JIS
+    /// X 0208.
+    pub fn push_kanji_str(&mut self, data: &str) -> QrResult<()> {
+        let data = encode_shift_jis(data).ok_or(QrError::InvalidCharacter)?;
+        self.push_kanji_data(&data)
+    }
 }
 
 #[cfg(test)]
 mod kanji_tests {
     use crate::bits::Bits;
-    use crate::types::{QrError, Version};
+    use crate::optimize::Segment;
+    use crate::types::{Mode, Overflow, QrError, Version};
     use alloc::vec;
 
     #[test]
@@ -597,7 +795,119 @@ mod kanji_tests {
     #[test]
     fn test_data_too_long() {
         let mut bits = Bits::new(Version::Micro(3));
-        assert_eq!(bits.push_kanji_data(b"\x93_\x93_\x93_\x93_\x93_\x93_\x93_\x93_"), Err(QrError::DataTooLong));
+        assert_eq!(
+            bits.push_kanji_data(b"\x93_\x93_\x93_\x93_\x93_\x93_\x93_\x93_"),
+            Err(QrError::DataTooLong(Overflow {
+                bits_needed: 109,
+                bits_available: 96,
+                segment: Some(Segment { mode: Mode::Kanji, begin: 0, end: 16 })
+            }))
+        );
+    }
+
+    #[test]
+    fn test_str() {
+        let mut bits = Bits::new(Version::Normal(1));
+        assert_eq!(bits.push_kanji_str("\u{70b9}\u{8317}"), Ok(()));
+        assert_eq!(bits.into_bytes(), vec![0b1000_0000, 0b0010_0110, 0b1100_1111, 0b1110_1010, 0b1010_1000]);
+
+        let mut bits = Bits::new(Version::Normal(1));
+        assert_eq!(bits.push_kanji_str("\u{70b9}A"), Err(QrError::InvalidCharacter));
+    }
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Mode::Hanzi mode
+
+impl Bits {
+    /// Encodes GB 2312 double-byte data to the bits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::DataTooLong)` on overflow.
+    ///
+    /// Returns `Err(QrError::InvalidCharacter)` if the data is not GB 2312
+    /// double-byte data (e.g. if the length of data is not an even number).
+    ///
+    /// Returns `Err(QrError::UnsupportedCharacterSet)` if the version is not a
+    /// normal QR code version.
+    pub fn push_hanzi_data(&mut self, data: &[u8]) -> QrResult<()> {
+        self.push_header(Mode::Hanzi, data.len() / 2)?;
+        for hanzi in data.chunks(2) {
+            let &[hi, lo] = hanzi else {
+                return Err(QrError::InvalidCharacter);
+            };
+            let base = match (hi, lo) {
+                (0xa1..=0xaa, 0xa1..=0xfe) => 0xa1a1,
+                (0xb0..=0xfa, 0xa1..=0xfe) => 0xa6a1,
+                _ => return Err(QrError::InvalidCharacter),
+            };
+            let bytes = u16::from(hi) * 256 + u16::from(lo) - base;
+            let number = (bytes >> 8) * 0x60 + (bytes & 0xff);
+            self.push_number(13, number);
+        }
+        Ok(())
+    }
+
+    /// Encodes a string of GB 2312 characters to the bits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::DataTooLong)` on overflow.
+    ///
+    /// Returns `Err(QrError::InvalidCharacter)` if any character is not in GB
+    /// 2312.
+    pub fn push_hanzi_str(&mut self, data: &str) -> QrResult<()> {
+        let data = encode_gb2312(data).ok_or(QrError::InvalidCharacter)?;
+        self.push_hanzi_data(&data)
+    }
+}
+
+#[cfg(test)]
+mod hanzi_tests {
+    use crate::bits::Bits;
+    use crate::types::{QrError, Version};
+    use alloc::vec;
+
+    #[test]
+    fn test_encode() {
+        // "啊" = 0xb0a1 -> 0x0a * 0x60 + 0x00, "亍" = 0xd8a1 -> 0x32 * 0x60 + 0x00.
+        let mut bits = Bits::new(Version::Normal(1));
+        assert_eq!(bits.push_hanzi_data(b"\xb0\xa1\xd8\xa1"), Ok(()));
+        assert_eq!(
+            bits.into_bytes(),
+            vec![0b1101_0001, 0b0000_0010, 0b0001_1110, 0b0000_0100, 0b1011_0000, 0b0000_0000]
+        );
+    }
+
+    #[test]
+    fn test_str() {
+        let mut bits = Bits::new(Version::Normal(1));
+        assert_eq!(bits.push_hanzi_str("\u{554a}\u{4e8d}"), Ok(()));
+        assert_eq!(
+            bits.into_bytes(),
+            vec![0b1101_0001, 0b0000_0010, 0b0001_1110, 0b0000_0100, 0b1011_0000, 0b0000_0000]
+        );
+
+        let mut bits = Bits::new(Version::Normal(1));
+        assert_eq!(bits.push_hanzi_str("\u{9ad4}"), Err(QrError::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_invalid_character() {
+        let mut bits = Bits::new(Version::Normal(1));
+        assert_eq!(bits.push_hanzi_data(b"\xab\xa1"), Err(QrError::InvalidCharacter));
+        let mut bits = Bits::new(Version::Normal(1));
+        assert_eq!(bits.push_hanzi_data(b"\xb0\xa1\xb0"), Err(QrError::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_micro_qr_unsupported() {
+        let mut bits = Bits::new(Version::Micro(4));
+        assert_eq!(bits.push_hanzi_data(b"\xb0\xa1"), Err(QrError::UnsupportedCharacterSet));
+        let mut bits = Bits::new(Version::RectMicro(7, 43));
+        assert_eq!(bits.push_hanzi_data(b"\xb0\xa1"), Err(QrError::UnsupportedCharacterSet));
     }
 }
 
@@ -628,7 +938,10 @@ impl Bits {
     /// If the mode is not supported in the provided version, this method
     /// returns `Err(QrError::UnsupportedCharacterSet)`.
     pub fn push_fnc1_first_position(&mut self) -> QrResult<()> {
-        self.push_mode_indicator(ExtendedMode::Fnc1First)
+        let start = self.len();
+        self.push_mode_indicator(ExtendedMode::Fnc1First)?;
+        self.push_header_segment(SegmentKind::Fnc1First, start);
+        Ok(())
     }
 
     /// Encodes an indicator that the following data are formatted in accordance
@@ -659,18 +972,87 @@ impl Bits {
     /// If the mode is not supported in the provided version, this method
     /// returns `Err(QrError::UnsupportedCharacterSet)`.
     pub fn push_fnc1_second_position(&mut self, application_indicator: u8) -> QrResult<()> {
+        let start = self.len();
         self.push_mode_indicator(ExtendedMode::Fnc1Second)?;
         self.push_number(8, u16::from(application_indicator));
+        self.push_header_segment(SegmentKind::Fnc1Second(application_indicator), start);
+        Ok(())
+    }
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Structured append
+
+impl Bits {
+    /// Encodes the header of a symbol in a structured append sequence.
+    ///
+    /// A structured append sequence splits the data into up to 16 symbols. The
+    /// header records the position `index` (0-based) of this symbol and the
+    /// `total` number of symbols in the sequence, and the `parity`, which is
+    /// the XOR of all bytes of the data before splitting. All symbols in the
+    /// same sequence must have the same parity.
+    ///
+    /// ```
+    /// #![allow(unused_must_use)]
+    ///
+    /// use qrcode::bits::Bits;
+    /// use qrcode::types::Version;
+    ///
+    /// let mut bits = Bits::new(Version::Normal(1));
+    /// bits.push_structured_append_header(0, 2, b'A' ^ b'B');
+    /// bits.push_alphanumeric_data(b"A");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the mode is not supported in the provided version, this method
+    /// returns `Err(QrError::UnsupportedCharacterSet)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `total` is not between 1 and 16, or if `index` is not less
+    /// than `total`.
+    pub fn push_structured_append_header(&mut self, index: u8, total: u8, parity: u8) -> QrResult<()> {
+        assert!((1..=16).contains(&total), "structured append supports only 1 to 16 symbols");
+        assert!(index < total, "symbol index out of range");
+        let start = self.len();
+        self.reserve(20);
+        self.push_mode_indicator(ExtendedMode::StructuredAppend)?;
+        self.push_number(4, u16::from(index));
+        self.push_number(4, u16::from(total - 1));
+        self.push_number(8, u16::from(parity));
+        self.push_header_segment(SegmentKind::StructuredAppend { index, total, parity }, start);
         Ok(())
     }
 }
 
+#[cfg(test)]
+mod structured_append_tests {
+    use crate::bits::Bits;
+    use crate::types::{QrError, Version};
+    use alloc::vec;
+
+    #[test]
+    fn test_header() {
+        let mut bits = Bits::new(Version::Normal(1));
+        assert_eq!(bits.push_structured_append_header(2, 4, 0x5c), Ok(()));
+        assert_eq!(bits.into_bytes(), vec![0b0011_0010, 0b0011_0101, 0b1100_0000]);
+    }
+
+    #[test]
+    fn test_micro_qr_unsupported() {
+        let mut bits = Bits::new(Version::Micro(4));
+        assert_eq!(bits.push_structured_append_header(0, 2, 0), Err(QrError::UnsupportedCharacterSet));
+    }
+}
+
 //}}}
 //------------------------------------------------------------------------------
 //{{{ Finish
 
 // This table is copied from ISO/IEC 18004:2006 §6.4.10, Table 7.
-static DATA_LENGTHS: [[usize; 4]; 44] = [
+static DATA_LENGTHS: [[usize; 4]; 76] = [
     // Normal versions
     [152, 128, 104, 72],
     [272, 224, 176, 128],
@@ -717,6 +1099,39 @@ static DATA_LENGTHS: [[usize; 4]; 44] = [
     [40, 32, 0, 0],
     [84, 68, 0, 0],
     [128, 112, 80, 0],
+    // rMQR versions, from ISO/IEC 23941.
+    [0, 48, 0, 24],    // R7x43
+    [0, 96, 0, 56],    // R7x59
+    [0, 160, 0, 80],   // R7x77
+    [0, 224, 0, 112],  // R7x99
+    [0, 352, 0, 192],  // R7x139
+    [0, 96, 0, 56],    // R9x43
+    [0, 168, 0, 88],   // R9x59
+    [0, 248, 0, 136],  // R9x77
+    [0, 336, 0, 176],  // R9x99
+    [0, 504, 0, 264],  // R9x139
+    [0, 56, 0, 40],    // R11x27
+    [0, 152, 0, 88],   // R11x43
+    [0, 248, 0, 120],  // R11x59
+    [0, 344, 0, 184],  // R11x77
+    [0, 456, 0, 264],  // R11x99
+    [0, 672, 0, 336],  // R11x139
+    [0, 96, 0, 56],    // R13x27
+    [0, 216, 0, 104],  // R13x43
+    [0, 304, 0, 160],  // R13x59
+    [0, 424, 0, 232],  // R13x77
+    [0, 584, 0, 280],  // R13x99
+    [0, 848, 0, 432],  // R13x139
+    [0, 264, 0, 120],  // R15x43
+    [0, 384, 0, 208],  // R15x59
+    [0, 536, 0, 248],  // R15x77
+    [0, 704, 0, 384],  // R15x99
+    [0, 1016, 0, 552], // R15x139
+    [0, 312, 0, 168],  // R17x43
+    [0, 448, 0, 224],  // R17x59
+    [0, 624, 0, 304],  // R17x77
+    [0, 800, 0, 448],  // R17x99
+    [0, 1216, 0, 608], // R17x139
 ];
 
 impl Bits {
@@ -724,24 +1139,43 @@ impl Bits {
     ///
     /// # Errors
     ///
-    /// Returns `Err(QrError::DataTooLong)` on overflow.
+    /// Returns `Err(QrError::DataTooLong)` on overflow, with the data segment
+    /// crossing the capacity of the error correction level if any.
     ///
     /// Returns `Err(QrError::InvalidVersion)` if it is not valid to use the
     /// `ec_level` for the given version (e.g. `Version::Micro(1)` with
     /// `EcLevel::H`).
     pub fn push_terminator(&mut self, ec_level: EcLevel) -> QrResult<()> {
-        let terminator_size = if let Version::Micro(a) = self.version { a.as_usize() * 2 + 1 } else { 4 };
+        let terminator_size = match self.version {
+            Version::Micro(a) => a.as_usize() * 2 + 1,
+            Version::RectMicro(..) => 3,
+            Version::Normal(_) => 4,
+        };
 
         let cur_length = self.len();
         let data_length = self.max_len(ec_level)?;
         if cur_length > data_length {
-            return Err(QrError::DataTooLong);
+            let mut end = 0;
+            let segment = self.segments.iter().find_map(|s| {
+                end += s.bits_count;
+                match s.kind {
+                    SegmentKind::Data(segment) if end > data_length => Some(segment),
+                    _ => None,
+                }
+            });
+            return Err(QrError::DataTooLong(Overflow {
+                bits_needed: cur_length,
+                bits_available: data_length,
+                segment,
+            }));
         }
 
         let terminator_size = min(terminator_size, data_length - cur_length);
         if terminator_size > 0 {
             self.push_number(terminator_size, 0);
         }
+        self.terminator_bits_count = terminator_size;
+        self.padding_bits_count = data_length - self.len();
 
         if self.len() < data_length {
             const PADDING_BYTES: &[u8] = &[0b1110_1100, 0b0001_0001];
@@ -764,7 +1198,8 @@ impl Bits {
 #[cfg(test)]
 mod finish_tests {
     use crate::bits::Bits;
-    use crate::types::{EcLevel, QrError, Version};
+    use crate::optimize::Segment;
+    use crate::types::{EcLevel, Mode, Overflow, QrError, Version};
     use alloc::vec;
 
     #[test]
@@ -772,6 +1207,7 @@ mod finish_tests {
         let mut bits = Bits::new(Version::Normal(1));
         assert_eq!(bits.push_alphanumeric_data(b"HELLO WORLD"), Ok(()));
         assert_eq!(bits.push_terminator(EcLevel::Q), Ok(()));
+        assert_eq!((bits.terminator_bits_count(), bits.padding_bits_count()), (4, 26));
         assert_eq!(
             bits.into_bytes(),
             vec![
@@ -785,7 +1221,14 @@ mod finish_tests {
     fn test_too_long() {
         let mut bits = Bits::new(Version::Micro(1));
         assert_eq!(bits.push_numeric_data(b"9999999"), Ok(()));
-        assert_eq!(bits.push_terminator(EcLevel::L), Err(QrError::DataTooLong));
+        assert_eq!(
+            bits.push_terminator(EcLevel::L),
+            Err(QrError::DataTooLong(Overflow {
+                bits_needed: 27,
+                bits_available: 20,
+                segment: Some(Segment { mode: Mode::Numeric, begin: 0, end: 7 })
+            }))
+        );
     }
 
     #[test]
@@ -793,6 +1236,7 @@ mod finish_tests {
         let mut bits = Bits::new(Version::Micro(1));
         assert_eq!(bits.push_numeric_data(b"99999"), Ok(()));
         assert_eq!(bits.push_terminator(EcLevel::L), Ok(()));
+        assert_eq!((bits.terminator_bits_count(), bits.padding_bits_count()), (0, 0));
         assert_eq!(bits.into_bytes(), vec![0b101_11111, 0b00111_110, 0b0011__0000]);
     }
 
@@ -809,6 +1253,7 @@ mod finish_tests {
         let mut bits = Bits::new(Version::Micro(1));
         assert_eq!(bits.push_numeric_data(b"999"), Ok(()));
         assert_eq!(bits.push_terminator(EcLevel::L), Ok(()));
+        assert_eq!((bits.terminator_bits_count(), bits.padding_bits_count()), (3, 4));
         assert_eq!(bits.into_bytes(), vec![0b011_11111, 0b00111_000, 0b0000__0000]);
     }
 
@@ -832,8 +1277,8 @@ impl Bits {
     ///
     /// Returns `Err(QrError::DataTooLong)` on overflow.
     ///
-    /// Returns `Err(QrError::InvalidData)` if the segment refers to incorrectly
-    /// encoded byte sequence.
+    /// Returns `Err(QrError::InvalidCharacter)` if the segment refers to
+    /// incorrectly encoded byte sequence.
     pub fn push_segments<I>(&mut self, data: &[u8], segments_iter: I) -> QrResult<()>
     where
         I: Iterator<Item = Segment>,
@@ -845,6 +1290,7 @@ impl Bits {
                 Mode::Alphanumeric => self.push_alphanumeric_data(slice),
                 Mode::Byte => self.push_byte_data(slice),
                 Mode::Kanji => self.push_kanji_data(slice),
+                Mode::Hanzi => self.push_hanzi_data(slice),
             }?;
         }
         Ok(())
@@ -856,15 +1302,41 @@ impl Bits {
     ///
     /// Returns `Err(QrError::DataTooLong)` on overflow.
     pub fn push_optimal_data(&mut self, data: &[u8]) -> QrResult<()> {
-        let segments = Parser::new(data).optimize(self.version);
-        self.push_segments(data, segments)
+        let segments = Parser::new(data).collect::<Vec<Segment>>();
+        self.push_segments(data, optimize_segments(&segments, self.version).into_iter())
+    }
+
+    /// Pushes the text to the bits, using the optimal encoding. Characters in
+    /// JIS X 0208 are encoded in the Kanji mode, and the rest in UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::DataTooLong)` on overflow.
+    pub fn push_optimal_str(&mut self, data: &str) -> QrResult<()> {
+        let (bytes, segments) = parse_str(data);
+        self.push_segments(&bytes, optimize_str_segments(&segments, self.version).into_iter())
+    }
+
+    /// Pushes the text to the bits, using the optimal encoding. Characters in
+    /// GB 2312 are encoded in the Hanzi mode, and the rest in UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::DataTooLong)` on overflow.
+    ///
+    /// Returns `Err(QrError::UnsupportedCharacterSet)` if the text contains
+    /// GB 2312 characters and the version is not a normal QR code version.
+    pub fn push_optimal_hanzi_str(&mut self, data: &str) -> QrResult<()> {
+        let (bytes, segments) = parse_hanzi_str(data);
+        self.push_segments(&bytes, optimize_str_segments(&segments, self.version).into_iter())
     }
 }
 
 #[cfg(test)]
 mod encode_tests {
-    use crate::bits::Bits;
-    use crate::types::{EcLevel, QrError, QrResult, Version};
+    use crate::bits::{Bits, EncodedSegment, SegmentKind};
+    use crate::optimize::Segment;
+    use crate::types::{EcLevel, Mode, Overflow, QrError, QrResult, Version};
     use alloc::vec;
     use alloc::vec::Vec;
 
@@ -896,7 +1368,44 @@ mod encode_tests {
     #[test]
     fn test_too_long() {
         let res = encode(b">>>>>>>>", Version::Normal(1), EcLevel::H);
-        assert_eq!(res, Err(QrError::DataTooLong));
+        assert_eq!(
+            res,
+            Err(QrError::DataTooLong(Overflow {
+                bits_needed: 76,
+                bits_available: 72,
+                segment: Some(Segment { mode: Mode::Byte, begin: 0, end: 8 })
+            }))
+        );
+    }
+
+    #[test]
+    fn test_str() {
+        let mut bits = Bits::new(Version::Normal(1));
+        assert_eq!(bits.push_optimal_str("\u{70b9}\u{8317}"), Ok(()));
+        assert_eq!(bits.into_bytes(), vec![0b1000_0000, 0b0010_0110, 0b1100_1111, 0b1110_1010, 0b1010_1000]);
+    }
+
+    #[test]
+    fn test_segments() {
+        let mut bits = Bits::new(Version::Normal(1));
+        assert_eq!(bits.push_fnc1_second_position(37), Ok(()));
+        assert_eq!(bits.push_alphanumeric_data(b"AA1234BBB112"), Ok(()));
+        assert_eq!(bits.push_byte_data(b"text\r"), Ok(()));
+        assert_eq!(
+            bits.segments(),
+            &[
+                EncodedSegment { kind: SegmentKind::Fnc1Second(37), bits_count: 12 },
+                EncodedSegment {
+                    kind: SegmentKind::Data(Segment { mode: Mode::Alphanumeric, begin: 0, end: 12 }),
+                    bits_count: 79
+                },
+                EncodedSegment {
+                    kind: SegmentKind::Data(Segment { mode: Mode::Byte, begin: 12, end: 17 }),
+                    bits_count: 52
+                },
+            ]
+        );
+        assert_eq!(bits.segments().iter().map(|s| s.bits_count).sum::<usize>(), bits.len());
     }
 }
 
@@ -913,23 +1422,187 @@ mod encode_tests {
 ///
 /// Returns `Err(QrError::DataTooLong)` if the data is too long to fit even the
 /// highest QR code version.
-#[allow(clippy::missing_panics_doc)] // the panic caused by the expect() will never actually happen since the `version`s are known good constants.
 pub fn encode_auto(data: &[u8], ec_level: EcLevel) -> QrResult<Bits> {
-    let segments = Parser::new(data).collect::<Vec<Segment>>();
+    encode_auto_with_eci(data, ec_level, None)
+}
+
+/// Automatically determines the minimum version to store the data with an
+/// optional ECI designator in front, and encode the result.
+///
+/// The size of the ECI designator is taken into account when choosing the
+/// version. This method will not consider any Micro QR code versions.
+///
+/// Since the Kanji mode always holds Shift JIS characters, it is only used
+/// without a designator or with the Shift JIS designator (20). Otherwise, e.g.
+/// for UTF-8 text, byte pairs looking like Shift JIS are kept in the byte mode.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::DataTooLong)` if the data is too long to fit even the
+/// highest QR code version.
+///
+/// Returns `Err(QrError::InvalidEciDesignator)` if the ECI designator is
+/// invalid.
+pub fn encode_auto_with_eci(data: &[u8], ec_level: EcLevel, eci_designator: Option<u32>) -> QrResult<Bits> {
+    let segments = parse_with_eci(data, eci_designator);
+    encode_auto_segments(data, &segments, optimize_segments, ec_level, eci_designator)
+}
+
+/// Automatically determines the minimum version to store the text, and encode
+/// the result. Characters in JIS X 0208 are encoded in the Kanji mode, and the
+/// rest in UTF-8.
+///
+/// The UTF-8 ECI designator (26) is added in front if any non-ASCII character
+/// is encoded in UTF-8. This method will not consider any Micro QR code
+/// versions.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::DataTooLong)` if the data is too long to fit even the
+/// highest QR code version.
+pub fn encode_auto_str(data: &str, ec_level: EcLevel) -> QrResult<Bits> {
+    let needs_eci = data.chars().any(|c| !c.is_ascii() && to_shift_jis(c).is_none());
+    let (bytes, segments) = parse_str(data);
+    encode_auto_segments(&bytes, &segments, optimize_str_segments, ec_level, needs_eci.then_some(26))
+}
+
+/// Automatically determines the minimum version to store the text, and encode
+/// the result. Characters in GB 2312 are encoded in the Hanzi mode, and the
+/// rest in UTF-8.
+///
+/// The UTF-8 ECI designator (26) is added in front if any non-ASCII character
+/// is encoded in UTF-8. This method will not consider any Micro QR code
+/// versions.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::DataTooLong)` if the data is too long to fit even the
+/// highest QR code version.
+pub fn encode_auto_hanzi_str(data: &str, ec_level: EcLevel) -> QrResult<Bits> {
+    let needs_eci = data.chars().any(|c| !c.is_ascii() && to_gb2312(c).is_none());
+    let (bytes, segments) = parse_hanzi_str(data);
+    encode_auto_segments(&bytes, &segments, optimize_str_segments, ec_level, needs_eci.then_some(26))
+}
+
+/// Finds the minimum version to store the segments optimized by `optimize`,
+/// and encode the result.
+fn encode_auto_segments(
+    data: &[u8],
+    segments: &[Segment],
+    optimize: fn(&[Segment], Version) -> Vec<Segment>,
+    ec_level: EcLevel,
+    eci_designator: Option<u32>,
+) -> QrResult<Bits> {
+    let header_len = match eci_designator {
+        None => 0,
+        Some(0..=127) => 12,
+        Some(128..=16383) => 20,
+        Some(16384..=999_999) => 28,
+        Some(_) => return Err(QrError::InvalidEciDesignator),
+    };
+    let (min_version, opt_segments, total_len) =
+        find_min_version_for_segments(segments, optimize, ec_level, header_len)?;
+    let mut bits = Bits::new(min_version);
+    bits.reserve(total_len);
+    if let Some(eci_designator) = eci_designator {
+        bits.push_eci_designator(eci_designator)?;
+    }
+    bits.push_segments(data, opt_segments.into_iter())?;
+    bits.push_terminator(ec_level)?;
+    Ok(bits)
+}
+
+/// Finds the minimum version (QR code only) to store the segments optimized
+/// by `optimize` after `header_len` bits of headers. Returns the version, the
+/// optimized segments and the total number of bits.
+#[allow(clippy::missing_panics_doc)] // the panic caused by the expect() will never actually happen since the `version`s are known good constants.
+fn find_min_version_for_segments(
+    segments: &[Segment],
+    optimize: fn(&[Segment], Version) -> Vec<Segment>,
+    ec_level: EcLevel,
+    header_len: usize,
+) -> QrResult<(Version, Vec<Segment>, usize)> {
+    let mut opt_segments = Vec::new();
+    let mut data_capacity = 0;
     for version in &[Version::Normal(9), Version::Normal(26), Version::Normal(40)] {
-        let opt_segments = Optimizer::new(segments.iter().copied(), *version).collect::<Vec<_>>();
-        let total_len = total_encoded_len(&opt_segments, *version);
-        let data_capacity = version.fetch(ec_level, &DATA_LENGTHS).expect("invalid DATA_LENGTHS");
+        opt_segments = optimize(segments, *version);
+        let total_len = header_len + total_encoded_len(&opt_segments, *version);
+        data_capacity = version.fetch(ec_level, &DATA_LENGTHS).expect("invalid DATA_LENGTHS");
         if total_len <= data_capacity {
-            let min_version = find_min_version(total_len, ec_level);
-            let mut bits = Bits::new(min_version);
-            bits.reserve(total_len);
-            bits.push_segments(data, opt_segments.into_iter())?;
-            bits.push_terminator(ec_level)?;
+            return Ok((find_min_version(total_len, ec_level), opt_segments, total_len));
+        }
+    }
+    Err(overflow_error(&opt_segments, Version::Normal(40), header_len, data_capacity))
+}
+
+/// Constructs the `QrError::DataTooLong` error for the segments encoded after
+/// `header_len` bits of headers, which do not fit in `bits_available` bits.
+fn overflow_error(segments: &[Segment], version: Version, header_len: usize, bits_available: usize) -> QrError {
+    let mut bits_needed = header_len;
+    let mut segment = None;
+    for s in segments {
+        bits_needed += s.encoded_len(version);
+        if segment.is_none() && bits_needed > bits_available {
+            segment = Some(*s);
+        }
+    }
+    QrError::DataTooLong(Overflow { bits_needed, bits_available, segment })
+}
+
+/// Automatically determines the minimum version to store the data, and encode
+/// the result, with the UTF-8 ECI designator (26) in front if needed.
+///
+/// The designator is only added when the data is valid UTF-8 containing
+/// non-ASCII characters, so that readers defaulting to ISO-8859-1 will not
+/// misinterpret the data. ASCII data is encoded the same as `encode_auto()`.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::DataTooLong)` if the data is too long to fit even the
+/// highest QR code version.
+pub fn encode_auto_utf8(data: &[u8], ec_level: EcLevel) -> QrResult<Bits> {
+    encode_auto_with_eci(data, ec_level, needs_utf8_eci(data).then_some(26))
+}
+
+/// Classifies the data into segments, allowing the Kanji mode only when the
+/// data is declared as Shift JIS or not declared at all.
+pub(crate) fn parse_with_eci(data: &[u8], eci_designator: Option<u32>) -> Vec<Segment> {
+    match eci_designator {
+        None | Some(20) => Parser::new(data).collect(),
+        Some(_) => parse_non_kanji(data),
+    }
+}
+
+/// Checks whether the data is UTF-8 text containing non-ASCII characters,
+/// which should be introduced by the UTF-8 ECI designator.
+pub(crate) fn needs_utf8_eci(data: &[u8]) -> bool {
+    !data.is_ascii() && core::str::from_utf8(data).is_ok()
+}
+
+/// Automatically determines the minimum version to store the data, and encode
+/// the result, considering Micro QR code versions first.
+///
+/// The Micro QR code versions M1 to M4 are tried in order, skipping those not
+/// supporting the error correction level (e.g. M1 only supports level L, and
+/// no Micro QR code supports level H). If the data does not fit into any of
+/// them, this falls back to `encode_auto()`.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::DataTooLong)` if the data is too long to fit even the
+/// highest QR code version.
+pub fn encode_auto_micro(data: &[u8], ec_level: EcLevel) -> QrResult<Bits> {
+    for a in 1..=4 {
+        let mut bits = Bits::new(Version::Micro(a));
+        if bits.max_len(ec_level).is_err() {
+            continue;
+        }
+        let encoded = bits.push_optimal_data(data).and_then(|()| bits.push_terminator(ec_level));
+        if encoded.is_ok() {
             return Ok(bits);
         }
     }
-    Err(QrError::DataTooLong)
+    encode_auto(data, ec_level)
 }
 
 /// Finds the smallest version (QR code only) that can store N bits of data
@@ -953,8 +1626,12 @@ fn find_min_version(length: usize, ec_level: EcLevel) -> Version {
 
 #[cfg(test)]
 mod encode_auto_tests {
-    use crate::bits::{encode_auto, find_min_version};
-    use crate::types::{EcLevel, Version};
+    use crate::bits::{
+        encode_auto, encode_auto_hanzi_str, encode_auto_micro, encode_auto_str, encode_auto_utf8, encode_auto_with_eci,
+        find_min_version, SegmentKind,
+    };
+    use crate::optimize::Segment;
+    use crate::types::{EcLevel, Mode, Overflow, QrError, Version};
 
     #[test]
     fn test_find_min_version() {
@@ -984,6 +1661,177 @@ mod encode_auto_tests {
         let bits = encode_auto(b"This is a mixed data test. 1234567890", EcLevel::H).unwrap();
         assert_eq!(bits.version(), Version::Normal(4));
     }
+
+    #[test]
+    fn test_utf8() {
+        let bits = encode_auto_utf8(b"HELLO WORLD", EcLevel::Q).unwrap();
+        assert_eq!(bits.into_bytes(), encode_auto(b"HELLO WORLD", EcLevel::Q).unwrap().into_bytes());
+
+        // 17 bytes fit in version 1-L only without the ECI designator.
+        let data = "\u{3b1}\u{3b2}\u{3b3}\u{3b4}\u{3b5}\u{3b6}\u{3b7}\u{3b8}!".as_bytes();
+        assert_eq!(encode_auto(data, EcLevel::L).unwrap().version(), Version::Normal(1));
+        let bits = encode_auto_utf8(data, EcLevel::L).unwrap();
+        assert_eq!(bits.version(), Version::Normal(2));
+        assert_eq!(&bits.into_bytes()[..2], &[0b0111_0001, 0b1010_0100]);
+
+        // Not UTF-8, e.g. Shift JIS.
+        let bits = encode_auto_utf8(b"\x93\x5f\xe4\xaa", EcLevel::L).unwrap();
+        assert_eq!(bits.into_bytes()[0] >> 4, 0b1000);
+
+        // "あいう" in UTF-8 looks like Shift JIS, but must stay in bytes.
+        let bits = encode_auto_utf8("\u{3042}\u{3044}\u{3046}".as_bytes(), EcLevel::L).unwrap();
+        assert_eq!(
+            bits.segments().iter().map(|s| s.kind).collect::<Vec<_>>(),
+            [SegmentKind::Eci(26), SegmentKind::Data(Segment { mode: Mode::Byte, begin: 0, end: 9 })]
+        );
+        let bits = encode_auto_with_eci(b"\x93\x5f", EcLevel::L, Some(20)).unwrap();
+        assert_eq!(bits.segments()[1].kind, SegmentKind::Data(Segment { mode: Mode::Kanji, begin: 0, end: 2 }));
+    }
+
+    #[test]
+    fn test_str() {
+        // "QRコードは1994年に開発された" is shorter in Kanji mode than in UTF-8.
+        let data = "QR\u{30b3}\u{30fc}\u{30c9}\u{306f}1994\u{5e74}\u{306b}\u{958b}\u{767a}\u{3055}\u{308c}\u{305f}";
+        assert_eq!(encode_auto_utf8(data.as_bytes(), EcLevel::M).unwrap().version(), Version::Normal(3));
+        let bits = encode_auto_str(data, EcLevel::M).unwrap();
+        assert_eq!(bits.version(), Version::Normal(2));
+        assert_eq!(bits.into_bytes()[0] >> 4, 0b0010);
+
+        // Characters outside JIS X 0208 are encoded in UTF-8 with ECI.
+        let bits = encode_auto_str("\u{30b3}\u{e9}", EcLevel::M).unwrap();
+        assert_eq!(&bits.into_bytes()[..2], &[0b0111_0001, 0b1010_1000]);
+    }
+
+    #[test]
+    fn test_hanzi_str() {
+        // "二维码生成器，版本2024" is shorter in Hanzi mode than in UTF-8.
+        let data = "\u{4e8c}\u{7ef4}\u{7801}\u{751f}\u{6210}\u{5668}\u{ff0c}\u{7248}\u{672c}2024";
+        assert_eq!(encode_auto_utf8(data.as_bytes(), EcLevel::M).unwrap().version(), Version::Normal(3));
+        let bits = encode_auto_hanzi_str(data, EcLevel::M).unwrap();
+        assert_eq!(bits.version(), Version::Normal(2));
+        assert_eq!(bits.into_bytes()[0], 0b1101_0001);
+    }
+
+    #[test]
+    fn test_micro() {
+        assert_eq!(encode_auto_micro(b"12345", EcLevel::L).unwrap().version(), Version::Micro(1));
+        assert_eq!(encode_auto_micro(b"12345", EcLevel::M).unwrap().version(), Version::Micro(2));
+        assert_eq!(encode_auto_micro(b"AB-123", EcLevel::L).unwrap().version(), Version::Micro(2));
+        assert_eq!(encode_auto_micro(b"serial", EcLevel::L).unwrap().version(), Version::Micro(3));
+        assert_eq!(encode_auto_micro(b"SN-2024-0001", EcLevel::Q).unwrap().version(), Version::Micro(4));
+    }
+
+    #[test]
+    fn test_too_long() {
+        let overflow = Overflow {
+            bits_needed: 19220,
+            bits_available: 10208,
+            segment: Some(Segment { mode: Mode::Byte, begin: 0, end: 2400 }),
+        };
+        assert_eq!(encode_auto(&[b'a'; 2400], EcLevel::H).err(), Some(QrError::DataTooLong(overflow)));
+        assert_eq!(overflow.excess_bits(), 9012);
+        assert_eq!(overflow.excess_chars(), Some(1127));
+
+        let overflow = Overflow { segment: None, ..overflow };
+        assert_eq!(overflow.excess_chars(), None);
+    }
+
+    #[test]
+    fn test_micro_fallback() {
+        assert_eq!(encode_auto_micro(b"12345", EcLevel::H).unwrap().version(), Version::Normal(1));
+        let bits = encode_auto_micro(b"This is a mixed data test. 1234567890", EcLevel::H).unwrap();
+        assert_eq!(bits.version(), Version::Normal(4));
+    }
+}
+
+/// Splits the data into a structured append sequence, and encodes each part
+/// with the smallest QR code version not exceeding `max_version`.
+///
+/// Every symbol except the last one is filled to the capacity of
+/// `max_version`. The sequence always contains at least one symbol.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::DataTooLong)` if the data cannot fit into 16 symbols
+/// of `max_version`.
+///
+/// Returns `Err(QrError::InvalidVersion)` if `max_version` is not a normal QR
+/// code version, since Micro QR code does not support structured append.
+#[allow(clippy::missing_panics_doc)] // the expect() never fails since the number of chunks is limited to 16 above.
+pub fn encode_structured_append(data: &[u8], ec_level: EcLevel, max_version: Version) -> QrResult<Vec<Bits>> {
+    let Version::Normal(max_version_number) = max_version else {
+        return Err(QrError::InvalidVersion);
+    };
+    let parity = data.iter().fold(0, |a, b| a ^ b);
+
+    // The header has a fixed size, so the index and total used for measuring
+    // the capacity do not matter.
+    let fits = |chunk: &[u8]| encode_structured_append_part(chunk, max_version, ec_level, 0, 1, 0).is_ok();
+
+    let mut chunks = Vec::new();
+    let mut rest = data;
+    loop {
+        // Binary search for the longest prefix which still fits.
+        let (mut low, mut high) = (0, rest.len());
+        while low < high {
+            let mid = (low + high + 1) / 2;
+            if fits(&rest[..mid]) {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        if low == 0 && !rest.is_empty() {
+            // Not even a single byte fits, so report why.
+            encode_structured_append_part(&rest[..1], max_version, ec_level, 0, 1, 0)?;
+        }
+        chunks.push(&rest[..low]);
+        rest = &rest[low..];
+        if rest.is_empty() {
+            break;
+        }
+        if chunks.len() == 16 {
+            // Report the rest as the excess over 16 full symbols.
+            let bits_available = 16 * max_version.fetch(ec_level, &DATA_LENGTHS)?;
+            let segments = Parser::new(rest).collect::<Vec<Segment>>();
+            let rest_len = 20 + total_encoded_len(&optimize_segments(&segments, max_version), max_version);
+            return Err(QrError::DataTooLong(Overflow {
+                bits_needed: bits_available + rest_len,
+                bits_available,
+                segment: None,
+            }));
+        }
+    }
+
+    let total = u8::try_from(chunks.len()).expect("at most 16 symbols");
+    chunks
+        .iter()
+        .zip(0..)
+        .map(|(chunk, index)| {
+            let encode_part =
+                |v| encode_structured_append_part(chunk, Version::Normal(v), ec_level, index, total, parity);
+            (1..max_version_number)
+                .map(encode_part)
+                .find(Result::is_ok)
+                .unwrap_or_else(|| encode_part(max_version_number))
+        })
+        .collect()
+}
+
+/// Encodes one symbol of a structured append sequence.
+fn encode_structured_append_part(
+    data: &[u8],
+    version: Version,
+    ec_level: EcLevel,
+    index: u8,
+    total: u8,
+    parity: u8,
+) -> QrResult<Bits> {
+    let mut bits = Bits::new(version);
+    bits.push_structured_append_header(index, total, parity)?;
+    bits.push_optimal_data(data)?;
+    bits.push_terminator(ec_level)?;
+    Ok(bits)
 }
 
 #[cfg(feature = "bench")]
@@ -1004,3 +1852,278 @@ fn bench_find_min_version(bencher: &mut test::Bencher) {
 
 //}}}
 //------------------------------------------------------------------------------
+//{{{ Capacity
+
+/// The space used and left by some data in a QR code version and error
+/// correction level.
+///
+/// This can be used to tell how many more characters can be entered before
+/// the data needs a larger symbol:
+///
+/// ```
+/// use qrcode::bits::Capacity;
+/// use qrcode::types::{EcLevel, Mode, Version};
+///
+/// let capacity = Capacity::with_min_version(b"HELLO WORLD", EcLevel::M).unwrap();
+/// assert_eq!(capacity.version, Version::Normal(1));
+/// assert_eq!((capacity.bits_used, capacity.bits_available), (74, 128));
+/// assert_eq!(capacity.remaining_chars(Mode::Alphanumeric), 7);
+/// ```
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Capacity {
+    /// The QR code version.
+    pub version: Version,
+
+    /// The error correction level.
+    pub ec_level: EcLevel,
+
+    /// The number of bits needed to encode the data optimally, excluding the
+    /// terminator and padding.
+    pub bits_used: usize,
+
+    /// The number of data bits the version and error correction level can
+    /// hold.
+    pub bits_available: usize,
+}
+
+impl Capacity {
+    /// Computes the capacity used by the data in the given version and error
+    /// correction level, using the same encoding as `Bits::push_optimal_data()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::DataTooLong)` if the data does not fit.
+    ///
+    /// Returns `Err(QrError::InvalidVersion)` if it is not valid to use the
+    /// `ec_level` for the given version.
+    ///
+    /// Returns `Err(QrError::UnsupportedCharacterSet)` if the data needs a
+    /// mode not supported by the version, e.g. bytes in `Version::Micro(2)`.
+    pub fn with_version(data: &[u8], version: Version, ec_level: EcLevel) -> QrResult<Self> {
+        let bits_available = version.fetch(ec_level, &DATA_LENGTHS)?;
+        let segments = Parser::new(data).collect::<Vec<Segment>>();
+        let opt_segments = optimize_segments(&segments, version);
+        if opt_segments.iter().any(|segment| !supports_mode(version, segment.mode)) {
+            return Err(QrError::UnsupportedCharacterSet);
+        }
+        let bits_used = total_encoded_len(&opt_segments, version);
+        if bits_used > bits_available {
+            return Err(overflow_error(&opt_segments, version, 0, bits_available));
+        }
+        Ok(Self { version, ec_level, bits_used, bits_available })
+    }
+
+    /// Computes the capacity used by the data in the smallest QR code version
+    /// which can store it, i.e. the version chosen by `encode_auto()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::DataTooLong)` if the data is too long to fit even
+    /// the highest QR code version.
+    pub fn with_min_version(data: &[u8], ec_level: EcLevel) -> QrResult<Self> {
+        let segments = Parser::new(data).collect::<Vec<Segment>>();
+        let (version, _, bits_used) = find_min_version_for_segments(&segments, optimize_segments, ec_level, 0)?;
+        let bits_available = version.fetch(ec_level, &DATA_LENGTHS)?;
+        Ok(Self { version, ec_level, bits_used, bits_available })
+    }
+
+    /// The number of data bits left unused.
+    pub const fn bits_remaining(&self) -> usize {
+        self.bits_available - self.bits_used
+    }
+
+    /// Computes how many more characters of the given mode can be appended to
+    /// the data without exceeding the capacity.
+    ///
+    /// The characters are assumed to be encoded in a new segment, so this is a
+    /// lower bound when the data already ends with characters of the same
+    /// mode. For the Kanji and Hanzi modes, this counts double-byte
+    /// characters. Returns 0 if the mode is not supported by the version.
+    ///
+    /// ```
+    /// use qrcode::bits::Capacity;
+    /// use qrcode::types::{EcLevel, Mode, Version};
+    ///
+    /// let capacity = Capacity::with_version(b"", Version::Normal(1), EcLevel::M).unwrap();
+    /// assert_eq!(capacity.remaining_chars(Mode::Numeric), 34);
+    /// assert_eq!(capacity.remaining_chars(Mode::Byte), 14);
+    /// ```
+    pub fn remaining_chars(&self, mode: Mode) -> usize {
+        if !supports_mode(self.version, mode) {
+            return 0;
+        }
+        let length_bits_count = mode.length_bits_count(self.version);
+        let subset_bits_count = if mode == Mode::Hanzi { 4 } else { 0 };
+        let header_bits_count = self.version.mode_bits_count() + subset_bits_count + length_bits_count;
+        let Some(room) = self.bits_remaining().checked_sub(header_bits_count) else {
+            return 0;
+        };
+
+        // Start from an overestimate and step back, since the number of bits
+        // of the Numeric and Alphanumeric modes is rounded.
+        let mut count = match mode {
+            Mode::Numeric => room * 3 / 10,
+            Mode::Alphanumeric => room * 2 / 11,
+            Mode::Byte => room / 8,
+            Mode::Kanji | Mode::Hanzi => room / 13,
+        } + 1;
+        while mode.data_bits_count(count) > room {
+            count -= 1;
+        }
+        min(count, (1 << length_bits_count) - 1)
+    }
+}
+
+/// Checks whether the data mode can be used in the version.
+fn supports_mode(version: Version, mode: Mode) -> bool {
+    match version {
+        Version::Micro(1) => mode == Mode::Numeric,
+        Version::Micro(2) => matches!(mode, Mode::Numeric | Mode::Alphanumeric),
+        Version::Micro(_) | Version::RectMicro(..) => mode != Mode::Hanzi,
+        Version::Normal(_) => true,
+    }
+}
+
+/// Computes the capacity used by the data in the smallest QR code version for
+/// each error correction level, in the order L, M, Q, H.
+///
+/// The levels which the data is too long for are omitted.
+///
+/// ```
+/// use qrcode::bits::min_versions;
+/// use qrcode::types::{EcLevel, Version};
+///
+/// let versions = min_versions(b"HELLO WORLD").into_iter().map(|c| (c.ec_level, c.version)).collect::<Vec<_>>();
+/// assert_eq!(
+///     versions,
+///     [
+///         (EcLevel::L, Version::Normal(1)),
+///         (EcLevel::M, Version::Normal(1)),
+///         (EcLevel::Q, Version::Normal(1)),
+///         (EcLevel::H, Version::Normal(2)),
+///     ]
+/// );
+/// ```
+pub fn min_versions(data: &[u8]) -> Vec<Capacity> {
+    [EcLevel::L, EcLevel::M, EcLevel::Q, EcLevel::H]
+        .into_iter()
+        .filter_map(|ec_level| Capacity::with_min_version(data, ec_level).ok())
+        .collect()
+}
+
+/// Finds the highest error correction level at which the data fits in the
+/// given version.
+///
+/// ```
+/// use qrcode::bits::max_ec_level;
+/// use qrcode::types::{EcLevel, Version};
+///
+/// assert_eq!(max_ec_level(b"HELLO WORLD", Version::Normal(1)), Ok(EcLevel::Q));
+/// ```
+///
+/// # Errors
+///
+/// Returns `Err(QrError::DataTooLong)` if the data does not fit even at the
+/// lowest level, or other errors of `Capacity::with_version()`.
+pub fn max_ec_level(data: &[u8], version: Version) -> QrResult<EcLevel> {
+    let mut error = QrError::InvalidVersion;
+    for ec_level in [EcLevel::H, EcLevel::Q, EcLevel::M, EcLevel::L] {
+        match Capacity::with_version(data, version, ec_level) {
+            Ok(_) => return Ok(ec_level),
+            // Prefer reporting why the data does not fit over the levels not
+            // supported by Micro QR code.
+            Err(QrError::InvalidVersion) => {}
+            Err(e) => error = e,
+        }
+    }
+    Err(error)
+}
+
+#[cfg(test)]
+mod capacity_tests {
+    use crate::bits::{encode_auto, max_ec_level, min_versions, Capacity};
+    use crate::optimize::Segment;
+    use crate::types::{EcLevel, Mode, Overflow, QrError, Version};
+
+    #[test]
+    fn test_with_version() {
+        let capacity = Capacity::with_version(b"12345", Version::Micro(1), EcLevel::L).unwrap();
+        assert_eq!((capacity.bits_used, capacity.bits_available), (20, 20));
+        assert_eq!(capacity.remaining_chars(Mode::Numeric), 0);
+
+        assert_eq!(
+            Capacity::with_version(b"123456", Version::Micro(1), EcLevel::L),
+            Err(QrError::DataTooLong(Overflow {
+                bits_needed: 23,
+                bits_available: 20,
+                segment: Some(Segment { mode: Mode::Numeric, begin: 0, end: 6 })
+            }))
+        );
+        assert_eq!(Capacity::with_version(b"1", Version::Micro(1), EcLevel::M), Err(QrError::InvalidVersion));
+        assert_eq!(
+            Capacity::with_version(b"abc", Version::Micro(2), EcLevel::L),
+            Err(QrError::UnsupportedCharacterSet)
+        );
+    }
+
+    #[test]
+    fn test_with_min_version() {
+        let data = b"This is a mixed data test. 1234567890";
+        let capacity = Capacity::with_min_version(data, EcLevel::H).unwrap();
+        let bits = encode_auto(data, EcLevel::H).unwrap();
+        assert_eq!(capacity.version, bits.version());
+        assert_eq!(capacity.bits_available, bits.max_len(EcLevel::H).unwrap());
+        assert!(capacity.bits_used <= bits.len());
+
+        assert_eq!(
+            Capacity::with_min_version(&[b'a'; 3000], EcLevel::L),
+            Err(QrError::DataTooLong(Overflow {
+                bits_needed: 24020,
+                bits_available: 23648,
+                segment: Some(Segment { mode: Mode::Byte, begin: 0, end: 3000 })
+            }))
+        );
+    }
+
+    #[test]
+    fn test_remaining_chars() {
+        // Table 7 of ISO/IEC 18004:2015, version 40-L.
+        let capacity = Capacity::with_version(b"", Version::Normal(40), EcLevel::L).unwrap();
+        assert_eq!(capacity.remaining_chars(Mode::Numeric), 7089);
+        assert_eq!(capacity.remaining_chars(Mode::Alphanumeric), 4296);
+        assert_eq!(capacity.remaining_chars(Mode::Byte), 2953);
+        assert_eq!(capacity.remaining_chars(Mode::Kanji), 1817);
+
+        // Limited by the character count indicator of M1.
+        let capacity = Capacity::with_version(b"", Version::Micro(1), EcLevel::L).unwrap();
+        assert_eq!(capacity.remaining_chars(Mode::Numeric), 5);
+        assert_eq!(capacity.remaining_chars(Mode::Alphanumeric), 0);
+
+        let capacity = Capacity::with_version(b"", Version::Micro(3), EcLevel::M).unwrap();
+        assert_eq!(capacity.remaining_chars(Mode::Byte), 7);
+        assert_eq!(capacity.remaining_chars(Mode::Hanzi), 0);
+    }
+
+    #[test]
+    fn test_max_ec_level() {
+        assert_eq!(max_ec_level(b"HELLO WORLD", Version::Normal(2)), Ok(EcLevel::H));
+        assert_eq!(max_ec_level(b"12345", Version::Micro(2)), Ok(EcLevel::M));
+        assert_eq!(max_ec_level(b"HELLO WORLD", Version::Micro(1)), Err(QrError::UnsupportedCharacterSet));
+        assert_eq!(
+            max_ec_level(&[b'a'; 20], Version::Normal(1)),
+            Err(QrError::DataTooLong(Overflow {
+                bits_needed: 172,
+                bits_available: 152,
+                segment: Some(Segment { mode: Mode::Byte, begin: 0, end: 20 })
+            }))
+        );
+    }
+
+    #[test]
+    fn test_min_versions() {
+        assert_eq!(min_versions(&[b'a'; 2000]).len(), 2);
+        assert!(min_versions(&[b'a'; 3000]).is_empty());
+    }
+}
+
+//}}}
@@ -0,0 +1,306 @@
+//! The `builder` module provides a configurable way to construct a QR code.
+//!
+//! ```
+//! use qrcode::{EcLevel, QrCodeBuilder, Version};
+//!
+//! let code = QrCodeBuilder::new()
+//!     .ec_level(EcLevel::L)
+//!     .boost_ec_level(true)
+//!     .min_version(Version::Normal(2))
+//!     .build(b"Some data")
+//!     .unwrap();
+//! assert_eq!(code.version(), Version::Normal(2));
+//! assert_eq!(code.error_correction_level(), EcLevel::H);
+//! ```
+
+use crate::bits::{needs_utf8_eci, parse_with_eci, Bits};
+use crate::canvas::{supported_mask_patterns, MaskPattern};
+use crate::optimize::optimize_segments;
+use crate::types::{EcLevel, QrError, QrResult, Version};
+use crate::QrCode;
+
+/// A builder of QR codes, for when the defaults of `QrCode::new()` are not
+/// suitable.
+///
+/// The builder searches for the smallest version within the allowed range
+/// which can store the data at the requested error correction level.
+#[derive(Debug, Clone)]
+pub struct QrCodeBuilder {
+    min_version: Version,
+    max_version: Version,
+    ec_level: EcLevel,
+    boost_ec_level: bool,
+    mask_pattern: Option<MaskPattern>,
+    micro_allowed: bool,
+    eci_designator: Option<u32>,
+    auto_utf8_eci: bool,
+}
+
+impl Default for QrCodeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QrCodeBuilder {
+    /// Creates a new builder. By default, all QR code versions from 1 to 40 are
+    /// allowed, the error correction level is M, the mask pattern is chosen
+    /// automatically, Micro QR code is not allowed, and the UTF-8 ECI
+    /// designator is added for non-ASCII UTF-8 text.
+    pub const fn new() -> Self {
+        Self {
+            min_version: Version::Normal(1),
+            max_version: Version::Normal(40),
+            ec_level: EcLevel::M,
+            boost_ec_level: false,
+            mask_pattern: None,
+            micro_allowed: false,
+            eci_designator: None,
+            auto_utf8_eci: true,
+        }
+    }
+
+    /// Sets the smallest QR code version allowed. Default is version 1.
+    pub fn min_version(&mut self, version: Version) -> &mut Self {
+        self.min_version = version;
+        self
+    }
+
+    /// Sets the largest QR code version allowed. Default is version 40.
+    pub fn max_version(&mut self, version: Version) -> &mut Self {
+        self.max_version = version;
+        self
+    }
+
+    /// Sets the error correction level. Default is level M.
+    pub fn ec_level(&mut self, ec_level: EcLevel) -> &mut Self {
+        self.ec_level = ec_level;
+        self
+    }
+
+    /// Whether to raise the error correction level as high as possible, as
+    /// long as the data still fits in the same version. Default is false.
+    pub fn boost_ec_level(&mut self, boost_ec_level: bool) -> &mut Self {
+        self.boost_ec_level = boost_ec_level;
+        self
+    }
+
+    /// Forces a mask pattern, instead of choosing the one with the lowest
+    /// penalty score. Micro QR code versions which do not support the pattern
+    /// will be skipped, rather than failing with
+    /// `QrError::UnsupportedMaskPattern`.
+    pub fn mask_pattern(&mut self, mask_pattern: MaskPattern) -> &mut Self {
+        self.mask_pattern = Some(mask_pattern);
+        self
+    }
+
+    /// Whether to try Micro QR code versions M1 to M4 before the QR code
+    /// versions. Default is false.
+    ///
+    /// Micro QR code versions are not restricted by `min_version()`.
+    pub fn micro_allowed(&mut self, micro_allowed: bool) -> &mut Self {
+        self.micro_allowed = micro_allowed;
+        self
+    }
+
+    /// Adds an ECI designator in front of the data, to specify its character
+    /// set. Since Micro QR code does not support ECI, only QR code versions
+    /// will be used.
+    pub fn eci_designator(&mut self, eci_designator: u32) -> &mut Self {
+        self.eci_designator = Some(eci_designator);
+        self
+    }
+
+    /// Whether to add the UTF-8 ECI designator (26) when the data is UTF-8
+    /// text containing non-ASCII characters, so that readers will not decode
+    /// it as ISO-8859-1. This has no effect if an ECI designator is given
+    /// explicitly. Default is true.
+    pub fn auto_utf8_eci(&mut self, auto_utf8_eci: bool) -> &mut Self {
+        self.auto_utf8_eci = auto_utf8_eci;
+        self
+    }
+
+    /// Encodes the data into the bits of the given version, without the
+    /// terminator.
+    fn encode(data: &[u8], version: Version, eci_designator: Option<u32>) -> QrResult<Bits> {
+        let mut bits = Bits::new(version);
+        if let Some(eci_designator) = eci_designator {
+            bits.push_eci_designator(eci_designator)?;
+        }
+        let segments = parse_with_eci(data, eci_designator);
+        bits.push_segments(data, optimize_segments(&segments, version).into_iter())?;
+        Ok(bits)
+    }
+
+    /// Finds the error correction level to use in the version, and terminates
+    /// the bits.
+    fn terminate(&self, bits: &Bits) -> QrResult<(Bits, EcLevel)> {
+        let ec_levels = [EcLevel::H, EcLevel::Q, EcLevel::M, EcLevel::L];
+        let boosted_levels = if self.boost_ec_level { &ec_levels[..] } else { &[] };
+        let mut error = QrError::InvalidVersion;
+        for ec_level in boosted_levels.iter().copied().filter(|l| *l > self.ec_level).chain([self.ec_level]) {
+            let mut bits = bits.clone();
+            match bits.push_terminator(ec_level) {
+                Ok(()) => return Ok((bits, ec_level)),
+                Err(QrError::InvalidVersion) => {}
+                Err(e) => error = e,
+            }
+        }
+        Err(error)
+    }
+
+    /// Encodes the data into a QR code using the options.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::DataTooLong)` if the data does not fit into any
+    /// allowed version.
+    ///
+    /// Returns `Err(QrError::InvalidVersion)` if the version range is empty or
+    /// is not made of QR code versions.
+    ///
+    /// Returns `Err(QrError::InvalidEciDesignator)` if the ECI designator is
+    /// invalid.
+    pub fn build<D: AsRef<[u8]>>(&self, data: D) -> QrResult<QrCode> {
+        let data = data.as_ref();
+        let (Version::Normal(min), Version::Normal(max)) = (self.min_version, self.max_version) else {
+            return Err(QrError::InvalidVersion);
+        };
+        if !(1..=max).contains(&min) || max > 40 {
+            return Err(QrError::InvalidVersion);
+        }
+
+        let eci_designator = self.eci_designator.or((self.auto_utf8_eci && needs_utf8_eci(data)).then_some(26));
+        let mut error = QrError::InvalidVersion;
+        let micro_versions = (1..=4).map(Version::Micro).filter(|_| self.micro_allowed && eci_designator.is_none());
+        for version in micro_versions.chain((min..=max).map(Version::Normal)) {
+            if let Some(mask_pattern) = self.mask_pattern {
+                if !supported_mask_patterns(version).contains(&mask_pattern) {
+                    continue;
+                }
+            }
+            let bits = match Self::encode(data, version, eci_designator) {
+                Ok(bits) => bits,
+                Err(e @ (QrError::DataTooLong(_) | QrError::UnsupportedCharacterSet)) => {
+                    error = e;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            match self.terminate(&bits) {
+                Ok((bits, ec_level)) => return QrCode::with_bits_and_optional_mask(bits, ec_level, self.mask_pattern),
+                Err(e) => error = e,
+            }
+        }
+        Err(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bits::SegmentKind;
+    use crate::builder::QrCodeBuilder;
+    use crate::canvas::MaskPattern;
+    use crate::optimize::Segment;
+    use crate::types::{EcLevel, Mode, Overflow, QrError, Version};
+
+    #[test]
+    fn test_default() {
+        let code = QrCodeBuilder::new().build(b"HELLO WORLD").unwrap();
+        assert_eq!(code.version(), Version::Normal(1));
+        assert_eq!(code.error_correction_level(), EcLevel::M);
+    }
+
+    #[test]
+    fn test_version_range() {
+        let code = QrCodeBuilder::new().min_version(Version::Normal(5)).build(b"HELLO WORLD").unwrap();
+        assert_eq!(code.version(), Version::Normal(5));
+
+        let res = QrCodeBuilder::new().max_version(Version::Normal(2)).build([0_u8; 100]);
+        assert_eq!(
+            res.err(),
+            Some(QrError::DataTooLong(Overflow {
+                bits_needed: 812,
+                bits_available: 224,
+                segment: Some(Segment { mode: Mode::Byte, begin: 0, end: 100 })
+            }))
+        );
+
+        let res = QrCodeBuilder::new().min_version(Version::Normal(3)).max_version(Version::Normal(2)).build(b"1");
+        assert_eq!(res.err(), Some(QrError::InvalidVersion));
+        let res = QrCodeBuilder::new().max_version(Version::Micro(4)).build(b"1");
+        assert_eq!(res.err(), Some(QrError::InvalidVersion));
+    }
+
+    #[test]
+    fn test_boost_ec_level() {
+        let code = QrCodeBuilder::new().ec_level(EcLevel::L).boost_ec_level(true).build(b"HELLO WORLD").unwrap();
+        assert_eq!(code.version(), Version::Normal(1));
+        assert_eq!(code.error_correction_level(), EcLevel::Q);
+
+        let code = QrCodeBuilder::new().ec_level(EcLevel::L).boost_ec_level(true).build([b'a'; 17]).unwrap();
+        assert_eq!(code.version(), Version::Normal(1));
+        assert_eq!(code.error_correction_level(), EcLevel::L);
+    }
+
+    #[test]
+    fn test_mask_pattern() {
+        for mask_pattern in [MaskPattern::Checkerboard, MaskPattern::Meadow] {
+            let code = QrCodeBuilder::new().mask_pattern(mask_pattern).build(b"Some data").unwrap();
+            assert_eq!(code.decode().unwrap().mask_pattern, mask_pattern);
+        }
+    }
+
+    #[test]
+    fn test_micro_allowed() {
+        let code = QrCodeBuilder::new().micro_allowed(true).ec_level(EcLevel::L).build(b"12345").unwrap();
+        assert_eq!(code.version(), Version::Micro(1));
+
+        // M1 only supports level L.
+        let code = QrCodeBuilder::new().micro_allowed(true).ec_level(EcLevel::M).build(b"12345").unwrap();
+        assert_eq!(code.version(), Version::Micro(2));
+
+        // Micro QR code does not support the checkerboard mask.
+        let code =
+            QrCodeBuilder::new().micro_allowed(true).mask_pattern(MaskPattern::Checkerboard).build(b"1").unwrap();
+        assert_eq!(code.version(), Version::Normal(1));
+
+        // Micro QR code does not support ECI.
+        let code = QrCodeBuilder::new().micro_allowed(true).eci_designator(26).build(b"1").unwrap();
+        assert_eq!(code.version(), Version::Normal(1));
+    }
+
+    #[test]
+    fn test_auto_utf8_eci() {
+        let code = QrCodeBuilder::new().build("\u{3b1}\u{3b2}\u{3b3}").unwrap();
+        assert_eq!(code.decode().unwrap().eci, Some(26));
+
+        let code = QrCodeBuilder::new().auto_utf8_eci(false).build("\u{3b1}\u{3b2}\u{3b3}").unwrap();
+        assert_eq!(code.decode().unwrap().eci, None);
+
+        let code = QrCodeBuilder::new().eci_designator(9).build("\u{3b1}\u{3b2}\u{3b3}").unwrap();
+        assert_eq!(code.decode().unwrap().eci, Some(9));
+
+        // UTF-8 byte pairs looking like Shift JIS are not put in Kanji mode.
+        let code = QrCodeBuilder::new().build("\u{3042}\u{3044}\u{3046}").unwrap();
+        assert_eq!(
+            code.segments().iter().map(|s| s.kind).collect::<Vec<_>>(),
+            [SegmentKind::Eci(26), SegmentKind::Data(Segment { mode: Mode::Byte, begin: 0, end: 9 })]
+        );
+
+        let code = QrCodeBuilder::new().micro_allowed(true).ec_level(EcLevel::L).build(b"ascii").unwrap();
+        assert_eq!(code.version(), Version::Micro(3));
+        assert_eq!(code.decode().unwrap().eci, None);
+    }
+
+    #[test]
+    fn test_eci_designator() {
+        let code = QrCodeBuilder::new().eci_designator(26).build("caf\u{e9}").unwrap();
+        let decoded = code.decode().unwrap();
+        assert_eq!(decoded.eci, Some(26));
+        assert_eq!(decoded.data, "caf\u{e9}".as_bytes());
+
+        let res = QrCodeBuilder::new().eci_designator(1_000_000).build(b"1");
+        assert_eq!(res.err(), Some(QrError::InvalidEciDesignator));
+    }
+}
@@ -14,10 +14,13 @@
 use alloc::boxed::Box;
 use alloc::vec;
 use alloc::vec::Vec;
-use core::{cmp::max, iter};
+use core::{
+    cmp::{max, min},
+    iter,
+};
 
 use crate::cast::As;
-use crate::types::{Color, EcLevel, Version};
+use crate::types::{Color, EcLevel, QrError, QrResult, Version};
 
 //------------------------------------------------------------------------------
 //{{{ Modules
@@ -82,9 +85,12 @@ impl Module {
 /// into a QR code.
 #[derive(Clone)]
 pub struct Canvas {
-    /// The width and height of the canvas (cached as it is needed frequently).
+    /// The width of the canvas (cached as it is needed frequently).
     width: i16,
 
+    /// The height of the canvas, which differs from the width only in rMQR.
+    height: i16,
+
     /// The version of the QR code.
     version: Version,
 
@@ -100,15 +106,16 @@ impl Canvas {
     /// Constructs a new canvas big enough for a QR code of the given version.
     pub fn new(version: Version, ec_level: EcLevel) -> Self {
         let width = version.width();
-        Self { width, version, ec_level, modules: vec![Module::Empty; (width * width).as_usize()] }
+        let height = version.height();
+        Self { width, height, version, ec_level, modules: vec![Module::Empty; (width * height).as_usize()] }
     }
 
     /// Converts the canvas into a human-readable string.
     #[cfg(test)]
     fn to_debug_str(&self) -> alloc::string::String {
         let width = self.width;
-        let mut res = alloc::string::String::with_capacity((width * (width + 1)) as usize);
-        for y in 0..width {
+        let mut res = alloc::string::String::with_capacity((self.height * (width + 1)) as usize);
+        for y in 0..self.height {
             res.push('\n');
             for x in 0..width {
                 res.push(match self.get(x, y) {
@@ -125,7 +132,7 @@ impl Canvas {
 
     fn coords_to_index(&self, x: i16, y: i16) -> usize {
         let x = if x < 0 { x + self.width } else { x }.as_usize();
-        let y = if y < 0 { y + self.width } else { y }.as_usize();
+        let y = if y < 0 { y + self.height } else { y }.as_usize();
         y * self.width.as_usize() + x
     }
 
@@ -222,7 +229,8 @@ impl Canvas {
     /// Draws a single finder pattern with the center at (x, y).
     fn draw_finder_pattern_at(&mut self, x: i16, y: i16) {
         let (dx_left, dx_right) = if x >= 0 { (-3, 4) } else { (-4, 3) };
-        let (dy_top, dy_bottom) = if y >= 0 { (-3, 4) } else { (-4, 3) };
+        // The separator is omitted if the symbol is too short, as in R7 rMQR.
+        let (dy_top, dy_bottom) = if y >= 0 { (-3, min(4, self.height - 1 - y)) } else { (-4, 3) };
         for j in dy_top..=dy_bottom {
             for i in dx_left..=dx_right {
                 self.put(
@@ -254,6 +262,29 @@ impl Canvas {
                 self.draw_finder_pattern_at(-4, 3);
                 self.draw_finder_pattern_at(3, -4);
             }
+            Version::RectMicro(..) => self.draw_rect_micro_corner_patterns(),
+        }
+    }
+
+    /// Draws the finder sub-pattern and the corner patterns of rMQR.
+    ///
+    /// The finder sub-pattern is a 5×5 square pattern at the bottom-right
+    /// corner, which looks the same as an alignment pattern of QR code. The
+    /// corner patterns are small L-shaped marks at the top-right and (if the
+    /// symbol is tall enough) bottom-left corners.
+    fn draw_rect_micro_corner_patterns(&mut self) {
+        self.draw_alignment_pattern_at(-3, -3);
+
+        self.put(-1, 0, Color::Dark);
+        self.put(-2, 0, Color::Dark);
+        self.put(-1, 1, Color::Dark);
+        self.put(-2, 1, Color::Light);
+
+        if self.height >= 11 {
+            self.put(0, -1, Color::Dark);
+            self.put(1, -1, Color::Dark);
+            self.put(0, -2, Color::Dark);
+            self.put(1, -2, Color::Light);
         }
     }
 }
@@ -340,6 +371,15 @@ impl Canvas {
         }
     }
 
+    /// Draws a 3×3 rMQR alignment pattern with the center at (x, y).
+    fn draw_rect_micro_alignment_pattern_at(&mut self, x: i16, y: i16) {
+        for j in -1..=1 {
+            for i in -1..=1 {
+                self.put(x + i, y + j, if (i, j) == (0, 0) { Color::Light } else { Color::Dark });
+            }
+        }
+    }
+
     /// Draws the alignment patterns.
     ///
     /// The alignment patterns are 5×5 square patterns inside the QR code symbol
@@ -347,6 +387,12 @@ impl Canvas {
     fn draw_alignment_patterns(&mut self) {
         match self.version {
             Version::Micro(_) | Version::Normal(1) => {}
+            Version::RectMicro(_, w) => {
+                for x in rect_micro_alignment_pattern_positions(w) {
+                    self.draw_rect_micro_alignment_pattern_at(*x, 1);
+                    self.draw_rect_micro_alignment_pattern_at(*x, -2);
+                }
+            }
             Version::Normal(2..=6) => self.draw_alignment_pattern_at(-7, -7),
             Version::Normal(a) => {
                 let positions = ALIGNMENT_PATTERN_POSITIONS[(a - 7).as_usize()];
@@ -534,6 +580,20 @@ static ALIGNMENT_PATTERN_POSITIONS: [&[i16]; 34] = [
     &[6, 30, 58, 86, 114, 142, 170],
 ];
 
+/// Obtains the x-coordinates of the center of the alignment patterns of an
+/// rMQR symbol with the given width. Each x-coordinate has one alignment
+/// pattern at the top edge and one at the bottom edge.
+fn rect_micro_alignment_pattern_positions(width: i16) -> &'static [i16] {
+    match width {
+        43 => &[21],
+        59 => &[19, 39],
+        77 => &[25, 51],
+        99 => &[23, 49, 75],
+        139 => &[27, 55, 83, 111],
+        _ => &[],
+    }
+}
+
 //}}}
 //------------------------------------------------------------------------------
 //{{{ Timing patterns
@@ -574,10 +634,36 @@ impl Canvas {
         let (y, x1, x2) = match self.version {
             Version::Micro(_) => (0, 8, width - 1),
             Version::Normal(_) => (6, 8, width - 9),
+            Version::RectMicro(..) => return self.draw_rect_micro_timing_patterns(),
         };
         self.draw_line(x1, y, x2, y, Color::Dark, Color::Light);
         self.draw_line(y, x1, y, x2, Color::Dark, Color::Light);
     }
+
+    /// Draws the timing patterns of rMQR.
+    ///
+    /// The timing patterns run along all four edges, and through the center
+    /// column of every alignment pattern. They fill the gaps between the other
+    /// function patterns, so they must be drawn last.
+    fn draw_rect_micro_timing_patterns(&mut self) {
+        let (width, height) = (self.width, self.height);
+        let color = |i: i16| if i % 2 == 0 { Color::Dark } else { Color::Light };
+        for x in 0..width {
+            for y in [0, height - 1] {
+                if self.get(x, y) == Module::Empty {
+                    self.put(x, y, color(x));
+                }
+            }
+        }
+        let columns = iter::once(0).chain(rect_micro_alignment_pattern_positions(width).iter().copied());
+        for x in columns.chain(iter::once(width - 1)) {
+            for y in 0..height {
+                if self.get(x, y) == Module::Empty {
+                    self.put(x, y, color(y));
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -659,8 +745,10 @@ impl Canvas {
     }
 
     /// Draws the format info patterns for an encoded number.
-    fn draw_format_info_patterns_with_number(&mut self, format_info: u16) {
-        let format_info = u32::from(format_info);
+    ///
+    /// For rMQR, `format_info` is the unmasked BCH code, and the two copies
+    /// will be masked with different patterns.
+    fn draw_format_info_patterns_with_number(&mut self, format_info: u32) {
         match self.version {
             Version::Micro(_) => {
                 self.draw_number(format_info, 15, Color::Dark, Color::Light, &FORMAT_INFO_COORDS_MICRO_QR);
@@ -670,6 +758,12 @@ impl Canvas {
                 self.draw_number(format_info, 15, Color::Dark, Color::Light, &FORMAT_INFO_COORDS_QR_SIDE);
                 self.put(8, -8, Color::Dark); // Dark module.
             }
+            Version::RectMicro(..) => {
+                let tl = format_info ^ FORMAT_INFO_MASK_RECT_MICRO_TL;
+                let br = format_info ^ FORMAT_INFO_MASK_RECT_MICRO_BR;
+                self.draw_number(tl, 18, Color::Dark, Color::Light, &FORMAT_INFO_COORDS_RECT_MICRO_TL);
+                self.draw_number(br, 18, Color::Dark, Color::Light, &FORMAT_INFO_COORDS_RECT_MICRO_BR);
+            }
         }
     }
 
@@ -681,7 +775,7 @@ impl Canvas {
     /// Draws the version information patterns.
     fn draw_version_info_patterns(&mut self) {
         match self.version {
-            Version::Micro(_) | Version::Normal(1..=6) => {}
+            Version::Micro(_) | Version::Normal(1..=6) | Version::RectMicro(..) => {}
             Version::Normal(a) => {
                 let version_info = VERSION_INFOS[(a - 7).as_usize()];
                 self.draw_number(version_info, 18, Color::Dark, Color::Light, &VERSION_INFO_COORDS_BL);
@@ -953,6 +1047,48 @@ static FORMAT_INFO_COORDS_MICRO_QR: [(i16, i16); 15] = [
     (8, 1),
 ];
 
+static FORMAT_INFO_COORDS_RECT_MICRO_TL: [(i16, i16); 18] = [
+    (11, 3),
+    (11, 2),
+    (11, 1),
+    (10, 5),
+    (10, 4),
+    (10, 3),
+    (10, 2),
+    (10, 1),
+    (9, 5),
+    (9, 4),
+    (9, 3),
+    (9, 2),
+    (9, 1),
+    (8, 5),
+    (8, 4),
+    (8, 3),
+    (8, 2),
+    (8, 1),
+];
+
+static FORMAT_INFO_COORDS_RECT_MICRO_BR: [(i16, i16); 18] = [
+    (-3, -6),
+    (-4, -6),
+    (-5, -6),
+    (-6, -2),
+    (-6, -3),
+    (-6, -4),
+    (-6, -5),
+    (-6, -6),
+    (-7, -2),
+    (-7, -3),
+    (-7, -4),
+    (-7, -5),
+    (-7, -6),
+    (-8, -2),
+    (-8, -3),
+    (-8, -4),
+    (-8, -5),
+    (-8, -6),
+];
+
 static VERSION_INFOS: [u32; 34] = [
     0x07c94, 0x085bc, 0x09a99, 0x0a4d3, 0x0bbf6, 0x0c762, 0x0d847, 0x0e60d, 0x0f928, 0x10b78, 0x1145d, 0x12a17,
     0x13532, 0x149a6, 0x15683, 0x168c9, 0x177ec, 0x18ec4, 0x191e1, 0x1afab, 0x1b08e, 0x1cc1a, 0x1d33f, 0x1ed75,
@@ -984,8 +1120,9 @@ impl Canvas {
 pub fn is_functional(version: Version, width: i16, x: i16, y: i16) -> bool {
     debug_assert!(width == version.width());
 
+    let height = version.height();
     let x = if x < 0 { x + width } else { x };
-    let y = if y < 0 { y + width } else { y };
+    let y = if y < 0 { y + height } else { y };
 
     match version {
         Version::Micro(_) => x == 0 || y == 0 || (x < 9 && y < 9),
@@ -1015,13 +1152,30 @@ pub fn is_functional(version: Version, width: i16, x: i16, y: i16) -> bool {
                 }
             }
         }
+        Version::RectMicro(..) => {
+            x == 0 || y == 0 || x == width - 1 || y == height - 1 || // Timing patterns
+                (x < 8 && y < 8) ||                                  // Finder pattern
+                (x >= width - 5 && y >= height - 5) ||               // Finder sub-pattern
+                (x >= width - 2 && y <= 1) ||                        // Top-right corner pattern
+                (height >= 11 && x <= 1 && y >= height - 2) ||       // Bottom-left corner pattern
+                ((8..=10).contains(&x) && (1..=5).contains(&y)) ||   // Format info
+                (x == 11 && (1..=3).contains(&y)) ||
+                ((width - 8..=width - 6).contains(&x) && (height - 6..=height - 2).contains(&y)) ||
+                ((width - 5..=width - 3).contains(&x) && y == height - 6) ||
+                rect_micro_alignment_pattern_positions(width)
+                    .iter()
+                    .any(|&cx| x == cx || ((cx - x).abs() <= 1 && (y <= 2 || y >= height - 3)))
+        }
     }
 }
 
 #[cfg(test)]
 mod all_functional_patterns_tests {
-    use crate::canvas::{is_functional, Canvas};
-    use crate::types::{EcLevel, Version};
+    use crate::bits::Bits;
+    use crate::canvas::{is_functional, Canvas, Module};
+    use crate::ec::construct_codewords;
+    use crate::types::{EcLevel, Version, RECT_MICRO_VERSIONS};
+    use alloc::vec;
 
     #[test]
     fn test_all_functional_patterns_qr() {
@@ -1127,6 +1281,45 @@ mod all_functional_patterns_tests {
         assert!(is_functional(version, version.width(), 0, 9));
         assert!(!is_functional(version, version.width(), 1, 9));
     }
+
+    #[test]
+    fn test_all_functional_patterns_rect_micro() {
+        let mut c = Canvas::new(Version::RectMicro(7, 43), EcLevel::M);
+        c.draw_all_functional_patterns();
+        assert_eq!(
+            &*c.to_debug_str(),
+            "\n\
+             #######.#.#.#.#.#.#.###.#.#.#.#.#.#.#.#.###\n\
+             #.....#..#.#????????#.#????????????##...#.#\n\
+             #.###.#.#.##????????###????????????########\n\
+             #.###.#..##.?????????.?????????????...#...#\n\
+             #.###.#...#?????????###????????????#..#.#.#\n\
+             #.....#.###?????????#.#????????????##.#...#\n\
+             #######.#.#.#.#.#.#.###.#.#.#.#.#.#.#.#####"
+        );
+    }
+
+    #[test]
+    fn test_is_functional_rect_micro() {
+        for &(h, w) in &RECT_MICRO_VERSIONS {
+            let version = Version::RectMicro(h, w);
+            let mut c = Canvas::new(version, EcLevel::M);
+            c.draw_all_functional_patterns();
+            let mut empty_count = 0;
+            for y in 0..h {
+                for x in 0..w {
+                    let is_empty = c.get(x, y) == Module::Empty;
+                    assert_eq!(is_functional(version, w, x, y), !is_empty, "{version:?} at ({x}, {y})");
+                    empty_count += usize::from(is_empty);
+                }
+            }
+
+            let data_len = Bits::new(version).max_len(EcLevel::M).unwrap() / 8;
+            let (data, ec) = construct_codewords(&vec![0; data_len], version, EcLevel::M).unwrap();
+            let total_bits = (data.len() + ec.len()) * 8;
+            assert!((total_bits..total_bits + 8).contains(&empty_count), "{version:?}");
+        }
+    }
 }
 
 //}}}
@@ -1137,20 +1330,30 @@ struct DataModuleIter {
     x: i16,
     y: i16,
     width: i16,
+    height: i16,
     timing_pattern_column: i16,
 }
 
 impl DataModuleIter {
     const fn new(version: Version) -> Self {
-        let width = version.width();
-        Self {
-            x: width - 1,
-            y: width - 1,
-            width,
-            timing_pattern_column: match version {
-                Version::Micro(_) => 0,
-                Version::Normal(_) => 6,
-            },
+        let height = version.height();
+        match version {
+            Version::Micro(_) | Version::Normal(_) => {
+                let width = version.width();
+                Self {
+                    x: width - 1,
+                    y: height - 1,
+                    width,
+                    height,
+                    timing_pattern_column: if version.is_micro() { 0 } else { 6 },
+                }
+            }
+            // The right-most column of rMQR is a timing pattern, so the
+            // placement starts from the second right-most column, and goes all
+            // the way to the left edge.
+            Version::RectMicro(_, w) => {
+                Self { x: w - 2, y: height - 1, width: w - 1, height, timing_pattern_column: -1 }
+            }
         }
     }
 }
@@ -1159,10 +1362,10 @@ impl Iterator for DataModuleIter {
     type Item = (i16, i16);
 
     fn next(&mut self) -> Option<(i16, i16)> {
-        let adjusted_ref_col = if self.x <= self.timing_pattern_column { self.x + 1 } else { self.x };
-        if adjusted_ref_col <= 0 {
+        if self.x < 0 {
             return None;
         }
+        let adjusted_ref_col = if self.x <= self.timing_pattern_column { self.x + 1 } else { self.x };
 
         let res = (self.x, self.y);
         let column_type = (self.width - adjusted_ref_col) % 4;
@@ -1172,7 +1375,7 @@ impl Iterator for DataModuleIter {
                 self.y -= 1;
                 self.x += 1;
             }
-            0 if self.y < self.width - 1 => {
+            0 if self.y < self.height - 1 => {
                 self.y += 1;
                 self.x += 1;
             }
@@ -1383,10 +1586,16 @@ impl Canvas {
         }
     }
 
+    /// Whether the last data codeword only occupies 4 bits, which is the case
+    /// for M1 and M3 Micro QR codes at every error correction level, e.g. M3-L
+    /// holds 84 data bits (10.5 codewords) and M3-M holds 68 (8.5 codewords).
+    fn is_half_codeword_at_end(&self) -> bool {
+        matches!(self.version, Version::Micro(1 | 3))
+    }
+
     /// Draws the encoded data and error correction codes to the empty modules.
     pub fn draw_data(&mut self, data: &[u8], ec: &[u8]) {
-        let is_half_codeword_at_end =
-            matches!((self.version, self.ec_level), (Version::Micro(1), EcLevel::L) | (Version::Micro(3), EcLevel::M));
+        let is_half_codeword_at_end = self.is_half_codeword_at_end();
         let mut coords = DataModuleIter::new(self.version);
         self.draw_codewords(data, is_half_codeword_at_end, &mut coords);
         self.draw_codewords(ec, false, &mut coords);
@@ -1420,6 +1629,18 @@ mod draw_codewords_test {
         );
     }
 
+    #[test]
+    fn test_micro_qr_3_l() {
+        // M3-L has 84 data bits, so only the upper half of the 11th data
+        // codeword is drawn, leaving room for all 48 bits of error correction.
+        let mut c = Canvas::new(Version::Micro(3), EcLevel::L);
+        c.draw_all_functional_patterns();
+        c.draw_data(b"\0\0\0\0\0\0\0\0\0\0\xf0", b"\xff\xff\xff\xff\xff\xff");
+        let debug_str = c.to_debug_str();
+        assert!(!debug_str.contains('?'));
+        assert_eq!(debug_str.matches('*').count(), 4 + 48);
+    }
+
     #[test]
     fn test_qr_2() {
         let mut c = Canvas::new(Version::Normal(2), EcLevel::L);
@@ -1466,7 +1687,7 @@ mod draw_codewords_test {
 
 /// The mask patterns. Since QR code and Micro QR code do not use the same
 /// pattern number, we name them according to their shape instead of the number.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum MaskPattern {
     /// QR code pattern 000: `(x + y) % 2 == 0`.
     Checkerboard = 0b000,
@@ -1539,7 +1760,7 @@ impl Canvas {
     pub fn apply_mask(&mut self, pattern: MaskPattern) {
         let mask_fn = get_mask_function(pattern);
         for x in 0..self.width {
-            for y in 0..self.width {
+            for y in 0..self.height {
                 let module = self.get_mut(x, y);
                 *module = module.mask(mask_fn(x, y));
             }
@@ -1581,8 +1802,19 @@ impl Canvas {
                 let simple_format_number = symbol_number << 2 | micro_pattern_number;
                 FORMAT_INFOS_MICRO_QR[simple_format_number]
             }
+            Version::RectMicro(..) => {
+                assert!(pattern == MaskPattern::LargeCheckerboard, "Unsupported mask pattern in rMQR code");
+                let ec_bit = match self.ec_level {
+                    EcLevel::M => 0,
+                    EcLevel::H => 1,
+                    _ => panic!("Unsupported ec_level in rMQR code"),
+                };
+                let index = self.version.rect_micro_index().expect("valid rMQR version");
+                let format_number = FORMAT_INFOS_RECT_MICRO[ec_bit << 5 | index];
+                return self.draw_format_info_patterns_with_number(format_number);
+            }
         };
-        self.draw_format_info_patterns_with_number(format_number);
+        self.draw_format_info_patterns_with_number(u32::from(format_number));
     }
 }
 
@@ -1691,10 +1923,62 @@ static FORMAT_INFOS_MICRO_QR: [u16; 32] = [
     0x34e3, 0x31d4, 0x3e8d, 0x3bba,
 ];
 
+/// The unmasked format information of rMQR, indexed by `ec_bit << 5 | index`,
+/// where `ec_bit` is 0 for level M and 1 for level H, and `index` is the
+/// position of the version in `RECT_MICRO_VERSIONS`.
+static FORMAT_INFOS_RECT_MICRO: [u32; 64] = [
+    0x00000, 0x01f25, 0x0216f, 0x03e4a, 0x042de, 0x05dfb, 0x063b1, 0x07c94, 0x085bc, 0x09a99, 0x0a4d3, 0x0bbf6,
+    0x0c762, 0x0d847, 0x0e60d, 0x0f928, 0x10b78, 0x1145d, 0x12a17, 0x13532, 0x149a6, 0x15683, 0x168c9, 0x177ec,
+    0x18ec4, 0x191e1, 0x1afab, 0x1b08e, 0x1cc1a, 0x1d33f, 0x1ed75, 0x1f250, 0x209d5, 0x216f0, 0x228ba, 0x2379f,
+    0x24b0b, 0x2542e, 0x26a64, 0x27541, 0x28c69, 0x2934c, 0x2ad06, 0x2b223, 0x2ceb7, 0x2d192, 0x2efd8, 0x2f0fd,
+    0x302ad, 0x31d88, 0x323c2, 0x33ce7, 0x34073, 0x35f56, 0x3611c, 0x37e39, 0x38711, 0x39834, 0x3a67e, 0x3b95b,
+    0x3c5cf, 0x3daea, 0x3e4a0, 0x3fb85,
+];
+
+/// The mask applied to the rMQR format information next to the finder pattern.
+const FORMAT_INFO_MASK_RECT_MICRO_TL: u32 = 0b01_1111_1010_1011_0010;
+
+/// The mask applied to the rMQR format information next to the finder
+/// sub-pattern.
+const FORMAT_INFO_MASK_RECT_MICRO_BR: u32 = 0b10_0000_1010_0111_1011;
+
 //}}}
 //------------------------------------------------------------------------------
 //{{{ Penalty score
 
+/// The penalty scores of a masked symbol, broken down by the rules of
+/// ISO/IEC 18004:2006 §6.8.2. A symbol having lower total score is more
+/// desirable.
+///
+/// QR code uses the first four rules, and Micro QR code uses only the light
+/// side rule. The rules not used by the version are always zero.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct PenaltyScores {
+    /// The score for runs of 5 or more adjacent modules with the same color,
+    /// in both rows and columns.
+    pub adjacent: u16,
+
+    /// The score for 2×2 blocks with the same color.
+    pub block: u16,
+
+    /// The score for finder-like patterns, in both rows and columns.
+    pub finder: u16,
+
+    /// The score for the deviation from 50% dark modules.
+    pub balance: u16,
+
+    /// The score for light modules on the right and bottom sides of a Micro
+    /// QR code.
+    pub light_side: u16,
+}
+
+impl PenaltyScores {
+    /// Computes the sum of all scores.
+    pub const fn total(&self) -> u16 {
+        self.adjacent + self.block + self.finder + self.balance + self.light_side
+    }
+}
+
 impl Canvas {
     /// Compute the penalty score for having too many adjacent modules with the
     /// same color.
@@ -1813,27 +2097,34 @@ impl Canvas {
         (h + v + 15 * max(h, v)).as_u16()
     }
 
-    /// Compute the total penalty scores. A QR code having higher points is less
-    /// desirable.
-    fn compute_total_penalty_scores(&self) -> u16 {
+    /// Compute the penalty scores of each rule.
+    pub fn compute_penalty_scores(&self) -> PenaltyScores {
         match self.version {
-            Version::Normal(_) => {
-                let s1_a = self.compute_adjacent_penalty_score(true);
-                let s1_b = self.compute_adjacent_penalty_score(false);
-                let s2 = self.compute_block_penalty_score();
-                let s3_a = self.compute_finder_penalty_score(true);
-                let s3_b = self.compute_finder_penalty_score(false);
-                let s4 = self.compute_balance_penalty_score();
-                s1_a + s1_b + s2 + s3_a + s3_b + s4
+            Version::Normal(_) => PenaltyScores {
+                adjacent: self.compute_adjacent_penalty_score(true) + self.compute_adjacent_penalty_score(false),
+                block: self.compute_block_penalty_score(),
+                finder: self.compute_finder_penalty_score(true) + self.compute_finder_penalty_score(false),
+                balance: self.compute_balance_penalty_score(),
+                light_side: 0,
+            },
+            Version::Micro(_) => {
+                PenaltyScores { light_side: self.compute_light_side_penalty_score(), ..PenaltyScores::default() }
             }
-            Version::Micro(_) => self.compute_light_side_penalty_score(),
+            // rMQR has only one mask pattern, so there is nothing to compare.
+            Version::RectMicro(..) => PenaltyScores::default(),
         }
     }
+
+    /// Compute the total penalty scores. A QR code having higher points is less
+    /// desirable.
+    fn compute_total_penalty_scores(&self) -> u16 {
+        self.compute_penalty_scores().total()
+    }
 }
 
 #[cfg(test)]
 mod penalty_tests {
-    use crate::canvas::{Canvas, MaskPattern};
+    use crate::canvas::{Canvas, MaskPattern, PenaltyScores};
     use crate::types::{Color, EcLevel, Version};
 
     fn create_test_canvas() -> Canvas {
@@ -1903,6 +2194,27 @@ mod penalty_tests {
         assert_eq!(c.compute_balance_penalty_score(), 2);
     }
 
+    #[test]
+    fn test_penalty_scores() {
+        let c = create_test_canvas();
+        let scores = c.compute_penalty_scores();
+        assert_eq!(scores, PenaltyScores { adjacent: 180, block: 90, finder: 40, balance: 2, light_side: 0 });
+        assert_eq!(scores.total(), 312);
+    }
+
+    #[test]
+    fn test_penalty_scores_of_all_masks() {
+        let c = create_test_canvas().unmask(MaskPattern::Checkerboard);
+        let all_scores = c.compute_penalty_scores_of_all_masks();
+        assert_eq!(all_scores.len(), 8);
+        assert_eq!(all_scores[0], (MaskPattern::Checkerboard, create_test_canvas().compute_penalty_scores()));
+
+        let best = all_scores.iter().min_by_key(|(_, scores)| scores.total()).unwrap();
+        let mut actual = c.clone();
+        actual.apply_mask(best.0);
+        assert_eq!(actual.into_colors(), c.apply_best_mask().into_colors());
+    }
+
     #[test]
     fn test_penalty_score_light_sides() {
         static HORIZONTAL_SIDE: [Color; 17] = [
@@ -1972,23 +2284,46 @@ static ALL_PATTERNS_QR: [MaskPattern; 8] = [
 static ALL_PATTERNS_MICRO_QR: [MaskPattern; 4] =
     [MaskPattern::HorizontalLines, MaskPattern::LargeCheckerboard, MaskPattern::Diamonds, MaskPattern::Meadow];
 
+static ALL_PATTERNS_RECT_MICRO_QR: [MaskPattern; 1] = [MaskPattern::LargeCheckerboard];
+
+/// Obtains all mask patterns which can be used in the given version.
+pub fn supported_mask_patterns(version: Version) -> &'static [MaskPattern] {
+    match version {
+        Version::Normal(_) => &ALL_PATTERNS_QR,
+        Version::Micro(_) => &ALL_PATTERNS_MICRO_QR,
+        Version::RectMicro(..) => &ALL_PATTERNS_RECT_MICRO_QR,
+    }
+}
+
 impl Canvas {
     /// Construct a new canvas and apply the best masking that gives the lowest
     /// penalty score.
-    #[allow(clippy::missing_panics_doc)] // the expect() only panics when the input iterators (ALL_PATTERNS_QR, ALL_PATTERNS_MICRO_QR) are empty
+    #[allow(clippy::missing_panics_doc)] // the expect() only panics when `supported_mask_patterns()` is empty
     #[must_use]
     pub fn apply_best_mask(&self) -> Self {
-        match self.version {
-            Version::Normal(_) => ALL_PATTERNS_QR.iter(),
-            Version::Micro(_) => ALL_PATTERNS_MICRO_QR.iter(),
-        }
-        .map(|ptn| {
-            let mut c = self.clone();
-            c.apply_mask(*ptn);
-            c
-        })
-        .min_by_key(Self::compute_total_penalty_scores)
-        .expect("at least one pattern")
+        supported_mask_patterns(self.version)
+            .iter()
+            .map(|ptn| {
+                let mut c = self.clone();
+                c.apply_mask(*ptn);
+                c
+            })
+            .min_by_key(Self::compute_total_penalty_scores)
+            .expect("at least one pattern")
+    }
+
+    /// Computes the penalty scores of every supported mask pattern, in the
+    /// order of `supported_mask_patterns()`. The canvas should contain the
+    /// unmasked data.
+    pub fn compute_penalty_scores_of_all_masks(&self) -> Vec<(MaskPattern, PenaltyScores)> {
+        supported_mask_patterns(self.version)
+            .iter()
+            .map(|ptn| {
+                let mut c = self.clone();
+                c.apply_mask(*ptn);
+                (*ptn, c.compute_penalty_scores())
+            })
+            .collect()
     }
 
     /// Convert the modules into a vector of booleans.
@@ -2005,3 +2340,260 @@ impl Canvas {
 
 //}}}
 //------------------------------------------------------------------------------
+//{{{ Reading a finished symbol
+
+impl Canvas {
+    /// Constructs a canvas from the colors of a finished QR code symbol, e.g.
+    /// the result of `.into_colors()`. All modules are considered masked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of colors does not match the size of the version.
+    pub fn from_colors(version: Version, ec_level: EcLevel, colors: &[Color]) -> Self {
+        let width = version.width();
+        let height = version.height();
+        assert_eq!(colors.len(), (width * height).as_usize(), "number of colors does not match the version");
+        Self { width, height, version, ec_level, modules: colors.iter().map(|c| Module::Masked(*c)).collect() }
+    }
+
+    /// Removes the given mask from the data modules of a finished symbol, so
+    /// that another mask can be applied. This is the inverse of
+    /// `.apply_mask()`, except that the format info is left unchanged.
+    #[must_use]
+    pub fn unmask(&self, pattern: MaskPattern) -> Self {
+        let mask_fn = get_mask_function(pattern);
+        let mut c = self.clone();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if !is_functional(self.version, self.width, x, y) {
+                    let module = c.get_mut(x, y);
+                    let color = Color::from(*module);
+                    *module = Module::Unmasked(if mask_fn(x, y) { !color } else { color });
+                }
+            }
+        }
+        c
+    }
+
+    /// Reads a big-endian integer from the canvas with the given coordinates.
+    /// This is the inverse of `draw_number()`.
+    fn read_number(&self, coords: &[(i16, i16)]) -> u32 {
+        coords.iter().fold(0, |number, &(x, y)| number << 1 | u32::from(self.get(x, y).is_dark()))
+    }
+
+    /// Reads the data and error correction codewords from the data modules,
+    /// after removing the given mask. This is the inverse of `.draw_data()`
+    /// followed by `.apply_mask()`.
+    ///
+    /// `data_len` is the number of data codewords. All remaining complete
+    /// codewords are returned as error correction codewords.
+    pub fn read_data(&self, pattern: MaskPattern, data_len: usize) -> (Vec<u8>, Vec<u8>) {
+        let mask_fn = get_mask_function(pattern);
+
+        let mut functional = Self::new(self.version, self.ec_level);
+        functional.draw_all_functional_patterns();
+
+        let mut bits = DataModuleIter::new(self.version)
+            .filter(|&(x, y)| functional.get(x, y) == Module::Empty)
+            .map(|(x, y)| self.get(x, y).is_dark() != mask_fn(x, y));
+
+        let data = read_codewords(&mut bits, data_len, self.is_half_codeword_at_end());
+        let ec = read_codewords(&mut bits, usize::MAX, false);
+        (data, ec)
+    }
+}
+
+/// Packs the bits into at most `count` codewords. Stops early when the bits are
+/// exhausted, dropping the incomplete codeword.
+fn read_codewords<I>(bits: &mut I, count: usize, is_half_codeword_at_end: bool) -> Vec<u8>
+where
+    I: Iterator<Item = bool>,
+{
+    let mut codewords = Vec::new();
+    for i in 0..count {
+        let bits_end = if is_half_codeword_at_end && i == count - 1 { 4 } else { 0 };
+        let mut codeword = 0;
+        for j in (bits_end..=7).rev() {
+            match bits.next() {
+                Some(true) => codeword |= 1 << j,
+                Some(false) => {}
+                None => return codewords,
+            }
+        }
+        codewords.push(codeword);
+    }
+    codewords
+}
+
+/// Finds the index of the entry in `table` nearest to `number` in Hamming
+/// distance, provided that the two differ by at most 3 bits.
+fn find_nearest_code<T: Copy + Into<u32>>(number: u32, table: &[T]) -> Option<(usize, u32)> {
+    table
+        .iter()
+        .map(|code| (number ^ (*code).into()).count_ones())
+        .enumerate()
+        .min_by_key(|(_, distance)| *distance)
+        .filter(|(_, distance)| *distance <= 3)
+}
+
+/// Reads the error correction level and mask pattern from the format info of
+/// a finished QR code symbol.
+///
+/// The format info is error-corrected; in normal QR codes both copies are
+/// considered.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::InvalidFormatInfo)` if the format info is too damaged
+/// to be read, or if it describes a different Micro QR code version.
+///
+/// # Panics
+///
+/// Panics if the number of colors does not match the size of the version.
+pub fn read_format_info(version: Version, colors: &[Color]) -> QrResult<(EcLevel, MaskPattern)> {
+    static EC_LEVELS: [EcLevel; 4] = [EcLevel::L, EcLevel::M, EcLevel::Q, EcLevel::H];
+
+    let canvas = Canvas::from_colors(version, EcLevel::L, colors);
+    match version {
+        Version::Normal(_) => {
+            let main = find_nearest_code(canvas.read_number(&FORMAT_INFO_COORDS_QR_MAIN), &FORMAT_INFOS_QR);
+            let side = find_nearest_code(canvas.read_number(&FORMAT_INFO_COORDS_QR_SIDE), &FORMAT_INFOS_QR);
+            let (index, _) =
+                main.into_iter().chain(side).min_by_key(|(_, distance)| *distance).ok_or(QrError::InvalidFormatInfo)?;
+            Ok((EC_LEVELS[(index >> 3) ^ 1], ALL_PATTERNS_QR[index & 7]))
+        }
+        Version::Micro(a) => {
+            let (index, _) =
+                find_nearest_code(canvas.read_number(&FORMAT_INFO_COORDS_MICRO_QR), &FORMAT_INFOS_MICRO_QR)
+                    .ok_or(QrError::InvalidFormatInfo)?;
+            let (symbol_version, ec_level) = match index >> 2 {
+                0b000 => (1, EcLevel::L),
+                0b001 => (2, EcLevel::L),
+                0b010 => (2, EcLevel::M),
+                0b011 => (3, EcLevel::L),
+                0b100 => (3, EcLevel::M),
+                0b101 => (4, EcLevel::L),
+                0b110 => (4, EcLevel::M),
+                _ => (4, EcLevel::Q),
+            };
+            if symbol_version != a {
+                return Err(QrError::InvalidFormatInfo);
+            }
+            Ok((ec_level, ALL_PATTERNS_MICRO_QR[index & 3]))
+        }
+        Version::RectMicro(..) => {
+            let tl = canvas.read_number(&FORMAT_INFO_COORDS_RECT_MICRO_TL) ^ FORMAT_INFO_MASK_RECT_MICRO_TL;
+            let br = canvas.read_number(&FORMAT_INFO_COORDS_RECT_MICRO_BR) ^ FORMAT_INFO_MASK_RECT_MICRO_BR;
+            let (index, _) = find_nearest_code(tl, &FORMAT_INFOS_RECT_MICRO)
+                .into_iter()
+                .chain(find_nearest_code(br, &FORMAT_INFOS_RECT_MICRO))
+                .min_by_key(|(_, distance)| *distance)
+                .ok_or(QrError::InvalidFormatInfo)?;
+            if Some(index & 0x1f) != version.rect_micro_index() {
+                return Err(QrError::InvalidFormatInfo);
+            }
+            let ec_level = if index >> 5 == 0 { EcLevel::M } else { EcLevel::H };
+            Ok((ec_level, MaskPattern::LargeCheckerboard))
+        }
+    }
+}
+
+/// Reads the version from the version info of a finished QR code symbol, if
+/// present. Only versions 7 and above carry the version info.
+///
+/// The `colors` are interpreted as a symbol of `version`, which may be an
+/// estimation. Since the version info is placed beside the top-right and
+/// bottom-left finder patterns, it can still be read when the estimated
+/// version is slightly off.
+///
+/// # Panics
+///
+/// Panics if the number of colors does not match the size of the version.
+pub fn read_version_info(version: Version, colors: &[Color]) -> Option<Version> {
+    if !matches!(version, Version::Normal(7..)) {
+        return None;
+    }
+    let canvas = Canvas::from_colors(version, EcLevel::L, colors);
+    let bl = find_nearest_code(canvas.read_number(&VERSION_INFO_COORDS_BL), &VERSION_INFOS);
+    let tr = find_nearest_code(canvas.read_number(&VERSION_INFO_COORDS_TR), &VERSION_INFOS);
+    let (index, _) = bl.into_iter().chain(tr).min_by_key(|(_, distance)| *distance)?;
+    Some(Version::Normal(index.as_i16() + 7))
+}
+
+#[cfg(test)]
+mod read_tests {
+    use crate::canvas::{read_format_info, read_version_info, Canvas, MaskPattern};
+    use crate::types::{Color, EcLevel, QrError, Version};
+
+    fn create_test_canvas(version: Version, ec_level: EcLevel, pattern: MaskPattern) -> Canvas {
+        let mut c = Canvas::new(version, ec_level);
+        c.draw_all_functional_patterns();
+        c.draw_data(b"\x20\x5b\x0b\x78\xd1\x72\xdc\x4d\x43\x40\xec\x11\x00", b"\xa8\x48\x16\x52\xd9\x36\x9c");
+        c.apply_mask(pattern);
+        c
+    }
+
+    #[test]
+    fn test_read_format_info_qr() {
+        let c = create_test_canvas(Version::Normal(1), EcLevel::Q, MaskPattern::Fields);
+        let colors = c.into_colors();
+        assert_eq!(read_format_info(Version::Normal(1), &colors), Ok((EcLevel::Q, MaskPattern::Fields)));
+    }
+
+    #[test]
+    fn test_read_format_info_qr_damaged() {
+        let mut c = create_test_canvas(Version::Normal(1), EcLevel::H, MaskPattern::Diamonds);
+        for x in 0..6 {
+            let color: Color = c.get(x, 8).into();
+            c.put(x, 8, !color);
+        }
+        let colors = c.into_colors();
+        assert_eq!(read_format_info(Version::Normal(1), &colors), Ok((EcLevel::H, MaskPattern::Diamonds)));
+    }
+
+    #[test]
+    fn test_read_format_info_micro_qr() {
+        let c = create_test_canvas(Version::Micro(3), EcLevel::M, MaskPattern::Meadow);
+        let colors = c.into_colors();
+        assert_eq!(read_format_info(Version::Micro(3), &colors), Ok((EcLevel::M, MaskPattern::Meadow)));
+        assert_eq!(read_format_info(Version::Micro(3), &[Color::Light; 15 * 15]), Err(QrError::InvalidFormatInfo));
+    }
+
+    #[test]
+    fn test_read_version_info() {
+        let mut c = Canvas::new(Version::Normal(17), EcLevel::L);
+        c.draw_all_functional_patterns();
+        let colors = c.into_colors();
+        assert_eq!(read_version_info(Version::Normal(17), &colors), Some(Version::Normal(17)));
+
+        let c = create_test_canvas(Version::Normal(1), EcLevel::Q, MaskPattern::Checkerboard);
+        assert_eq!(read_version_info(Version::Normal(1), &c.into_colors()), None);
+    }
+
+    #[test]
+    fn test_read_data() {
+        let c = create_test_canvas(Version::Normal(1), EcLevel::Q, MaskPattern::VerticalLines);
+        let colors = c.into_colors();
+        let (data, ec) =
+            Canvas::from_colors(Version::Normal(1), EcLevel::Q, &colors).read_data(MaskPattern::VerticalLines, 13);
+        assert_eq!(&*data, b"\x20\x5b\x0b\x78\xd1\x72\xdc\x4d\x43\x40\xec\x11\x00");
+        assert_eq!(&ec[..7], b"\xa8\x48\x16\x52\xd9\x36\x9c");
+        assert_eq!(ec.len(), 13);
+    }
+
+    #[test]
+    fn test_read_data_half_codeword() {
+        let mut c = Canvas::new(Version::Micro(1), EcLevel::L);
+        c.draw_all_functional_patterns();
+        c.draw_data(b"\x6e\x5d\xe2", b"\x2b\x63");
+        c.apply_mask(MaskPattern::Diamonds);
+        let colors = c.into_colors();
+        let (data, ec) =
+            Canvas::from_colors(Version::Micro(1), EcLevel::L, &colors).read_data(MaskPattern::Diamonds, 3);
+        assert_eq!(&*data, b"\x6e\x5d\xe0");
+        assert_eq!(&*ec, b"\x2b\x63");
+    }
+}
+
+//}}}
+//------------------------------------------------------------------------------
@@ -9,6 +9,13 @@ impl Truncate for u16 {
     }
 }
 
+impl Truncate for u32 {
+    #[allow(clippy::cast_possible_truncation)]
+    fn truncate_as_u8(self) -> u8 {
+        (self & 0xff) as u8
+    }
+}
+
 #[allow(clippy::wrong_self_convention)]
 pub trait As {
     fn as_u16(self) -> u16;
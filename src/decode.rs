@@ -0,0 +1,532 @@
+//! The `decode` module reads the data back from a QR code symbol.
+//!
+//! ```
+//! use qrcode::decode::decode;
+//! use qrcode::QrCode;
+//!
+//! let code = QrCode::new(b"Some data").unwrap();
+//! let decoded = decode(&code.to_colors(), code.width()).unwrap();
+//! assert_eq!(decoded.data, b"Some data");
+//! ```
+//!
+//! This is mainly intended to verify that an encoded symbol actually reads
+//! back. The input must be a module matrix without the quiet zone. Damaged
+//! modules are repaired using the error correction codes, as long as there
+//! are not too many of them.
+
+use alloc::vec::Vec;
+
+use crate::bits::Bits;
+use crate::canvas::{self, Canvas, MaskPattern};
+use crate::cast::{As, Truncate};
+use crate::ec;
+use crate::types::{Color, EcLevel, Mode, QrError, QrResult, Version, RECT_MICRO_VERSIONS};
+
+//------------------------------------------------------------------------------
+//{{{ Decoded result
+
+/// The information read back from a QR code symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decoded {
+    /// The version of the symbol.
+    pub version: Version,
+
+    /// The error correction level of the symbol.
+    pub ec_level: EcLevel,
+
+    /// The mask pattern applied to the symbol.
+    pub mask_pattern: MaskPattern,
+
+    /// The decoded data. Numeric and alphanumeric segments are returned as
+    /// ASCII, Kanji segments are returned as Shift JIS, and Hanzi segments are
+    /// returned as GB 2312. In FNC1 mode, the field separator `%` of the
+    /// alphanumeric segments is returned as GS (0x1D), and `%%` as `%`.
+    pub data: Vec<u8>,
+
+    /// The first ECI designator found in the data, if any.
+    pub eci: Option<u32>,
+
+    /// The number of codewords repaired by error correction.
+    pub errors_corrected: usize,
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Bit reader
+
+/// Reads big-endian integers of arbitrary width from a byte slice.
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    len: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Creates a new reader over the first `len` bits of `data`.
+    fn new(data: &'a [u8], len: usize) -> Self {
+        debug_assert!(len <= data.len() * 8);
+        Self { data, position: 0, len }
+    }
+
+    /// The number of bits not yet read.
+    const fn remaining(&self) -> usize {
+        self.len - self.position
+    }
+
+    /// Reads the next `n` bits without advancing the reader.
+    fn peek(&self, n: usize) -> Option<u32> {
+        if n > self.remaining() {
+            return None;
+        }
+        let number = (self.position..self.position + n).fold(0, |number, i| {
+            let bit = (self.data[i / 8] >> (7 - i % 8)) & 1;
+            number << 1 | u32::from(bit)
+        });
+        Some(number)
+    }
+
+    /// Reads the next `n` bits.
+    ///
+    /// Returns `Err(QrError::CorruptedData)` if there are not enough bits.
+    fn read(&mut self, n: usize) -> QrResult<u32> {
+        let number = self.peek(n).ok_or(QrError::CorruptedData)?;
+        self.position += n;
+        Ok(number)
+    }
+}
+
+#[test]
+fn test_bit_reader() {
+    let mut reader = BitReader::new(b"\x5a\x9a\xcb", 20);
+    assert_eq!(reader.read(3), Ok(0b010));
+    assert_eq!(reader.read(7), Ok(0b110_1010));
+    assert_eq!(reader.peek(4), Some(0b0110));
+    assert_eq!(reader.read(9), Ok(0b0_1101_0110));
+    assert_eq!(reader.remaining(), 1);
+    assert_eq!(reader.read(2), Err(QrError::CorruptedData));
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Segment parsing
+
+/// The characters of the alphanumeric mode, indexed by their base-45 digit.
+static ALPHANUMERIC_CHARS: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// Parses the numeric digits of a segment.
+fn read_numeric_data(reader: &mut BitReader, count: usize, data: &mut Vec<u8>) -> QrResult<()> {
+    let mut remaining = count;
+    while remaining > 0 {
+        let digits = remaining.min(3);
+        let number = reader.read(digits * 3 + 1)?;
+        if number >= [1, 10, 100, 1000][digits] {
+            return Err(QrError::CorruptedData);
+        }
+        for i in (0..digits).rev() {
+            let digit = number / [1, 10, 100][i] % 10;
+            data.push(b'0' + digit.truncate_as_u8());
+        }
+        remaining -= digits;
+    }
+    Ok(())
+}
+
+/// Parses the alphanumeric characters of a segment.
+fn read_alphanumeric_data(reader: &mut BitReader, count: usize, data: &mut Vec<u8>) -> QrResult<()> {
+    let mut remaining = count;
+    while remaining > 0 {
+        let chars = remaining.min(2);
+        let number = reader.read(chars * 5 + 1)?.as_usize();
+        if number >= [1, 45, 45 * 45][chars] {
+            return Err(QrError::CorruptedData);
+        }
+        if chars == 2 {
+            data.push(ALPHANUMERIC_CHARS[number / 45]);
+        }
+        data.push(ALPHANUMERIC_CHARS[number % 45]);
+        remaining -= chars;
+    }
+    Ok(())
+}
+
+/// Converts the alphanumeric characters starting at `begin` in FNC1 mode,
+/// where `%` represents GS (0x1D) and `%%` represents `%`.
+fn unescape_fnc1_alphanumeric_data(data: &mut Vec<u8>, begin: usize) {
+    let mut escaped = data.split_off(begin).into_iter();
+    while let Some(c) = escaped.next() {
+        if c != b'%' {
+            data.push(c);
+        } else if escaped.as_slice().first() == Some(&b'%') {
+            escaped.next();
+            data.push(b'%');
+        } else {
+            data.push(0x1d);
+        }
+    }
+}
+
+/// Parses the 8-bit bytes of a segment.
+fn read_byte_data(reader: &mut BitReader, count: usize, data: &mut Vec<u8>) -> QrResult<()> {
+    for _ in 0..count {
+        data.push(reader.read(8)?.truncate_as_u8());
+    }
+    Ok(())
+}
+
+/// Parses the Kanji characters of a segment, converting them back to Shift JIS.
+fn read_kanji_data(reader: &mut BitReader, count: usize, data: &mut Vec<u8>) -> QrResult<()> {
+    for _ in 0..count {
+        let number = reader.read(13)?;
+        let bytes = (number / 0xc0) << 8 | (number % 0xc0);
+        let cp = if bytes + 0x8140 <= 0x9ffc { bytes + 0x8140 } else { bytes + 0xc140 };
+        data.extend_from_slice(&cp.as_u16().to_be_bytes());
+    }
+    Ok(())
+}
+
+/// Parses the Hanzi characters of a segment, converting them back to GB 2312.
+fn read_hanzi_data(reader: &mut BitReader, count: usize, data: &mut Vec<u8>) -> QrResult<()> {
+    for _ in 0..count {
+        let number = reader.read(13)?;
+        let bytes = (number / 0x60) << 8 | (number % 0x60);
+        let cp = if bytes + 0xa1a1 <= 0xaafe { bytes + 0xa1a1 } else { bytes + 0xa6a1 };
+        data.extend_from_slice(&cp.as_u16().to_be_bytes());
+    }
+    Ok(())
+}
+
+/// Parses an ECI designator, after the mode indicator.
+fn read_eci_designator(reader: &mut BitReader) -> QrResult<u32> {
+    let first = reader.read(8)?;
+    match first {
+        0..=0x7f => Ok(first),
+        0x80..=0xbf => Ok((first & 0x3f) << 8 | reader.read(8)?),
+        0xc0..=0xdf => Ok((first & 0x1f) << 16 | reader.read(16)?),
+        _ => Err(QrError::CorruptedData),
+    }
+}
+
+/// Parses the segments in the data codewords of a symbol.
+fn read_segments(raw: &[u8], version: Version, ec_level: EcLevel, decoded: &mut Decoded) -> QrResult<()> {
+    let len = Bits::new(version).max_len(ec_level)?;
+    let mut reader = BitReader::new(raw, len);
+    let mode_bits_count = version.mode_bits_count();
+    let terminator_size = match version {
+        Version::Micro(a) => a.as_usize() * 2 + 1,
+        Version::RectMicro(..) => 3,
+        Version::Normal(_) => 4,
+    };
+    let mut is_fnc1 = false;
+
+    loop {
+        let remaining = reader.remaining();
+        if remaining == 0 || reader.peek(terminator_size.min(remaining)) == Some(0) {
+            return Ok(());
+        }
+
+        let mode_indicator = reader.read(mode_bits_count)?;
+        let mode = match (version, mode_indicator) {
+            (Version::Micro(_), 0) | (Version::RectMicro(..), 0b001) | (Version::Normal(_), 0b0001) => Mode::Numeric,
+            (Version::Micro(_), 1) | (Version::RectMicro(..), 0b010) | (Version::Normal(_), 0b0010) => {
+                Mode::Alphanumeric
+            }
+            (Version::Micro(_), 0b10) | (Version::RectMicro(..), 0b011) | (Version::Normal(_), 0b0100) => Mode::Byte,
+            (Version::Micro(_), 0b11) | (Version::RectMicro(..), 0b100) | (Version::Normal(_), 0b1000) => Mode::Kanji,
+            (Version::Normal(_), 0b1101) => match reader.read(4)? {
+                0b0001 => Mode::Hanzi,
+                _ => return Err(QrError::CorruptedData),
+            },
+            (Version::RectMicro(..), 0b111) | (Version::Normal(_), 0b0111) => {
+                let designator = read_eci_designator(&mut reader)?;
+                decoded.eci.get_or_insert(designator);
+                continue;
+            }
+            (Version::RectMicro(..), 0b101) | (Version::Normal(_), 0b0101) => {
+                is_fnc1 = true;
+                continue;
+            }
+            (Version::RectMicro(..), 0b110) | (Version::Normal(_), 0b1001) => {
+                reader.read(8)?;
+                is_fnc1 = true;
+                continue;
+            }
+            (Version::Normal(_), 0b0011) => {
+                reader.read(16)?;
+                continue;
+            }
+            _ => return Err(QrError::CorruptedData),
+        };
+
+        let count = reader.read(mode.length_bits_count(version))?.as_usize();
+        let begin = decoded.data.len();
+        match mode {
+            Mode::Numeric => read_numeric_data(&mut reader, count, &mut decoded.data),
+            Mode::Alphanumeric => read_alphanumeric_data(&mut reader, count, &mut decoded.data),
+            Mode::Byte => read_byte_data(&mut reader, count, &mut decoded.data),
+            Mode::Kanji => read_kanji_data(&mut reader, count, &mut decoded.data),
+            Mode::Hanzi => read_hanzi_data(&mut reader, count, &mut decoded.data),
+        }?;
+        if is_fnc1 && mode == Mode::Alphanumeric {
+            unescape_fnc1_alphanumeric_data(&mut decoded.data, begin);
+        }
+    }
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Decoder
+
+/// Determines the version of a symbol from its dimensions.
+fn version_from_dimensions(width: usize, height: usize) -> QrResult<Version> {
+    match (width, height) {
+        (21..=177, _) if width == height && width % 4 == 1 => Ok(Version::Normal(((width - 17) / 4).as_i16())),
+        (11..=17, _) if width == height && width % 2 == 1 => Ok(Version::Micro(((width - 9) / 2).as_i16())),
+        _ => RECT_MICRO_VERSIONS
+            .iter()
+            .find(|(h, w)| (w.as_usize(), h.as_usize()) == (width, height))
+            .map(|&(h, w)| Version::RectMicro(h, w))
+            .ok_or(QrError::InvalidVersion),
+    }
+}
+
+/// Decodes a QR code, Micro QR code or rMQR code symbol from its module colors.
+///
+/// The `colors` are arranged in left-to-right, then top-to-bottom order, the
+/// same as `QrCode::to_colors()`, and `width` is the number of modules per
+/// row. The quiet zone must not be included.
+///
+/// The version is determined from the dimensions. For versions 7 and above,
+/// it is cross-checked against the version info, which is only ignored when
+/// both copies are too damaged to be read.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::InvalidVersion)` if the dimensions do not correspond
+/// to any QR code version, or the version info describes a different version.
+///
+/// Returns `Err(QrError::InvalidFormatInfo)` if the format information cannot
+/// be read.
+///
+/// Returns `Err(QrError::CorruptedData)` if there are too many errors to be
+/// corrected, or if the data is not a valid bit stream.
+pub fn decode(colors: &[Color], width: usize) -> QrResult<Decoded> {
+    if width == 0 || colors.len() % width != 0 {
+        return Err(QrError::InvalidVersion);
+    }
+    let version = version_from_dimensions(width, colors.len() / width)?;
+    if canvas::read_version_info(version, colors).is_some_and(|v| v != version) {
+        return Err(QrError::InvalidVersion);
+    }
+    let (ec_level, mask_pattern) = canvas::read_format_info(version, colors)?;
+
+    let data_len = (Bits::new(version).max_len(ec_level)? + 7) / 8;
+    let (data, ec) = Canvas::from_colors(version, ec_level, colors).read_data(mask_pattern, data_len);
+    let blocks = ec::deconstruct_codewords(&data, &ec, version, ec_level)?;
+
+    let ec_bytes_per_block = ec.len() / blocks.len();
+    let mut raw = Vec::with_capacity(data_len);
+    let mut errors_corrected = 0;
+    for mut block in blocks {
+        errors_corrected += ec::correct_errors(&mut block, ec_bytes_per_block, &[])?;
+        raw.extend_from_slice(&block[..block.len() - ec_bytes_per_block]);
+    }
+
+    // Small symbols reserve some codewords for misdecode protection.
+    if errors_corrected > ec::max_allowed_errors(version, ec_level)? {
+        return Err(QrError::CorruptedData);
+    }
+
+    let mut decoded = Decoded { version, ec_level, mask_pattern, data: Vec::new(), eci: None, errors_corrected };
+    read_segments(&raw, version, ec_level, &mut decoded)?;
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod decode_tests {
+    use crate::bits::Bits;
+    use crate::canvas::MaskPattern;
+    use crate::decode::decode;
+    use crate::types::{Color, EcLevel, QrError, Version, RECT_MICRO_VERSIONS};
+    use crate::QrCode;
+    use alloc::vec::Vec;
+
+    fn round_trip(data: &[u8], version: Version, ec_level: EcLevel) {
+        let code = QrCode::with_version(data, version, ec_level).unwrap();
+        let decoded = decode(&code.to_colors(), code.width()).unwrap();
+        assert_eq!(decoded.version, version);
+        assert_eq!(decoded.ec_level, ec_level);
+        assert_eq!(decoded.data, data, "{version:?} {ec_level:?}");
+    }
+
+    #[test]
+    fn test_annex_i_qr() {
+        let code = QrCode::with_version(b"01234567", Version::Normal(1), EcLevel::M).unwrap();
+        let decoded = decode(&code.to_colors(), code.width()).unwrap();
+        assert_eq!(decoded.data, b"01234567");
+        assert_eq!(decoded.eci, None);
+    }
+
+    #[test]
+    fn test_annex_i_micro_qr() {
+        let code = QrCode::with_version(b"01234567", Version::Micro(2), EcLevel::L).unwrap();
+        let decoded = decode(&code.to_colors(), code.width()).unwrap();
+        assert_eq!(decoded.mask_pattern, MaskPattern::LargeCheckerboard);
+        assert_eq!(decoded.data, b"01234567");
+    }
+
+    #[test]
+    fn test_all_normal_versions() {
+        let data = (0..2000_u32).map(|i| (i * 7 + i / 13).to_le_bytes()[0]).collect::<Vec<_>>();
+        for v in 1..=40 {
+            for ec_level in [EcLevel::L, EcLevel::M, EcLevel::Q, EcLevel::H] {
+                let version = Version::Normal(v);
+                let capacity = Bits::new(version).max_len(ec_level).unwrap() / 8 - 3;
+                round_trip(&data[..capacity.min(data.len())], version, ec_level);
+            }
+        }
+    }
+
+    #[test]
+    fn test_micro_versions() {
+        round_trip(b"12345", Version::Micro(1), EcLevel::L);
+        round_trip(b"A1B2C3", Version::Micro(2), EcLevel::L);
+        round_trip(b"A1B2", Version::Micro(2), EcLevel::M);
+        round_trip(b"hello!", Version::Micro(3), EcLevel::L);
+        round_trip(b"12345678901234567890123", Version::Micro(3), EcLevel::L);
+        round_trip(b"123456789012345678", Version::Micro(3), EcLevel::M);
+        round_trip(b"Micro QR code", Version::Micro(4), EcLevel::L);
+        round_trip(b"MICRO QR CODE", Version::Micro(4), EcLevel::M);
+        round_trip(b"\x93\x5f\xe4\xaa", Version::Micro(4), EcLevel::Q);
+    }
+
+    #[test]
+    fn test_all_rect_micro_versions() {
+        let data = (0..400_u32).map(|i| (i * 7 + i / 13).to_le_bytes()[0]).collect::<Vec<_>>();
+        for &(h, w) in &RECT_MICRO_VERSIONS {
+            for ec_level in [EcLevel::M, EcLevel::H] {
+                let version = Version::RectMicro(h, w);
+                let capacity = Bits::new(version).max_len(ec_level).unwrap() / 8 - 2;
+                round_trip(&data[..capacity], version, ec_level);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rect_micro_mixed_modes() {
+        round_trip(b"0123456789ABCDEF", Version::RectMicro(7, 59), EcLevel::M);
+        round_trip(b"\x82\xa0\x81\x41 rMQR 1234567890", Version::RectMicro(11, 77), EcLevel::H);
+    }
+
+    #[test]
+    fn test_mixed_modes() {
+        round_trip(b"01049123451234591597033130128%10ABC123", Version::Normal(3), EcLevel::M);
+        round_trip(b"\x82\xa0\x81\x41\x41\xb1\x81\xf0 hello 1234567890", Version::Normal(4), EcLevel::Q);
+        round_trip(b"", Version::Normal(1), EcLevel::H);
+    }
+
+    #[test]
+    fn test_eci() {
+        let mut bits = Bits::new(Version::Normal(1));
+        bits.push_eci_designator(9).unwrap();
+        bits.push_byte_data(b"\xa1\xa2\xa3\xa4\xa5").unwrap();
+        bits.push_terminator(EcLevel::L).unwrap();
+        let code = QrCode::with_bits(bits, EcLevel::L).unwrap();
+
+        let decoded = code.decode().unwrap();
+        assert_eq!(decoded.eci, Some(9));
+        assert_eq!(decoded.data, b"\xa1\xa2\xa3\xa4\xa5");
+    }
+
+    #[test]
+    fn test_fnc1() {
+        let mut bits = Bits::new(Version::Normal(2));
+        bits.push_fnc1_first_position().unwrap();
+        bits.push_numeric_data(b"01049123451234591597033130128").unwrap();
+        bits.push_alphanumeric_data(b"%10A%%1").unwrap();
+        bits.push_terminator(EcLevel::L).unwrap();
+        let code = QrCode::with_bits(bits, EcLevel::L).unwrap();
+
+        let decoded = code.decode().unwrap();
+        assert_eq!(decoded.data, b"01049123451234591597033130128\x1d10A%1");
+    }
+
+    #[test]
+    fn test_invalid_dimensions() {
+        assert_eq!(decode(&[Color::Light; 22 * 22], 22), Err(QrError::InvalidVersion));
+        assert_eq!(decode(&[Color::Light; 21 * 20], 21), Err(QrError::InvalidVersion));
+        assert_eq!(decode(&[], 0), Err(QrError::InvalidVersion));
+    }
+
+    /// Overwrites both copies of the version info of a symbol.
+    fn put_version_info(colors: &mut [Color], width: usize, version_info: u32) {
+        for i in 0..18 {
+            let color = if version_info & (1 << (17 - i)) == 0 { Color::Light } else { Color::Dark };
+            let (x, y) = (5 - i / 3, width - 9 - i % 3);
+            colors[y * width + x] = color;
+            colors[x * width + y] = color;
+        }
+    }
+
+    #[test]
+    fn test_version_info() {
+        let code = QrCode::with_version(b"01234567", Version::Normal(7), EcLevel::L).unwrap();
+        let mut colors = code.to_colors();
+
+        // Version 7 with 3 errors in both copies.
+        put_version_info(&mut colors, code.width(), 0x07c94 ^ 0b111);
+        assert_eq!(decode(&colors, code.width()).unwrap().version, Version::Normal(7));
+
+        // Unreadable version info falls back to the dimensions.
+        put_version_info(&mut colors, code.width(), 0x07c94 ^ 0x3ffff);
+        assert_eq!(decode(&colors, code.width()).unwrap().version, Version::Normal(7));
+
+        // Version 8.
+        put_version_info(&mut colors, code.width(), 0x085bc);
+        assert_eq!(decode(&colors, code.width()), Err(QrError::InvalidVersion));
+    }
+
+    #[test]
+    fn test_corrected_data() {
+        let code = QrCode::with_version(b"01234567", Version::Normal(1), EcLevel::L).unwrap();
+        let mut colors = code.to_colors();
+        colors[20 * 21 + 20] = !colors[20 * 21 + 20];
+        let decoded = decode(&colors, 21).unwrap();
+        assert_eq!(decoded.data, b"01234567");
+        assert_eq!(decoded.errors_corrected, 1);
+    }
+
+    #[test]
+    fn test_corrected_data_multiple_blocks() {
+        let data = b"The quick brown fox jumps over the lazy dog. The quick brown fox jumps over the lazy dog.";
+        let code = QrCode::with_version(data, Version::Normal(10), EcLevel::H).unwrap();
+        let mut colors = code.to_colors();
+        for y in 30..38 {
+            for x in 30..38 {
+                colors[y * 57 + x] = !colors[y * 57 + x];
+            }
+        }
+        let decoded = decode(&colors, 57).unwrap();
+        assert_eq!(decoded.data, data);
+        assert!(decoded.errors_corrected > 0);
+    }
+
+    #[test]
+    fn test_corrupted_data() {
+        let code = QrCode::with_version(b"01234567", Version::Normal(1), EcLevel::L).unwrap();
+        let mut colors = code.to_colors();
+        for y in 9..21 {
+            colors[y * 21 + 20] = !colors[y * 21 + 20];
+            colors[y * 21 + 19] = !colors[y * 21 + 19];
+        }
+        assert_eq!(decode(&colors, 21), Err(QrError::CorruptedData));
+    }
+
+    #[test]
+    fn test_missing_format_info() {
+        let colors = [Color::Light; 21 * 21];
+        assert_eq!(decode(&colors, 21), Err(QrError::InvalidFormatInfo));
+    }
+}
+
+//}}}
+//------------------------------------------------------------------------------
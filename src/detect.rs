@@ -0,0 +1,718 @@
+//! The `detect` module locates a QR code symbol in a photograph or a scan, and
+//! samples its modules for the decoder.
+//!
+//! ```
+//! use image::Luma;
+//! use qrcode::decode::decode;
+//! use qrcode::detect::detect;
+//! use qrcode::QrCode;
+//!
+//! let code = QrCode::new(b"Some data").unwrap();
+//! let image = code.render::<Luma<u8>>().build();
+//!
+//! let detected = detect(&image).unwrap();
+//! let decoded = decode(&detected.colors, detected.width).unwrap();
+//! assert_eq!(decoded.data, b"Some data");
+//! ```
+//!
+//! Only normal QR codes are detected. Micro QR codes have a single finder
+//! pattern, which is not enough to locate the symbol this way.
+
+#![cfg(feature = "image")]
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use image::GrayImage;
+
+use crate::canvas;
+use crate::cast::As;
+use crate::types::{Color, QrError, QrResult, Version};
+
+//------------------------------------------------------------------------------
+//{{{ Numeric helpers
+
+#[allow(clippy::cast_precision_loss)]
+fn to_f64(n: usize) -> f64 {
+    n as f64
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn floor_to_isize(f: f64) -> isize {
+    f.floor() as isize
+}
+
+/// A point in image coordinates, where the pixel (x, y) covers the square
+/// from (x, y) to (x+1, y+1).
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+impl Point {
+    fn distance(self, other: Self) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Binarization
+
+/// A black-and-white image.
+struct BitMatrix {
+    width: usize,
+    height: usize,
+    dark: Vec<bool>,
+}
+
+impl BitMatrix {
+    /// Checks whether the pixel is dark. Returns `None` if the coordinates are
+    /// outside of the image.
+    fn get(&self, x: isize, y: isize) -> Option<bool> {
+        let x = usize::try_from(x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(y).ok().filter(|y| *y < self.height)?;
+        Some(self.dark[y * self.width + x])
+    }
+}
+
+/// Computes a global threshold with Otsu's method.
+fn otsu_threshold(pixels: &[u8]) -> u8 {
+    let mut histogram = [0_usize; 256];
+    for p in pixels {
+        histogram[usize::from(*p)] += 1;
+    }
+
+    let total = to_f64(pixels.len());
+    let sum_all = histogram.iter().enumerate().map(|(i, n)| to_f64(i * n)).sum::<f64>();
+    let mut sum_below = 0.0;
+    let mut count_below = 0;
+    let mut best = (0.0, 0_u8);
+    for (threshold, n) in (0_u8..=255).zip(histogram) {
+        count_below += n;
+        sum_below += f64::from(threshold) * to_f64(n);
+        let count_above = pixels.len() - count_below;
+        if count_below == 0 || count_above == 0 {
+            continue;
+        }
+        let mean_below = sum_below / to_f64(count_below);
+        let mean_above = (sum_all - sum_below) / to_f64(count_above);
+        let variance = to_f64(count_below) * to_f64(count_above) * (mean_below - mean_above).powi(2) / total;
+        if variance > best.0 {
+            best = (variance, threshold);
+        }
+    }
+    best.1
+}
+
+/// Converts a grayscale image to black and white.
+///
+/// Each pixel is compared with the mean of its neighborhood, so that uneven
+/// lighting is tolerated. Where the neighborhood is too flat to tell, a global
+/// threshold is used instead.
+fn binarize(image: &GrayImage) -> BitMatrix {
+    let width = image.width().as_usize();
+    let height = image.height().as_usize();
+    let pixels = image.as_raw();
+    let global_threshold = otsu_threshold(pixels);
+
+    // Summed-area tables of the pixel values and their squares.
+    let stride = width + 1;
+    let mut sums = vec![0_u64; stride * (height + 1)];
+    let mut square_sums = vec![0_u64; stride * (height + 1)];
+    for y in 0..height {
+        let (mut row_sum, mut row_square_sum) = (0, 0);
+        for x in 0..width {
+            let p = u64::from(pixels[y * width + x]);
+            row_sum += p;
+            row_square_sum += p * p;
+            sums[(y + 1) * stride + x + 1] = sums[y * stride + x + 1] + row_sum;
+            square_sums[(y + 1) * stride + x + 1] = square_sums[y * stride + x + 1] + row_square_sum;
+        }
+    }
+
+    let radius = (width.max(height) / 16).max(8);
+    let area_sum = |table: &[u64], x0: usize, y0: usize, x1: usize, y1: usize| {
+        table[y1 * stride + x1] + table[y0 * stride + x0] - table[y0 * stride + x1] - table[y1 * stride + x0]
+    };
+
+    let mut dark = Vec::with_capacity(width * height);
+    for y in 0..height {
+        let (y0, y1) = (y.saturating_sub(radius), (y + radius + 1).min(height));
+        for x in 0..width {
+            let (x0, x1) = (x.saturating_sub(radius), (x + radius + 1).min(width));
+            let count = to_f64((x1 - x0) * (y1 - y0));
+            #[allow(clippy::cast_precision_loss)]
+            let mean = area_sum(&sums, x0, y0, x1, y1) as f64 / count;
+            #[allow(clippy::cast_precision_loss)]
+            let variance = area_sum(&square_sums, x0, y0, x1, y1) as f64 / count - mean * mean;
+
+            let p = pixels[y * width + x];
+            dark.push(if variance < 256.0 { p <= global_threshold } else { f64::from(p) < mean });
+        }
+    }
+
+    BitMatrix { width, height, dark }
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Pattern scanning
+
+/// The run lengths of a finder pattern (dark-light-dark-light-dark) crossing
+/// its center.
+static FINDER_RATIO: [usize; 5] = [1, 1, 3, 1, 1];
+
+/// Checks whether the run lengths are proportional to `ratio`. Each run may
+/// deviate by half of its expected length.
+fn matches_ratio(runs: &[usize], ratio: &[usize]) -> bool {
+    let total = runs.iter().sum::<usize>();
+    let units = ratio.iter().sum::<usize>();
+    if total < units {
+        return false;
+    }
+    let module_size = to_f64(total) / to_f64(units);
+    runs.iter()
+        .zip(ratio)
+        .all(|(run, r)| (to_f64(*run) - module_size * to_f64(*r)).abs() < module_size * to_f64(*r) / 2.0)
+}
+
+/// Measures the `count` alternating runs of a pattern along the direction
+/// `(dx, dy)`, where the dark pixel `(x, y)` belongs to the middle run.
+///
+/// Returns the run lengths, and the offset from `(x, y)` to the center of the
+/// middle run. Returns `None` if some runs are missing.
+fn measure_runs(
+    bits: &BitMatrix,
+    x: isize,
+    y: isize,
+    (dx, dy): (isize, isize),
+    count: usize,
+) -> Option<(Vec<usize>, f64)> {
+    let half = count / 2;
+    let walk = |sign: isize| {
+        let mut runs = vec![0_usize; half + 1];
+        let mut index = 0;
+        let mut t = 0;
+        while let Some(dark) = bits.get(x + sign * t * dx, y + sign * t * dy) {
+            if dark != (index % 2 == 0) {
+                index += 1;
+                if index > half {
+                    break;
+                }
+            }
+            runs[index] += 1;
+            t += 1;
+        }
+        runs
+    };
+
+    let backward = walk(-1);
+    let forward = walk(1);
+    if backward.iter().chain(&forward).any(|run| *run == 0) {
+        return None;
+    }
+
+    let mut runs = backward[1..].iter().rev().copied().collect::<Vec<_>>();
+    runs.push(backward[0] + forward[0] - 1);
+    runs.extend_from_slice(&forward[1..]);
+    let offset = (to_f64(forward[0]) - to_f64(backward[0])) / 2.0;
+    Some((runs, offset))
+}
+
+/// A run of pixels of the same color in a row.
+struct Run {
+    start: usize,
+    len: usize,
+    dark: bool,
+}
+
+/// Splits the row `y` between `x0` and `x1` into runs.
+fn row_runs(bits: &BitMatrix, y: usize, x0: usize, x1: usize) -> Vec<Run> {
+    let row = &bits.dark[y * bits.width..][x0..x1];
+    let mut runs = Vec::<Run>::new();
+    for (i, dark) in row.iter().enumerate() {
+        match runs.last_mut() {
+            Some(run) if run.dark == *dark => run.len += 1,
+            _ => runs.push(Run { start: x0 + i, len: 1, dark: *dark }),
+        }
+    }
+    runs
+}
+
+/// A possible center of a pattern, with the number of times it was found.
+#[derive(Debug, Copy, Clone)]
+struct Candidate {
+    center: Point,
+    module_size: f64,
+    count: usize,
+}
+
+/// Adds a found pattern to the candidates, merging it with an existing
+/// candidate at the same location.
+fn add_candidate(candidates: &mut Vec<Candidate>, center: Point, module_size: f64) {
+    let existing = candidates.iter_mut().find(|c| {
+        (c.center.x - center.x).abs() <= module_size
+            && (c.center.y - center.y).abs() <= module_size
+            && (c.module_size - module_size).abs() <= c.module_size / 2.0
+    });
+    if let Some(c) = existing {
+        let n = to_f64(c.count);
+        c.center.x = (c.center.x * n + center.x) / (n + 1.0);
+        c.center.y = (c.center.y * n + center.y) / (n + 1.0);
+        c.module_size = (c.module_size * n + module_size) / (n + 1.0);
+        c.count += 1;
+    } else {
+        candidates.push(Candidate { center, module_size, count: 1 });
+    }
+}
+
+/// Confirms a pattern found in a row by measuring it vertically, then
+/// horizontally again through the refined center. Returns the center and the
+/// module size.
+fn cross_check(bits: &BitMatrix, x: isize, y: isize, ratio: &[usize]) -> Option<(Point, f64)> {
+    let (vertical, dy) = measure_runs(bits, x, y, (0, 1), ratio.len())?;
+    if !matches_ratio(&vertical, ratio) {
+        return None;
+    }
+    let center_y = to_f64(y.as_usize()) + dy + 0.5;
+
+    let y = floor_to_isize(center_y);
+    let (horizontal, dx) = measure_runs(bits, x, y, (1, 0), ratio.len())?;
+    if !matches_ratio(&horizontal, ratio) {
+        return None;
+    }
+    let center_x = to_f64(x.as_usize()) + dx + 0.5;
+
+    let units = to_f64(ratio.iter().sum());
+    let module_size = (to_f64(vertical.iter().sum()) + to_f64(horizontal.iter().sum())) / (2.0 * units);
+    Some((Point { x: center_x, y: center_y }, module_size))
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Finder patterns
+
+/// Scans the whole image for finder patterns.
+fn find_finder_candidates(bits: &BitMatrix) -> Vec<Candidate> {
+    let mut candidates = Vec::new();
+    for y in 0..bits.height {
+        let runs = row_runs(bits, y, 0, bits.width);
+        for window in runs.windows(5) {
+            if !window[0].dark {
+                continue;
+            }
+            let lengths = window.iter().map(|run| run.len).collect::<Vec<_>>();
+            if !matches_ratio(&lengths, &FINDER_RATIO) {
+                continue;
+            }
+            let x = window[2].start + window[2].len / 2;
+            if let Some((center, module_size)) = cross_check(bits, x.as_isize(), y.as_isize(), &FINDER_RATIO) {
+                add_candidate(&mut candidates, center, module_size);
+            }
+        }
+    }
+    candidates
+}
+
+/// Chooses the three finder patterns forming a symbol, and returns them as
+/// top-left, top-right and bottom-left.
+fn select_finder_patterns(mut candidates: Vec<Candidate>) -> Option<[Candidate; 3]> {
+    candidates.sort_by_key(|c| core::cmp::Reverse(c.count));
+    candidates.truncate(8);
+
+    let mut best: Option<(f64, [Candidate; 3])> = None;
+    for i in 0..candidates.len() {
+        for j in i + 1..candidates.len() {
+            for k in j + 1..candidates.len() {
+                let triple = [candidates[i], candidates[j], candidates[k]];
+                if let Some(score) = score_triple(&triple) {
+                    if best.map_or(true, |(s, _)| score < s) {
+                        best = Some((score, triple));
+                    }
+                }
+            }
+        }
+    }
+    let [a, b, c] = best?.1;
+
+    // The top-left pattern is opposite to the longest side.
+    let (ab, bc, ca) = (a.center.distance(b.center), b.center.distance(c.center), c.center.distance(a.center));
+    let (top_left, mut top_right, mut bottom_left) = if bc >= ab && bc >= ca {
+        (a, b, c)
+    } else if ca >= ab {
+        (b, c, a)
+    } else {
+        (c, a, b)
+    };
+
+    // Ensure the symbol is not mirrored, remembering that y points down.
+    let (ux, uy) = (top_right.center.x - top_left.center.x, top_right.center.y - top_left.center.y);
+    let (vx, vy) = (bottom_left.center.x - top_left.center.x, bottom_left.center.y - top_left.center.y);
+    if ux * vy - uy * vx < 0.0 {
+        core::mem::swap(&mut top_right, &mut bottom_left);
+    }
+
+    Some([top_left, top_right, bottom_left])
+}
+
+/// Measures how far three patterns are from forming an isosceles right
+/// triangle with similar module sizes. Lower is better.
+fn score_triple(triple: &[Candidate; 3]) -> Option<f64> {
+    let sizes = triple.map(|c| c.module_size);
+    let max_size = sizes.iter().copied().fold(0.0, f64::max);
+    let min_size = sizes.iter().copied().fold(f64::INFINITY, f64::min);
+    if min_size < max_size / 2.0 {
+        return None;
+    }
+
+    let mut distances = [
+        triple[0].center.distance(triple[1].center),
+        triple[1].center.distance(triple[2].center),
+        triple[2].center.distance(triple[0].center),
+    ];
+    distances.sort_by(f64::total_cmp);
+    let [a, b, c] = distances;
+
+    // Finder pattern centers are at least 14 modules apart.
+    if a < 10.0 * min_size {
+        return None;
+    }
+    let score = (b - a) / b + (c - a.hypot(b)).abs() / c;
+    (score < 0.5).then_some(score)
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Alignment patterns
+
+/// The run lengths of an alignment pattern (light-dark-light) crossing its
+/// center.
+static ALIGNMENT_RATIO: [usize; 3] = [1, 1, 1];
+
+/// Searches for an alignment pattern around the estimated location, within
+/// `allowance` modules.
+fn find_alignment_pattern(bits: &BitMatrix, estimate: Point, module_size: f64, allowance: f64) -> Option<Point> {
+    let radius = allowance * module_size;
+    let clamp = |v: f64, limit: usize| floor_to_isize(v).clamp(0, limit.as_isize()).as_usize();
+    let (x0, x1) = (clamp(estimate.x - radius, bits.width), clamp(estimate.x + radius, bits.width));
+    let (y0, y1) = (clamp(estimate.y - radius, bits.height), clamp(estimate.y + radius, bits.height));
+    if x1 - x0 < 3 || y1 <= y0 {
+        return None;
+    }
+
+    let is_module_sized = |run: usize| (to_f64(run) - module_size).abs() < module_size / 2.0;
+
+    let mut candidates = Vec::new();
+    for y in y0..y1 {
+        let runs = row_runs(bits, y, x0, x1);
+        for window in runs.windows(3) {
+            if !window[1].dark || !window.iter().all(|run| is_module_sized(run.len)) {
+                continue;
+            }
+            let x = window[1].start + window[1].len / 2;
+            if let Some((center, size)) = cross_check(bits, x.as_isize(), y.as_isize(), &ALIGNMENT_RATIO) {
+                if (size - module_size).abs() < module_size / 2.0 {
+                    add_candidate(&mut candidates, center, size);
+                }
+            }
+        }
+    }
+
+    candidates
+        .into_iter()
+        .max_by(|a, b| a.count.cmp(&b.count).then(b.center.distance(estimate).total_cmp(&a.center.distance(estimate))))
+        .map(|c| c.center)
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Perspective transform
+
+/// A projective mapping between two planes, as a 3×3 matrix acting on
+/// homogeneous coordinates.
+#[derive(Debug, Copy, Clone)]
+struct PerspectiveTransform([[f64; 3]; 3]);
+
+impl PerspectiveTransform {
+    /// Creates the mapping from the unit square to a quadrilateral. The
+    /// corners (0, 0), (1, 0), (1, 1) and (0, 1) are mapped to `p[0]` to
+    /// `p[3]` respectively.
+    fn square_to_quadrilateral(p: [Point; 4]) -> Self {
+        let dx3 = p[0].x - p[1].x + p[2].x - p[3].x;
+        let dy3 = p[0].y - p[1].y + p[2].y - p[3].y;
+        if dx3 == 0.0 && dy3 == 0.0 {
+            return Self([
+                [p[1].x - p[0].x, p[2].x - p[1].x, p[0].x],
+                [p[1].y - p[0].y, p[2].y - p[1].y, p[0].y],
+                [0.0, 0.0, 1.0],
+            ]);
+        }
+
+        let (dx1, dx2) = (p[1].x - p[2].x, p[3].x - p[2].x);
+        let (dy1, dy2) = (p[1].y - p[2].y, p[3].y - p[2].y);
+        let denominator = dx1 * dy2 - dx2 * dy1;
+        let g = (dx3 * dy2 - dx2 * dy3) / denominator;
+        let h = (dx1 * dy3 - dx3 * dy1) / denominator;
+        Self([
+            [p[1].x - p[0].x + g * p[1].x, p[3].x - p[0].x + h * p[3].x, p[0].x],
+            [p[1].y - p[0].y + g * p[1].y, p[3].y - p[0].y + h * p[3].y, p[0].y],
+            [g, h, 1.0],
+        ])
+    }
+
+    /// Creates the mapping from the quadrilateral `from` to the quadrilateral
+    /// `to`.
+    fn quadrilateral_to_quadrilateral(from: [Point; 4], to: [Point; 4]) -> Self {
+        Self::square_to_quadrilateral(to).compose(&Self::square_to_quadrilateral(from).adjugate())
+    }
+
+    /// Computes the adjugate matrix, which is the inverse mapping since
+    /// homogeneous coordinates are invariant under scaling.
+    fn adjugate(&self) -> Self {
+        let m = &self.0;
+        let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+        Self([
+            [cofactor(1, 2, 1, 2), -cofactor(0, 2, 1, 2), cofactor(0, 1, 1, 2)],
+            [-cofactor(1, 2, 0, 2), cofactor(0, 2, 0, 2), -cofactor(0, 1, 0, 2)],
+            [cofactor(1, 2, 0, 1), -cofactor(0, 2, 0, 1), cofactor(0, 1, 0, 1)],
+        ])
+    }
+
+    /// Computes the mapping which applies `other` first, then `self`.
+    fn compose(&self, other: &Self) -> Self {
+        let mut result = [[0.0; 3]; 3];
+        for (i, row) in result.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = (0..3).map(|k| self.0[i][k] * other.0[k][j]).sum();
+            }
+        }
+        Self(result)
+    }
+
+    fn apply(&self, p: Point) -> Point {
+        let m = &self.0;
+        let w = m[2][0] * p.x + m[2][1] * p.y + m[2][2];
+        Point { x: (m[0][0] * p.x + m[0][1] * p.y + m[0][2]) / w, y: (m[1][0] * p.x + m[1][1] * p.y + m[1][2]) / w }
+    }
+}
+
+#[cfg(test)]
+mod perspective_transform_tests {
+    use crate::detect::{PerspectiveTransform, Point};
+
+    fn assert_close(a: Point, b: Point) {
+        assert!(a.distance(b) < 1e-6, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn test_quadrilateral_to_quadrilateral() {
+        let from = [
+            Point { x: 3.5, y: 3.5 },
+            Point { x: 21.5, y: 3.5 },
+            Point { x: 18.5, y: 18.5 },
+            Point { x: 3.5, y: 21.5 },
+        ];
+        let to = [
+            Point { x: 10.0, y: 20.0 },
+            Point { x: 200.0, y: 35.0 },
+            Point { x: 180.0, y: 170.0 },
+            Point { x: 25.0, y: 230.0 },
+        ];
+        let transform = PerspectiveTransform::quadrilateral_to_quadrilateral(from, to);
+        for (f, t) in from.into_iter().zip(to) {
+            assert_close(transform.apply(f), t);
+        }
+    }
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Detection
+
+/// A QR code symbol located in an image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Detected {
+    /// The version of the symbol.
+    pub version: Version,
+
+    /// The number of modules per side.
+    pub width: usize,
+
+    /// The sampled module colors, in left-to-right, then top-to-bottom order.
+    /// This can be passed to `decode::decode()`.
+    pub colors: Vec<Color>,
+}
+
+/// Samples the center of every module of a symbol of the given width.
+fn sample_grid(bits: &BitMatrix, transform: &PerspectiveTransform, width: usize) -> Vec<Color> {
+    let mut colors = Vec::with_capacity(width * width);
+    for y in 0..width {
+        for x in 0..width {
+            let p = transform.apply(Point { x: to_f64(x) + 0.5, y: to_f64(y) + 0.5 });
+            let dark = bits.get(floor_to_isize(p.x), floor_to_isize(p.y)).unwrap_or(false);
+            colors.push(if dark { Color::Dark } else { Color::Light });
+        }
+    }
+    colors
+}
+
+/// Locates a QR code symbol in a grayscale image, and samples its modules.
+///
+/// The image is converted to black and white, then scanned for the three
+/// finder patterns. The version is estimated from the distance between them
+/// (or read from the version info when present), and the bottom-right
+/// alignment pattern is used to correct for perspective distortion.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::SymbolNotFound)` if no QR code symbol can be located.
+pub fn detect(image: &GrayImage) -> QrResult<Detected> {
+    let bits = binarize(image);
+    let [top_left, top_right, bottom_left] =
+        select_finder_patterns(find_finder_candidates(&bits)).ok_or(QrError::SymbolNotFound)?;
+    let (tl, tr, bl) = (top_left.center, top_right.center, bottom_left.center);
+
+    let module_size = (top_left.module_size + top_right.module_size + bottom_left.module_size) / 3.0;
+    let modules_between = (tl.distance(tr) + tl.distance(bl)) / (2.0 * module_size);
+    let mut version = floor_to_isize((modules_between - 10.0) / 4.0 + 0.5);
+    if !(1..=40).contains(&version) {
+        return Err(QrError::SymbolNotFound);
+    }
+
+    // The bottom-right corner if the symbol is a parallelogram.
+    let br = Point { x: tr.x + bl.x - tl.x, y: tr.y + bl.y - tl.y };
+    let transform_for = |width: f64, br_module: f64, br: Point| {
+        PerspectiveTransform::quadrilateral_to_quadrilateral(
+            [
+                Point { x: 3.5, y: 3.5 },
+                Point { x: width - 3.5, y: 3.5 },
+                Point { x: br_module, y: br_module },
+                Point { x: 3.5, y: width - 3.5 },
+            ],
+            [tl, tr, br, bl],
+        )
+    };
+
+    if version >= 7 {
+        let v = Version::Normal(version.as_i16());
+        let width = v.width().as_usize();
+        let colors = sample_grid(&bits, &transform_for(to_f64(width), to_f64(width) - 3.5, br), width);
+        if let Some(Version::Normal(v)) = canvas::read_version_info(v, &colors) {
+            version = v.as_isize();
+        }
+    }
+
+    let version = Version::Normal(version.as_i16());
+    let width = version.width().as_usize();
+    let width_f = to_f64(width);
+
+    let mut transform = transform_for(width_f, width_f - 3.5, br);
+    if width > 21 {
+        // The bottom-right alignment pattern is 3 modules inwards from the
+        // finder pattern centers.
+        let correction = 1.0 - 3.0 / (width_f - 7.0);
+        let estimate = Point { x: tl.x + correction * (br.x - tl.x), y: tl.y + correction * (br.y - tl.y) };
+        let alignment = [4.0, 8.0, 16.0]
+            .into_iter()
+            .find_map(|allowance| find_alignment_pattern(&bits, estimate, module_size, allowance));
+        if let Some(alignment) = alignment {
+            transform = transform_for(width_f, width_f - 6.5, alignment);
+        }
+    }
+
+    Ok(Detected { version, width, colors: sample_grid(&bits, &transform, width) })
+}
+
+#[cfg(test)]
+mod detect_tests {
+    use crate::decode::decode;
+    use crate::detect::{detect, PerspectiveTransform, Point};
+    use crate::types::{EcLevel, QrError, Version};
+    use crate::QrCode;
+    use image::{GrayImage, Luma};
+
+    fn detect_and_decode(image: &GrayImage) -> Vec<u8> {
+        let detected = detect(image).unwrap();
+        decode(&detected.colors, detected.width).unwrap().data
+    }
+
+    /// Warps the image so that its corners land on `corners`, with a gradient
+    /// of lighting from left to right.
+    fn warp(image: &GrayImage, corners: [Point; 4], size: u32) -> GrayImage {
+        let (w, h) = (f64::from(image.width()), f64::from(image.height()));
+        let source = [Point { x: 0.0, y: 0.0 }, Point { x: w, y: 0.0 }, Point { x: w, y: h }, Point { x: 0.0, y: h }];
+        let inverse = PerspectiveTransform::quadrilateral_to_quadrilateral(corners, source);
+        GrayImage::from_fn(size, size, |x, y| {
+            let p = inverse.apply(Point { x: f64::from(x) + 0.5, y: f64::from(y) + 0.5 });
+            let shade = u8::try_from(x * 60 / size).unwrap();
+            let value = if p.x >= 0.0 && p.y >= 0.0 && p.x < w && p.y < h {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let (px, py) = (p.x as u32, p.y as u32);
+                image.get_pixel(px, py).0[0]
+            } else {
+                255
+            };
+            Luma([value.saturating_sub(shade).max(30)])
+        })
+    }
+
+    #[test]
+    fn test_detect_rendered() {
+        let code = QrCode::new(b"https://example.com/").unwrap();
+        let image = code.render::<Luma<u8>>().build();
+        let detected = detect(&image).unwrap();
+        assert_eq!(detected.version, code.version());
+        assert_eq!(detected.colors, code.to_colors());
+    }
+
+    #[test]
+    fn test_detect_version_1() {
+        let code = QrCode::with_version(b"01234567", Version::Normal(1), EcLevel::H).unwrap();
+        let image = code.render::<Luma<u8>>().module_dimensions(3, 3).build();
+        assert_eq!(detect_and_decode(&image), b"01234567");
+    }
+
+    #[test]
+    fn test_detect_large_version() {
+        let data = "QR code detection test. ".repeat(15);
+        let code = QrCode::with_version(data.as_bytes(), Version::Normal(15), EcLevel::M).unwrap();
+        let image = code.render::<Luma<u8>>().module_dimensions(4, 4).build();
+        let detected = detect(&image).unwrap();
+        assert_eq!(detected.version, Version::Normal(15));
+        assert_eq!(decode(&detected.colors, detected.width).unwrap().data, data.as_bytes());
+    }
+
+    #[test]
+    fn test_detect_rotated() {
+        let code = QrCode::new(b"rotated").unwrap();
+        let image = code.render::<Luma<u8>>().build();
+        let rotated = image::imageops::rotate90(&image);
+        assert_eq!(detect_and_decode(&rotated), b"rotated");
+        let rotated = image::imageops::rotate180(&image);
+        assert_eq!(detect_and_decode(&rotated), b"rotated");
+    }
+
+    #[test]
+    fn test_detect_perspective() {
+        let code = QrCode::with_version(b"Hello, perspective!", Version::Normal(3), EcLevel::M).unwrap();
+        let image = code.render::<Luma<u8>>().module_dimensions(10, 10).build();
+        let corners = [
+            Point { x: 60.0, y: 40.0 },
+            Point { x: 520.0, y: 90.0 },
+            Point { x: 560.0, y: 560.0 },
+            Point { x: 30.0, y: 500.0 },
+        ];
+        let warped = warp(&image, corners, 600);
+        assert_eq!(detect_and_decode(&warped), b"Hello, perspective!");
+    }
+
+    #[test]
+    fn test_no_symbol() {
+        let image = GrayImage::from_pixel(100, 100, Luma([255]));
+        assert_eq!(detect(&image), Err(QrError::SymbolNotFound));
+    }
+}
+
+//}}}
@@ -1,9 +1,10 @@
 //! The `ec` module applies the Reed-Solomon error correction codes.
 
+use alloc::vec;
 use alloc::vec::Vec;
 use core::ops::Deref;
 
-use crate::types::{EcLevel, QrResult, Version};
+use crate::types::{EcLevel, QrError, QrResult, Version};
 
 //------------------------------------------------------------------------------
 //{{{ Error correction primitive
@@ -63,6 +64,244 @@ mod ec_tests {
     }
 }
 
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Error correction decoding
+
+/// Multiplies two elements of GF(256).
+fn gf_mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    EXP_TABLE[(usize::from(LOG_TABLE[usize::from(a)]) + usize::from(LOG_TABLE[usize::from(b)])) % 255]
+}
+
+/// Computes the multiplicative inverse of a non-zero element of GF(256).
+fn gf_inv(a: u8) -> u8 {
+    debug_assert_ne!(a, 0);
+    EXP_TABLE[(255 - usize::from(LOG_TABLE[usize::from(a)])) % 255]
+}
+
+/// Evaluates a polynomial, stored with the constant term first, at `x`.
+fn poly_eval(poly: &[u8], x: u8) -> u8 {
+    poly.iter().rev().fold(0, |acc, coeff| gf_mul(acc, x) ^ coeff)
+}
+
+/// Computes the syndromes S<sub>i</sub> = r(2<sup>i</sup>) of a received
+/// block, for i = 0, 1, …, `ec_code_size` − 1.
+fn syndromes(block: &[u8], ec_code_size: usize) -> Vec<u8> {
+    (0..ec_code_size)
+        .map(|i| {
+            let x = EXP_TABLE[i % 255];
+            block.iter().fold(0, |acc, coeff| gf_mul(acc, x) ^ coeff)
+        })
+        .collect()
+}
+
+/// Corrects the errors in a block of data followed by its `ec_code_size`
+/// bytes of error correction code, in place.
+///
+/// The `erasures` are the indices of bytes in `block` known to be unreliable.
+/// A block can be repaired if twice the number of errors plus the number of
+/// erasures does not exceed `ec_code_size`.
+///
+/// Uses the Berlekamp-Massey algorithm to find the error locator, and the
+/// Forney algorithm to compute the error values. Returns the number of bytes
+/// which were changed.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::CorruptedData)` if the block has too many errors to
+/// be corrected. The block is left unchanged in this case.
+///
+/// # Panics
+///
+/// Panics if an erasure index is out of bounds of `block`.
+pub fn correct_errors(block: &mut [u8], ec_code_size: usize, erasures: &[usize]) -> QrResult<usize> {
+    let n = block.len();
+    assert!(erasures.iter().all(|&e| e < n), "erasure index out of bounds");
+
+    let synd = syndromes(block, ec_code_size);
+    if synd.iter().all(|s| *s == 0) {
+        return Ok(0);
+    }
+    if erasures.len() > ec_code_size || n > 255 {
+        return Err(QrError::CorruptedData);
+    }
+
+    // The byte at index `j` is the coefficient of x^(n-1-j), and thus is
+    // located by 2^(n-1-j).
+    let locator_of = |j: usize| EXP_TABLE[n - 1 - j];
+
+    // Erasure locator Γ(x) = ∏ (1 - X_k x), which seeds the error locator.
+    let mut lambda = vec![1_u8];
+    for &e in erasures {
+        let x = locator_of(e);
+        let mut next = lambda.clone();
+        next.push(0);
+        for (i, coeff) in lambda.iter().enumerate() {
+            next[i + 1] ^= gf_mul(*coeff, x);
+        }
+        lambda = next;
+    }
+
+    // Berlekamp-Massey, extended to errors and erasures.
+    let f = erasures.len();
+    let mut prev = lambda.clone();
+    let mut l = f;
+    for r in f + 1..=ec_code_size {
+        let delta = lambda
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j < r)
+            .fold(0, |acc, (j, coeff)| acc ^ gf_mul(*coeff, synd[r - 1 - j]));
+
+        // prev ← x · prev
+        prev.insert(0, 0);
+        if delta == 0 {
+            continue;
+        }
+
+        let mut next = lambda.clone();
+        next.resize(next.len().max(prev.len()), 0);
+        for (i, coeff) in prev.iter().enumerate() {
+            next[i] ^= gf_mul(*coeff, delta);
+        }
+
+        if 2 * l < r + f {
+            let delta_inv = gf_inv(delta);
+            prev = lambda.iter().map(|coeff| gf_mul(*coeff, delta_inv)).collect();
+            l = r + f - l;
+        }
+        lambda = next;
+    }
+
+    while lambda.last() == Some(&0) {
+        lambda.pop();
+    }
+    if lambda.len() != l + 1 || 2 * (l - f) + f > ec_code_size {
+        return Err(QrError::CorruptedData);
+    }
+
+    // Chien search: the byte at `j` is erroneous if Λ(X_j⁻¹) = 0.
+    let positions = (0..n).filter(|&j| poly_eval(&lambda, gf_inv(locator_of(j))) == 0).collect::<Vec<_>>();
+    if positions.len() != l {
+        return Err(QrError::CorruptedData);
+    }
+
+    // Error evaluator Ω(x) = S(x) Λ(x) mod x^ec_code_size.
+    let mut omega = vec![0_u8; ec_code_size];
+    for (i, s) in synd.iter().enumerate() {
+        for (j, coeff) in lambda.iter().enumerate().take(ec_code_size - i) {
+            omega[i + j] ^= gf_mul(*s, *coeff);
+        }
+    }
+
+    // Formal derivative Λ'(x); only the odd terms survive in GF(2^8).
+    let lambda_prime =
+        lambda.iter().enumerate().skip(1).map(|(i, coeff)| if i % 2 == 1 { *coeff } else { 0 }).collect::<Vec<_>>();
+
+    // Forney: e_k = X_k Ω(X_k⁻¹) / Λ'(X_k⁻¹), as the first root of the
+    // generator polynomial is 2^0. The errors are corrected on a copy, which
+    // is only written back once it is verified.
+    let mut fixed = block.to_vec();
+    let mut corrected = 0;
+    for j in positions {
+        let x = locator_of(j);
+        let x_inv = gf_inv(x);
+        let denominator = poly_eval(&lambda_prime, x_inv);
+        if denominator == 0 {
+            return Err(QrError::CorruptedData);
+        }
+        let magnitude = gf_mul(gf_mul(x, poly_eval(&omega, x_inv)), gf_inv(denominator));
+        if magnitude != 0 {
+            fixed[j] ^= magnitude;
+            corrected += 1;
+        }
+    }
+
+    if syndromes(&fixed, ec_code_size).iter().any(|s| *s != 0) {
+        return Err(QrError::CorruptedData);
+    }
+    block.copy_from_slice(&fixed);
+    Ok(corrected)
+}
+
+#[cfg(test)]
+mod correct_errors_tests {
+    use crate::ec::{correct_errors, create_error_correction_code};
+    use crate::types::QrError;
+    use alloc::vec::Vec;
+
+    fn create_block(data: &[u8], ec_code_size: usize) -> Vec<u8> {
+        let mut block = data.to_vec();
+        block.extend(create_error_correction_code(data, ec_code_size));
+        block
+    }
+
+    #[test]
+    fn test_no_errors() {
+        let mut block = create_block(b" [\x0bx\xd1r\xdcMC@\xec\x11\xec\x11\xec\x11", 10);
+        let original = block.clone();
+        assert_eq!(correct_errors(&mut block, 10, &[]), Ok(0));
+        assert_eq!(block, original);
+    }
+
+    #[test]
+    fn test_errors() {
+        let original = create_block(b"CUF\x86W&U\xc2w2\x06\x12\x06g&", 18);
+        for count in 1..=9 {
+            let mut block = original.clone();
+            for k in 0..count {
+                block[k * 3 + 1] ^= 0x5a ^ (1 << (k % 8));
+            }
+            assert_eq!(correct_errors(&mut block, 18, &[]), Ok(count));
+            assert_eq!(block, original);
+        }
+    }
+
+    #[test]
+    fn test_erasures() {
+        let original = create_block(b"CUF\x86W&U\xc2w2\x06\x12\x06g&", 18);
+        let mut block = original.clone();
+        let erasures = (0..18).map(|k| k + 10).collect::<Vec<_>>();
+        for &e in &erasures {
+            block[e] = 0;
+        }
+        let changed = erasures.iter().filter(|&&e| original[e] != 0).count();
+        assert_eq!(correct_errors(&mut block, 18, &erasures), Ok(changed));
+        assert_eq!(block, original);
+    }
+
+    #[test]
+    fn test_errors_and_erasures() {
+        let original = create_block(b" [\x0bx\xd1r\xdcMC@\xec\x11\xec", 13);
+        let mut block = original.clone();
+        block[0] ^= 0xff;
+        block[5] ^= 0x01;
+        block[12] ^= 0x80;
+        block[3] ^= 0x33;
+        block[20] ^= 0x44;
+        assert_eq!(correct_errors(&mut block, 13, &[3, 20, 22]), Ok(5));
+        assert_eq!(block, original);
+    }
+
+    #[test]
+    fn test_too_many_errors() {
+        let mut block = create_block(b" [\x0bx\xd1r\xdcMC@\xec\x11\xec", 13);
+        for k in 0..8 {
+            block[k * 2] ^= 0x11;
+        }
+        let corrupted = block.clone();
+        assert_eq!(correct_errors(&mut block, 13, &[]), Err(QrError::CorruptedData));
+        assert_eq!(block, corrupted);
+
+        let erasures = (0..14).collect::<Vec<_>>();
+        assert_eq!(correct_errors(&mut block, 13, &erasures), Err(QrError::CorruptedData));
+        assert_eq!(block, corrupted);
+    }
+}
+
 //}}}
 //------------------------------------------------------------------------------
 //{{{ Interleave support
@@ -93,6 +332,32 @@ fn test_interleave() {
     assert_eq!(&*res, b"15ag26bh37ci48djekfl");
 }
 
+/// This method splits an interleaved slice back into blocks of the given
+/// lengths. It is the inverse of `interleave()`.
+///
+/// The lengths must be sorted in ascending order.
+fn deinterleave<T: Copy>(data: &[T], lengths: &[usize]) -> Vec<Vec<T>> {
+    let mut blocks = lengths.iter().map(|len| Vec::with_capacity(*len)).collect::<Vec<_>>();
+    let last_block_len = lengths.last().copied().unwrap_or(0);
+    let mut data = data.iter();
+    for i in 0..last_block_len {
+        for (block, len) in blocks.iter_mut().zip(lengths) {
+            if i < *len {
+                if let Some(t) = data.next() {
+                    block.push(*t);
+                }
+            }
+        }
+    }
+    blocks
+}
+
+#[test]
+fn test_deinterleave() {
+    let res = deinterleave(b"15ag26bh37ci48djekfl", &[4, 4, 6, 6]);
+    assert_eq!(res, [&b"1234"[..], b"5678", b"abcdef", b"ghijkl"]);
+}
+
 //}}}
 //------------------------------------------------------------------------------
 //{{{ QR code error correction
@@ -163,6 +428,62 @@ mod construct_codewords_test {
     }
 }
 
+/// Splits the interleaved data and error correction codewords read from a QR
+/// code matrix back into blocks. This is the inverse of
+/// `construct_codewords()`.
+///
+/// Each returned block contains the data codewords followed by the error
+/// correction codewords of that block.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::InvalidVersion)` if it is not valid to use the
+///  `ec_level` for the given version (e.g. `Version::Micro(1)` with
+/// `EcLevel::H`).
+pub fn deconstruct_codewords(data: &[u8], ec: &[u8], version: Version, ec_level: EcLevel) -> QrResult<Vec<Vec<u8>>> {
+    let (block_1_size, block_1_count, block_2_size, block_2_count) = version.fetch(ec_level, &DATA_BYTES_PER_BLOCK)?;
+    let ec_bytes = version.fetch(ec_level, &EC_BYTES_PER_BLOCK)?;
+
+    let blocks_count = block_1_count + block_2_count;
+    let mut data_lengths = vec![block_1_size; block_1_count];
+    data_lengths.resize(blocks_count, block_2_size);
+
+    debug_assert_eq!(data.len(), data_lengths.iter().sum());
+
+    let data_blocks = deinterleave(data, &data_lengths);
+    let ec_blocks = deinterleave(ec, &vec![ec_bytes; blocks_count]);
+
+    Ok(data_blocks
+        .into_iter()
+        .zip(ec_blocks)
+        .map(|(mut block, ec_block)| {
+            block.extend(ec_block);
+            block
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod deconstruct_codewords_test {
+    use crate::ec::{construct_codewords, deconstruct_codewords};
+    use crate::types::{EcLevel, Version};
+
+    #[test]
+    fn test_round_trip() {
+        let msg = b"CUF\x86W&U\xc2w2\x06\x12\x06g&\xf6\xf6B\x07v\x86\xf2\x07&V\x16\xc6\xc7\x92\x06\
+                    \xb6\xe6\xf7w2\x07v\x86W&R\x06\x86\x972\x07F\xf7vV\xc2\x06\x972\x10\xec\x11\xec\
+                    \x11\xec\x11\xec";
+        let (blocks_vec, ec_vec) = construct_codewords(msg, Version::Normal(5), EcLevel::Q).unwrap();
+        let blocks = deconstruct_codewords(&blocks_vec, &ec_vec, Version::Normal(5), EcLevel::Q).unwrap();
+
+        assert_eq!(blocks.len(), 4);
+        assert_eq!(&blocks[0][..15], &msg[..15]);
+        assert_eq!(&blocks[0][15..], b"\xd5\xc7\x0b-s\xf7\xf1\xdf\xe5\xf8\x9au\x9aoV\xa1o'");
+        assert_eq!(&blocks[3][..16], &msg[46..]);
+        assert_eq!(blocks[3].len(), 16 + 18);
+    }
+}
+
 //}}}
 //------------------------------------------------------------------------------
 //{{{ Number of allowed errors
@@ -377,7 +698,7 @@ static GENERATOR_POLYNOMIALS: [&[u8]; 70] = [
 ///
 /// This is a copy of ISO/IEC 18004:2006, §6.5.1, Table 9 (The 4th column divide
 /// by the sum of the 6th column).
-static EC_BYTES_PER_BLOCK: [[usize; 4]; 44] = [
+static EC_BYTES_PER_BLOCK: [[usize; 4]; 76] = [
     // Normal versions.
     [7, 10, 13, 17],  // 1
     [10, 16, 22, 28], // 2
@@ -424,6 +745,39 @@ static EC_BYTES_PER_BLOCK: [[usize; 4]; 44] = [
     [5, 6, 0, 0],   // M2
     [6, 8, 0, 0],   // M3
     [8, 10, 14, 0], // M4
+    // rMQR versions.
+    [0, 7, 0, 10],  // R7x43
+    [0, 9, 0, 14],  // R7x59
+    [0, 12, 0, 22], // R7x77
+    [0, 16, 0, 30], // R7x99
+    [0, 24, 0, 22], // R7x139
+    [0, 9, 0, 14],  // R9x43
+    [0, 12, 0, 22], // R9x59
+    [0, 18, 0, 16], // R9x77
+    [0, 24, 0, 22], // R9x99
+    [0, 18, 0, 22], // R9x139
+    [0, 8, 0, 10],  // R11x27
+    [0, 12, 0, 20], // R11x43
+    [0, 16, 0, 16], // R11x59
+    [0, 24, 0, 22], // R11x77
+    [0, 16, 0, 28], // R11x99
+    [0, 24, 0, 30], // R11x139
+    [0, 9, 0, 14],  // R13x27
+    [0, 14, 0, 28], // R13x43
+    [0, 22, 0, 20], // R13x59
+    [0, 16, 0, 28], // R13x77
+    [0, 20, 0, 26], // R13x99
+    [0, 20, 0, 28], // R13x139
+    [0, 18, 0, 18], // R15x43
+    [0, 26, 0, 24], // R15x59
+    [0, 18, 0, 24], // R15x77
+    [0, 24, 0, 22], // R15x99
+    [0, 24, 0, 26], // R15x139
+    [0, 22, 0, 20], // R17x43
+    [0, 16, 0, 30], // R17x59
+    [0, 22, 0, 28], // R17x77
+    [0, 20, 0, 26], // R17x99
+    [0, 20, 0, 26], // R17x139
 ];
 
 /// `DATA_BYTES_PER_BLOCK` provides the number of codewords (bytes) used for
@@ -435,7 +789,7 @@ static EC_BYTES_PER_BLOCK: [[usize; 4]; 44] = [
 /// Every entry is a 4-tuple. Take `DATA_BYTES_PER_BLOCK[39][3] == (15, 20, 16, 61)`
 /// as an example, this means in version 40 with correction level H, there are
 /// 20 blocks with 15 bytes in size, and 61 blocks with 16 bytes in size.
-static DATA_BYTES_PER_BLOCK: [[(usize, usize, usize, usize); 4]; 44] = [
+static DATA_BYTES_PER_BLOCK: [[(usize, usize, usize, usize); 4]; 76] = [
     // Normal versions.
     [(19, 1, 0, 0), (16, 1, 0, 0), (13, 1, 0, 0), (9, 1, 0, 0)], // 1
     [(34, 1, 0, 0), (28, 1, 0, 0), (22, 1, 0, 0), (16, 1, 0, 0)], // 2
@@ -482,6 +836,39 @@ static DATA_BYTES_PER_BLOCK: [[(usize, usize, usize, usize); 4]; 44] = [
     [(5, 1, 0, 0), (4, 1, 0, 0), (0, 0, 0, 0), (0, 0, 0, 0)], // M2
     [(11, 1, 0, 0), (9, 1, 0, 0), (0, 0, 0, 0), (0, 0, 0, 0)], // M3
     [(16, 1, 0, 0), (14, 1, 0, 0), (10, 1, 0, 0), (0, 0, 0, 0)], // M4
+    // rMQR versions.
+    [(0, 0, 0, 0), (6, 1, 0, 0), (0, 0, 0, 0), (3, 1, 0, 0)], // R7x43
+    [(0, 0, 0, 0), (12, 1, 0, 0), (0, 0, 0, 0), (7, 1, 0, 0)], // R7x59
+    [(0, 0, 0, 0), (20, 1, 0, 0), (0, 0, 0, 0), (10, 1, 0, 0)], // R7x77
+    [(0, 0, 0, 0), (28, 1, 0, 0), (0, 0, 0, 0), (14, 1, 0, 0)], // R7x99
+    [(0, 0, 0, 0), (44, 1, 0, 0), (0, 0, 0, 0), (12, 2, 0, 0)], // R7x139
+    [(0, 0, 0, 0), (12, 1, 0, 0), (0, 0, 0, 0), (7, 1, 0, 0)], // R9x43
+    [(0, 0, 0, 0), (21, 1, 0, 0), (0, 0, 0, 0), (11, 1, 0, 0)], // R9x59
+    [(0, 0, 0, 0), (31, 1, 0, 0), (0, 0, 0, 0), (8, 1, 9, 1)], // R9x77
+    [(0, 0, 0, 0), (42, 1, 0, 0), (0, 0, 0, 0), (11, 2, 0, 0)], // R9x99
+    [(0, 0, 0, 0), (31, 1, 32, 1), (0, 0, 0, 0), (11, 3, 0, 0)], // R9x139
+    [(0, 0, 0, 0), (7, 1, 0, 0), (0, 0, 0, 0), (5, 1, 0, 0)], // R11x27
+    [(0, 0, 0, 0), (19, 1, 0, 0), (0, 0, 0, 0), (11, 1, 0, 0)], // R11x43
+    [(0, 0, 0, 0), (31, 1, 0, 0), (0, 0, 0, 0), (7, 1, 8, 1)], // R11x59
+    [(0, 0, 0, 0), (43, 1, 0, 0), (0, 0, 0, 0), (11, 1, 12, 1)], // R11x77
+    [(0, 0, 0, 0), (28, 1, 29, 1), (0, 0, 0, 0), (16, 1, 17, 1)], // R11x99
+    [(0, 0, 0, 0), (42, 2, 0, 0), (0, 0, 0, 0), (14, 3, 0, 0)], // R11x139
+    [(0, 0, 0, 0), (12, 1, 0, 0), (0, 0, 0, 0), (7, 1, 0, 0)], // R13x27
+    [(0, 0, 0, 0), (27, 1, 0, 0), (0, 0, 0, 0), (13, 1, 0, 0)], // R13x43
+    [(0, 0, 0, 0), (38, 1, 0, 0), (0, 0, 0, 0), (10, 2, 0, 0)], // R13x59
+    [(0, 0, 0, 0), (26, 1, 27, 1), (0, 0, 0, 0), (14, 1, 15, 1)], // R13x77
+    [(0, 0, 0, 0), (36, 1, 37, 1), (0, 0, 0, 0), (11, 1, 12, 2)], // R13x99
+    [(0, 0, 0, 0), (35, 2, 36, 1), (0, 0, 0, 0), (13, 2, 14, 2)], // R13x139
+    [(0, 0, 0, 0), (33, 1, 0, 0), (0, 0, 0, 0), (7, 1, 8, 1)], // R15x43
+    [(0, 0, 0, 0), (48, 1, 0, 0), (0, 0, 0, 0), (13, 2, 0, 0)], // R15x59
+    [(0, 0, 0, 0), (33, 1, 34, 1), (0, 0, 0, 0), (10, 2, 11, 1)], // R15x77
+    [(0, 0, 0, 0), (44, 2, 0, 0), (0, 0, 0, 0), (12, 4, 0, 0)], // R15x99
+    [(0, 0, 0, 0), (42, 2, 43, 1), (0, 0, 0, 0), (13, 1, 14, 4)], // R15x139
+    [(0, 0, 0, 0), (39, 1, 0, 0), (0, 0, 0, 0), (10, 1, 11, 1)], // R17x43
+    [(0, 0, 0, 0), (28, 2, 0, 0), (0, 0, 0, 0), (14, 2, 0, 0)], // R17x59
+    [(0, 0, 0, 0), (39, 2, 0, 0), (0, 0, 0, 0), (12, 1, 13, 2)], // R17x77
+    [(0, 0, 0, 0), (33, 2, 34, 1), (0, 0, 0, 0), (14, 4, 0, 0)], // R17x99
+    [(0, 0, 0, 0), (38, 4, 0, 0), (0, 0, 0, 0), (12, 2, 13, 4)], // R17x139
 ];
 
 //}}}
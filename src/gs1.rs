@@ -0,0 +1,636 @@
+//! The `gs1` module builds and parses GS1 element strings, which are encoded
+//! in QR code after the FNC1 mode indicator in the first position.
+//!
+//! ```
+//! use qrcode::gs1;
+//!
+//! let elements = [("01", "09506000134352"), ("10", "ABC123"), ("17", "251231")];
+//! let data = gs1::element_string(&elements).unwrap();
+//! assert_eq!(data, b"010950600013435210ABC123\x1d17251231");
+//! assert_eq!(gs1::parse(&data).unwrap(), elements.map(|(ai, value)| (ai.to_owned(), value.to_owned())));
+//! ```
+
+use crate::bits::Bits;
+use crate::optimize::{optimize_segments, Parser, Segment};
+use crate::payload::push_percent_encoded;
+use crate::types::{EcLevel, Mode, QrError, QrResult, Version};
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter::once;
+use core::str;
+
+//------------------------------------------------------------------------------
+//{{{ Application Identifiers
+
+/// The group separator (GS), which terminates the data field of an Application
+/// Identifier without a predefined length, unless it is the last element.
+pub const GROUP_SEPARATOR: u8 = 0x1d;
+
+/// The format of the data field of an Application Identifier.
+#[derive(Copy, Clone)]
+struct AiFormat {
+    /// The Application Identifier, where `n` matches any digit.
+    pattern: &'static str,
+
+    /// Whether the data field only contains digits. Otherwise, it may contain
+    /// any character in the GS1 AI encodable character set 82.
+    is_numeric: bool,
+
+    /// The minimum length of the data field.
+    min_len: usize,
+
+    /// The maximum length of the data field.
+    max_len: usize,
+
+    /// The number of leading digits of the data field which end with a GS1
+    /// check digit, or 0 if there is no check digit.
+    check_digit_len: usize,
+}
+
+const fn numeric(pattern: &'static str, min_len: usize, max_len: usize) -> AiFormat {
+    AiFormat { pattern, is_numeric: true, min_len, max_len, check_digit_len: 0 }
+}
+
+const fn alphanumeric(pattern: &'static str, min_len: usize, max_len: usize) -> AiFormat {
+    AiFormat { pattern, is_numeric: false, min_len, max_len, check_digit_len: 0 }
+}
+
+const fn with_check_digit(format: AiFormat, check_digit_len: usize) -> AiFormat {
+    AiFormat { check_digit_len, ..format }
+}
+
+/// The commonly used Application Identifiers, from the GS1 General
+/// Specifications.
+static AI_FORMATS: &[AiFormat] = &[
+    with_check_digit(numeric("00", 18, 18), 18),        // SSCC
+    with_check_digit(numeric("01", 14, 14), 14),        // GTIN
+    with_check_digit(numeric("02", 14, 14), 14),        // CONTENT
+    alphanumeric("10", 1, 20),                          // BATCH/LOT
+    numeric("11", 6, 6),                                // PROD DATE
+    numeric("12", 6, 6),                                // DUE DATE
+    numeric("13", 6, 6),                                // PACK DATE
+    numeric("15", 6, 6),                                // BEST BEFORE
+    numeric("16", 6, 6),                                // SELL BY
+    numeric("17", 6, 6),                                // USE BY / EXPIRY
+    numeric("20", 2, 2),                                // VARIANT
+    alphanumeric("21", 1, 20),                          // SERIAL
+    alphanumeric("22", 1, 20),                          // CPV
+    alphanumeric("235", 1, 28),                         // TPX
+    alphanumeric("240", 1, 30),                         // ADDITIONAL ID
+    alphanumeric("241", 1, 30),                         // CUST. PART No.
+    numeric("242", 1, 6),                               // MTO VARIANT
+    alphanumeric("243", 1, 20),                         // PCN
+    alphanumeric("250", 1, 30),                         // SECONDARY SERIAL
+    alphanumeric("251", 1, 30),                         // REF. TO SOURCE
+    with_check_digit(alphanumeric("253", 13, 30), 13),  // GDTI
+    alphanumeric("254", 1, 20),                         // GLN EXTENSION COMPONENT
+    with_check_digit(numeric("255", 13, 25), 13),       // GCN
+    numeric("30", 1, 8),                                // VAR. COUNT
+    numeric("310n", 6, 6),                              // NET WEIGHT (kg)
+    numeric("311n", 6, 6),                              // LENGTH (m)
+    numeric("312n", 6, 6),                              // WIDTH (m)
+    numeric("313n", 6, 6),                              // HEIGHT (m)
+    numeric("314n", 6, 6),                              // AREA (m²)
+    numeric("315n", 6, 6),                              // NET VOLUME (l)
+    numeric("316n", 6, 6),                              // NET VOLUME (m³)
+    numeric("32nn", 6, 6),                              // imperial trade measures
+    numeric("33nn", 6, 6),                              // logistic measures
+    numeric("34nn", 6, 6),                              // logistic measures
+    numeric("35nn", 6, 6),                              // imperial trade and logistic measures
+    numeric("36nn", 6, 6),                              // imperial trade and logistic measures
+    numeric("37", 1, 8),                                // COUNT
+    numeric("390n", 1, 15),                             // AMOUNT
+    numeric("391n", 4, 18),                             // AMOUNT with ISO currency code
+    numeric("392n", 1, 15),                             // PRICE
+    numeric("393n", 4, 18),                             // PRICE with ISO currency code
+    alphanumeric("400", 1, 30),                         // ORDER NUMBER
+    alphanumeric("401", 1, 30),                         // GINC
+    with_check_digit(numeric("402", 17, 17), 17),       // GSIN
+    alphanumeric("403", 1, 30),                         // ROUTE
+    with_check_digit(numeric("410", 13, 13), 13),       // SHIP TO LOC
+    with_check_digit(numeric("411", 13, 13), 13),       // BILL TO
+    with_check_digit(numeric("412", 13, 13), 13),       // PURCHASE FROM
+    with_check_digit(numeric("413", 13, 13), 13),       // SHIP FOR LOC
+    with_check_digit(numeric("414", 13, 13), 13),       // LOC No.
+    with_check_digit(numeric("415", 13, 13), 13),       // PAY TO
+    with_check_digit(numeric("416", 13, 13), 13),       // PROD/SERV LOC
+    with_check_digit(numeric("417", 13, 13), 13),       // PARTY
+    alphanumeric("420", 1, 20),                         // SHIP TO POST
+    alphanumeric("421", 4, 12),                         // SHIP TO POST with ISO country code
+    numeric("422", 3, 3),                               // ORIGIN
+    numeric("423", 3, 15),                              // COUNTRY - INITIAL PROCESS.
+    numeric("424", 3, 3),                               // COUNTRY - PROCESS.
+    numeric("425", 3, 15),                              // COUNTRY - DISASSEMBLY
+    numeric("426", 3, 3),                               // COUNTRY - FULL PROCESS
+    numeric("7001", 13, 13),                            // NSN
+    numeric("7003", 10, 10),                            // EXPIRY TIME
+    with_check_digit(alphanumeric("8003", 15, 30), 14), // GRAI
+    alphanumeric("8004", 1, 30),                        // GIAI
+    with_check_digit(numeric("8006", 18, 18), 14),      // ITIP
+    with_check_digit(numeric("8017", 18, 18), 18),      // GSRN - PROVIDER
+    with_check_digit(numeric("8018", 18, 18), 18),      // GSRN - RECIPIENT
+    alphanumeric("8010", 1, 30),                        // CPID
+    numeric("8011", 1, 12),                             // CPID SERIAL
+    alphanumeric("8013", 1, 25),                        // GMN
+    numeric("8019", 1, 10),                             // SRIN
+    alphanumeric("8020", 1, 25),                        // REF No.
+    alphanumeric("90", 1, 30),                          // INTERNAL
+    alphanumeric("91", 1, 90),                          // INTERNAL
+    alphanumeric("92", 1, 90),                          // INTERNAL
+    alphanumeric("93", 1, 90),                          // INTERNAL
+    alphanumeric("94", 1, 90),                          // INTERNAL
+    alphanumeric("95", 1, 90),                          // INTERNAL
+    alphanumeric("96", 1, 90),                          // INTERNAL
+    alphanumeric("97", 1, 90),                          // INTERNAL
+    alphanumeric("98", 1, 90),                          // INTERNAL
+    alphanumeric("99", 1, 90),                          // INTERNAL
+];
+
+/// The first two digits of the Application Identifiers whose element strings
+/// have a predefined length, and thus never need to be terminated by GS.
+static PREDEFINED_LENGTH_PREFIXES: [&str; 22] = [
+    "00", "01", "02", "03", "04", "11", "12", "13", "14", "15", "16", "17", "18", "19", "20", "31", "32", "33", "34",
+    "35", "36", "41",
+];
+
+/// Finds the format of an Application Identifier.
+fn find_format(ai: &str) -> Option<&'static AiFormat> {
+    AI_FORMATS.iter().find(|format| {
+        format.pattern.len() == ai.len()
+            && format.pattern.bytes().zip(ai.bytes()).all(|(p, c)| p == c || (p == b'n' && c.is_ascii_digit()))
+    })
+}
+
+/// Checks whether the element string of the Application Identifier has a
+/// predefined length.
+fn has_predefined_length(ai: &str) -> bool {
+    ai.get(..2).is_some_and(|prefix| PREDEFINED_LENGTH_PREFIXES.contains(&prefix))
+}
+
+/// Checks whether the character is in the GS1 AI encodable character set 82.
+const fn is_cset82(c: u8) -> bool {
+    matches!(c, b'!' | b'"' | b'%'..=b'?' | b'A'..=b'Z' | b'_' | b'a'..=b'z')
+}
+
+/// Checks the GS1 check digit, which is the last of the digits.
+pub(crate) fn is_valid_check_digit(digits: &[u8]) -> bool {
+    let Some((&check_digit, digits)) = digits.split_last() else {
+        return false;
+    };
+    let sum = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| u32::from(d - b'0') * if i % 2 == 0 { 3 } else { 1 })
+        .sum::<u32>();
+    u32::from(check_digit - b'0') == (10 - sum % 10) % 10
+}
+
+/// Validates the data field of an Application Identifier.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::InvalidData)` if the Application Identifier is
+/// unknown, or the value has a wrong length or check digit.
+///
+/// Returns `Err(QrError::InvalidCharacter)` if the value contains characters
+/// not allowed by the Application Identifier.
+pub fn validate(ai: &str, value: &str) -> QrResult<()> {
+    let format = find_format(ai).ok_or(QrError::InvalidData)?;
+    let value = value.as_bytes();
+    if !(format.min_len..=format.max_len).contains(&value.len()) {
+        return Err(QrError::InvalidData);
+    }
+    let (check_digit_part, rest) = value.split_at(format.check_digit_len);
+    let is_valid_char = if format.is_numeric { u8::is_ascii_digit } else { |c: &u8| is_cset82(*c) };
+    if !check_digit_part.iter().all(u8::is_ascii_digit) || !rest.iter().all(is_valid_char) {
+        return Err(QrError::InvalidCharacter);
+    }
+    if !check_digit_part.is_empty() && !is_valid_check_digit(check_digit_part) {
+        return Err(QrError::InvalidData);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod ai_tests {
+    use crate::gs1::{is_valid_check_digit, validate};
+    use crate::types::QrError;
+
+    #[test]
+    fn test_check_digit() {
+        assert!(is_valid_check_digit(b"09506000134352"));
+        assert!(is_valid_check_digit(b"376104250021234569"));
+        assert!(is_valid_check_digit(b"0"));
+        assert!(!is_valid_check_digit(b"09506000134353"));
+        assert!(!is_valid_check_digit(b""));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("01", "09506000134352"), Ok(()));
+        assert_eq!(validate("00", "376104250021234569"), Ok(()));
+        assert_eq!(validate("10", "ABC-123/x"), Ok(()));
+        assert_eq!(validate("3103", "000525"), Ok(()));
+        assert_eq!(validate("253", "9506000134352"), Ok(()));
+        assert_eq!(validate("253", "9506000134352ABC"), Ok(()));
+        assert_eq!(validate("255", "9506000134352"), Ok(()));
+        assert_eq!(validate("255", "9506000134352123"), Ok(()));
+        assert_eq!(validate("8006", "095060001343520102"), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_invalid() {
+        assert_eq!(validate("01", "09506000134353"), Err(QrError::InvalidData));
+        assert_eq!(validate("01", "0950600013435"), Err(QrError::InvalidData));
+        assert_eq!(validate("01", "0950600013435A"), Err(QrError::InvalidCharacter));
+        assert_eq!(validate("17", "25123"), Err(QrError::InvalidData));
+        assert_eq!(validate("10", ""), Err(QrError::InvalidData));
+        assert_eq!(validate("10", "ABCDEFGHIJKLMNOPQRSTU"), Err(QrError::InvalidData));
+        assert_eq!(validate("10", "AB#C"), Err(QrError::InvalidCharacter));
+        assert_eq!(validate("14", "123"), Err(QrError::InvalidData));
+        assert_eq!(validate("253", "950600013435"), Err(QrError::InvalidData));
+        assert_eq!(validate("255", "9506000134353"), Err(QrError::InvalidData));
+        assert_eq!(validate("3170", "000525"), Err(QrError::InvalidData));
+        assert_eq!(validate("x1", "000525"), Err(QrError::InvalidData));
+    }
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Element strings
+
+/// Concatenates the Application Identifiers and their values into an element
+/// string, inserting GS after each variable-length element except the last.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::InvalidData)` if there are no elements, or any of
+/// them fails `validate()`.
+///
+/// Returns `Err(QrError::InvalidCharacter)` if any value contains characters
+/// not allowed by its Application Identifier.
+pub fn element_string(elements: &[(&str, &str)]) -> QrResult<Vec<u8>> {
+    if elements.is_empty() {
+        return Err(QrError::InvalidData);
+    }
+    let mut data = Vec::new();
+    for (i, &(ai, value)) in elements.iter().enumerate() {
+        validate(ai, value)?;
+        data.extend_from_slice(ai.as_bytes());
+        data.extend_from_slice(value.as_bytes());
+        if i + 1 < elements.len() && !has_predefined_length(ai) {
+            data.push(GROUP_SEPARATOR);
+        }
+    }
+    Ok(data)
+}
+
+/// Parses an element string back into the Application Identifiers and their
+/// values. The data fields are separated by GS as produced by
+/// `element_string()` and `QrCode::decode()`.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::InvalidData)` if the element string contains an
+/// unknown Application Identifier, or any element fails `validate()`.
+///
+/// Returns `Err(QrError::InvalidCharacter)` if any value contains characters
+/// not allowed by its Application Identifier.
+pub fn parse(data: &[u8]) -> QrResult<Vec<(String, String)>> {
+    let mut elements = Vec::new();
+    let mut rest = data;
+    loop {
+        while let [GROUP_SEPARATOR, tail @ ..] = rest {
+            rest = tail;
+        }
+        if rest.is_empty() {
+            return Ok(elements);
+        }
+
+        let ai = (2..=4)
+            .filter_map(|len| str::from_utf8(rest.get(..len)?).ok())
+            .find(|ai| find_format(ai).is_some())
+            .ok_or(QrError::InvalidData)?;
+        rest = &rest[ai.len()..];
+
+        let value_len = match find_format(ai) {
+            Some(format) if has_predefined_length(ai) => format.max_len.min(rest.len()),
+            _ => rest.iter().position(|&c| c == GROUP_SEPARATOR).unwrap_or(rest.len()),
+        };
+        let value = str::from_utf8(&rest[..value_len]).map_err(|_| QrError::InvalidCharacter)?;
+        validate(ai, value)?;
+        elements.push((ai.to_owned(), value.to_owned()));
+        rest = &rest[value_len..];
+    }
+}
+
+#[cfg(test)]
+mod element_string_tests {
+    use crate::gs1::{element_string, parse};
+    use crate::types::QrError;
+    use alloc::borrow::ToOwned;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_element_string() {
+        let data = element_string(&[("01", "09506000134352"), ("10", "ABC123"), ("17", "251231"), ("21", "X%1")]);
+        assert_eq!(data.unwrap(), b"010950600013435210ABC123\x1d1725123121X%1");
+    }
+
+    #[test]
+    fn test_element_string_invalid() {
+        assert_eq!(element_string(&[]), Err(QrError::InvalidData));
+        assert_eq!(element_string(&[("01", "09506000134352"), ("01", "1")]), Err(QrError::InvalidData));
+    }
+
+    #[test]
+    fn test_parse() {
+        let elements = parse(b"0109506000134352\x1d10ABC123\x1d3103000525\x1d21X%1").unwrap();
+        let expected = [("01", "09506000134352"), ("10", "ABC123"), ("3103", "000525"), ("21", "X%1")];
+        assert_eq!(elements, expected.map(|(ai, value)| (ai.to_owned(), value.to_owned())));
+        assert_eq!(parse(b"").unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(parse(b"0109506000134353"), Err(QrError::InvalidData));
+        assert_eq!(parse(b"010950600013435"), Err(QrError::InvalidData));
+        assert_eq!(parse(b"14123"), Err(QrError::InvalidData));
+        assert_eq!(parse(b"10\xff"), Err(QrError::InvalidCharacter));
+    }
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Encoding
+
+/// Pushes the element string to the bits in FNC1 mode, escaping the data in
+/// the alphanumeric segments where `%` represents GS, and `%%` represents `%`.
+fn push_element_string(bits: &mut Bits, data: &[u8], segments: &[Segment]) -> QrResult<()> {
+    bits.push_fnc1_first_position()?;
+    for segment in segments {
+        if segment.mode == Mode::Alphanumeric {
+            let mut escaped = Vec::with_capacity(segment.end - segment.begin);
+            for &c in &data[segment.begin..segment.end] {
+                match c {
+                    GROUP_SEPARATOR => escaped.push(b'%'),
+                    b'%' => escaped.extend_from_slice(b"%%"),
+                    _ => escaped.push(c),
+                }
+            }
+            bits.push_escaped_alphanumeric_data(&escaped, segment.end - segment.begin)?;
+        } else {
+            bits.push_segments(data, once(*segment))?;
+        }
+    }
+    Ok(())
+}
+
+/// Encodes the Application Identifiers and their values as a GS1 QR code in
+/// the smallest version.
+///
+/// # Errors
+///
+/// Returns `Err(QrError::DataTooLong)` if the data is too long to fit even the
+/// highest QR code version.
+///
+/// Returns `Err(QrError::InvalidData)` or `Err(QrError::InvalidCharacter)` if
+/// the elements are invalid, see `element_string()`.
+pub fn encode(elements: &[(&str, &str)], ec_level: EcLevel) -> QrResult<Bits> {
+    let data = element_string(elements)?;
+
+    // GS is encoded as `%` in the alphanumeric mode, so it is classified the
+    // same way when finding the segments. The segments are optimized without
+    // the cost of escaping `%` as `%%`, but each version is checked against the
+    // escaped data actually pushed.
+    let classified = data.iter().map(|&c| if c == GROUP_SEPARATOR { b'%' } else { c }).collect::<Vec<_>>();
+    let segments = Parser::new(&classified).collect::<Vec<_>>();
+
+    let mut error = QrError::InvalidVersion;
+    for version in 1..=40 {
+        let version = Version::Normal(version);
+        let mut bits = Bits::new(version);
+        let segments = optimize_segments(&segments, version);
+        match push_element_string(&mut bits, &data, &segments).and_then(|()| bits.push_terminator(ec_level)) {
+            Ok(()) => return Ok(bits),
+            Err(e @ QrError::DataTooLong(_)) => error = e,
+            Err(e) => return Err(e),
+        }
+    }
+    Err(error)
+}
+
+#[cfg(test)]
+mod encode_tests {
+    use crate::bits::SegmentKind;
+    use crate::gs1::encode;
+    use crate::optimize::Segment;
+    use crate::types::{EcLevel, Mode, QrError, Version};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_encode() {
+        let bits = encode(&[("01", "09506000134352"), ("10", "ABC123"), ("17", "251231")], EcLevel::M).unwrap();
+        assert_eq!(bits.version(), Version::Normal(2));
+        // FNC1 followed by the numeric mode.
+        assert_eq!(bits.into_bytes()[0], 0b0101_0001);
+    }
+
+    #[test]
+    fn test_encode_escaped() {
+        // The `%` is escaped as `%%` in the alphanumeric mode, but the segments
+        // still refer to the element string.
+        let bits = encode(&[("10", "AB%CD"), ("21", "12345678901234567890")], EcLevel::M).unwrap();
+        let segments = bits.segments().iter().map(|s| s.kind).collect::<Vec<_>>();
+        assert_eq!(
+            segments,
+            [
+                SegmentKind::Fnc1First,
+                SegmentKind::Data(Segment { mode: Mode::Alphanumeric, begin: 0, end: 8 }),
+                SegmentKind::Data(Segment { mode: Mode::Numeric, begin: 8, end: 30 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_encode_invalid() {
+        assert_eq!(encode(&[("01", "09506000134353")], EcLevel::M).err(), Some(QrError::InvalidData));
+    }
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Digital Link
+
+/// The Application Identifiers which can be the primary key of a GS1 Digital
+/// Link URI, and their key qualifiers in the order they appear in the path.
+static PRIMARY_KEYS: [(&str, &[&str]); 16] = [
+    ("00", &[]),
+    ("01", &["22", "10", "21"]),
+    ("253", &[]),
+    ("255", &[]),
+    ("401", &[]),
+    ("402", &[]),
+    ("414", &["254"]),
+    ("415", &["8020"]),
+    ("417", &[]),
+    ("8003", &[]),
+    ("8004", &[]),
+    ("8006", &["22", "10", "21"]),
+    ("8010", &["8011"]),
+    ("8013", &[]),
+    ("8017", &["8019"]),
+    ("8018", &["8019"]),
+];
+
+/// A builder of GS1 Digital Link URIs, which carry the same Application
+/// Identifiers as element strings in the form of a web address.
+///
+/// ```
+/// use qrcode::gs1::DigitalLinkBuilder;
+/// use qrcode::QrCode;
+///
+/// let uri = DigitalLinkBuilder::new().build(&[("01", "09506000134352"), ("10", "ABC")]).unwrap();
+/// assert_eq!(uri, "HTTPS://ID.GS1.ORG/01/09506000134352/10/ABC");
+///
+/// let code = QrCode::new(uri).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct DigitalLinkBuilder<'a> {
+    domain: &'a str,
+    uppercase: bool,
+}
+
+impl Default for DigitalLinkBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> DigitalLinkBuilder<'a> {
+    /// Creates a new builder. By default, the URI uses the GS1 resolver
+    /// `https://id.gs1.org`, with the scheme and host in uppercase.
+    pub const fn new() -> Self {
+        Self { domain: "https://id.gs1.org", uppercase: true }
+    }
+
+    /// Sets the scheme, host and optional path prefix of the URI, e.g.
+    /// `https://example.com/dl`.
+    pub fn domain(&mut self, domain: &'a str) -> &mut Self {
+        self.domain = domain;
+        self
+    }
+
+    /// Whether to convert the scheme and host of the URI to uppercase. They
+    /// are case-insensitive, and converting them allows the Alphanumeric mode
+    /// to be used, which reduces the size of the QR code. The path is never
+    /// converted since it is case-sensitive. Default is true.
+    pub fn uppercase(&mut self, uppercase: bool) -> &mut Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Builds the Digital Link URI of the Application Identifiers and their
+    /// values.
+    ///
+    /// The primary key and its key qualifiers are put in the path in the
+    /// standard order, and all other elements are put in the query string as
+    /// data attributes. The values are percent-encoded with uppercase hex
+    /// digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if there is not exactly one primary
+    /// key, an Application Identifier appears more than once, or any element
+    /// fails `validate()`.
+    ///
+    /// Returns `Err(QrError::InvalidCharacter)` if any value contains
+    /// characters not allowed by its Application Identifier.
+    pub fn build(&self, elements: &[(&str, &str)]) -> QrResult<String> {
+        for (i, &(ai, value)) in elements.iter().enumerate() {
+            validate(ai, value)?;
+            if elements[..i].iter().any(|&(other, _)| other == ai) {
+                return Err(QrError::InvalidData);
+            }
+        }
+        let mut primary_keys =
+            elements.iter().filter_map(|&(ai, value)| Some((ai, value, PRIMARY_KEYS.iter().find(|k| k.0 == ai)?.1)));
+        let (Some((key, key_value, qualifiers)), None) = (primary_keys.next(), primary_keys.next()) else {
+            return Err(QrError::InvalidData);
+        };
+
+        let mut uri = self.domain.trim_end_matches('/').to_owned();
+        if self.uppercase {
+            let authority_end = uri.find("://").map_or(0, |i| i + 3);
+            let host_end = uri[authority_end..].find('/').map_or(uri.len(), |i| authority_end + i);
+            uri[..host_end].make_ascii_uppercase();
+        }
+
+        push_path_segment(&mut uri, key, key_value);
+        for qualifier in qualifiers {
+            if let Some(&(ai, value)) = elements.iter().find(|e| e.0 == *qualifier) {
+                push_path_segment(&mut uri, ai, value);
+            }
+        }
+
+        let attributes = elements.iter().filter(|e| e.0 != key && !qualifiers.contains(&e.0));
+        for (i, &(ai, value)) in attributes.enumerate() {
+            uri.push(if i == 0 { '?' } else { '&' });
+            uri.push_str(ai);
+            uri.push('=');
+            push_percent_encoded(&mut uri, value);
+        }
+        Ok(uri)
+    }
+}
+
+/// Appends `/{ai}/{value}` to the URI.
+fn push_path_segment(uri: &mut String, ai: &str, value: &str) {
+    uri.push('/');
+    uri.push_str(ai);
+    uri.push('/');
+    push_percent_encoded(uri, value);
+}
+
+#[cfg(test)]
+mod digital_link_tests {
+    use crate::gs1::DigitalLinkBuilder;
+    use crate::types::QrError;
+
+    #[test]
+    fn test_gtin() {
+        let elements = [("17", "251231"), ("21", "x"), ("01", "09506000134352"), ("10", "ABC+1/2"), ("3103", "000525")];
+        let uri = DigitalLinkBuilder::new().build(&elements).unwrap();
+        assert_eq!(uri, "HTTPS://ID.GS1.ORG/01/09506000134352/10/ABC%2B1%2F2/21/x?17=251231&3103=000525");
+    }
+
+    #[test]
+    fn test_domain() {
+        let elements = [("414", "9506000134352"), ("254", "5a")];
+        let uri = DigitalLinkBuilder::new().domain("https://Example.com/dl/").build(&elements).unwrap();
+        assert_eq!(uri, "HTTPS://EXAMPLE.COM/dl/414/9506000134352/254/5a");
+        let uri =
+            DigitalLinkBuilder::new().domain("https://Example.com/dl/").uppercase(false).build(&elements).unwrap();
+        assert_eq!(uri, "https://Example.com/dl/414/9506000134352/254/5a");
+    }
+
+    #[test]
+    fn test_payment_slip() {
+        let elements = [("8020", "1234-56"), ("415", "9506000134352"), ("3902", "1999")];
+        let uri = DigitalLinkBuilder::new().build(&elements).unwrap();
+        assert_eq!(uri, "HTTPS://ID.GS1.ORG/415/9506000134352/8020/1234-56?3902=1999");
+    }
+
+    #[test]
+    fn test_invalid_primary_key() {
+        let builder = DigitalLinkBuilder::new();
+        assert_eq!(builder.build(&[("10", "ABC")]), Err(QrError::InvalidData));
+        assert_eq!(builder.build(&[("01", "09506000134353")]), Err(QrError::InvalidData));
+        assert_eq!(builder.build(&[("01", "09506000134352"), ("00", "376104250021234569")]), Err(QrError::InvalidData));
+        assert_eq!(builder.build(&[("01", "09506000134352"), ("10", "A"), ("10", "B")]), Err(QrError::InvalidData));
+    }
+}
+
+//}}}
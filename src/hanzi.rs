@@ -0,0 +1,1145 @@
+//! Conversion of Unicode text to GB 2312, for encoding in the Hanzi mode.
+//!
+//! Only the characters in GB 2312 are supported, which are exactly those
+//! representable in the Hanzi mode with the GB 2312 subset.
+
+use alloc::vec::Vec;
+
+/// Converts a character to its GB 2312 double-byte code (in EUC-CN form), or
+/// `None` if the character is not in GB 2312.
+///
+/// ASCII characters are never converted, since they have single-byte codes in
+/// EUC-CN.
+///
+/// ```
+/// use qrcode::hanzi::to_gb2312;
+///
+/// assert_eq!(to_gb2312('\u{4e2d}'), Some(0xd6d0)); // "中"
+/// assert_eq!(to_gb2312('A'), None);
+/// ```
+pub fn to_gb2312(c: char) -> Option<u16> {
+    let c = u16::try_from(u32::from(c)).ok()?;
+    let index = UNICODE_CODE_POINTS.binary_search(&c).ok()?;
+    Some(GB2312_CODES[index])
+}
+
+/// Converts a string to GB 2312 double-byte data, or `None` if any of the
+/// characters is not in GB 2312.
+///
+/// ```
+/// use qrcode::hanzi::encode_gb2312;
+///
+/// assert_eq!(encode_gb2312("\u{4e2d}\u{6587}"), Some(b"\xd6\xd0\xce\xc4".to_vec())); // "中文"
+/// assert_eq!(encode_gb2312("\u{4e2d}A"), None);
+/// ```
+pub fn encode_gb2312(data: &str) -> Option<Vec<u8>> {
+    let mut result = Vec::with_capacity(data.len() / 3 * 2);
+    for c in data.chars() {
+        result.extend_from_slice(&to_gb2312(c)?.to_be_bytes());
+    }
+    Some(result)
+}
+
+/// The Unicode code points of all characters in GB 2312, sorted.
+static UNICODE_CODE_POINTS: [u16; 7445] = [
+    0x00a4, 0x00a7, 0x00a8, 0x00b0, 0x00b1, 0x00d7, 0x00e0, 0x00e1, 0x00e8, 0x00e9, 0x00ea, 0x00ec, 0x00ed, 0x00f2,
+    0x00f3, 0x00f7, 0x00f9, 0x00fa, 0x00fc, 0x0101, 0x0113, 0x011b, 0x012b, 0x014d, 0x016b, 0x01ce, 0x01d0, 0x01d2,
+    0x01d4, 0x01d6, 0x01d8, 0x01da, 0x01dc, 0x02c7, 0x02c9, 0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397,
+    0x0398, 0x0399, 0x039a, 0x039b, 0x039c, 0x039d, 0x039e, 0x039f, 0x03a0, 0x03a1, 0x03a3, 0x03a4, 0x03a5, 0x03a6,
+    0x03a7, 0x03a8, 0x03a9, 0x03b1, 0x03b2, 0x03b3, 0x03b4, 0x03b5, 0x03b6, 0x03b7, 0x03b8, 0x03b9, 0x03ba, 0x03bb,
+    0x03bc, 0x03bd, 0x03be, 0x03bf, 0x03c0, 0x03c1, 0x03c3, 0x03c4, 0x03c5, 0x03c6, 0x03c7, 0x03c8, 0x03c9, 0x0401,
+    0x0410, 0x0411, 0x0412, 0x0413, 0x0414, 0x0415, 0x0416, 0x0417, 0x0418, 0x0419, 0x041a, 0x041b, 0x041c, 0x041d,
+    0x041e, 0x041f, 0x0420, 0x0421, 0x0422, 0x0423, 0x0424, 0x0425, 0x0426, 0x0427, 0x0428, 0x0429, 0x042a, 0x042b,
+    0x042c, 0x042d, 0x042e, 0x042f, 0x0430, 0x0431, 0x0432, 0x0433, 0x0434, 0x0435, 0x0436, 0x0437, 0x0438, 0x0439,
+    0x043a, 0x043b, 0x043c, 0x043d, 0x043e, 0x043f, 0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447,
+    0x0448, 0x0449, 0x044a, 0x044b, 0x044c, 0x044d, 0x044e, 0x044f, 0x0451, 0x2015, 0x2016, 0x2018, 0x2019, 0x201c,
+    0x201d, 0x2026, 0x2030, 0x2032, 0x2033, 0x203b, 0x2103, 0x2116, 0x2160, 0x2161, 0x2162, 0x2163, 0x2164, 0x2165,
+    0x2166, 0x2167, 0x2168, 0x2169, 0x216a, 0x216b, 0x2190, 0x2191, 0x2192, 0x2193, 0x2208, 0x220f, 0x2211, 0x221a,
+    0x221d, 0x221e, 0x2220, 0x2225, 0x2227, 0x2228, 0x2229, 0x222a, 0x222b, 0x222e, 0x2234, 0x2235, 0x2236, 0x2237,
+    0x223d, 0x2248, 0x224c, 0x2260, 0x2261, 0x2264, 0x2265, 0x226e, 0x226f, 0x2299, 0x22a5, 0x2312, 0x2460, 0x2461,
+    0x2462, 0x2463, 0x2464, 0x2465, 0x2466, 0x2467, 0x2468, 0x2469, 0x2474, 0x2475, 0x2476, 0x2477, 0x2478, 0x2479,
+    0x247a, 0x247b, 0x247c, 0x247d, 0x247e, 0x247f, 0x2480, 0x2481, 0x2482, 0x2483, 0x2484, 0x2485, 0x2486, 0x2487,
+    0x2488, 0x2489, 0x248a, 0x248b, 0x248c, 0x248d, 0x248e, 0x248f, 0x2490, 0x2491, 0x2492, 0x2493, 0x2494, 0x2495,
+    0x2496, 0x2497, 0x2498, 0x2499, 0x249a, 0x249b, 0x2500, 0x2501, 0x2502, 0x2503, 0x2504, 0x2505, 0x2506, 0x2507,
+    0x2508, 0x2509, 0x250a, 0x250b, 0x250c, 0x250d, 0x250e, 0x250f, 0x2510, 0x2511, 0x2512, 0x2513, 0x2514, 0x2515,
+    0x2516, 0x2517, 0x2518, 0x2519, 0x251a, 0x251b, 0x251c, 0x251d, 0x251e, 0x251f, 0x2520, 0x2521, 0x2522, 0x2523,
+    0x2524, 0x2525, 0x2526, 0x2527, 0x2528, 0x2529, 0x252a, 0x252b, 0x252c, 0x252d, 0x252e, 0x252f, 0x2530, 0x2531,
+    0x2532, 0x2533, 0x2534, 0x2535, 0x2536, 0x2537, 0x2538, 0x2539, 0x253a, 0x253b, 0x253c, 0x253d, 0x253e, 0x253f,
+    0x2540, 0x2541, 0x2542, 0x2543, 0x2544, 0x2545, 0x2546, 0x2547, 0x2548, 0x2549, 0x254a, 0x254b, 0x25a0, 0x25a1,
+    0x25b2, 0x25b3, 0x25c6, 0x25c7, 0x25cb, 0x25ce, 0x25cf, 0x2605, 0x2606, 0x2640, 0x2642, 0x3000, 0x3001, 0x3002,
+    0x3003, 0x3005, 0x3008, 0x3009, 0x300a, 0x300b, 0x300c, 0x300d, 0x300e, 0x300f, 0x3010, 0x3011, 0x3013, 0x3014,
+    0x3015, 0x3016, 0x3017, 0x3041, 0x3042, 0x3043, 0x3044, 0x3045, 0x3046, 0x3047, 0x3048, 0x3049, 0x304a, 0x304b,
+    0x304c, 0x304d, 0x304e, 0x304f, 0x3050, 0x3051, 0x3052, 0x3053, 0x3054, 0x3055, 0x3056, 0x3057, 0x3058, 0x3059,
+    0x305a, 0x305b, 0x305c, 0x305d, 0x305e, 0x305f, 0x3060, 0x3061, 0x3062, 0x3063, 0x3064, 0x3065, 0x3066, 0x3067,
+    0x3068, 0x3069, 0x306a, 0x306b, 0x306c, 0x306d, 0x306e, 0x306f, 0x3070, 0x3071, 0x3072, 0x3073, 0x3074, 0x3075,
+    0x3076, 0x3077, 0x3078, 0x3079, 0x307a, 0x307b, 0x307c, 0x307d, 0x307e, 0x307f, 0x3080, 0x3081, 0x3082, 0x3083,
+    0x3084, 0x3085, 0x3086, 0x3087, 0x3088, 0x3089, 0x308a, 0x308b, 0x308c, 0x308d, 0x308e, 0x308f, 0x3090, 0x3091,
+    0x3092, 0x3093, 0x30a1, 0x30a2, 0x30a3, 0x30a4, 0x30a5, 0x30a6, 0x30a7, 0x30a8, 0x30a9, 0x30aa, 0x30ab, 0x30ac,
+    0x30ad, 0x30ae, 0x30af, 0x30b0, 0x30b1, 0x30b2, 0x30b3, 0x30b4, 0x30b5, 0x30b6, 0x30b7, 0x30b8, 0x30b9, 0x30ba,
+    0x30bb, 0x30bc, 0x30bd, 0x30be, 0x30bf, 0x30c0, 0x30c1, 0x30c2, 0x30c3, 0x30c4, 0x30c5, 0x30c6, 0x30c7, 0x30c8,
+    0x30c9, 0x30ca, 0x30cb, 0x30cc, 0x30cd, 0x30ce, 0x30cf, 0x30d0, 0x30d1, 0x30d2, 0x30d3, 0x30d4, 0x30d5, 0x30d6,
+    0x30d7, 0x30d8, 0x30d9, 0x30da, 0x30db, 0x30dc, 0x30dd, 0x30de, 0x30df, 0x30e0, 0x30e1, 0x30e2, 0x30e3, 0x30e4,
+    0x30e5, 0x30e6, 0x30e7, 0x30e8, 0x30e9, 0x30ea, 0x30eb, 0x30ec, 0x30ed, 0x30ee, 0x30ef, 0x30f0, 0x30f1, 0x30f2,
+    0x30f3, 0x30f4, 0x30f5, 0x30f6, 0x30fb, 0x3105, 0x3106, 0x3107, 0x3108, 0x3109, 0x310a, 0x310b, 0x310c, 0x310d,
+    0x310e, 0x310f, 0x3110, 0x3111, 0x3112, 0x3113, 0x3114, 0x3115, 0x3116, 0x3117, 0x3118, 0x3119, 0x311a, 0x311b,
+    0x311c, 0x311d, 0x311e, 0x311f, 0x3120, 0x3121, 0x3122, 0x3123, 0x3124, 0x3125, 0x3126, 0x3127, 0x3128, 0x3129,
+    0x3220, 0x3221, 0x3222, 0x3223, 0x3224, 0x3225, 0x3226, 0x3227, 0x3228, 0x3229, 0x4e00, 0x4e01, 0x4e03, 0x4e07,
+    0x4e08, 0x4e09, 0x4e0a, 0x4e0b, 0x4e0c, 0x4e0d, 0x4e0e, 0x4e10, 0x4e11, 0x4e13, 0x4e14, 0x4e15, 0x4e16, 0x4e18,
+    0x4e19, 0x4e1a, 0x4e1b, 0x4e1c, 0x4e1d, 0x4e1e, 0x4e22, 0x4e24, 0x4e25, 0x4e27, 0x4e28, 0x4e2a, 0x4e2b, 0x4e2c,
+    0x4e2d, 0x4e30, 0x4e32, 0x4e34, 0x4e36, 0x4e38, 0x4e39, 0x4e3a, 0x4e3b, 0x4e3d, 0x4e3e, 0x4e3f, 0x4e43, 0x4e45,
+    0x4e47, 0x4e48, 0x4e49, 0x4e4b, 0x4e4c, 0x4e4d, 0x4e4e, 0x4e4f, 0x4e50, 0x4e52, 0x4e53, 0x4e54, 0x4e56, 0x4e58,
+    0x4e59, 0x4e5c, 0x4e5d, 0x4e5e, 0x4e5f, 0x4e60, 0x4e61, 0x4e66, 0x4e69, 0x4e70, 0x4e71, 0x4e73, 0x4e7e, 0x4e86,
+    0x4e88, 0x4e89, 0x4e8b, 0x4e8c, 0x4e8d, 0x4e8e, 0x4e8f, 0x4e91, 0x4e92, 0x4e93, 0x4e94, 0x4e95, 0x4e98, 0x4e9a,
+    0x4e9b, 0x4e9f, 0x4ea0, 0x4ea1, 0x4ea2, 0x4ea4, 0x4ea5, 0x4ea6, 0x4ea7, 0x4ea8, 0x4ea9, 0x4eab, 0x4eac, 0x4ead,
+    0x4eae, 0x4eb2, 0x4eb3, 0x4eb5, 0x4eba, 0x4ebb, 0x4ebf, 0x4ec0, 0x4ec1, 0x4ec2, 0x4ec3, 0x4ec4, 0x4ec5, 0x4ec6,
+    0x4ec7, 0x4ec9, 0x4eca, 0x4ecb, 0x4ecd, 0x4ece, 0x4ed1, 0x4ed3, 0x4ed4, 0x4ed5, 0x4ed6, 0x4ed7, 0x4ed8, 0x4ed9,
+    0x4edd, 0x4ede, 0x4edf, 0x4ee1, 0x4ee3, 0x4ee4, 0x4ee5, 0x4ee8, 0x4eea, 0x4eeb, 0x4eec, 0x4ef0, 0x4ef2, 0x4ef3,
+    0x4ef5, 0x4ef6, 0x4ef7, 0x4efb, 0x4efd, 0x4eff, 0x4f01, 0x4f09, 0x4f0a, 0x4f0d, 0x4f0e, 0x4f0f, 0x4f10, 0x4f11,
+    0x4f17, 0x4f18, 0x4f19, 0x4f1a, 0x4f1b, 0x4f1e, 0x4f1f, 0x4f20, 0x4f22, 0x4f24, 0x4f25, 0x4f26, 0x4f27, 0x4f2a,
+    0x4f2b, 0x4f2f, 0x4f30, 0x4f32, 0x4f34, 0x4f36, 0x4f38, 0x4f3a, 0x4f3c, 0x4f3d, 0x4f43, 0x4f46, 0x4f4d, 0x4f4e,
+    0x4f4f, 0x4f50, 0x4f51, 0x4f53, 0x4f55, 0x4f57, 0x4f58, 0x4f59, 0x4f5a, 0x4f5b, 0x4f5c, 0x4f5d, 0x4f5e, 0x4f5f,
+    0x4f60, 0x4f63, 0x4f64, 0x4f65, 0x4f67, 0x4f69, 0x4f6c, 0x4f6f, 0x4f70, 0x4f73, 0x4f74, 0x4f76, 0x4f7b, 0x4f7c,
+    0x4f7e, 0x4f7f, 0x4f83, 0x4f84, 0x4f88, 0x4f89, 0x4f8b, 0x4f8d, 0x4f8f, 0x4f91, 0x4f94, 0x4f97, 0x4f9b, 0x4f9d,
+    0x4fa0, 0x4fa3, 0x4fa5, 0x4fa6, 0x4fa7, 0x4fa8, 0x4fa9, 0x4faa, 0x4fac, 0x4fae, 0x4faf, 0x4fb5, 0x4fbf, 0x4fc3,
+    0x4fc4, 0x4fc5, 0x4fca, 0x4fce, 0x4fcf, 0x4fd0, 0x4fd1, 0x4fd7, 0x4fd8, 0x4fda, 0x4fdc, 0x4fdd, 0x4fde, 0x4fdf,
+    0x4fe1, 0x4fe3, 0x4fe6, 0x4fe8, 0x4fe9, 0x4fea, 0x4fed, 0x4fee, 0x4fef, 0x4ff1, 0x4ff3, 0x4ff8, 0x4ffa, 0x4ffe,
+    0x500c, 0x500d, 0x500f, 0x5012, 0x5014, 0x5018, 0x5019, 0x501a, 0x501c, 0x501f, 0x5021, 0x5025, 0x5026, 0x5028,
+    0x5029, 0x502a, 0x502c, 0x502d, 0x502e, 0x503a, 0x503c, 0x503e, 0x5043, 0x5047, 0x5048, 0x504c, 0x504e, 0x504f,
+    0x5055, 0x505a, 0x505c, 0x5065, 0x506c, 0x5076, 0x5077, 0x507b, 0x507e, 0x507f, 0x5080, 0x5085, 0x5088, 0x508d,
+    0x50a3, 0x50a5, 0x50a7, 0x50a8, 0x50a9, 0x50ac, 0x50b2, 0x50ba, 0x50bb, 0x50cf, 0x50d6, 0x50da, 0x50e6, 0x50e7,
+    0x50ec, 0x50ed, 0x50ee, 0x50f3, 0x50f5, 0x50fb, 0x5106, 0x5107, 0x510b, 0x5112, 0x5121, 0x513f, 0x5140, 0x5141,
+    0x5143, 0x5144, 0x5145, 0x5146, 0x5148, 0x5149, 0x514b, 0x514d, 0x5151, 0x5154, 0x5155, 0x5156, 0x515a, 0x515c,
+    0x5162, 0x5165, 0x5168, 0x516b, 0x516c, 0x516d, 0x516e, 0x5170, 0x5171, 0x5173, 0x5174, 0x5175, 0x5176, 0x5177,
+    0x5178, 0x5179, 0x517b, 0x517c, 0x517d, 0x5180, 0x5181, 0x5182, 0x5185, 0x5188, 0x5189, 0x518c, 0x518d, 0x5192,
+    0x5195, 0x5196, 0x5197, 0x5199, 0x519b, 0x519c, 0x51a0, 0x51a2, 0x51a4, 0x51a5, 0x51ab, 0x51ac, 0x51af, 0x51b0,
+    0x51b1, 0x51b2, 0x51b3, 0x51b5, 0x51b6, 0x51b7, 0x51bb, 0x51bc, 0x51bd, 0x51c0, 0x51c4, 0x51c6, 0x51c7, 0x51c9,
+    0x51cb, 0x51cc, 0x51cf, 0x51d1, 0x51db, 0x51dd, 0x51e0, 0x51e1, 0x51e4, 0x51eb, 0x51ed, 0x51ef, 0x51f0, 0x51f3,
+    0x51f5, 0x51f6, 0x51f8, 0x51f9, 0x51fa, 0x51fb, 0x51fc, 0x51fd, 0x51ff, 0x5200, 0x5201, 0x5202, 0x5203, 0x5206,
+    0x5207, 0x5208, 0x520a, 0x520d, 0x520e, 0x5211, 0x5212, 0x5216, 0x5217, 0x5218, 0x5219, 0x521a, 0x521b, 0x521d,
+    0x5220, 0x5224, 0x5228, 0x5229, 0x522b, 0x522d, 0x522e, 0x5230, 0x5233, 0x5236, 0x5237, 0x5238, 0x5239, 0x523a,
+    0x523b, 0x523d, 0x523f, 0x5240, 0x5241, 0x5242, 0x5243, 0x524a, 0x524c, 0x524d, 0x5250, 0x5251, 0x5254, 0x5256,
+    0x525c, 0x525e, 0x5261, 0x5265, 0x5267, 0x5269, 0x526a, 0x526f, 0x5272, 0x527d, 0x527f, 0x5281, 0x5282, 0x5288,
+    0x5290, 0x5293, 0x529b, 0x529d, 0x529e, 0x529f, 0x52a0, 0x52a1, 0x52a2, 0x52a3, 0x52a8, 0x52a9, 0x52aa, 0x52ab,
+    0x52ac, 0x52ad, 0x52b1, 0x52b2, 0x52b3, 0x52be, 0x52bf, 0x52c3, 0x52c7, 0x52c9, 0x52cb, 0x52d0, 0x52d2, 0x52d6,
+    0x52d8, 0x52df, 0x52e4, 0x52f0, 0x52f9, 0x52fa, 0x52fe, 0x52ff, 0x5300, 0x5305, 0x5306, 0x5308, 0x530d, 0x530f,
+    0x5310, 0x5315, 0x5316, 0x5317, 0x5319, 0x531a, 0x531d, 0x5320, 0x5321, 0x5323, 0x5326, 0x532a, 0x532e, 0x5339,
+    0x533a, 0x533b, 0x533e, 0x533f, 0x5341, 0x5343, 0x5345, 0x5347, 0x5348, 0x5349, 0x534a, 0x534e, 0x534f, 0x5351,
+    0x5352, 0x5353, 0x5355, 0x5356, 0x5357, 0x535a, 0x535c, 0x535e, 0x535f, 0x5360, 0x5361, 0x5362, 0x5363, 0x5364,
+    0x5366, 0x5367, 0x5369, 0x536b, 0x536e, 0x536f, 0x5370, 0x5371, 0x5373, 0x5374, 0x5375, 0x5377, 0x5378, 0x537a,
+    0x537f, 0x5382, 0x5384, 0x5385, 0x5386, 0x5389, 0x538b, 0x538c, 0x538d, 0x5395, 0x5398, 0x539a, 0x539d, 0x539f,
+    0x53a2, 0x53a3, 0x53a5, 0x53a6, 0x53a8, 0x53a9, 0x53ae, 0x53b6, 0x53bb, 0x53bf, 0x53c1, 0x53c2, 0x53c8, 0x53c9,
+    0x53ca, 0x53cb, 0x53cc, 0x53cd, 0x53d1, 0x53d4, 0x53d6, 0x53d7, 0x53d8, 0x53d9, 0x53db, 0x53df, 0x53e0, 0x53e3,
+    0x53e4, 0x53e5, 0x53e6, 0x53e8, 0x53e9, 0x53ea, 0x53eb, 0x53ec, 0x53ed, 0x53ee, 0x53ef, 0x53f0, 0x53f1, 0x53f2,
+    0x53f3, 0x53f5, 0x53f6, 0x53f7, 0x53f8, 0x53f9, 0x53fb, 0x53fc, 0x53fd, 0x5401, 0x5403, 0x5404, 0x5406, 0x5408,
+    0x5409, 0x540a, 0x540c, 0x540d, 0x540e, 0x540f, 0x5410, 0x5411, 0x5412, 0x5413, 0x5415, 0x5416, 0x5417, 0x541b,
+    0x541d, 0x541e, 0x541f, 0x5420, 0x5421, 0x5423, 0x5426, 0x5427, 0x5428, 0x5429, 0x542b, 0x542c, 0x542d, 0x542e,
+    0x542f, 0x5431, 0x5432, 0x5434, 0x5435, 0x5438, 0x5439, 0x543b, 0x543c, 0x543e, 0x5440, 0x5443, 0x5446, 0x5448,
+    0x544a, 0x544b, 0x5450, 0x5452, 0x5453, 0x5454, 0x5455, 0x5456, 0x5457, 0x5458, 0x5459, 0x545b, 0x545c, 0x5462,
+    0x5464, 0x5466, 0x5468, 0x5471, 0x5472, 0x5473, 0x5475, 0x5476, 0x5477, 0x5478, 0x547b, 0x547c, 0x547d, 0x5480,
+    0x5482, 0x5484, 0x5486, 0x548b, 0x548c, 0x548e, 0x548f, 0x5490, 0x5492, 0x5494, 0x5495, 0x5496, 0x5499, 0x549a,
+    0x549b, 0x549d, 0x54a3, 0x54a4, 0x54a6, 0x54a7, 0x54a8, 0x54a9, 0x54aa, 0x54ab, 0x54ac, 0x54ad, 0x54af, 0x54b1,
+    0x54b3, 0x54b4, 0x54b8, 0x54bb, 0x54bd, 0x54bf, 0x54c0, 0x54c1, 0x54c2, 0x54c4, 0x54c6, 0x54c7, 0x54c8, 0x54c9,
+    0x54cc, 0x54cd, 0x54ce, 0x54cf, 0x54d0, 0x54d1, 0x54d2, 0x54d3, 0x54d4, 0x54d5, 0x54d7, 0x54d9, 0x54da, 0x54dc,
+    0x54dd, 0x54de, 0x54df, 0x54e5, 0x54e6, 0x54e7, 0x54e8, 0x54e9, 0x54ea, 0x54ed, 0x54ee, 0x54f2, 0x54f3, 0x54fa,
+    0x54fc, 0x54fd, 0x54ff, 0x5501, 0x5506, 0x5507, 0x5509, 0x550f, 0x5510, 0x5511, 0x5514, 0x551b, 0x5520, 0x5522,
+    0x5523, 0x5524, 0x5527, 0x552a, 0x552c, 0x552e, 0x552f, 0x5530, 0x5531, 0x5533, 0x5537, 0x553c, 0x553e, 0x553f,
+    0x5541, 0x5543, 0x5544, 0x5546, 0x5549, 0x554a, 0x5550, 0x5555, 0x5556, 0x555c, 0x5561, 0x5564, 0x5565, 0x5566,
+    0x5567, 0x556a, 0x556c, 0x556d, 0x556e, 0x5575, 0x5576, 0x5577, 0x5578, 0x557b, 0x557c, 0x557e, 0x5580, 0x5581,
+    0x5582, 0x5583, 0x5584, 0x5587, 0x5588, 0x5589, 0x558a, 0x558b, 0x558f, 0x5591, 0x5594, 0x5598, 0x5599, 0x559c,
+    0x559d, 0x559f, 0x55a7, 0x55b1, 0x55b3, 0x55b5, 0x55b7, 0x55b9, 0x55bb, 0x55bd, 0x55be, 0x55c4, 0x55c5, 0x55c9,
+    0x55cc, 0x55cd, 0x55d1, 0x55d2, 0x55d3, 0x55d4, 0x55d6, 0x55dc, 0x55dd, 0x55df, 0x55e1, 0x55e3, 0x55e4, 0x55e5,
+    0x55e6, 0x55e8, 0x55ea, 0x55eb, 0x55ec, 0x55ef, 0x55f2, 0x55f3, 0x55f5, 0x55f7, 0x55fd, 0x55fe, 0x5600, 0x5601,
+    0x5608, 0x5609, 0x560c, 0x560e, 0x560f, 0x5618, 0x561b, 0x561e, 0x561f, 0x5623, 0x5624, 0x5627, 0x562c, 0x562d,
+    0x5631, 0x5632, 0x5634, 0x5636, 0x5639, 0x563b, 0x563f, 0x564c, 0x564d, 0x564e, 0x5654, 0x5657, 0x5658, 0x5659,
+    0x565c, 0x5662, 0x5664, 0x5668, 0x5669, 0x566a, 0x566b, 0x566c, 0x5671, 0x5676, 0x567b, 0x567c, 0x5685, 0x5686,
+    0x568e, 0x568f, 0x5693, 0x56a3, 0x56af, 0x56b7, 0x56bc, 0x56ca, 0x56d4, 0x56d7, 0x56da, 0x56db, 0x56dd, 0x56de,
+    0x56df, 0x56e0, 0x56e1, 0x56e2, 0x56e4, 0x56eb, 0x56ed, 0x56f0, 0x56f1, 0x56f4, 0x56f5, 0x56f9, 0x56fa, 0x56fd,
+    0x56fe, 0x56ff, 0x5703, 0x5704, 0x5706, 0x5708, 0x5709, 0x570a, 0x571c, 0x571f, 0x5723, 0x5728, 0x5729, 0x572a,
+    0x572c, 0x572d, 0x572e, 0x572f, 0x5730, 0x5733, 0x5739, 0x573a, 0x573b, 0x573e, 0x5740, 0x5742, 0x5747, 0x574a,
+    0x574c, 0x574d, 0x574e, 0x574f, 0x5750, 0x5751, 0x5757, 0x575a, 0x575b, 0x575c, 0x575d, 0x575e, 0x575f, 0x5760,
+    0x5761, 0x5764, 0x5766, 0x5768, 0x5769, 0x576a, 0x576b, 0x576d, 0x576f, 0x5773, 0x5776, 0x5777, 0x577b, 0x577c,
+    0x5782, 0x5783, 0x5784, 0x5785, 0x5786, 0x578b, 0x578c, 0x5792, 0x5793, 0x579b, 0x57a0, 0x57a1, 0x57a2, 0x57a3,
+    0x57a4, 0x57a6, 0x57a7, 0x57a9, 0x57ab, 0x57ad, 0x57ae, 0x57b2, 0x57b4, 0x57b8, 0x57c2, 0x57c3, 0x57cb, 0x57ce,
+    0x57cf, 0x57d2, 0x57d4, 0x57d5, 0x57d8, 0x57d9, 0x57da, 0x57dd, 0x57df, 0x57e0, 0x57e4, 0x57ed, 0x57ef, 0x57f4,
+    0x57f8, 0x57f9, 0x57fa, 0x57fd, 0x5800, 0x5802, 0x5806, 0x5807, 0x580b, 0x580d, 0x5811, 0x5815, 0x5819, 0x581e,
+    0x5820, 0x5821, 0x5824, 0x582a, 0x5830, 0x5835, 0x5844, 0x584c, 0x584d, 0x5851, 0x5854, 0x5858, 0x585e, 0x5865,
+    0x586b, 0x586c, 0x587e, 0x5880, 0x5881, 0x5883, 0x5885, 0x5889, 0x5892, 0x5893, 0x5899, 0x589a, 0x589e, 0x589f,
+    0x58a8, 0x58a9, 0x58bc, 0x58c1, 0x58c5, 0x58d1, 0x58d5, 0x58e4, 0x58eb, 0x58ec, 0x58ee, 0x58f0, 0x58f3, 0x58f6,
+    0x58f9, 0x5902, 0x5904, 0x5907, 0x590d, 0x590f, 0x5914, 0x5915, 0x5916, 0x5919, 0x591a, 0x591c, 0x591f, 0x5924,
+    0x5925, 0x5927, 0x5929, 0x592a, 0x592b, 0x592d, 0x592e, 0x592f, 0x5931, 0x5934, 0x5937, 0x5938, 0x5939, 0x593a,
+    0x593c, 0x5941, 0x5942, 0x5944, 0x5947, 0x5948, 0x5949, 0x594b, 0x594e, 0x594f, 0x5951, 0x5954, 0x5955, 0x5956,
+    0x5957, 0x5958, 0x595a, 0x5960, 0x5962, 0x5965, 0x5973, 0x5974, 0x5976, 0x5978, 0x5979, 0x597d, 0x5981, 0x5982,
+    0x5983, 0x5984, 0x5986, 0x5987, 0x5988, 0x598a, 0x598d, 0x5992, 0x5993, 0x5996, 0x5997, 0x5999, 0x599e, 0x59a3,
+    0x59a4, 0x59a5, 0x59a8, 0x59a9, 0x59aa, 0x59ab, 0x59ae, 0x59af, 0x59b2, 0x59b9, 0x59bb, 0x59be, 0x59c6, 0x59ca,
+    0x59cb, 0x59d0, 0x59d1, 0x59d2, 0x59d3, 0x59d4, 0x59d7, 0x59d8, 0x59da, 0x59dc, 0x59dd, 0x59e3, 0x59e5, 0x59e8,
+    0x59ec, 0x59f9, 0x59fb, 0x59ff, 0x5a01, 0x5a03, 0x5a04, 0x5a05, 0x5a06, 0x5a07, 0x5a08, 0x5a09, 0x5a0c, 0x5a11,
+    0x5a13, 0x5a18, 0x5a1c, 0x5a1f, 0x5a20, 0x5a23, 0x5a25, 0x5a29, 0x5a31, 0x5a32, 0x5a34, 0x5a36, 0x5a3c, 0x5a40,
+    0x5a46, 0x5a49, 0x5a4a, 0x5a55, 0x5a5a, 0x5a62, 0x5a67, 0x5a6a, 0x5a74, 0x5a75, 0x5a76, 0x5a77, 0x5a7a, 0x5a7f,
+    0x5a92, 0x5a9a, 0x5a9b, 0x5aaa, 0x5ab2, 0x5ab3, 0x5ab5, 0x5ab8, 0x5abe, 0x5ac1, 0x5ac2, 0x5ac9, 0x5acc, 0x5ad2,
+    0x5ad4, 0x5ad6, 0x5ad8, 0x5adc, 0x5ae0, 0x5ae1, 0x5ae3, 0x5ae6, 0x5ae9, 0x5aeb, 0x5af1, 0x5b09, 0x5b16, 0x5b17,
+    0x5b32, 0x5b34, 0x5b37, 0x5b40, 0x5b50, 0x5b51, 0x5b53, 0x5b54, 0x5b55, 0x5b57, 0x5b58, 0x5b59, 0x5b5a, 0x5b5b,
+    0x5b5c, 0x5b5d, 0x5b5f, 0x5b62, 0x5b63, 0x5b64, 0x5b65, 0x5b66, 0x5b69, 0x5b6a, 0x5b6c, 0x5b70, 0x5b71, 0x5b73,
+    0x5b75, 0x5b7a, 0x5b7d, 0x5b80, 0x5b81, 0x5b83, 0x5b84, 0x5b85, 0x5b87, 0x5b88, 0x5b89, 0x5b8b, 0x5b8c, 0x5b8f,
+    0x5b93, 0x5b95, 0x5b97, 0x5b98, 0x5b99, 0x5b9a, 0x5b9b, 0x5b9c, 0x5b9d, 0x5b9e, 0x5ba0, 0x5ba1, 0x5ba2, 0x5ba3,
+    0x5ba4, 0x5ba5, 0x5ba6, 0x5baa, 0x5bab, 0x5bb0, 0x5bb3, 0x5bb4, 0x5bb5, 0x5bb6, 0x5bb8, 0x5bb9, 0x5bbd, 0x5bbe,
+    0x5bbf, 0x5bc2, 0x5bc4, 0x5bc5, 0x5bc6, 0x5bc7, 0x5bcc, 0x5bd0, 0x5bd2, 0x5bd3, 0x5bdd, 0x5bde, 0x5bdf, 0x5be1,
+    0x5be4, 0x5be5, 0x5be8, 0x5bee, 0x5bf0, 0x5bf8, 0x5bf9, 0x5bfa, 0x5bfb, 0x5bfc, 0x5bff, 0x5c01, 0x5c04, 0x5c06,
+    0x5c09, 0x5c0a, 0x5c0f, 0x5c11, 0x5c14, 0x5c15, 0x5c16, 0x5c18, 0x5c1a, 0x5c1c, 0x5c1d, 0x5c22, 0x5c24, 0x5c25,
+    0x5c27, 0x5c2c, 0x5c31, 0x5c34, 0x5c38, 0x5c39, 0x5c3a, 0x5c3b, 0x5c3c, 0x5c3d, 0x5c3e, 0x5c3f, 0x5c40, 0x5c41,
+    0x5c42, 0x5c45, 0x5c48, 0x5c49, 0x5c4a, 0x5c4b, 0x5c4e, 0x5c4f, 0x5c50, 0x5c51, 0x5c55, 0x5c59, 0x5c5e, 0x5c60,
+    0x5c61, 0x5c63, 0x5c65, 0x5c66, 0x5c6e, 0x5c6f, 0x5c71, 0x5c79, 0x5c7a, 0x5c7f, 0x5c81, 0x5c82, 0x5c88, 0x5c8c,
+    0x5c8d, 0x5c90, 0x5c91, 0x5c94, 0x5c96, 0x5c97, 0x5c98, 0x5c99, 0x5c9a, 0x5c9b, 0x5c9c, 0x5ca2, 0x5ca3, 0x5ca9,
+    0x5cab, 0x5cac, 0x5cad, 0x5cb1, 0x5cb3, 0x5cb5, 0x5cb7, 0x5cb8, 0x5cbd, 0x5cbf, 0x5cc1, 0x5cc4, 0x5ccb, 0x5cd2,
+    0x5cd9, 0x5ce1, 0x5ce4, 0x5ce5, 0x5ce6, 0x5ce8, 0x5cea, 0x5ced, 0x5cf0, 0x5cfb, 0x5d02, 0x5d03, 0x5d06, 0x5d07,
+    0x5d0e, 0x5d14, 0x5d16, 0x5d1b, 0x5d1e, 0x5d24, 0x5d26, 0x5d27, 0x5d29, 0x5d2d, 0x5d2e, 0x5d34, 0x5d3d, 0x5d3e,
+    0x5d47, 0x5d4a, 0x5d4b, 0x5d4c, 0x5d58, 0x5d5b, 0x5d5d, 0x5d69, 0x5d6b, 0x5d6c, 0x5d6f, 0x5d74, 0x5d82, 0x5d99,
+    0x5d9d, 0x5db7, 0x5dc5, 0x5dcd, 0x5ddb, 0x5ddd, 0x5dde, 0x5de1, 0x5de2, 0x5de5, 0x5de6, 0x5de7, 0x5de8, 0x5de9,
+    0x5deb, 0x5dee, 0x5def, 0x5df1, 0x5df2, 0x5df3, 0x5df4, 0x5df7, 0x5dfd, 0x5dfe, 0x5e01, 0x5e02, 0x5e03, 0x5e05,
+    0x5e06, 0x5e08, 0x5e0c, 0x5e0f, 0x5e10, 0x5e11, 0x5e14, 0x5e15, 0x5e16, 0x5e18, 0x5e19, 0x5e1a, 0x5e1b, 0x5e1c,
+    0x5e1d, 0x5e26, 0x5e27, 0x5e2d, 0x5e2e, 0x5e31, 0x5e37, 0x5e38, 0x5e3b, 0x5e3c, 0x5e3d, 0x5e42, 0x5e44, 0x5e45,
+    0x5e4c, 0x5e54, 0x5e55, 0x5e5b, 0x5e5e, 0x5e61, 0x5e62, 0x5e72, 0x5e73, 0x5e74, 0x5e76, 0x5e78, 0x5e7a, 0x5e7b,
+    0x5e7c, 0x5e7d, 0x5e7f, 0x5e80, 0x5e84, 0x5e86, 0x5e87, 0x5e8a, 0x5e8b, 0x5e8f, 0x5e90, 0x5e91, 0x5e93, 0x5e94,
+    0x5e95, 0x5e96, 0x5e97, 0x5e99, 0x5e9a, 0x5e9c, 0x5e9e, 0x5e9f, 0x5ea0, 0x5ea5, 0x5ea6, 0x5ea7, 0x5ead, 0x5eb3,
+    0x5eb5, 0x5eb6, 0x5eb7, 0x5eb8, 0x5eb9, 0x5ebe, 0x5ec9, 0x5eca, 0x5ed1, 0x5ed2, 0x5ed3, 0x5ed6, 0x5edb, 0x5ee8,
+    0x5eea, 0x5ef4, 0x5ef6, 0x5ef7, 0x5efa, 0x5efe, 0x5eff, 0x5f00, 0x5f01, 0x5f02, 0x5f03, 0x5f04, 0x5f08, 0x5f0a,
+    0x5f0b, 0x5f0f, 0x5f11, 0x5f13, 0x5f15, 0x5f17, 0x5f18, 0x5f1b, 0x5f1f, 0x5f20, 0x5f25, 0x5f26, 0x5f27, 0x5f29,
+    0x5f2a, 0x5f2d, 0x5f2f, 0x5f31, 0x5f39, 0x5f3a, 0x5f3c, 0x5f40, 0x5f50, 0x5f52, 0x5f53, 0x5f55, 0x5f56, 0x5f57,
+    0x5f58, 0x5f5d, 0x5f61, 0x5f62, 0x5f64, 0x5f66, 0x5f69, 0x5f6a, 0x5f6c, 0x5f6d, 0x5f70, 0x5f71, 0x5f73, 0x5f77,
+    0x5f79, 0x5f7b, 0x5f7c, 0x5f80, 0x5f81, 0x5f82, 0x5f84, 0x5f85, 0x5f87, 0x5f88, 0x5f89, 0x5f8a, 0x5f8b, 0x5f8c,
+    0x5f90, 0x5f92, 0x5f95, 0x5f97, 0x5f98, 0x5f99, 0x5f9c, 0x5fa1, 0x5fa8, 0x5faa, 0x5fad, 0x5fae, 0x5fb5, 0x5fb7,
+    0x5fbc, 0x5fbd, 0x5fc3, 0x5fc4, 0x5fc5, 0x5fc6, 0x5fc9, 0x5fcc, 0x5fcd, 0x5fcf, 0x5fd0, 0x5fd1, 0x5fd2, 0x5fd6,
+    0x5fd7, 0x5fd8, 0x5fd9, 0x5fdd, 0x5fe0, 0x5fe1, 0x5fe4, 0x5fe7, 0x5fea, 0x5feb, 0x5fed, 0x5fee, 0x5ff1, 0x5ff5,
+    0x5ff8, 0x5ffb, 0x5ffd, 0x5ffe, 0x5fff, 0x6000, 0x6001, 0x6002, 0x6003, 0x6004, 0x6005, 0x6006, 0x600a, 0x600d,
+    0x600e, 0x600f, 0x6012, 0x6014, 0x6015, 0x6016, 0x6019, 0x601b, 0x601c, 0x601d, 0x6020, 0x6021, 0x6025, 0x6026,
+    0x6027, 0x6028, 0x6029, 0x602a, 0x602b, 0x602f, 0x6035, 0x603b, 0x603c, 0x603f, 0x6041, 0x6042, 0x6043, 0x604b,
+    0x604d, 0x6050, 0x6052, 0x6055, 0x6059, 0x605a, 0x605d, 0x6062, 0x6063, 0x6064, 0x6067, 0x6068, 0x6069, 0x606a,
+    0x606b, 0x606c, 0x606d, 0x606f, 0x6070, 0x6073, 0x6076, 0x6078, 0x6079, 0x607a, 0x607b, 0x607c, 0x607d, 0x607f,
+    0x6083, 0x6084, 0x6089, 0x608c, 0x608d, 0x6092, 0x6094, 0x6096, 0x609a, 0x609b, 0x609d, 0x609f, 0x60a0, 0x60a3,
+    0x60a6, 0x60a8, 0x60ab, 0x60ac, 0x60ad, 0x60af, 0x60b1, 0x60b2, 0x60b4, 0x60b8, 0x60bb, 0x60bc, 0x60c5, 0x60c6,
+    0x60ca, 0x60cb, 0x60d1, 0x60d5, 0x60d8, 0x60da, 0x60dc, 0x60dd, 0x60df, 0x60e0, 0x60e6, 0x60e7, 0x60e8, 0x60e9,
+    0x60eb, 0x60ec, 0x60ed, 0x60ee, 0x60ef, 0x60f0, 0x60f3, 0x60f4, 0x60f6, 0x60f9, 0x60fa, 0x6100, 0x6101, 0x6106,
+    0x6108, 0x6109, 0x610d, 0x610e, 0x610f, 0x6115, 0x611a, 0x611f, 0x6120, 0x6123, 0x6124, 0x6126, 0x6127, 0x612b,
+    0x613f, 0x6148, 0x614a, 0x614c, 0x614e, 0x6151, 0x6155, 0x615d, 0x6162, 0x6167, 0x6168, 0x6170, 0x6175, 0x6177,
+    0x618b, 0x618e, 0x6194, 0x619d, 0x61a7, 0x61a8, 0x61a9, 0x61ac, 0x61b7, 0x61be, 0x61c2, 0x61c8, 0x61ca, 0x61cb,
+    0x61d1, 0x61d2, 0x61d4, 0x61e6, 0x61f5, 0x61ff, 0x6206, 0x6208, 0x620a, 0x620b, 0x620c, 0x620d, 0x620e, 0x620f,
+    0x6210, 0x6211, 0x6212, 0x6215, 0x6216, 0x6217, 0x6218, 0x621a, 0x621b, 0x621f, 0x6221, 0x6222, 0x6224, 0x6225,
+    0x622a, 0x622c, 0x622e, 0x6233, 0x6234, 0x6237, 0x623d, 0x623e, 0x623f, 0x6240, 0x6241, 0x6243, 0x6247, 0x6248,
+    0x6249, 0x624b, 0x624c, 0x624d, 0x624e, 0x6251, 0x6252, 0x6253, 0x6254, 0x6258, 0x625b, 0x6263, 0x6266, 0x6267,
+    0x6269, 0x626a, 0x626b, 0x626c, 0x626d, 0x626e, 0x626f, 0x6270, 0x6273, 0x6276, 0x6279, 0x627c, 0x627e, 0x627f,
+    0x6280, 0x6284, 0x6289, 0x628a, 0x6291, 0x6292, 0x6293, 0x6295, 0x6296, 0x6297, 0x6298, 0x629a, 0x629b, 0x629f,
+    0x62a0, 0x62a1, 0x62a2, 0x62a4, 0x62a5, 0x62a8, 0x62ab, 0x62ac, 0x62b1, 0x62b5, 0x62b9, 0x62bb, 0x62bc, 0x62bd,
+    0x62bf, 0x62c2, 0x62c4, 0x62c5, 0x62c6, 0x62c7, 0x62c8, 0x62c9, 0x62ca, 0x62cc, 0x62cd, 0x62ce, 0x62d0, 0x62d2,
+    0x62d3, 0x62d4, 0x62d6, 0x62d7, 0x62d8, 0x62d9, 0x62da, 0x62db, 0x62dc, 0x62df, 0x62e2, 0x62e3, 0x62e5, 0x62e6,
+    0x62e7, 0x62e8, 0x62e9, 0x62ec, 0x62ed, 0x62ee, 0x62ef, 0x62f1, 0x62f3, 0x62f4, 0x62f6, 0x62f7, 0x62fc, 0x62fd,
+    0x62fe, 0x62ff, 0x6301, 0x6302, 0x6307, 0x6308, 0x6309, 0x630e, 0x6311, 0x6316, 0x631a, 0x631b, 0x631d, 0x631e,
+    0x631f, 0x6320, 0x6321, 0x6322, 0x6323, 0x6324, 0x6325, 0x6328, 0x632a, 0x632b, 0x632f, 0x6332, 0x6339, 0x633a,
+    0x633d, 0x6342, 0x6343, 0x6345, 0x6346, 0x6349, 0x634b, 0x634c, 0x634d, 0x634e, 0x634f, 0x6350, 0x6355, 0x635e,
+    0x635f, 0x6361, 0x6362, 0x6363, 0x6367, 0x6369, 0x636d, 0x636e, 0x6371, 0x6376, 0x6377, 0x637a, 0x637b, 0x6380,
+    0x6382, 0x6387, 0x6388, 0x6389, 0x638a, 0x638c, 0x638e, 0x638f, 0x6390, 0x6392, 0x6396, 0x6398, 0x63a0, 0x63a2,
+    0x63a3, 0x63a5, 0x63a7, 0x63a8, 0x63a9, 0x63aa, 0x63ac, 0x63ad, 0x63ae, 0x63b0, 0x63b3, 0x63b4, 0x63b7, 0x63b8,
+    0x63ba, 0x63bc, 0x63be, 0x63c4, 0x63c6, 0x63c9, 0x63cd, 0x63ce, 0x63cf, 0x63d0, 0x63d2, 0x63d6, 0x63de, 0x63e0,
+    0x63e1, 0x63e3, 0x63e9, 0x63ea, 0x63ed, 0x63f2, 0x63f4, 0x63f6, 0x63f8, 0x63fd, 0x63ff, 0x6400, 0x6401, 0x6402,
+    0x6405, 0x640b, 0x640c, 0x640f, 0x6410, 0x6413, 0x6414, 0x641b, 0x641c, 0x641e, 0x6420, 0x6421, 0x6426, 0x642a,
+    0x642c, 0x642d, 0x6434, 0x643a, 0x643d, 0x643f, 0x6441, 0x6444, 0x6445, 0x6446, 0x6447, 0x6448, 0x644a, 0x6452,
+    0x6454, 0x6458, 0x645e, 0x6467, 0x6469, 0x646d, 0x6478, 0x6479, 0x647a, 0x6482, 0x6484, 0x6485, 0x6487, 0x6491,
+    0x6492, 0x6495, 0x6496, 0x6499, 0x649e, 0x64a4, 0x64a9, 0x64ac, 0x64ad, 0x64ae, 0x64b0, 0x64b5, 0x64b7, 0x64b8,
+    0x64ba, 0x64bc, 0x64c0, 0x64c2, 0x64c5, 0x64cd, 0x64ce, 0x64d0, 0x64d2, 0x64d7, 0x64d8, 0x64de, 0x64e2, 0x64e4,
+    0x64e6, 0x6500, 0x6509, 0x6512, 0x6518, 0x6525, 0x652b, 0x652e, 0x652f, 0x6534, 0x6535, 0x6536, 0x6538, 0x6539,
+    0x653b, 0x653e, 0x653f, 0x6545, 0x6548, 0x6549, 0x654c, 0x654f, 0x6551, 0x6555, 0x6556, 0x6559, 0x655b, 0x655d,
+    0x655e, 0x6562, 0x6563, 0x6566, 0x656b, 0x656c, 0x6570, 0x6572, 0x6574, 0x6577, 0x6587, 0x658b, 0x658c, 0x6590,
+    0x6591, 0x6593, 0x6597, 0x6599, 0x659b, 0x659c, 0x659f, 0x65a1, 0x65a4, 0x65a5, 0x65a7, 0x65a9, 0x65ab, 0x65ad,
+    0x65af, 0x65b0, 0x65b9, 0x65bc, 0x65bd, 0x65c1, 0x65c3, 0x65c4, 0x65c5, 0x65c6, 0x65cb, 0x65cc, 0x65ce, 0x65cf,
+    0x65d2, 0x65d6, 0x65d7, 0x65e0, 0x65e2, 0x65e5, 0x65e6, 0x65e7, 0x65e8, 0x65e9, 0x65ec, 0x65ed, 0x65ee, 0x65ef,
+    0x65f0, 0x65f1, 0x65f6, 0x65f7, 0x65fa, 0x6600, 0x6602, 0x6603, 0x6606, 0x660a, 0x660c, 0x660e, 0x660f, 0x6613,
+    0x6614, 0x6615, 0x6619, 0x661d, 0x661f, 0x6620, 0x6625, 0x6627, 0x6628, 0x662d, 0x662f, 0x6631, 0x6634, 0x6635,
+    0x6636, 0x663c, 0x663e, 0x6641, 0x6643, 0x664b, 0x664c, 0x664f, 0x6652, 0x6653, 0x6654, 0x6655, 0x6656, 0x6657,
+    0x665a, 0x665f, 0x6661, 0x6664, 0x6666, 0x6668, 0x666e, 0x666f, 0x6670, 0x6674, 0x6676, 0x6677, 0x667a, 0x667e,
+    0x6682, 0x6684, 0x6687, 0x668c, 0x6691, 0x6696, 0x6697, 0x669d, 0x66a7, 0x66a8, 0x66ae, 0x66b4, 0x66b9, 0x66be,
+    0x66d9, 0x66db, 0x66dc, 0x66dd, 0x66e6, 0x66e9, 0x66f0, 0x66f2, 0x66f3, 0x66f4, 0x66f7, 0x66f9, 0x66fc, 0x66fe,
+    0x66ff, 0x6700, 0x6708, 0x6709, 0x670a, 0x670b, 0x670d, 0x6710, 0x6714, 0x6715, 0x6717, 0x671b, 0x671d, 0x671f,
+    0x6726, 0x6728, 0x672a, 0x672b, 0x672c, 0x672d, 0x672f, 0x6731, 0x6734, 0x6735, 0x673a, 0x673d, 0x6740, 0x6742,
+    0x6743, 0x6746, 0x6748, 0x6749, 0x674c, 0x674e, 0x674f, 0x6750, 0x6751, 0x6753, 0x6756, 0x675c, 0x675e, 0x675f,
+    0x6760, 0x6761, 0x6765, 0x6768, 0x6769, 0x676a, 0x676d, 0x676f, 0x6770, 0x6772, 0x6773, 0x6775, 0x6777, 0x677c,
+    0x677e, 0x677f, 0x6781, 0x6784, 0x6787, 0x6789, 0x678b, 0x6790, 0x6795, 0x6797, 0x6798, 0x679a, 0x679c, 0x679d,
+    0x679e, 0x67a2, 0x67a3, 0x67a5, 0x67a7, 0x67a8, 0x67aa, 0x67ab, 0x67ad, 0x67af, 0x67b0, 0x67b3, 0x67b5, 0x67b6,
+    0x67b7, 0x67b8, 0x67c1, 0x67c3, 0x67c4, 0x67cf, 0x67d0, 0x67d1, 0x67d2, 0x67d3, 0x67d4, 0x67d8, 0x67d9, 0x67da,
+    0x67dc, 0x67dd, 0x67de, 0x67e0, 0x67e2, 0x67e5, 0x67e9, 0x67ec, 0x67ef, 0x67f0, 0x67f1, 0x67f3, 0x67f4, 0x67fd,
+    0x67ff, 0x6800, 0x6805, 0x6807, 0x6808, 0x6809, 0x680a, 0x680b, 0x680c, 0x680e, 0x680f, 0x6811, 0x6813, 0x6816,
+    0x6817, 0x681d, 0x6821, 0x6829, 0x682a, 0x6832, 0x6833, 0x6837, 0x6838, 0x6839, 0x683c, 0x683d, 0x683e, 0x6840,
+    0x6841, 0x6842, 0x6843, 0x6844, 0x6845, 0x6846, 0x6848, 0x6849, 0x684a, 0x684c, 0x684e, 0x6850, 0x6851, 0x6853,
+    0x6854, 0x6855, 0x6860, 0x6861, 0x6862, 0x6863, 0x6864, 0x6865, 0x6866, 0x6867, 0x6868, 0x6869, 0x686b, 0x6874,
+    0x6876, 0x6877, 0x6881, 0x6883, 0x6885, 0x6886, 0x688f, 0x6893, 0x6897, 0x68a2, 0x68a6, 0x68a7, 0x68a8, 0x68ad,
+    0x68af, 0x68b0, 0x68b3, 0x68b5, 0x68c0, 0x68c2, 0x68c9, 0x68cb, 0x68cd, 0x68d2, 0x68d5, 0x68d8, 0x68da, 0x68e0,
+    0x68e3, 0x68ee, 0x68f0, 0x68f1, 0x68f5, 0x68f9, 0x68fa, 0x68fc, 0x6901, 0x6905, 0x690b, 0x690d, 0x690e, 0x6910,
+    0x6912, 0x691f, 0x6920, 0x6924, 0x692d, 0x6930, 0x6934, 0x6939, 0x693d, 0x693f, 0x6942, 0x6954, 0x6957, 0x695a,
+    0x695d, 0x695e, 0x6960, 0x6963, 0x6966, 0x696b, 0x696e, 0x6971, 0x6977, 0x6978, 0x6979, 0x697c, 0x6980, 0x6982,
+    0x6984, 0x6986, 0x6987, 0x6988, 0x6989, 0x698d, 0x6994, 0x6995, 0x6998, 0x699b, 0x699c, 0x69a7, 0x69a8, 0x69ab,
+    0x69ad, 0x69b1, 0x69b4, 0x69b7, 0x69bb, 0x69c1, 0x69ca, 0x69cc, 0x69ce, 0x69d0, 0x69d4, 0x69db, 0x69df, 0x69e0,
+    0x69ed, 0x69f2, 0x69fd, 0x69ff, 0x6a0a, 0x6a17, 0x6a18, 0x6a1f, 0x6a21, 0x6a28, 0x6a2a, 0x6a2f, 0x6a31, 0x6a35,
+    0x6a3d, 0x6a3e, 0x6a44, 0x6a47, 0x6a50, 0x6a58, 0x6a59, 0x6a5b, 0x6a61, 0x6a65, 0x6a71, 0x6a79, 0x6a7c, 0x6a80,
+    0x6a84, 0x6a8e, 0x6a90, 0x6a91, 0x6a97, 0x6aa0, 0x6aa9, 0x6aab, 0x6aac, 0x6b20, 0x6b21, 0x6b22, 0x6b23, 0x6b24,
+    0x6b27, 0x6b32, 0x6b37, 0x6b39, 0x6b3a, 0x6b3e, 0x6b43, 0x6b46, 0x6b47, 0x6b49, 0x6b4c, 0x6b59, 0x6b62, 0x6b63,
+    0x6b64, 0x6b65, 0x6b66, 0x6b67, 0x6b6a, 0x6b79, 0x6b7b, 0x6b7c, 0x6b81, 0x6b82, 0x6b83, 0x6b84, 0x6b86, 0x6b87,
+    0x6b89, 0x6b8a, 0x6b8b, 0x6b8d, 0x6b92, 0x6b93, 0x6b96, 0x6b9a, 0x6b9b, 0x6ba1, 0x6baa, 0x6bb3, 0x6bb4, 0x6bb5,
+    0x6bb7, 0x6bbf, 0x6bc1, 0x6bc2, 0x6bc5, 0x6bcb, 0x6bcd, 0x6bcf, 0x6bd2, 0x6bd3, 0x6bd4, 0x6bd5, 0x6bd6, 0x6bd7,
+    0x6bd9, 0x6bdb, 0x6be1, 0x6bea, 0x6beb, 0x6bef, 0x6bf3, 0x6bf5, 0x6bf9, 0x6bfd, 0x6c05, 0x6c06, 0x6c07, 0x6c0d,
+    0x6c0f, 0x6c10, 0x6c11, 0x6c13, 0x6c14, 0x6c15, 0x6c16, 0x6c18, 0x6c19, 0x6c1a, 0x6c1b, 0x6c1f, 0x6c21, 0x6c22,
+    0x6c24, 0x6c26, 0x6c27, 0x6c28, 0x6c29, 0x6c2a, 0x6c2e, 0x6c2f, 0x6c30, 0x6c32, 0x6c34, 0x6c35, 0x6c38, 0x6c3d,
+    0x6c40, 0x6c41, 0x6c42, 0x6c46, 0x6c47, 0x6c49, 0x6c4a, 0x6c50, 0x6c54, 0x6c55, 0x6c57, 0x6c5b, 0x6c5c, 0x6c5d,
+    0x6c5e, 0x6c5f, 0x6c60, 0x6c61, 0x6c64, 0x6c68, 0x6c69, 0x6c6a, 0x6c70, 0x6c72, 0x6c74, 0x6c76, 0x6c79, 0x6c7d,
+    0x6c7e, 0x6c81, 0x6c82, 0x6c83, 0x6c85, 0x6c86, 0x6c88, 0x6c89, 0x6c8c, 0x6c8f, 0x6c90, 0x6c93, 0x6c94, 0x6c99,
+    0x6c9b, 0x6c9f, 0x6ca1, 0x6ca3, 0x6ca4, 0x6ca5, 0x6ca6, 0x6ca7, 0x6ca9, 0x6caa, 0x6cab, 0x6cad, 0x6cae, 0x6cb1,
+    0x6cb2, 0x6cb3, 0x6cb8, 0x6cb9, 0x6cbb, 0x6cbc, 0x6cbd, 0x6cbe, 0x6cbf, 0x6cc4, 0x6cc5, 0x6cc9, 0x6cca, 0x6ccc,
+    0x6cd0, 0x6cd3, 0x6cd4, 0x6cd5, 0x6cd6, 0x6cd7, 0x6cdb, 0x6cde, 0x6ce0, 0x6ce1, 0x6ce2, 0x6ce3, 0x6ce5, 0x6ce8,
+    0x6cea, 0x6ceb, 0x6cee, 0x6cef, 0x6cf0, 0x6cf1, 0x6cf3, 0x6cf5, 0x6cf6, 0x6cf7, 0x6cf8, 0x6cfa, 0x6cfb, 0x6cfc,
+    0x6cfd, 0x6cfe, 0x6d01, 0x6d04, 0x6d07, 0x6d0b, 0x6d0c, 0x6d0e, 0x6d12, 0x6d17, 0x6d19, 0x6d1a, 0x6d1b, 0x6d1e,
+    0x6d25, 0x6d27, 0x6d2a, 0x6d2b, 0x6d2e, 0x6d31, 0x6d32, 0x6d33, 0x6d35, 0x6d39, 0x6d3b, 0x6d3c, 0x6d3d, 0x6d3e,
+    0x6d41, 0x6d43, 0x6d45, 0x6d46, 0x6d47, 0x6d48, 0x6d4a, 0x6d4b, 0x6d4d, 0x6d4e, 0x6d4f, 0x6d51, 0x6d52, 0x6d53,
+    0x6d54, 0x6d59, 0x6d5a, 0x6d5c, 0x6d5e, 0x6d60, 0x6d63, 0x6d66, 0x6d69, 0x6d6a, 0x6d6e, 0x6d6f, 0x6d74, 0x6d77,
+    0x6d78, 0x6d7c, 0x6d82, 0x6d85, 0x6d88, 0x6d89, 0x6d8c, 0x6d8e, 0x6d91, 0x6d93, 0x6d94, 0x6d95, 0x6d9b, 0x6d9d,
+    0x6d9e, 0x6d9f, 0x6da0, 0x6da1, 0x6da3, 0x6da4, 0x6da6, 0x6da7, 0x6da8, 0x6da9, 0x6daa, 0x6dab, 0x6dae, 0x6daf,
+    0x6db2, 0x6db5, 0x6db8, 0x6dbf, 0x6dc0, 0x6dc4, 0x6dc5, 0x6dc6, 0x6dc7, 0x6dcb, 0x6dcc, 0x6dd1, 0x6dd6, 0x6dd8,
+    0x6dd9, 0x6ddd, 0x6dde, 0x6de0, 0x6de1, 0x6de4, 0x6de6, 0x6deb, 0x6dec, 0x6dee, 0x6df1, 0x6df3, 0x6df7, 0x6df9,
+    0x6dfb, 0x6dfc, 0x6e05, 0x6e0a, 0x6e0c, 0x6e0d, 0x6e0e, 0x6e10, 0x6e11, 0x6e14, 0x6e16, 0x6e17, 0x6e1a, 0x6e1d,
+    0x6e20, 0x6e21, 0x6e23, 0x6e24, 0x6e25, 0x6e29, 0x6e2b, 0x6e2d, 0x6e2f, 0x6e32, 0x6e34, 0x6e38, 0x6e3a, 0x6e43,
+    0x6e44, 0x6e4d, 0x6e4e, 0x6e53, 0x6e54, 0x6e56, 0x6e58, 0x6e5b, 0x6e5f, 0x6e6b, 0x6e6e, 0x6e7e, 0x6e7f, 0x6e83,
+    0x6e85, 0x6e86, 0x6e89, 0x6e8f, 0x6e90, 0x6e98, 0x6e9c, 0x6e9f, 0x6ea2, 0x6ea5, 0x6ea7, 0x6eaa, 0x6eaf, 0x6eb1,
+    0x6eb2, 0x6eb4, 0x6eb6, 0x6eb7, 0x6eba, 0x6ebb, 0x6ebd, 0x6ec1, 0x6ec2, 0x6ec7, 0x6ecb, 0x6ecf, 0x6ed1, 0x6ed3,
+    0x6ed4, 0x6ed5, 0x6ed7, 0x6eda, 0x6ede, 0x6edf, 0x6ee0, 0x6ee1, 0x6ee2, 0x6ee4, 0x6ee5, 0x6ee6, 0x6ee8, 0x6ee9,
+    0x6ef4, 0x6ef9, 0x6f02, 0x6f06, 0x6f09, 0x6f0f, 0x6f13, 0x6f14, 0x6f15, 0x6f20, 0x6f24, 0x6f29, 0x6f2a, 0x6f2b,
+    0x6f2d, 0x6f2f, 0x6f31, 0x6f33, 0x6f36, 0x6f3e, 0x6f46, 0x6f47, 0x6f4b, 0x6f4d, 0x6f58, 0x6f5c, 0x6f5e, 0x6f62,
+    0x6f66, 0x6f6d, 0x6f6e, 0x6f72, 0x6f74, 0x6f78, 0x6f7a, 0x6f7c, 0x6f84, 0x6f88, 0x6f89, 0x6f8c, 0x6f8d, 0x6f8e,
+    0x6f9c, 0x6fa1, 0x6fa7, 0x6fb3, 0x6fb6, 0x6fb9, 0x6fc0, 0x6fc2, 0x6fc9, 0x6fd1, 0x6fd2, 0x6fde, 0x6fe0, 0x6fe1,
+    0x6fee, 0x6fef, 0x7011, 0x701a, 0x701b, 0x7023, 0x7035, 0x7039, 0x704c, 0x704f, 0x705e, 0x706b, 0x706c, 0x706d,
+    0x706f, 0x7070, 0x7075, 0x7076, 0x7078, 0x707c, 0x707e, 0x707f, 0x7080, 0x7085, 0x7089, 0x708a, 0x708e, 0x7092,
+    0x7094, 0x7095, 0x7096, 0x7099, 0x709c, 0x709d, 0x70ab, 0x70ac, 0x70ad, 0x70ae, 0x70af, 0x70b1, 0x70b3, 0x70b7,
+    0x70b8, 0x70b9, 0x70bb, 0x70bc, 0x70bd, 0x70c0, 0x70c1, 0x70c2, 0x70c3, 0x70c8, 0x70ca, 0x70d8, 0x70d9, 0x70db,
+    0x70df, 0x70e4, 0x70e6, 0x70e7, 0x70e8, 0x70e9, 0x70eb, 0x70ec, 0x70ed, 0x70ef, 0x70f7, 0x70f9, 0x70fd, 0x7109,
+    0x710a, 0x7110, 0x7113, 0x7115, 0x7116, 0x7118, 0x7119, 0x711a, 0x7126, 0x712f, 0x7130, 0x7131, 0x7136, 0x7145,
+    0x714a, 0x714c, 0x714e, 0x715c, 0x715e, 0x7164, 0x7166, 0x7167, 0x7168, 0x716e, 0x7172, 0x7173, 0x7178, 0x717a,
+    0x717d, 0x7184, 0x718a, 0x718f, 0x7194, 0x7198, 0x7199, 0x719f, 0x71a0, 0x71a8, 0x71ac, 0x71b3, 0x71b5, 0x71b9,
+    0x71c3, 0x71ce, 0x71d4, 0x71d5, 0x71e0, 0x71e5, 0x71e7, 0x71ee, 0x71f9, 0x7206, 0x721d, 0x7228, 0x722a, 0x722c,
+    0x7230, 0x7231, 0x7235, 0x7236, 0x7237, 0x7238, 0x7239, 0x723b, 0x723d, 0x723f, 0x7247, 0x7248, 0x724c, 0x724d,
+    0x7252, 0x7256, 0x7259, 0x725b, 0x725d, 0x725f, 0x7261, 0x7262, 0x7266, 0x7267, 0x7269, 0x726e, 0x726f, 0x7272,
+    0x7275, 0x7279, 0x727a, 0x727e, 0x727f, 0x7280, 0x7281, 0x7284, 0x728a, 0x728b, 0x728d, 0x728f, 0x7292, 0x729f,
+    0x72ac, 0x72ad, 0x72af, 0x72b0, 0x72b4, 0x72b6, 0x72b7, 0x72b8, 0x72b9, 0x72c1, 0x72c2, 0x72c3, 0x72c4, 0x72c8,
+    0x72cd, 0x72ce, 0x72d0, 0x72d2, 0x72d7, 0x72d9, 0x72de, 0x72e0, 0x72e1, 0x72e8, 0x72e9, 0x72ec, 0x72ed, 0x72ee,
+    0x72ef, 0x72f0, 0x72f1, 0x72f2, 0x72f3, 0x72f4, 0x72f7, 0x72f8, 0x72fa, 0x72fb, 0x72fc, 0x7301, 0x7303, 0x730a,
+    0x730e, 0x7313, 0x7315, 0x7316, 0x7317, 0x731b, 0x731c, 0x731d, 0x731e, 0x7321, 0x7322, 0x7325, 0x7329, 0x732a,
+    0x732b, 0x732c, 0x732e, 0x7331, 0x7334, 0x7337, 0x7338, 0x7339, 0x733e, 0x733f, 0x734d, 0x7350, 0x7352, 0x7357,
+    0x7360, 0x736c, 0x736d, 0x736f, 0x737e, 0x7384, 0x7387, 0x7389, 0x738b, 0x738e, 0x7391, 0x7396, 0x739b, 0x739f,
+    0x73a2, 0x73a9, 0x73ab, 0x73ae, 0x73af, 0x73b0, 0x73b2, 0x73b3, 0x73b7, 0x73ba, 0x73bb, 0x73c0, 0x73c2, 0x73c8,
+    0x73c9, 0x73ca, 0x73cd, 0x73cf, 0x73d0, 0x73d1, 0x73d9, 0x73de, 0x73e0, 0x73e5, 0x73e7, 0x73e9, 0x73ed, 0x73f2,
+    0x7403, 0x7405, 0x7406, 0x7409, 0x740a, 0x740f, 0x7410, 0x741a, 0x741b, 0x7422, 0x7425, 0x7426, 0x7428, 0x742a,
+    0x742c, 0x742e, 0x7430, 0x7433, 0x7434, 0x7435, 0x7436, 0x743c, 0x7441, 0x7455, 0x7457, 0x7459, 0x745a, 0x745b,
+    0x745c, 0x745e, 0x745f, 0x746d, 0x7470, 0x7476, 0x7477, 0x747e, 0x7480, 0x7481, 0x7483, 0x7487, 0x748b, 0x748e,
+    0x7490, 0x749c, 0x749e, 0x74a7, 0x74a8, 0x74a9, 0x74ba, 0x74d2, 0x74dc, 0x74de, 0x74e0, 0x74e2, 0x74e3, 0x74e4,
+    0x74e6, 0x74ee, 0x74ef, 0x74f4, 0x74f6, 0x74f7, 0x74ff, 0x7504, 0x750d, 0x750f, 0x7511, 0x7513, 0x7518, 0x7519,
+    0x751a, 0x751c, 0x751f, 0x7525, 0x7528, 0x7529, 0x752b, 0x752c, 0x752d, 0x752f, 0x7530, 0x7531, 0x7532, 0x7533,
+    0x7535, 0x7537, 0x7538, 0x753a, 0x753b, 0x753e, 0x7540, 0x7545, 0x7548, 0x754b, 0x754c, 0x754e, 0x754f, 0x7554,
+    0x7559, 0x755a, 0x755b, 0x755c, 0x7565, 0x7566, 0x756a, 0x7572, 0x7574, 0x7578, 0x7579, 0x757f, 0x7583, 0x7586,
+    0x758b, 0x758f, 0x7591, 0x7592, 0x7594, 0x7596, 0x7597, 0x7599, 0x759a, 0x759d, 0x759f, 0x75a0, 0x75a1, 0x75a3,
+    0x75a4, 0x75a5, 0x75ab, 0x75ac, 0x75ae, 0x75af, 0x75b0, 0x75b1, 0x75b2, 0x75b3, 0x75b4, 0x75b5, 0x75b8, 0x75b9,
+    0x75bc, 0x75bd, 0x75be, 0x75c2, 0x75c3, 0x75c4, 0x75c5, 0x75c7, 0x75c8, 0x75c9, 0x75ca, 0x75cd, 0x75d2, 0x75d4,
+    0x75d5, 0x75d6, 0x75d8, 0x75db, 0x75de, 0x75e2, 0x75e3, 0x75e4, 0x75e6, 0x75e7, 0x75e8, 0x75ea, 0x75eb, 0x75f0,
+    0x75f1, 0x75f4, 0x75f9, 0x75fc, 0x75ff, 0x7600, 0x7601, 0x7603, 0x7605, 0x760a, 0x760c, 0x7610, 0x7615, 0x7617,
+    0x7618, 0x7619, 0x761b, 0x761f, 0x7620, 0x7622, 0x7624, 0x7625, 0x7626, 0x7629, 0x762a, 0x762b, 0x762d, 0x7630,
+    0x7633, 0x7634, 0x7635, 0x7638, 0x763c, 0x763e, 0x763f, 0x7640, 0x7643, 0x764c, 0x764d, 0x7654, 0x7656, 0x765c,
+    0x765e, 0x7663, 0x766b, 0x766f, 0x7678, 0x767b, 0x767d, 0x767e, 0x7682, 0x7684, 0x7686, 0x7687, 0x7688, 0x768b,
+    0x768e, 0x7691, 0x7693, 0x7696, 0x7699, 0x76a4, 0x76ae, 0x76b1, 0x76b2, 0x76b4, 0x76bf, 0x76c2, 0x76c5, 0x76c6,
+    0x76c8, 0x76ca, 0x76cd, 0x76ce, 0x76cf, 0x76d0, 0x76d1, 0x76d2, 0x76d4, 0x76d6, 0x76d7, 0x76d8, 0x76db, 0x76df,
+    0x76e5, 0x76ee, 0x76ef, 0x76f1, 0x76f2, 0x76f4, 0x76f8, 0x76f9, 0x76fc, 0x76fe, 0x7701, 0x7704, 0x7707, 0x7708,
+    0x7709, 0x770b, 0x770d, 0x7719, 0x771a, 0x771f, 0x7720, 0x7722, 0x7726, 0x7728, 0x7729, 0x772d, 0x772f, 0x7735,
+    0x7736, 0x7737, 0x7738, 0x773a, 0x773c, 0x7740, 0x7741, 0x7743, 0x7747, 0x7750, 0x7751, 0x775a, 0x775b, 0x7761,
+    0x7762, 0x7763, 0x7765, 0x7766, 0x7768, 0x776b, 0x776c, 0x7779, 0x777d, 0x777e, 0x777f, 0x7780, 0x7784, 0x7785,
+    0x778c, 0x778d, 0x778e, 0x7791, 0x7792, 0x779f, 0x77a0, 0x77a2, 0x77a5, 0x77a7, 0x77a9, 0x77aa, 0x77ac, 0x77b0,
+    0x77b3, 0x77b5, 0x77bb, 0x77bd, 0x77bf, 0x77cd, 0x77d7, 0x77db, 0x77dc, 0x77e2, 0x77e3, 0x77e5, 0x77e7, 0x77e9,
+    0x77eb, 0x77ec, 0x77ed, 0x77ee, 0x77f3, 0x77f6, 0x77f8, 0x77fd, 0x77fe, 0x77ff, 0x7800, 0x7801, 0x7802, 0x7809,
+    0x780c, 0x780d, 0x7811, 0x7812, 0x7814, 0x7816, 0x7817, 0x7818, 0x781a, 0x781c, 0x781d, 0x781f, 0x7823, 0x7825,
+    0x7826, 0x7827, 0x7829, 0x782c, 0x782d, 0x7830, 0x7834, 0x7837, 0x7838, 0x7839, 0x783a, 0x783b, 0x783c, 0x783e,
+    0x7840, 0x7845, 0x7847, 0x784c, 0x784e, 0x7850, 0x7852, 0x7855, 0x7856, 0x7857, 0x785d, 0x786a, 0x786b, 0x786c,
+    0x786d, 0x786e, 0x7877, 0x787c, 0x7887, 0x7889, 0x788c, 0x788d, 0x788e, 0x7891, 0x7893, 0x7897, 0x7898, 0x789a,
+    0x789b, 0x789c, 0x789f, 0x78a1, 0x78a3, 0x78a5, 0x78a7, 0x78b0, 0x78b1, 0x78b2, 0x78b3, 0x78b4, 0x78b9, 0x78be,
+    0x78c1, 0x78c5, 0x78c9, 0x78ca, 0x78cb, 0x78d0, 0x78d4, 0x78d5, 0x78d9, 0x78e8, 0x78ec, 0x78f2, 0x78f4, 0x78f7,
+    0x78fa, 0x7901, 0x7905, 0x7913, 0x791e, 0x7924, 0x7934, 0x793a, 0x793b, 0x793c, 0x793e, 0x7940, 0x7941, 0x7946,
+    0x7948, 0x7949, 0x7953, 0x7956, 0x7957, 0x795a, 0x795b, 0x795c, 0x795d, 0x795e, 0x795f, 0x7960, 0x7962, 0x7965,
+    0x7967, 0x7968, 0x796d, 0x796f, 0x7977, 0x7978, 0x797a, 0x7980, 0x7981, 0x7984, 0x7985, 0x798a, 0x798f, 0x799a,
+    0x79a7, 0x79b3, 0x79b9, 0x79ba, 0x79bb, 0x79bd, 0x79be, 0x79c0, 0x79c1, 0x79c3, 0x79c6, 0x79c9, 0x79cb, 0x79cd,
+    0x79d1, 0x79d2, 0x79d5, 0x79d8, 0x79df, 0x79e3, 0x79e4, 0x79e6, 0x79e7, 0x79e9, 0x79eb, 0x79ed, 0x79ef, 0x79f0,
+    0x79f8, 0x79fb, 0x79fd, 0x7a00, 0x7a02, 0x7a03, 0x7a06, 0x7a0b, 0x7a0d, 0x7a0e, 0x7a14, 0x7a17, 0x7a1a, 0x7a1e,
+    0x7a20, 0x7a23, 0x7a33, 0x7a37, 0x7a39, 0x7a3b, 0x7a3c, 0x7a3d, 0x7a3f, 0x7a46, 0x7a51, 0x7a57, 0x7a70, 0x7a74,
+    0x7a76, 0x7a77, 0x7a78, 0x7a79, 0x7a7a, 0x7a7f, 0x7a80, 0x7a81, 0x7a83, 0x7a84, 0x7a86, 0x7a88, 0x7a8d, 0x7a91,
+    0x7a92, 0x7a95, 0x7a96, 0x7a97, 0x7a98, 0x7a9c, 0x7a9d, 0x7a9f, 0x7aa0, 0x7aa5, 0x7aa6, 0x7aa8, 0x7aac, 0x7aad,
+    0x7ab3, 0x7abf, 0x7acb, 0x7ad6, 0x7ad9, 0x7ade, 0x7adf, 0x7ae0, 0x7ae3, 0x7ae5, 0x7ae6, 0x7aed, 0x7aef, 0x7af9,
+    0x7afa, 0x7afd, 0x7aff, 0x7b03, 0x7b04, 0x7b06, 0x7b08, 0x7b0a, 0x7b0b, 0x7b0f, 0x7b11, 0x7b14, 0x7b15, 0x7b19,
+    0x7b1b, 0x7b1e, 0x7b20, 0x7b24, 0x7b25, 0x7b26, 0x7b28, 0x7b2a, 0x7b2b, 0x7b2c, 0x7b2e, 0x7b31, 0x7b33, 0x7b38,
+    0x7b3a, 0x7b3c, 0x7b3e, 0x7b45, 0x7b47, 0x7b49, 0x7b4b, 0x7b4c, 0x7b4f, 0x7b50, 0x7b51, 0x7b52, 0x7b54, 0x7b56,
+    0x7b58, 0x7b5a, 0x7b5b, 0x7b5d, 0x7b60, 0x7b62, 0x7b6e, 0x7b71, 0x7b72, 0x7b75, 0x7b77, 0x7b79, 0x7b7b, 0x7b7e,
+    0x7b80, 0x7b85, 0x7b8d, 0x7b90, 0x7b94, 0x7b95, 0x7b97, 0x7b9c, 0x7b9d, 0x7ba1, 0x7ba2, 0x7ba6, 0x7ba7, 0x7ba8,
+    0x7ba9, 0x7baa, 0x7bab, 0x7bac, 0x7bad, 0x7bb1, 0x7bb4, 0x7bb8, 0x7bc1, 0x7bc6, 0x7bc7, 0x7bcc, 0x7bd1, 0x7bd3,
+    0x7bd9, 0x7bda, 0x7bdd, 0x7be1, 0x7be5, 0x7be6, 0x7bea, 0x7bee, 0x7bf1, 0x7bf7, 0x7bfc, 0x7bfe, 0x7c07, 0x7c0b,
+    0x7c0c, 0x7c0f, 0x7c16, 0x7c1f, 0x7c26, 0x7c27, 0x7c2a, 0x7c38, 0x7c3f, 0x7c40, 0x7c41, 0x7c4d, 0x7c73, 0x7c74,
+    0x7c7b, 0x7c7c, 0x7c7d, 0x7c89, 0x7c91, 0x7c92, 0x7c95, 0x7c97, 0x7c98, 0x7c9c, 0x7c9d, 0x7c9e, 0x7c9f, 0x7ca2,
+    0x7ca4, 0x7ca5, 0x7caa, 0x7cae, 0x7cb1, 0x7cb2, 0x7cb3, 0x7cb9, 0x7cbc, 0x7cbd, 0x7cbe, 0x7cc1, 0x7cc5, 0x7cc7,
+    0x7cc8, 0x7cca, 0x7ccc, 0x7ccd, 0x7cd5, 0x7cd6, 0x7cd7, 0x7cd9, 0x7cdc, 0x7cdf, 0x7ce0, 0x7ce8, 0x7cef, 0x7cf8,
+    0x7cfb, 0x7d0a, 0x7d20, 0x7d22, 0x7d27, 0x7d2b, 0x7d2f, 0x7d6e, 0x7d77, 0x7da6, 0x7dae, 0x7e3b, 0x7e41, 0x7e47,
+    0x7e82, 0x7e9b, 0x7e9f, 0x7ea0, 0x7ea1, 0x7ea2, 0x7ea3, 0x7ea4, 0x7ea5, 0x7ea6, 0x7ea7, 0x7ea8, 0x7ea9, 0x7eaa,
+    0x7eab, 0x7eac, 0x7ead, 0x7eaf, 0x7eb0, 0x7eb1, 0x7eb2, 0x7eb3, 0x7eb5, 0x7eb6, 0x7eb7, 0x7eb8, 0x7eb9, 0x7eba,
+    0x7ebd, 0x7ebe, 0x7ebf, 0x7ec0, 0x7ec1, 0x7ec2, 0x7ec3, 0x7ec4, 0x7ec5, 0x7ec6, 0x7ec7, 0x7ec8, 0x7ec9, 0x7eca,
+    0x7ecb, 0x7ecc, 0x7ecd, 0x7ece, 0x7ecf, 0x7ed0, 0x7ed1, 0x7ed2, 0x7ed3, 0x7ed4, 0x7ed5, 0x7ed7, 0x7ed8, 0x7ed9,
+    0x7eda, 0x7edb, 0x7edc, 0x7edd, 0x7ede, 0x7edf, 0x7ee0, 0x7ee1, 0x7ee2, 0x7ee3, 0x7ee5, 0x7ee6, 0x7ee7, 0x7ee8,
+    0x7ee9, 0x7eea, 0x7eeb, 0x7eed, 0x7eee, 0x7eef, 0x7ef0, 0x7ef1, 0x7ef2, 0x7ef3, 0x7ef4, 0x7ef5, 0x7ef6, 0x7ef7,
+    0x7ef8, 0x7efa, 0x7efb, 0x7efc, 0x7efd, 0x7efe, 0x7eff, 0x7f00, 0x7f01, 0x7f02, 0x7f03, 0x7f04, 0x7f05, 0x7f06,
+    0x7f07, 0x7f08, 0x7f09, 0x7f0b, 0x7f0c, 0x7f0d, 0x7f0e, 0x7f0f, 0x7f11, 0x7f12, 0x7f13, 0x7f14, 0x7f15, 0x7f16,
+    0x7f17, 0x7f18, 0x7f19, 0x7f1a, 0x7f1b, 0x7f1c, 0x7f1d, 0x7f1f, 0x7f20, 0x7f21, 0x7f22, 0x7f23, 0x7f24, 0x7f25,
+    0x7f26, 0x7f27, 0x7f28, 0x7f29, 0x7f2a, 0x7f2b, 0x7f2c, 0x7f2d, 0x7f2e, 0x7f2f, 0x7f30, 0x7f31, 0x7f32, 0x7f33,
+    0x7f34, 0x7f35, 0x7f36, 0x7f38, 0x7f3a, 0x7f42, 0x7f44, 0x7f45, 0x7f50, 0x7f51, 0x7f54, 0x7f55, 0x7f57, 0x7f58,
+    0x7f5a, 0x7f5f, 0x7f61, 0x7f62, 0x7f68, 0x7f69, 0x7f6a, 0x7f6e, 0x7f71, 0x7f72, 0x7f74, 0x7f79, 0x7f7e, 0x7f81,
+    0x7f8a, 0x7f8c, 0x7f8e, 0x7f94, 0x7f9a, 0x7f9d, 0x7f9e, 0x7f9f, 0x7fa1, 0x7fa4, 0x7fa7, 0x7faf, 0x7fb0, 0x7fb2,
+    0x7fb8, 0x7fb9, 0x7fbc, 0x7fbd, 0x7fbf, 0x7fc1, 0x7fc5, 0x7fca, 0x7fcc, 0x7fce, 0x7fd4, 0x7fd5, 0x7fd8, 0x7fdf,
+    0x7fe0, 0x7fe1, 0x7fe5, 0x7fe6, 0x7fe9, 0x7fee, 0x7ff0, 0x7ff1, 0x7ff3, 0x7ffb, 0x7ffc, 0x8000, 0x8001, 0x8003,
+    0x8004, 0x8005, 0x8006, 0x800b, 0x800c, 0x800d, 0x8010, 0x8012, 0x8014, 0x8015, 0x8016, 0x8017, 0x8018, 0x8019,
+    0x801c, 0x8020, 0x8022, 0x8025, 0x8026, 0x8027, 0x8028, 0x8029, 0x802a, 0x8031, 0x8033, 0x8035, 0x8036, 0x8037,
+    0x8038, 0x803b, 0x803d, 0x803f, 0x8042, 0x8043, 0x8046, 0x804a, 0x804b, 0x804c, 0x804d, 0x8052, 0x8054, 0x8058,
+    0x805a, 0x8069, 0x806a, 0x8071, 0x807f, 0x8080, 0x8083, 0x8084, 0x8086, 0x8087, 0x8089, 0x808b, 0x808c, 0x8093,
+    0x8096, 0x8098, 0x809a, 0x809b, 0x809c, 0x809d, 0x809f, 0x80a0, 0x80a1, 0x80a2, 0x80a4, 0x80a5, 0x80a9, 0x80aa,
+    0x80ab, 0x80ad, 0x80ae, 0x80af, 0x80b1, 0x80b2, 0x80b4, 0x80b7, 0x80ba, 0x80bc, 0x80bd, 0x80be, 0x80bf, 0x80c0,
+    0x80c1, 0x80c2, 0x80c3, 0x80c4, 0x80c6, 0x80cc, 0x80cd, 0x80ce, 0x80d6, 0x80d7, 0x80d9, 0x80da, 0x80db, 0x80dc,
+    0x80dd, 0x80de, 0x80e1, 0x80e4, 0x80e5, 0x80e7, 0x80e8, 0x80e9, 0x80ea, 0x80eb, 0x80ec, 0x80ed, 0x80ef, 0x80f0,
+    0x80f1, 0x80f2, 0x80f3, 0x80f4, 0x80f6, 0x80f8, 0x80fa, 0x80fc, 0x80fd, 0x8102, 0x8106, 0x8109, 0x810a, 0x810d,
+    0x810e, 0x810f, 0x8110, 0x8111, 0x8112, 0x8113, 0x8114, 0x8116, 0x8118, 0x811a, 0x811e, 0x812c, 0x812f, 0x8131,
+    0x8132, 0x8136, 0x8138, 0x813e, 0x8146, 0x8148, 0x814a, 0x814b, 0x814c, 0x8150, 0x8151, 0x8153, 0x8154, 0x8155,
+    0x8159, 0x815a, 0x8160, 0x8165, 0x8167, 0x8169, 0x816d, 0x816e, 0x8170, 0x8171, 0x8174, 0x8179, 0x817a, 0x817b,
+    0x817c, 0x817d, 0x817e, 0x817f, 0x8180, 0x8182, 0x8188, 0x818a, 0x818f, 0x8191, 0x8198, 0x819b, 0x819c, 0x819d,
+    0x81a3, 0x81a6, 0x81a8, 0x81aa, 0x81b3, 0x81ba, 0x81bb, 0x81c0, 0x81c1, 0x81c2, 0x81c3, 0x81c6, 0x81ca, 0x81cc,
+    0x81e3, 0x81e7, 0x81ea, 0x81ec, 0x81ed, 0x81f3, 0x81f4, 0x81fb, 0x81fc, 0x81fe, 0x8200, 0x8201, 0x8202, 0x8204,
+    0x8205, 0x8206, 0x820c, 0x820d, 0x8210, 0x8212, 0x8214, 0x821b, 0x821c, 0x821e, 0x821f, 0x8221, 0x8222, 0x8223,
+    0x8228, 0x822a, 0x822b, 0x822c, 0x822d, 0x822f, 0x8230, 0x8231, 0x8233, 0x8234, 0x8235, 0x8236, 0x8237, 0x8238,
+    0x8239, 0x823b, 0x823e, 0x8244, 0x8247, 0x8249, 0x824b, 0x824f, 0x8258, 0x825a, 0x825f, 0x8268, 0x826e, 0x826f,
+    0x8270, 0x8272, 0x8273, 0x8274, 0x8279, 0x827a, 0x827d, 0x827e, 0x827f, 0x8282, 0x8284, 0x8288, 0x828a, 0x828b,
+    0x828d, 0x828e, 0x828f, 0x8291, 0x8292, 0x8297, 0x8298, 0x8299, 0x829c, 0x829d, 0x829f, 0x82a1, 0x82a4, 0x82a5,
+    0x82a6, 0x82a8, 0x82a9, 0x82aa, 0x82ab, 0x82ac, 0x82ad, 0x82ae, 0x82af, 0x82b0, 0x82b1, 0x82b3, 0x82b4, 0x82b7,
+    0x82b8, 0x82b9, 0x82bd, 0x82be, 0x82c1, 0x82c4, 0x82c7, 0x82c8, 0x82ca, 0x82cb, 0x82cc, 0x82cd, 0x82ce, 0x82cf,
+    0x82d1, 0x82d2, 0x82d3, 0x82d4, 0x82d5, 0x82d7, 0x82d8, 0x82db, 0x82dc, 0x82de, 0x82df, 0x82e0, 0x82e1, 0x82e3,
+    0x82e4, 0x82e5, 0x82e6, 0x82eb, 0x82ef, 0x82f1, 0x82f4, 0x82f7, 0x82f9, 0x82fb, 0x8301, 0x8302, 0x8303, 0x8304,
+    0x8305, 0x8306, 0x8307, 0x8308, 0x8309, 0x830c, 0x830e, 0x830f, 0x8311, 0x8314, 0x8315, 0x8317, 0x831a, 0x831b,
+    0x831c, 0x8327, 0x8328, 0x832b, 0x832c, 0x832d, 0x832f, 0x8331, 0x8333, 0x8334, 0x8335, 0x8336, 0x8338, 0x8339,
+    0x833a, 0x833c, 0x8340, 0x8343, 0x8346, 0x8347, 0x8349, 0x834f, 0x8350, 0x8351, 0x8352, 0x8354, 0x835a, 0x835b,
+    0x835c, 0x835e, 0x835f, 0x8360, 0x8361, 0x8363, 0x8364, 0x8365, 0x8366, 0x8367, 0x8368, 0x8369, 0x836a, 0x836b,
+    0x836c, 0x836d, 0x836e, 0x836f, 0x8377, 0x8378, 0x837b, 0x837c, 0x837d, 0x8385, 0x8386, 0x8389, 0x838e, 0x8392,
+    0x8393, 0x8398, 0x839b, 0x839c, 0x839e, 0x83a0, 0x83a8, 0x83a9, 0x83aa, 0x83ab, 0x83b0, 0x83b1, 0x83b2, 0x83b3,
+    0x83b4, 0x83b6, 0x83b7, 0x83b8, 0x83b9, 0x83ba, 0x83bc, 0x83bd, 0x83c0, 0x83c1, 0x83c5, 0x83c7, 0x83ca, 0x83cc,
+    0x83cf, 0x83d4, 0x83d6, 0x83d8, 0x83dc, 0x83dd, 0x83df, 0x83e0, 0x83e1, 0x83e5, 0x83e9, 0x83ea, 0x83f0, 0x83f1,
+    0x83f2, 0x83f8, 0x83f9, 0x83fd, 0x8401, 0x8403, 0x8404, 0x8406, 0x840b, 0x840c, 0x840d, 0x840e, 0x840f, 0x8411,
+    0x8418, 0x841c, 0x841d, 0x8424, 0x8425, 0x8426, 0x8427, 0x8428, 0x8431, 0x8438, 0x843c, 0x843d, 0x8446, 0x8451,
+    0x8457, 0x8459, 0x845a, 0x845b, 0x845c, 0x8461, 0x8463, 0x8469, 0x846b, 0x846c, 0x846d, 0x8471, 0x8473, 0x8475,
+    0x8476, 0x8478, 0x847a, 0x8482, 0x8487, 0x8488, 0x8489, 0x848b, 0x848c, 0x848e, 0x8497, 0x8499, 0x849c, 0x84a1,
+    0x84af, 0x84b2, 0x84b4, 0x84b8, 0x84b9, 0x84ba, 0x84bd, 0x84bf, 0x84c1, 0x84c4, 0x84c9, 0x84ca, 0x84cd, 0x84d0,
+    0x84d1, 0x84d3, 0x84d6, 0x84dd, 0x84df, 0x84e0, 0x84e3, 0x84e5, 0x84e6, 0x84ec, 0x84f0, 0x84fc, 0x84ff, 0x850c,
+    0x8511, 0x8513, 0x8517, 0x851a, 0x851f, 0x8521, 0x852b, 0x852c, 0x8537, 0x8538, 0x8539, 0x853a, 0x853b, 0x853c,
+    0x853d, 0x8543, 0x8548, 0x8549, 0x854a, 0x8556, 0x8559, 0x855e, 0x8564, 0x8568, 0x8572, 0x8574, 0x8579, 0x857a,
+    0x857b, 0x857e, 0x8584, 0x8585, 0x8587, 0x858f, 0x859b, 0x859c, 0x85a4, 0x85a8, 0x85aa, 0x85ae, 0x85af, 0x85b0,
+    0x85b7, 0x85b9, 0x85c1, 0x85c9, 0x85cf, 0x85d0, 0x85d3, 0x85d5, 0x85dc, 0x85e4, 0x85e9, 0x85fb, 0x85ff, 0x8605,
+    0x8611, 0x8616, 0x8627, 0x8629, 0x8638, 0x863c, 0x864d, 0x864e, 0x864f, 0x8650, 0x8651, 0x8654, 0x865a, 0x865e,
+    0x8662, 0x866b, 0x866c, 0x866e, 0x8671, 0x8679, 0x867a, 0x867b, 0x867c, 0x867d, 0x867e, 0x867f, 0x8680, 0x8681,
+    0x8682, 0x868a, 0x868b, 0x868c, 0x868d, 0x8693, 0x8695, 0x869c, 0x869d, 0x86a3, 0x86a4, 0x86a7, 0x86a8, 0x86a9,
+    0x86aa, 0x86ac, 0x86af, 0x86b0, 0x86b1, 0x86b4, 0x86b5, 0x86b6, 0x86ba, 0x86c0, 0x86c4, 0x86c6, 0x86c7, 0x86c9,
+    0x86ca, 0x86cb, 0x86ce, 0x86cf, 0x86d0, 0x86d1, 0x86d4, 0x86d8, 0x86d9, 0x86db, 0x86de, 0x86df, 0x86e4, 0x86e9,
+    0x86ed, 0x86ee, 0x86f0, 0x86f1, 0x86f2, 0x86f3, 0x86f4, 0x86f8, 0x86f9, 0x86fe, 0x8700, 0x8702, 0x8703, 0x8707,
+    0x8708, 0x8709, 0x870a, 0x870d, 0x8712, 0x8713, 0x8715, 0x8717, 0x8718, 0x871a, 0x871c, 0x871e, 0x8721, 0x8722,
+    0x8723, 0x8725, 0x8729, 0x872e, 0x8731, 0x8734, 0x8737, 0x873b, 0x873e, 0x873f, 0x8747, 0x8748, 0x8749, 0x874c,
+    0x874e, 0x8753, 0x8757, 0x8759, 0x8760, 0x8763, 0x8764, 0x8765, 0x876e, 0x8770, 0x8774, 0x8776, 0x877b, 0x877c,
+    0x877d, 0x877e, 0x8782, 0x8783, 0x8785, 0x8788, 0x878b, 0x878d, 0x8793, 0x8797, 0x879f, 0x87a8, 0x87ab, 0x87ac,
+    0x87ad, 0x87af, 0x87b3, 0x87b5, 0x87ba, 0x87bd, 0x87c0, 0x87c6, 0x87ca, 0x87cb, 0x87d1, 0x87d2, 0x87d3, 0x87db,
+    0x87e0, 0x87e5, 0x87ea, 0x87ee, 0x87f9, 0x87fe, 0x8803, 0x880a, 0x8813, 0x8815, 0x8816, 0x881b, 0x8821, 0x8822,
+    0x8832, 0x8839, 0x883c, 0x8840, 0x8844, 0x8845, 0x884c, 0x884d, 0x8854, 0x8857, 0x8859, 0x8861, 0x8862, 0x8863,
+    0x8864, 0x8865, 0x8868, 0x8869, 0x886b, 0x886c, 0x886e, 0x8870, 0x8872, 0x8877, 0x887d, 0x887e, 0x887f, 0x8881,
+    0x8882, 0x8884, 0x8885, 0x8888, 0x888b, 0x888d, 0x8892, 0x8896, 0x889c, 0x88a2, 0x88a4, 0x88ab, 0x88ad, 0x88b1,
+    0x88b7, 0x88bc, 0x88c1, 0x88c2, 0x88c5, 0x88c6, 0x88c9, 0x88ce, 0x88d2, 0x88d4, 0x88d5, 0x88d8, 0x88d9, 0x88df,
+    0x88e2, 0x88e3, 0x88e4, 0x88e5, 0x88e8, 0x88f0, 0x88f1, 0x88f3, 0x88f4, 0x88f8, 0x88f9, 0x88fc, 0x88fe, 0x8902,
+    0x890a, 0x8910, 0x8912, 0x8913, 0x8919, 0x891a, 0x891b, 0x8921, 0x8925, 0x892a, 0x892b, 0x8930, 0x8934, 0x8936,
+    0x8941, 0x8944, 0x895e, 0x895f, 0x8966, 0x897b, 0x897f, 0x8981, 0x8983, 0x8986, 0x89c1, 0x89c2, 0x89c4, 0x89c5,
+    0x89c6, 0x89c7, 0x89c8, 0x89c9, 0x89ca, 0x89cb, 0x89cc, 0x89ce, 0x89cf, 0x89d0, 0x89d1, 0x89d2, 0x89d6, 0x89da,
+    0x89dc, 0x89de, 0x89e3, 0x89e5, 0x89e6, 0x89eb, 0x89ef, 0x89f3, 0x8a00, 0x8a07, 0x8a3e, 0x8a48, 0x8a79, 0x8a89,
+    0x8a8a, 0x8a93, 0x8b07, 0x8b26, 0x8b66, 0x8b6c, 0x8ba0, 0x8ba1, 0x8ba2, 0x8ba3, 0x8ba4, 0x8ba5, 0x8ba6, 0x8ba7,
+    0x8ba8, 0x8ba9, 0x8baa, 0x8bab, 0x8bad, 0x8bae, 0x8baf, 0x8bb0, 0x8bb2, 0x8bb3, 0x8bb4, 0x8bb5, 0x8bb6, 0x8bb7,
+    0x8bb8, 0x8bb9, 0x8bba, 0x8bbc, 0x8bbd, 0x8bbe, 0x8bbf, 0x8bc0, 0x8bc1, 0x8bc2, 0x8bc3, 0x8bc4, 0x8bc5, 0x8bc6,
+    0x8bc8, 0x8bc9, 0x8bca, 0x8bcb, 0x8bcc, 0x8bcd, 0x8bce, 0x8bcf, 0x8bd1, 0x8bd2, 0x8bd3, 0x8bd4, 0x8bd5, 0x8bd6,
+    0x8bd7, 0x8bd8, 0x8bd9, 0x8bda, 0x8bdb, 0x8bdc, 0x8bdd, 0x8bde, 0x8bdf, 0x8be0, 0x8be1, 0x8be2, 0x8be3, 0x8be4,
+    0x8be5, 0x8be6, 0x8be7, 0x8be8, 0x8be9, 0x8beb, 0x8bec, 0x8bed, 0x8bee, 0x8bef, 0x8bf0, 0x8bf1, 0x8bf2, 0x8bf3,
+    0x8bf4, 0x8bf5, 0x8bf6, 0x8bf7, 0x8bf8, 0x8bf9, 0x8bfa, 0x8bfb, 0x8bfc, 0x8bfd, 0x8bfe, 0x8bff, 0x8c00, 0x8c01,
+    0x8c02, 0x8c03, 0x8c04, 0x8c05, 0x8c06, 0x8c07, 0x8c08, 0x8c0a, 0x8c0b, 0x8c0c, 0x8c0d, 0x8c0e, 0x8c0f, 0x8c10,
+    0x8c11, 0x8c12, 0x8c13, 0x8c14, 0x8c15, 0x8c16, 0x8c17, 0x8c18, 0x8c19, 0x8c1a, 0x8c1b, 0x8c1c, 0x8c1d, 0x8c1f,
+    0x8c20, 0x8c21, 0x8c22, 0x8c23, 0x8c24, 0x8c25, 0x8c26, 0x8c27, 0x8c28, 0x8c29, 0x8c2a, 0x8c2b, 0x8c2c, 0x8c2d,
+    0x8c2e, 0x8c2f, 0x8c30, 0x8c31, 0x8c32, 0x8c33, 0x8c34, 0x8c35, 0x8c36, 0x8c37, 0x8c41, 0x8c46, 0x8c47, 0x8c49,
+    0x8c4c, 0x8c55, 0x8c5a, 0x8c61, 0x8c62, 0x8c6a, 0x8c6b, 0x8c73, 0x8c78, 0x8c79, 0x8c7a, 0x8c82, 0x8c85, 0x8c89,
+    0x8c8a, 0x8c8c, 0x8c94, 0x8c98, 0x8d1d, 0x8d1e, 0x8d1f, 0x8d21, 0x8d22, 0x8d23, 0x8d24, 0x8d25, 0x8d26, 0x8d27,
+    0x8d28, 0x8d29, 0x8d2a, 0x8d2b, 0x8d2c, 0x8d2d, 0x8d2e, 0x8d2f, 0x8d30, 0x8d31, 0x8d32, 0x8d33, 0x8d34, 0x8d35,
+    0x8d36, 0x8d37, 0x8d38, 0x8d39, 0x8d3a, 0x8d3b, 0x8d3c, 0x8d3d, 0x8d3e, 0x8d3f, 0x8d40, 0x8d41, 0x8d42, 0x8d43,
+    0x8d44, 0x8d45, 0x8d46, 0x8d47, 0x8d48, 0x8d49, 0x8d4a, 0x8d4b, 0x8d4c, 0x8d4d, 0x8d4e, 0x8d4f, 0x8d50, 0x8d53,
+    0x8d54, 0x8d55, 0x8d56, 0x8d58, 0x8d59, 0x8d5a, 0x8d5b, 0x8d5c, 0x8d5d, 0x8d5e, 0x8d60, 0x8d61, 0x8d62, 0x8d63,
+    0x8d64, 0x8d66, 0x8d67, 0x8d6b, 0x8d6d, 0x8d70, 0x8d73, 0x8d74, 0x8d75, 0x8d76, 0x8d77, 0x8d81, 0x8d84, 0x8d85,
+    0x8d8a, 0x8d8b, 0x8d91, 0x8d94, 0x8d9f, 0x8da3, 0x8db1, 0x8db3, 0x8db4, 0x8db5, 0x8db8, 0x8dba, 0x8dbc, 0x8dbe,
+    0x8dbf, 0x8dc3, 0x8dc4, 0x8dc6, 0x8dcb, 0x8dcc, 0x8dce, 0x8dcf, 0x8dd1, 0x8dd6, 0x8dd7, 0x8dda, 0x8ddb, 0x8ddd,
+    0x8dde, 0x8ddf, 0x8de3, 0x8de4, 0x8de8, 0x8dea, 0x8deb, 0x8dec, 0x8def, 0x8df3, 0x8df5, 0x8df7, 0x8df8, 0x8df9,
+    0x8dfa, 0x8dfb, 0x8dfd, 0x8e05, 0x8e09, 0x8e0a, 0x8e0c, 0x8e0f, 0x8e14, 0x8e1d, 0x8e1e, 0x8e1f, 0x8e22, 0x8e23,
+    0x8e29, 0x8e2a, 0x8e2c, 0x8e2e, 0x8e2f, 0x8e31, 0x8e35, 0x8e39, 0x8e3a, 0x8e3d, 0x8e40, 0x8e41, 0x8e42, 0x8e44,
+    0x8e47, 0x8e48, 0x8e49, 0x8e4a, 0x8e4b, 0x8e51, 0x8e52, 0x8e59, 0x8e66, 0x8e69, 0x8e6c, 0x8e6d, 0x8e6f, 0x8e70,
+    0x8e72, 0x8e74, 0x8e76, 0x8e7c, 0x8e7f, 0x8e81, 0x8e85, 0x8e87, 0x8e8f, 0x8e90, 0x8e94, 0x8e9c, 0x8e9e, 0x8eab,
+    0x8eac, 0x8eaf, 0x8eb2, 0x8eba, 0x8ece, 0x8f66, 0x8f67, 0x8f68, 0x8f69, 0x8f6b, 0x8f6c, 0x8f6d, 0x8f6e, 0x8f6f,
+    0x8f70, 0x8f71, 0x8f72, 0x8f73, 0x8f74, 0x8f75, 0x8f76, 0x8f77, 0x8f78, 0x8f79, 0x8f7a, 0x8f7b, 0x8f7c, 0x8f7d,
+    0x8f7e, 0x8f7f, 0x8f81, 0x8f82, 0x8f83, 0x8f84, 0x8f85, 0x8f86, 0x8f87, 0x8f88, 0x8f89, 0x8f8a, 0x8f8b, 0x8f8d,
+    0x8f8e, 0x8f8f, 0x8f90, 0x8f91, 0x8f93, 0x8f94, 0x8f95, 0x8f96, 0x8f97, 0x8f98, 0x8f99, 0x8f9a, 0x8f9b, 0x8f9c,
+    0x8f9e, 0x8f9f, 0x8fa3, 0x8fa8, 0x8fa9, 0x8fab, 0x8fb0, 0x8fb1, 0x8fb6, 0x8fb9, 0x8fbd, 0x8fbe, 0x8fc1, 0x8fc2,
+    0x8fc4, 0x8fc5, 0x8fc7, 0x8fc8, 0x8fce, 0x8fd0, 0x8fd1, 0x8fd3, 0x8fd4, 0x8fd5, 0x8fd8, 0x8fd9, 0x8fdb, 0x8fdc,
+    0x8fdd, 0x8fde, 0x8fdf, 0x8fe2, 0x8fe4, 0x8fe5, 0x8fe6, 0x8fe8, 0x8fe9, 0x8fea, 0x8feb, 0x8fed, 0x8fee, 0x8ff0,
+    0x8ff3, 0x8ff7, 0x8ff8, 0x8ff9, 0x8ffd, 0x9000, 0x9001, 0x9002, 0x9003, 0x9004, 0x9005, 0x9006, 0x9009, 0x900a,
+    0x900b, 0x900d, 0x900f, 0x9010, 0x9011, 0x9012, 0x9014, 0x9016, 0x9017, 0x901a, 0x901b, 0x901d, 0x901e, 0x901f,
+    0x9020, 0x9021, 0x9022, 0x9026, 0x902d, 0x902e, 0x902f, 0x9035, 0x9036, 0x9038, 0x903b, 0x903c, 0x903e, 0x9041,
+    0x9042, 0x9044, 0x9047, 0x904d, 0x904f, 0x9050, 0x9051, 0x9052, 0x9053, 0x9057, 0x9058, 0x905b, 0x9062, 0x9063,
+    0x9065, 0x9068, 0x906d, 0x906e, 0x9074, 0x9075, 0x907d, 0x907f, 0x9080, 0x9082, 0x9083, 0x9088, 0x908b, 0x9091,
+    0x9093, 0x9095, 0x9097, 0x9099, 0x909b, 0x909d, 0x90a1, 0x90a2, 0x90a3, 0x90a6, 0x90aa, 0x90ac, 0x90ae, 0x90af,
+    0x90b0, 0x90b1, 0x90b3, 0x90b4, 0x90b5, 0x90b6, 0x90b8, 0x90b9, 0x90ba, 0x90bb, 0x90be, 0x90c1, 0x90c4, 0x90c5,
+    0x90c7, 0x90ca, 0x90ce, 0x90cf, 0x90d0, 0x90d1, 0x90d3, 0x90d7, 0x90db, 0x90dc, 0x90dd, 0x90e1, 0x90e2, 0x90e6,
+    0x90e7, 0x90e8, 0x90eb, 0x90ed, 0x90ef, 0x90f4, 0x90f8, 0x90fd, 0x90fe, 0x9102, 0x9104, 0x9119, 0x911e, 0x9122,
+    0x9123, 0x912f, 0x9131, 0x9139, 0x9143, 0x9146, 0x9149, 0x914a, 0x914b, 0x914c, 0x914d, 0x914e, 0x914f, 0x9150,
+    0x9152, 0x9157, 0x915a, 0x915d, 0x915e, 0x9161, 0x9162, 0x9163, 0x9164, 0x9165, 0x9169, 0x916a, 0x916c, 0x916e,
+    0x916f, 0x9170, 0x9171, 0x9172, 0x9174, 0x9175, 0x9176, 0x9177, 0x9178, 0x9179, 0x917d, 0x917e, 0x917f, 0x9185,
+    0x9187, 0x9189, 0x918b, 0x918c, 0x918d, 0x9190, 0x9191, 0x9192, 0x919a, 0x919b, 0x91a2, 0x91a3, 0x91aa, 0x91ad,
+    0x91ae, 0x91af, 0x91b4, 0x91b5, 0x91ba, 0x91c7, 0x91c9, 0x91ca, 0x91cc, 0x91cd, 0x91ce, 0x91cf, 0x91d1, 0x91dc,
+    0x9274, 0x928e, 0x92ae, 0x92c8, 0x933e, 0x936a, 0x938f, 0x93ca, 0x93d6, 0x943e, 0x946b, 0x9485, 0x9486, 0x9487,
+    0x9488, 0x9489, 0x948a, 0x948b, 0x948c, 0x948d, 0x948e, 0x948f, 0x9490, 0x9492, 0x9493, 0x9494, 0x9495, 0x9497,
+    0x9499, 0x949a, 0x949b, 0x949c, 0x949d, 0x949e, 0x949f, 0x94a0, 0x94a1, 0x94a2, 0x94a3, 0x94a4, 0x94a5, 0x94a6,
+    0x94a7, 0x94a8, 0x94a9, 0x94aa, 0x94ab, 0x94ac, 0x94ad, 0x94ae, 0x94af, 0x94b0, 0x94b1, 0x94b2, 0x94b3, 0x94b4,
+    0x94b5, 0x94b6, 0x94b7, 0x94b8, 0x94b9, 0x94ba, 0x94bb, 0x94bc, 0x94bd, 0x94be, 0x94bf, 0x94c0, 0x94c1, 0x94c2,
+    0x94c3, 0x94c4, 0x94c5, 0x94c6, 0x94c8, 0x94c9, 0x94ca, 0x94cb, 0x94cc, 0x94cd, 0x94ce, 0x94d0, 0x94d1, 0x94d2,
+    0x94d5, 0x94d6, 0x94d7, 0x94d8, 0x94d9, 0x94db, 0x94dc, 0x94dd, 0x94de, 0x94df, 0x94e0, 0x94e1, 0x94e2, 0x94e3,
+    0x94e4, 0x94e5, 0x94e7, 0x94e8, 0x94e9, 0x94ea, 0x94eb, 0x94ec, 0x94ed, 0x94ee, 0x94ef, 0x94f0, 0x94f1, 0x94f2,
+    0x94f3, 0x94f4, 0x94f5, 0x94f6, 0x94f7, 0x94f8, 0x94f9, 0x94fa, 0x94fc, 0x94fd, 0x94fe, 0x94ff, 0x9500, 0x9501,
+    0x9502, 0x9503, 0x9504, 0x9505, 0x9506, 0x9507, 0x9508, 0x9509, 0x950a, 0x950b, 0x950c, 0x950d, 0x950e, 0x950f,
+    0x9510, 0x9511, 0x9512, 0x9513, 0x9514, 0x9515, 0x9516, 0x9517, 0x9518, 0x9519, 0x951a, 0x951b, 0x951d, 0x951e,
+    0x951f, 0x9521, 0x9522, 0x9523, 0x9524, 0x9525, 0x9526, 0x9528, 0x9529, 0x952a, 0x952b, 0x952c, 0x952d, 0x952e,
+    0x952f, 0x9530, 0x9531, 0x9532, 0x9534, 0x9535, 0x9536, 0x9537, 0x9538, 0x9539, 0x953a, 0x953b, 0x953c, 0x953e,
+    0x953f, 0x9540, 0x9541, 0x9542, 0x9544, 0x9545, 0x9546, 0x9547, 0x9549, 0x954a, 0x954c, 0x954d, 0x954e, 0x954f,
+    0x9550, 0x9551, 0x9552, 0x9553, 0x9554, 0x9556, 0x9557, 0x9558, 0x9559, 0x955b, 0x955c, 0x955d, 0x955e, 0x955f,
+    0x9561, 0x9562, 0x9563, 0x9564, 0x9565, 0x9566, 0x9567, 0x9568, 0x9569, 0x956a, 0x956b, 0x956c, 0x956d, 0x956f,
+    0x9570, 0x9571, 0x9572, 0x9573, 0x9576, 0x957f, 0x95e8, 0x95e9, 0x95ea, 0x95eb, 0x95ed, 0x95ee, 0x95ef, 0x95f0,
+    0x95f1, 0x95f2, 0x95f3, 0x95f4, 0x95f5, 0x95f6, 0x95f7, 0x95f8, 0x95f9, 0x95fa, 0x95fb, 0x95fc, 0x95fd, 0x95fe,
+    0x9600, 0x9601, 0x9602, 0x9603, 0x9604, 0x9605, 0x9606, 0x9608, 0x9609, 0x960a, 0x960b, 0x960c, 0x960d, 0x960e,
+    0x960f, 0x9610, 0x9611, 0x9612, 0x9614, 0x9615, 0x9616, 0x9617, 0x9619, 0x961a, 0x961c, 0x961d, 0x961f, 0x9621,
+    0x9622, 0x962a, 0x962e, 0x9631, 0x9632, 0x9633, 0x9634, 0x9635, 0x9636, 0x963b, 0x963c, 0x963d, 0x963f, 0x9640,
+    0x9642, 0x9644, 0x9645, 0x9646, 0x9647, 0x9648, 0x9649, 0x964b, 0x964c, 0x964d, 0x9650, 0x9654, 0x9655, 0x965b,
+    0x965f, 0x9661, 0x9662, 0x9664, 0x9667, 0x9668, 0x9669, 0x966a, 0x966c, 0x9672, 0x9674, 0x9675, 0x9676, 0x9677,
+    0x9685, 0x9686, 0x9688, 0x968b, 0x968d, 0x968f, 0x9690, 0x9694, 0x9697, 0x9698, 0x9699, 0x969c, 0x96a7, 0x96b0,
+    0x96b3, 0x96b6, 0x96b9, 0x96bc, 0x96bd, 0x96be, 0x96c0, 0x96c1, 0x96c4, 0x96c5, 0x96c6, 0x96c7, 0x96c9, 0x96cc,
+    0x96cd, 0x96ce, 0x96cf, 0x96d2, 0x96d5, 0x96e0, 0x96e8, 0x96e9, 0x96ea, 0x96ef, 0x96f3, 0x96f6, 0x96f7, 0x96f9,
+    0x96fe, 0x9700, 0x9701, 0x9704, 0x9706, 0x9707, 0x9708, 0x9709, 0x970d, 0x970e, 0x970f, 0x9713, 0x9716, 0x971c,
+    0x971e, 0x972a, 0x972d, 0x9730, 0x9732, 0x9738, 0x9739, 0x973e, 0x9752, 0x9753, 0x9756, 0x9759, 0x975b, 0x975e,
+    0x9760, 0x9761, 0x9762, 0x9765, 0x9769, 0x9773, 0x9774, 0x9776, 0x977c, 0x9785, 0x978b, 0x978d, 0x9791, 0x9792,
+    0x9794, 0x9798, 0x97a0, 0x97a3, 0x97ab, 0x97ad, 0x97af, 0x97b2, 0x97b4, 0x97e6, 0x97e7, 0x97e9, 0x97ea, 0x97eb,
+    0x97ec, 0x97ed, 0x97f3, 0x97f5, 0x97f6, 0x9875, 0x9876, 0x9877, 0x9878, 0x9879, 0x987a, 0x987b, 0x987c, 0x987d,
+    0x987e, 0x987f, 0x9880, 0x9881, 0x9882, 0x9883, 0x9884, 0x9885, 0x9886, 0x9887, 0x9888, 0x9889, 0x988a, 0x988c,
+    0x988d, 0x988f, 0x9890, 0x9891, 0x9893, 0x9894, 0x9896, 0x9897, 0x9898, 0x989a, 0x989b, 0x989c, 0x989d, 0x989e,
+    0x989f, 0x98a0, 0x98a1, 0x98a2, 0x98a4, 0x98a5, 0x98a6, 0x98a7, 0x98ce, 0x98d1, 0x98d2, 0x98d3, 0x98d5, 0x98d8,
+    0x98d9, 0x98da, 0x98de, 0x98df, 0x98e7, 0x98e8, 0x990d, 0x9910, 0x992e, 0x9954, 0x9955, 0x9963, 0x9965, 0x9967,
+    0x9968, 0x9969, 0x996a, 0x996b, 0x996c, 0x996d, 0x996e, 0x996f, 0x9970, 0x9971, 0x9972, 0x9974, 0x9975, 0x9976,
+    0x9977, 0x997a, 0x997c, 0x997d, 0x997f, 0x9980, 0x9981, 0x9984, 0x9985, 0x9986, 0x9987, 0x9988, 0x998a, 0x998b,
+    0x998d, 0x998f, 0x9990, 0x9991, 0x9992, 0x9993, 0x9994, 0x9995, 0x9996, 0x9997, 0x9998, 0x9999, 0x99a5, 0x99a8,
+    0x9a6c, 0x9a6d, 0x9a6e, 0x9a6f, 0x9a70, 0x9a71, 0x9a73, 0x9a74, 0x9a75, 0x9a76, 0x9a77, 0x9a78, 0x9a79, 0x9a7a,
+    0x9a7b, 0x9a7c, 0x9a7d, 0x9a7e, 0x9a7f, 0x9a80, 0x9a81, 0x9a82, 0x9a84, 0x9a85, 0x9a86, 0x9a87, 0x9a88, 0x9a8a,
+    0x9a8b, 0x9a8c, 0x9a8f, 0x9a90, 0x9a91, 0x9a92, 0x9a93, 0x9a96, 0x9a97, 0x9a98, 0x9a9a, 0x9a9b, 0x9a9c, 0x9a9d,
+    0x9a9e, 0x9a9f, 0x9aa0, 0x9aa1, 0x9aa2, 0x9aa3, 0x9aa4, 0x9aa5, 0x9aa7, 0x9aa8, 0x9ab0, 0x9ab1, 0x9ab6, 0x9ab7,
+    0x9ab8, 0x9aba, 0x9abc, 0x9ac0, 0x9ac1, 0x9ac2, 0x9ac5, 0x9acb, 0x9acc, 0x9ad1, 0x9ad3, 0x9ad8, 0x9adf, 0x9ae1,
+    0x9ae6, 0x9aeb, 0x9aed, 0x9aef, 0x9af9, 0x9afb, 0x9b03, 0x9b08, 0x9b0f, 0x9b13, 0x9b1f, 0x9b23, 0x9b2f, 0x9b32,
+    0x9b3b, 0x9b3c, 0x9b41, 0x9b42, 0x9b43, 0x9b44, 0x9b45, 0x9b47, 0x9b48, 0x9b49, 0x9b4d, 0x9b4f, 0x9b51, 0x9b54,
+    0x9c7c, 0x9c7f, 0x9c81, 0x9c82, 0x9c85, 0x9c86, 0x9c87, 0x9c88, 0x9c8b, 0x9c8d, 0x9c8e, 0x9c90, 0x9c91, 0x9c92,
+    0x9c94, 0x9c95, 0x9c9a, 0x9c9b, 0x9c9c, 0x9c9e, 0x9c9f, 0x9ca0, 0x9ca1, 0x9ca2, 0x9ca3, 0x9ca4, 0x9ca5, 0x9ca6,
+    0x9ca7, 0x9ca8, 0x9ca9, 0x9cab, 0x9cad, 0x9cae, 0x9cb0, 0x9cb1, 0x9cb2, 0x9cb3, 0x9cb4, 0x9cb5, 0x9cb6, 0x9cb7,
+    0x9cb8, 0x9cba, 0x9cbb, 0x9cbc, 0x9cbd, 0x9cc3, 0x9cc4, 0x9cc5, 0x9cc6, 0x9cc7, 0x9cca, 0x9ccb, 0x9ccc, 0x9ccd,
+    0x9cce, 0x9ccf, 0x9cd0, 0x9cd3, 0x9cd4, 0x9cd5, 0x9cd6, 0x9cd7, 0x9cd8, 0x9cd9, 0x9cdc, 0x9cdd, 0x9cde, 0x9cdf,
+    0x9ce2, 0x9e1f, 0x9e20, 0x9e21, 0x9e22, 0x9e23, 0x9e25, 0x9e26, 0x9e28, 0x9e29, 0x9e2a, 0x9e2b, 0x9e2c, 0x9e2d,
+    0x9e2f, 0x9e31, 0x9e32, 0x9e33, 0x9e35, 0x9e36, 0x9e37, 0x9e38, 0x9e39, 0x9e3a, 0x9e3d, 0x9e3e, 0x9e3f, 0x9e41,
+    0x9e42, 0x9e43, 0x9e44, 0x9e45, 0x9e46, 0x9e47, 0x9e48, 0x9e49, 0x9e4a, 0x9e4b, 0x9e4c, 0x9e4e, 0x9e4f, 0x9e51,
+    0x9e55, 0x9e57, 0x9e58, 0x9e5a, 0x9e5b, 0x9e5c, 0x9e5e, 0x9e63, 0x9e64, 0x9e66, 0x9e67, 0x9e68, 0x9e69, 0x9e6a,
+    0x9e6b, 0x9e6c, 0x9e6d, 0x9e70, 0x9e71, 0x9e73, 0x9e7e, 0x9e7f, 0x9e82, 0x9e87, 0x9e88, 0x9e8b, 0x9e92, 0x9e93,
+    0x9e9d, 0x9e9f, 0x9ea6, 0x9eb4, 0x9eb8, 0x9ebb, 0x9ebd, 0x9ebe, 0x9ec4, 0x9ec9, 0x9ecd, 0x9ece, 0x9ecf, 0x9ed1,
+    0x9ed4, 0x9ed8, 0x9edb, 0x9edc, 0x9edd, 0x9edf, 0x9ee0, 0x9ee2, 0x9ee5, 0x9ee7, 0x9ee9, 0x9eea, 0x9eef, 0x9ef9,
+    0x9efb, 0x9efc, 0x9efe, 0x9f0b, 0x9f0d, 0x9f0e, 0x9f10, 0x9f13, 0x9f17, 0x9f19, 0x9f20, 0x9f22, 0x9f2c, 0x9f2f,
+    0x9f37, 0x9f39, 0x9f3b, 0x9f3d, 0x9f3e, 0x9f44, 0x9f50, 0x9f51, 0x9f7f, 0x9f80, 0x9f83, 0x9f84, 0x9f85, 0x9f86,
+    0x9f87, 0x9f88, 0x9f89, 0x9f8a, 0x9f8b, 0x9f8c, 0x9f99, 0x9f9a, 0x9f9b, 0x9f9f, 0x9fa0, 0xff01, 0xff02, 0xff03,
+    0xff04, 0xff05, 0xff06, 0xff07, 0xff08, 0xff09, 0xff0a, 0xff0b, 0xff0c, 0xff0d, 0xff0e, 0xff0f, 0xff10, 0xff11,
+    0xff12, 0xff13, 0xff14, 0xff15, 0xff16, 0xff17, 0xff18, 0xff19, 0xff1a, 0xff1b, 0xff1c, 0xff1d, 0xff1e, 0xff1f,
+    0xff20, 0xff21, 0xff22, 0xff23, 0xff24, 0xff25, 0xff26, 0xff27, 0xff28, 0xff29, 0xff2a, 0xff2b, 0xff2c, 0xff2d,
+    0xff2e, 0xff2f, 0xff30, 0xff31, 0xff32, 0xff33, 0xff34, 0xff35, 0xff36, 0xff37, 0xff38, 0xff39, 0xff3a, 0xff3b,
+    0xff3c, 0xff3d, 0xff3e, 0xff3f, 0xff40, 0xff41, 0xff42, 0xff43, 0xff44, 0xff45, 0xff46, 0xff47, 0xff48, 0xff49,
+    0xff4a, 0xff4b, 0xff4c, 0xff4d, 0xff4e, 0xff4f, 0xff50, 0xff51, 0xff52, 0xff53, 0xff54, 0xff55, 0xff56, 0xff57,
+    0xff58, 0xff59, 0xff5a, 0xff5b, 0xff5c, 0xff5d, 0xff5e, 0xffe0, 0xffe1, 0xffe3, 0xffe5,
+];
+
+/// The GB 2312 codes of the corresponding characters in `UNICODE_CODE_POINTS`.
+static GB2312_CODES: [u16; 7445] = [
+    0xa1e8, 0xa1ec, 0xa1a7, 0xa1e3, 0xa1c0, 0xa1c1, 0xa8a4, 0xa8a2, 0xa8a8, 0xa8a6, 0xa8ba, 0xa8ac, 0xa8aa, 0xa8b0,
+    0xa8ae, 0xa1c2, 0xa8b4, 0xa8b2, 0xa8b9, 0xa8a1, 0xa8a5, 0xa8a7, 0xa8a9, 0xa8ad, 0xa8b1, 0xa8a3, 0xa8ab, 0xa8af,
+    0xa8b3, 0xa8b5, 0xa8b6, 0xa8b7, 0xa8b8, 0xa1a6, 0xa1a5, 0xa6a1, 0xa6a2, 0xa6a3, 0xa6a4, 0xa6a5, 0xa6a6, 0xa6a7,
+    0xa6a8, 0xa6a9, 0xa6aa, 0xa6ab, 0xa6ac, 0xa6ad, 0xa6ae, 0xa6af, 0xa6b0, 0xa6b1, 0xa6b2, 0xa6b3, 0xa6b4, 0xa6b5,
+    0xa6b6, 0xa6b7, 0xa6b8, 0xa6c1, 0xa6c2, 0xa6c3, 0xa6c4, 0xa6c5, 0xa6c6, 0xa6c7, 0xa6c8, 0xa6c9, 0xa6ca, 0xa6cb,
+    0xa6cc, 0xa6cd, 0xa6ce, 0xa6cf, 0xa6d0, 0xa6d1, 0xa6d2, 0xa6d3, 0xa6d4, 0xa6d5, 0xa6d6, 0xa6d7, 0xa6d8, 0xa7a7,
+    0xa7a1, 0xa7a2, 0xa7a3, 0xa7a4, 0xa7a5, 0xa7a6, 0xa7a8, 0xa7a9, 0xa7aa, 0xa7ab, 0xa7ac, 0xa7ad, 0xa7ae, 0xa7af,
+    0xa7b0, 0xa7b1, 0xa7b2, 0xa7b3, 0xa7b4, 0xa7b5, 0xa7b6, 0xa7b7, 0xa7b8, 0xa7b9, 0xa7ba, 0xa7bb, 0xa7bc, 0xa7bd,
+    0xa7be, 0xa7bf, 0xa7c0, 0xa7c1, 0xa7d1, 0xa7d2, 0xa7d3, 0xa7d4, 0xa7d5, 0xa7d6, 0xa7d8, 0xa7d9, 0xa7da, 0xa7db,
+    0xa7dc, 0xa7dd, 0xa7de, 0xa7df, 0xa7e0, 0xa7e1, 0xa7e2, 0xa7e3, 0xa7e4, 0xa7e5, 0xa7e6, 0xa7e7, 0xa7e8, 0xa7e9,
+    0xa7ea, 0xa7eb, 0xa7ec, 0xa7ed, 0xa7ee, 0xa7ef, 0xa7f0, 0xa7f1, 0xa7d7, 0xa1aa, 0xa1ac, 0xa1ae, 0xa1af, 0xa1b0,
+    0xa1b1, 0xa1ad, 0xa1eb, 0xa1e4, 0xa1e5, 0xa1f9, 0xa1e6, 0xa1ed, 0xa2f1, 0xa2f2, 0xa2f3, 0xa2f4, 0xa2f5, 0xa2f6,
+    0xa2f7, 0xa2f8, 0xa2f9, 0xa2fa, 0xa2fb, 0xa2fc, 0xa1fb, 0xa1fc, 0xa1fa, 0xa1fd, 0xa1ca, 0xa1c7, 0xa1c6, 0xa1cc,
+    0xa1d8, 0xa1de, 0xa1cf, 0xa1ce, 0xa1c4, 0xa1c5, 0xa1c9, 0xa1c8, 0xa1d2, 0xa1d3, 0xa1e0, 0xa1df, 0xa1c3, 0xa1cb,
+    0xa1d7, 0xa1d6, 0xa1d5, 0xa1d9, 0xa1d4, 0xa1dc, 0xa1dd, 0xa1da, 0xa1db, 0xa1d1, 0xa1cd, 0xa1d0, 0xa2d9, 0xa2da,
+    0xa2db, 0xa2dc, 0xa2dd, 0xa2de, 0xa2df, 0xa2e0, 0xa2e1, 0xa2e2, 0xa2c5, 0xa2c6, 0xa2c7, 0xa2c8, 0xa2c9, 0xa2ca,
+    0xa2cb, 0xa2cc, 0xa2cd, 0xa2ce, 0xa2cf, 0xa2d0, 0xa2d1, 0xa2d2, 0xa2d3, 0xa2d4, 0xa2d5, 0xa2d6, 0xa2d7, 0xa2d8,
+    0xa2b1, 0xa2b2, 0xa2b3, 0xa2b4, 0xa2b5, 0xa2b6, 0xa2b7, 0xa2b8, 0xa2b9, 0xa2ba, 0xa2bb, 0xa2bc, 0xa2bd, 0xa2be,
+    0xa2bf, 0xa2c0, 0xa2c1, 0xa2c2, 0xa2c3, 0xa2c4, 0xa9a4, 0xa9a5, 0xa9a6, 0xa9a7, 0xa9a8, 0xa9a9, 0xa9aa, 0xa9ab,
+    0xa9ac, 0xa9ad, 0xa9ae, 0xa9af, 0xa9b0, 0xa9b1, 0xa9b2, 0xa9b3, 0xa9b4, 0xa9b5, 0xa9b6, 0xa9b7, 0xa9b8, 0xa9b9,
+    0xa9ba, 0xa9bb, 0xa9bc, 0xa9bd, 0xa9be, 0xa9bf, 0xa9c0, 0xa9c1, 0xa9c2, 0xa9c3, 0xa9c4, 0xa9c5, 0xa9c6, 0xa9c7,
+    0xa9c8, 0xa9c9, 0xa9ca, 0xa9cb, 0xa9cc, 0xa9cd, 0xa9ce, 0xa9cf, 0xa9d0, 0xa9d1, 0xa9d2, 0xa9d3, 0xa9d4, 0xa9d5,
+    0xa9d6, 0xa9d7, 0xa9d8, 0xa9d9, 0xa9da, 0xa9db, 0xa9dc, 0xa9dd, 0xa9de, 0xa9df, 0xa9e0, 0xa9e1, 0xa9e2, 0xa9e3,
+    0xa9e4, 0xa9e5, 0xa9e6, 0xa9e7, 0xa9e8, 0xa9e9, 0xa9ea, 0xa9eb, 0xa9ec, 0xa9ed, 0xa9ee, 0xa9ef, 0xa1f6, 0xa1f5,
+    0xa1f8, 0xa1f7, 0xa1f4, 0xa1f3, 0xa1f0, 0xa1f2, 0xa1f1, 0xa1ef, 0xa1ee, 0xa1e2, 0xa1e1, 0xa1a1, 0xa1a2, 0xa1a3,
+    0xa1a8, 0xa1a9, 0xa1b4, 0xa1b5, 0xa1b6, 0xa1b7, 0xa1b8, 0xa1b9, 0xa1ba, 0xa1bb, 0xa1be, 0xa1bf, 0xa1fe, 0xa1b2,
+    0xa1b3, 0xa1bc, 0xa1bd, 0xa4a1, 0xa4a2, 0xa4a3, 0xa4a4, 0xa4a5, 0xa4a6, 0xa4a7, 0xa4a8, 0xa4a9, 0xa4aa, 0xa4ab,
+    0xa4ac, 0xa4ad, 0xa4ae, 0xa4af, 0xa4b0, 0xa4b1, 0xa4b2, 0xa4b3, 0xa4b4, 0xa4b5, 0xa4b6, 0xa4b7, 0xa4b8, 0xa4b9,
+    0xa4ba, 0xa4bb, 0xa4bc, 0xa4bd, 0xa4be, 0xa4bf, 0xa4c0, 0xa4c1, 0xa4c2, 0xa4c3, 0xa4c4, 0xa4c5, 0xa4c6, 0xa4c7,
+    0xa4c8, 0xa4c9, 0xa4ca, 0xa4cb, 0xa4cc, 0xa4cd, 0xa4ce, 0xa4cf, 0xa4d0, 0xa4d1, 0xa4d2, 0xa4d3, 0xa4d4, 0xa4d5,
+    0xa4d6, 0xa4d7, 0xa4d8, 0xa4d9, 0xa4da, 0xa4db, 0xa4dc, 0xa4dd, 0xa4de, 0xa4df, 0xa4e0, 0xa4e1, 0xa4e2, 0xa4e3,
+    0xa4e4, 0xa4e5, 0xa4e6, 0xa4e7, 0xa4e8, 0xa4e9, 0xa4ea, 0xa4eb, 0xa4ec, 0xa4ed, 0xa4ee, 0xa4ef, 0xa4f0, 0xa4f1,
+    0xa4f2, 0xa4f3, 0xa5a1, 0xa5a2, 0xa5a3, 0xa5a4, 0xa5a5, 0xa5a6, 0xa5a7, 0xa5a8, 0xa5a9, 0xa5aa, 0xa5ab, 0xa5ac,
+    0xa5ad, 0xa5ae, 0xa5af, 0xa5b0, 0xa5b1, 0xa5b2, 0xa5b3, 0xa5b4, 0xa5b5, 0xa5b6, 0xa5b7, 0xa5b8, 0xa5b9, 0xa5ba,
+    0xa5bb, 0xa5bc, 0xa5bd, 0xa5be, 0xa5bf, 0xa5c0, 0xa5c1, 0xa5c2, 0xa5c3, 0xa5c4, 0xa5c5, 0xa5c6, 0xa5c7, 0xa5c8,
+    0xa5c9, 0xa5ca, 0xa5cb, 0xa5cc, 0xa5cd, 0xa5ce, 0xa5cf, 0xa5d0, 0xa5d1, 0xa5d2, 0xa5d3, 0xa5d4, 0xa5d5, 0xa5d6,
+    0xa5d7, 0xa5d8, 0xa5d9, 0xa5da, 0xa5db, 0xa5dc, 0xa5dd, 0xa5de, 0xa5df, 0xa5e0, 0xa5e1, 0xa5e2, 0xa5e3, 0xa5e4,
+    0xa5e5, 0xa5e6, 0xa5e7, 0xa5e8, 0xa5e9, 0xa5ea, 0xa5eb, 0xa5ec, 0xa5ed, 0xa5ee, 0xa5ef, 0xa5f0, 0xa5f1, 0xa5f2,
+    0xa5f3, 0xa5f4, 0xa5f5, 0xa5f6, 0xa1a4, 0xa8c5, 0xa8c6, 0xa8c7, 0xa8c8, 0xa8c9, 0xa8ca, 0xa8cb, 0xa8cc, 0xa8cd,
+    0xa8ce, 0xa8cf, 0xa8d0, 0xa8d1, 0xa8d2, 0xa8d3, 0xa8d4, 0xa8d5, 0xa8d6, 0xa8d7, 0xa8d8, 0xa8d9, 0xa8da, 0xa8db,
+    0xa8dc, 0xa8dd, 0xa8de, 0xa8df, 0xa8e0, 0xa8e1, 0xa8e2, 0xa8e3, 0xa8e4, 0xa8e5, 0xa8e6, 0xa8e7, 0xa8e8, 0xa8e9,
+    0xa2e5, 0xa2e6, 0xa2e7, 0xa2e8, 0xa2e9, 0xa2ea, 0xa2eb, 0xa2ec, 0xa2ed, 0xa2ee, 0xd2bb, 0xb6a1, 0xc6df, 0xcdf2,
+    0xd5c9, 0xc8fd, 0xc9cf, 0xcfc2, 0xd8a2, 0xb2bb, 0xd3eb, 0xd8a4, 0xb3f3, 0xd7a8, 0xc7d2, 0xd8a7, 0xcac0, 0xc7f0,
+    0xb1fb, 0xd2b5, 0xb4d4, 0xb6ab, 0xcbbf, 0xd8a9, 0xb6aa, 0xc1bd, 0xd1cf, 0xc9a5, 0xd8ad, 0xb8f6, 0xd1be, 0xe3dc,
+    0xd6d0, 0xb7e1, 0xb4ae, 0xc1d9, 0xd8bc, 0xcde8, 0xb5a4, 0xceaa, 0xd6f7, 0xc0f6, 0xbed9, 0xd8af, 0xc4cb, 0xbec3,
+    0xd8b1, 0xc3b4, 0xd2e5, 0xd6ae, 0xceda, 0xd5a7, 0xbaf5, 0xb7a6, 0xc0d6, 0xc6b9, 0xc5d2, 0xc7c7, 0xb9d4, 0xb3cb,
+    0xd2d2, 0xd8bf, 0xbec5, 0xc6f2, 0xd2b2, 0xcfb0, 0xcfe7, 0xcae9, 0xd8c0, 0xc2f2, 0xc2d2, 0xc8e9, 0xc7ac, 0xc1cb,
+    0xd3e8, 0xd5f9, 0xcac2, 0xb6fe, 0xd8a1, 0xd3da, 0xbff7, 0xd4c6, 0xbba5, 0xd8c1, 0xcee5, 0xbeae, 0xd8a8, 0xd1c7,
+    0xd0a9, 0xd8bd, 0xd9ef, 0xcdf6, 0xbfba, 0xbdbb, 0xbaa5, 0xd2e0, 0xb2fa, 0xbae0, 0xc4b6, 0xcfed, 0xbea9, 0xcda4,
+    0xc1c1, 0xc7d7, 0xd9f1, 0xd9f4, 0xc8cb, 0xd8e9, 0xd2da, 0xcab2, 0xc8ca, 0xd8ec, 0xd8ea, 0xd8c6, 0xbdf6, 0xc6cd,
+    0xb3f0, 0xd8eb, 0xbdf1, 0xbde9, 0xc8d4, 0xb4d3, 0xc2d8, 0xb2d6, 0xd7d0, 0xcacb, 0xcbfb, 0xd5cc, 0xb8b6, 0xcfc9,
+    0xd9da, 0xd8f0, 0xc7aa, 0xd8ee, 0xb4fa, 0xc1ee, 0xd2d4, 0xd8ed, 0xd2c7, 0xd8ef, 0xc3c7, 0xd1f6, 0xd6d9, 0xd8f2,
+    0xd8f5, 0xbcfe, 0xbcdb, 0xc8ce, 0xb7dd, 0xb7c2, 0xc6f3, 0xd8f8, 0xd2c1, 0xcee9, 0xbcbf, 0xb7fc, 0xb7a5, 0xd0dd,
+    0xd6da, 0xd3c5, 0xbbef, 0xbbe1, 0xd8f1, 0xc9a1, 0xceb0, 0xb4ab, 0xd8f3, 0xc9cb, 0xd8f6, 0xc2d7, 0xd8f7, 0xceb1,
+    0xd8f9, 0xb2ae, 0xb9c0, 0xd9a3, 0xb0e9, 0xc1e6, 0xc9ec, 0xcbc5, 0xcbc6, 0xd9a4, 0xb5e8, 0xb5ab, 0xcebb, 0xb5cd,
+    0xd7a1, 0xd7f4, 0xd3d3, 0xcce5, 0xbace, 0xd9a2, 0xd9dc, 0xd3e0, 0xd8fd, 0xb7f0, 0xd7f7, 0xd8fe, 0xd8fa, 0xd9a1,
+    0xc4e3, 0xd3b6, 0xd8f4, 0xd9dd, 0xd8fb, 0xc5e5, 0xc0d0, 0xd1f0, 0xb0db, 0xbcd1, 0xd9a6, 0xd9a5, 0xd9ac, 0xd9ae,
+    0xd9ab, 0xcab9, 0xd9a9, 0xd6b6, 0xb3de, 0xd9a8, 0xc0fd, 0xcacc, 0xd9aa, 0xd9a7, 0xd9b0, 0xb6b1, 0xb9a9, 0xd2c0,
+    0xcfc0, 0xc2c2, 0xbdc4, 0xd5ec, 0xb2e0, 0xc7c8, 0xbfeb, 0xd9ad, 0xd9af, 0xceea, 0xbaee, 0xc7d6, 0xb1e3, 0xb4d9,
+    0xb6ed, 0xd9b4, 0xbfa1, 0xd9de, 0xc7ce, 0xc0fe, 0xd9b8, 0xcbd7, 0xb7fd, 0xd9b5, 0xd9b7, 0xb1a3, 0xd3e1, 0xd9b9,
+    0xd0c5, 0xd9b6, 0xd9b1, 0xd9b2, 0xc1a9, 0xd9b3, 0xbcf3, 0xd0de, 0xb8a9, 0xbee3, 0xd9bd, 0xd9ba, 0xb0b3, 0xd9c2,
+    0xd9c4, 0xb1b6, 0xd9bf, 0xb5b9, 0xbef3, 0xccc8, 0xbaf2, 0xd2d0, 0xd9c3, 0xbde8, 0xb3ab, 0xd9c5, 0xbeeb, 0xd9c6,
+    0xd9bb, 0xc4df, 0xd9be, 0xd9c1, 0xd9c0, 0xd5ae, 0xd6b5, 0xc7e3, 0xd9c8, 0xbcd9, 0xd9ca, 0xd9bc, 0xd9cb, 0xc6ab,
+    0xd9c9, 0xd7f6, 0xcda3, 0xbda1, 0xd9cc, 0xc5bc, 0xcdb5, 0xd9cd, 0xd9c7, 0xb3a5, 0xbffe, 0xb8b5, 0xc0fc, 0xb0f8,
+    0xb4f6, 0xd9ce, 0xd9cf, 0xb4a2, 0xd9d0, 0xb4df, 0xb0c1, 0xd9d1, 0xc9b5, 0xcff1, 0xd9d2, 0xc1c5, 0xd9d6, 0xc9ae,
+    0xd9d5, 0xd9d4, 0xd9d7, 0xcbdb, 0xbda9, 0xc6a7, 0xd9d3, 0xd9d8, 0xd9d9, 0xc8e5, 0xc0dc, 0xb6f9, 0xd8a3, 0xd4ca,
+    0xd4aa, 0xd0d6, 0xb3e4, 0xd5d7, 0xcfc8, 0xb9e2, 0xbfcb, 0xc3e2, 0xb6d2, 0xcdc3, 0xd9ee, 0xd9f0, 0xb5b3, 0xb6b5,
+    0xbea4, 0xc8eb, 0xc8ab, 0xb0cb, 0xb9ab, 0xc1f9, 0xd9e2, 0xc0bc, 0xb9b2, 0xb9d8, 0xd0cb, 0xb1f8, 0xc6e4, 0xbedf,
+    0xb5e4, 0xd7c8, 0xd1f8, 0xbce6, 0xcade, 0xbcbd, 0xd9e6, 0xd8e7, 0xc4da, 0xb8d4, 0xc8bd, 0xb2e1, 0xd4d9, 0xc3b0,
+    0xc3e1, 0xdaa2, 0xc8df, 0xd0b4, 0xbefc, 0xc5a9, 0xb9da, 0xdaa3, 0xd4a9, 0xdaa4, 0xd9fb, 0xb6ac, 0xb7eb, 0xb1f9,
+    0xd9fc, 0xb3e5, 0xbef6, 0xbff6, 0xd2b1, 0xc0e4, 0xb6b3, 0xd9fe, 0xd9fd, 0xbebb, 0xc6e0, 0xd7bc, 0xdaa1, 0xc1b9,
+    0xb5f2, 0xc1e8, 0xbcf5, 0xb4d5, 0xc1dd, 0xc4fd, 0xbcb8, 0xb7b2, 0xb7ef, 0xd9ec, 0xc6be, 0xbfad, 0xbbcb, 0xb5ca,
+    0xdbc9, 0xd0d7, 0xcdb9, 0xb0bc, 0xb3f6, 0xbbf7, 0xdbca, 0xbaaf, 0xd4e4, 0xb5b6, 0xb5f3, 0xd8d6, 0xc8d0, 0xb7d6,
+    0xc7d0, 0xd8d7, 0xbfaf, 0xdbbb, 0xd8d8, 0xd0cc, 0xbbae, 0xebbe, 0xc1d0, 0xc1f5, 0xd4f2, 0xb8d5, 0xb4b4, 0xb3f5,
+    0xc9be, 0xc5d0, 0xc5d9, 0xc0fb, 0xb1f0, 0xd8d9, 0xb9ce, 0xb5bd, 0xd8da, 0xd6c6, 0xcba2, 0xc8af, 0xc9b2, 0xb4cc,
+    0xbfcc, 0xb9f4, 0xd8db, 0xd8dc, 0xb6e7, 0xbcc1, 0xccea, 0xcff7, 0xd8dd, 0xc7b0, 0xb9d0, 0xbda3, 0xccde, 0xc6ca,
+    0xd8e0, 0xd8de, 0xd8df, 0xb0fe, 0xbee7, 0xcaa3, 0xbcf4, 0xb8b1, 0xb8ee, 0xd8e2, 0xbdcb, 0xd8e4, 0xd8e3, 0xc5fc,
+    0xd8e5, 0xd8e6, 0xc1a6, 0xc8b0, 0xb0ec, 0xb9a6, 0xbcd3, 0xcef1, 0xdbbd, 0xc1d3, 0xb6af, 0xd6fa, 0xc5ac, 0xbdd9,
+    0xdbbe, 0xdbbf, 0xc0f8, 0xbea2, 0xc0cd, 0xdbc0, 0xcac6, 0xb2aa, 0xd3c2, 0xc3e3, 0xd1ab, 0xdbc2, 0xc0d5, 0xdbc3,
+    0xbfb1, 0xc4bc, 0xc7da, 0xdbc4, 0xd9e8, 0xc9d7, 0xb9b4, 0xcef0, 0xd4c8, 0xb0fc, 0xb4d2, 0xd0d9, 0xd9e9, 0xdecb,
+    0xd9eb, 0xd8b0, 0xbbaf, 0xb1b1, 0xb3d7, 0xd8ce, 0xd4d1, 0xbdb3, 0xbfef, 0xcfbb, 0xd8d0, 0xb7cb, 0xd8d1, 0xc6a5,
+    0xc7f8, 0xd2bd, 0xd8d2, 0xc4e4, 0xcaae, 0xc7a7, 0xd8a6, 0xc9fd, 0xcee7, 0xbbdc, 0xb0eb, 0xbbaa, 0xd0ad, 0xb1b0,
+    0xd7e4, 0xd7bf, 0xb5a5, 0xc2f4, 0xc4cf, 0xb2a9, 0xb2b7, 0xb1e5, 0xdfb2, 0xd5bc, 0xbfa8, 0xc2ac, 0xd8d5, 0xc2b1,
+    0xd8d4, 0xced4, 0xdae0, 0xcec0, 0xd8b4, 0xc3ae, 0xd3a1, 0xcea3, 0xbcb4, 0xc8b4, 0xc2d1, 0xbeed, 0xd0b6, 0xdae1,
+    0xc7e4, 0xb3a7, 0xb6f2, 0xccfc, 0xc0fa, 0xc0f7, 0xd1b9, 0xd1e1, 0xd8c7, 0xb2de, 0xc0e5, 0xbaf1, 0xd8c8, 0xd4ad,
+    0xcfe1, 0xd8c9, 0xd8ca, 0xcfc3, 0xb3f8, 0xbec7, 0xd8cb, 0xdbcc, 0xc8a5, 0xcfd8, 0xc8fe, 0xb2ce, 0xd3d6, 0xb2e6,
+    0xbcb0, 0xd3d1, 0xcbab, 0xb7b4, 0xb7a2, 0xcae5, 0xc8a1, 0xcadc, 0xb1e4, 0xd0f0, 0xc5d1, 0xdbc5, 0xb5fe, 0xbfda,
+    0xb9c5, 0xbee4, 0xc1ed, 0xdfb6, 0xdfb5, 0xd6bb, 0xbdd0, 0xd5d9, 0xb0c8, 0xb6a3, 0xbfc9, 0xcca8, 0xdfb3, 0xcab7,
+    0xd3d2, 0xd8cf, 0xd2b6, 0xbac5, 0xcbbe, 0xccbe, 0xdfb7, 0xb5f0, 0xdfb4, 0xd3f5, 0xb3d4, 0xb8f7, 0xdfba, 0xbacf,
+    0xbcaa, 0xb5f5, 0xcdac, 0xc3fb, 0xbaf3, 0xc0f4, 0xcdc2, 0xcff2, 0xdfb8, 0xcfc5, 0xc2c0, 0xdfb9, 0xc2f0, 0xbefd,
+    0xc1df, 0xcdcc, 0xd2f7, 0xb7cd, 0xdfc1, 0xdfc4, 0xb7f1, 0xb0c9, 0xb6d6, 0xb7d4, 0xbaac, 0xccfd, 0xbfd4, 0xcbb1,
+    0xc6f4, 0xd6a8, 0xdfc5, 0xcee2, 0xb3b3, 0xcefc, 0xb4b5, 0xcec7, 0xbaf0, 0xcee1, 0xd1bd, 0xdfc0, 0xb4f4, 0xb3ca,
+    0xb8e6, 0xdfbb, 0xc4c5, 0xdfbc, 0xdfbd, 0xdfbe, 0xc5bb, 0xdfbf, 0xdfc2, 0xd4b1, 0xdfc3, 0xc7ba, 0xced8, 0xc4d8,
+    0xdfca, 0xdfcf, 0xd6dc, 0xdfc9, 0xdfda, 0xceb6, 0xbac7, 0xdfce, 0xdfc8, 0xc5de, 0xc9eb, 0xbaf4, 0xc3fc, 0xbed7,
+    0xdfc6, 0xdfcd, 0xc5d8, 0xd5a6, 0xbacd, 0xbecc, 0xd3bd, 0xb8c0, 0xd6e4, 0xdfc7, 0xb9be, 0xbfa7, 0xc1fc, 0xdfcb,
+    0xdfcc, 0xdfd0, 0xdfdb, 0xdfe5, 0xdfd7, 0xdfd6, 0xd7c9, 0xdfe3, 0xdfe4, 0xe5eb, 0xd2a7, 0xdfd2, 0xbfa9, 0xd4db,
+    0xbfc8, 0xdfd4, 0xcfcc, 0xdfdd, 0xd1ca, 0xdfde, 0xb0a7, 0xc6b7, 0xdfd3, 0xbae5, 0xb6df, 0xcddb, 0xb9fe, 0xd4d5,
+    0xdfdf, 0xcfec, 0xb0a5, 0xdfe7, 0xdfd1, 0xd1c6, 0xdfd5, 0xdfd8, 0xdfd9, 0xdfdc, 0xbba9, 0xdfe0, 0xdfe1, 0xdfe2,
+    0xdfe6, 0xdfe8, 0xd3b4, 0xb8e7, 0xc5b6, 0xdfea, 0xc9da, 0xc1a8, 0xc4c4, 0xbfde, 0xcff8, 0xd5dc, 0xdfee, 0xb2b8,
+    0xbadf, 0xdfec, 0xdbc1, 0xd1e4, 0xcbf4, 0xb4bd, 0xb0a6, 0xdff1, 0xccc6, 0xdff2, 0xdfed, 0xdfe9, 0xdfeb, 0xdfef,
+    0xdff0, 0xbbbd, 0xdff3, 0xdff4, 0xbba3, 0xcadb, 0xcea8, 0xe0a7, 0xb3aa, 0xe0a6, 0xe0a1, 0xdffe, 0xcdd9, 0xdffc,
+    0xdffa, 0xbfd0, 0xd7c4, 0xc9cc, 0xdff8, 0xb0a1, 0xdffd, 0xdffb, 0xe0a2, 0xe0a8, 0xb7c8, 0xc6a1, 0xc9b6, 0xc0b2,
+    0xdff5, 0xc5be, 0xd8c4, 0xdff9, 0xc4f6, 0xe0a3, 0xe0a4, 0xe0a5, 0xd0a5, 0xe0b4, 0xcce4, 0xe0b1, 0xbfa6, 0xe0af,
+    0xceb9, 0xe0ab, 0xc9c6, 0xc0ae, 0xe0ae, 0xbaed, 0xbab0, 0xe0a9, 0xdff6, 0xe0b3, 0xe0b8, 0xb4ad, 0xe0b9, 0xcfb2,
+    0xbac8, 0xe0b0, 0xd0fa, 0xe0ac, 0xd4fb, 0xdff7, 0xc5e7, 0xe0ad, 0xd3f7, 0xe0b6, 0xe0b7, 0xe0c4, 0xd0e1, 0xe0bc,
+    0xe0c9, 0xe0ca, 0xe0be, 0xe0aa, 0xc9a4, 0xe0c1, 0xe0b2, 0xcac8, 0xe0c3, 0xe0b5, 0xcecb, 0xcbc3, 0xe0cd, 0xe0c6,
+    0xe0c2, 0xe0cb, 0xe0ba, 0xe0bf, 0xe0c0, 0xe0c5, 0xe0c7, 0xe0c8, 0xe0cc, 0xe0bb, 0xcbd4, 0xe0d5, 0xe0d6, 0xe0d2,
+    0xe0d0, 0xbcce, 0xe0d1, 0xb8c2, 0xd8c5, 0xd0ea, 0xc2ef, 0xe0cf, 0xe0bd, 0xe0d4, 0xe0d3, 0xe0d7, 0xe0dc, 0xe0d8,
+    0xd6f6, 0xb3b0, 0xd7ec, 0xcbbb, 0xe0da, 0xcefb, 0xbad9, 0xe0e1, 0xe0dd, 0xd2ad, 0xe0e2, 0xe0db, 0xe0d9, 0xe0df,
+    0xe0e0, 0xe0de, 0xe0e4, 0xc6f7, 0xd8ac, 0xd4eb, 0xe0e6, 0xcac9, 0xe0e5, 0xb8c1, 0xe0e7, 0xe0e8, 0xe0e9, 0xe0e3,
+    0xbabf, 0xcce7, 0xe0ea, 0xcff9, 0xe0eb, 0xc8c2, 0xbdc0, 0xc4d2, 0xe0ec, 0xe0ed, 0xc7f4, 0xcbc4, 0xe0ee, 0xbbd8,
+    0xd8b6, 0xd2f2, 0xe0ef, 0xcdc5, 0xb6da, 0xe0f1, 0xd4b0, 0xc0a7, 0xb4d1, 0xcea7, 0xe0f0, 0xe0f2, 0xb9cc, 0xb9fa,
+    0xcdbc, 0xe0f3, 0xc6d4, 0xe0f4, 0xd4b2, 0xc8a6, 0xe0f6, 0xe0f5, 0xe0f7, 0xcdc1, 0xcaa5, 0xd4da, 0xdbd7, 0xdbd9,
+    0xdbd8, 0xb9e7, 0xdbdc, 0xdbdd, 0xb5d8, 0xdbda, 0xdbdb, 0xb3a1, 0xdbdf, 0xbbf8, 0xd6b7, 0xdbe0, 0xbef9, 0xb7bb,
+    0xdbd0, 0xccae, 0xbfb2, 0xbbb5, 0xd7f8, 0xbfd3, 0xbfe9, 0xbce1, 0xccb3, 0xdbde, 0xb0d3, 0xceeb, 0xb7d8, 0xd7b9,
+    0xc6c2, 0xc0a4, 0xccb9, 0xdbe7, 0xdbe1, 0xc6ba, 0xdbe3, 0xdbe8, 0xc5f7, 0xdbea, 0xdbe9, 0xbfc0, 0xdbe6, 0xdbe5,
+    0xb4b9, 0xc0ac, 0xc2a2, 0xdbe2, 0xdbe4, 0xd0cd, 0xdbed, 0xc0dd, 0xdbf2, 0xb6e2, 0xdbf3, 0xdbd2, 0xb9b8, 0xd4ab,
+    0xdbec, 0xbfd1, 0xdbf0, 0xdbd1, 0xb5e6, 0xdbeb, 0xbfe5, 0xdbee, 0xdbf1, 0xdbf9, 0xb9a1, 0xb0a3, 0xc2f1, 0xb3c7,
+    0xdbef, 0xdbf8, 0xc6d2, 0xdbf4, 0xdbf5, 0xdbf7, 0xdbf6, 0xdbfe, 0xd3f2, 0xb2ba, 0xdbfd, 0xdca4, 0xdbfb, 0xdbfa,
+    0xdbfc, 0xc5e0, 0xbbf9, 0xdca3, 0xdca5, 0xccc3, 0xb6d1, 0xddc0, 0xdca1, 0xdca2, 0xc7b5, 0xb6e9, 0xdca7, 0xdca6,
+    0xdca9, 0xb1a4, 0xb5cc, 0xbfb0, 0xd1df, 0xb6c2, 0xdca8, 0xcbfa, 0xebf3, 0xcbdc, 0xcbfe, 0xccc1, 0xc8fb, 0xdcaa,
+    0xccee, 0xdcab, 0xdbd3, 0xdcaf, 0xdcac, 0xbeb3, 0xcafb, 0xdcad, 0xc9ca, 0xc4b9, 0xc7bd, 0xdcae, 0xd4f6, 0xd0e6,
+    0xc4ab, 0xb6d5, 0xdbd4, 0xb1da, 0xdbd5, 0xdbd6, 0xbabe, 0xc8c0, 0xcabf, 0xc8c9, 0xd7b3, 0xc9f9, 0xbfc7, 0xbaf8,
+    0xd2bc, 0xe2ba, 0xb4a6, 0xb1b8, 0xb8b4, 0xcfc4, 0xd9e7, 0xcfa6, 0xcde2, 0xd9ed, 0xb6e0, 0xd2b9, 0xb9bb, 0xe2b9,
+    0xe2b7, 0xb4f3, 0xccec, 0xccab, 0xb7f2, 0xd8b2, 0xd1eb, 0xbabb, 0xcaa7, 0xcdb7, 0xd2c4, 0xbfe4, 0xbcd0, 0xb6e1,
+    0xdec5, 0xdec6, 0xdbbc, 0xd1d9, 0xc6e6, 0xc4ce, 0xb7ee, 0xb7dc, 0xbffc, 0xd7e0, 0xc6f5, 0xb1bc, 0xdec8, 0xbdb1,
+    0xccd7, 0xdeca, 0xdec9, 0xb5ec, 0xc9dd, 0xb0c2, 0xc5ae, 0xc5ab, 0xc4cc, 0xbce9, 0xcbfd, 0xbac3, 0xe5f9, 0xc8e7,
+    0xe5fa, 0xcdfd, 0xd7b1, 0xb8be, 0xc2e8, 0xc8d1, 0xe5fb, 0xb6ca, 0xbccb, 0xd1fd, 0xe6a1, 0xc3ee, 0xe6a4, 0xe5fe,
+    0xe6a5, 0xcdd7, 0xb7c1, 0xe5fc, 0xe5fd, 0xe6a3, 0xc4dd, 0xe6a8, 0xe6a7, 0xc3c3, 0xc6de, 0xe6aa, 0xc4b7, 0xe6a2,
+    0xcabc, 0xbde3, 0xb9c3, 0xe6a6, 0xd0d5, 0xceaf, 0xe6a9, 0xe6b0, 0xd2a6, 0xbdaa, 0xe6ad, 0xe6af, 0xc0d1, 0xd2cc,
+    0xbca7, 0xe6b1, 0xd2f6, 0xd7cb, 0xcdfe, 0xcdde, 0xc2a6, 0xe6ab, 0xe6ac, 0xbdbf, 0xe6ae, 0xe6b3, 0xe6b2, 0xe6b6,
+    0xe6b8, 0xc4ef, 0xc4c8, 0xbeea, 0xc9ef, 0xe6b7, 0xb6f0, 0xc3e4, 0xd3e9, 0xe6b4, 0xe6b5, 0xc8a2, 0xe6bd, 0xe6b9,
+    0xc6c5, 0xcdf1, 0xe6bb, 0xe6bc, 0xbbe9, 0xe6be, 0xe6ba, 0xc0b7, 0xd3a4, 0xe6bf, 0xc9f4, 0xe6c3, 0xe6c4, 0xd0f6,
+    0xc3bd, 0xc3c4, 0xe6c2, 0xe6c1, 0xe6c7, 0xcfb1, 0xebf4, 0xe6ca, 0xe6c5, 0xbcde, 0xc9a9, 0xbcb5, 0xcfd3, 0xe6c8,
+    0xe6c9, 0xe6ce, 0xe6d0, 0xe6d1, 0xe6cb, 0xb5d5, 0xe6cc, 0xe6cf, 0xc4db, 0xe6c6, 0xe6cd, 0xe6d2, 0xe6d4, 0xe6d3,
+    0xe6d5, 0xd9f8, 0xe6d6, 0xe6d7, 0xd7d3, 0xe6dd, 0xe6de, 0xbfd7, 0xd4d0, 0xd7d6, 0xb4e6, 0xcbef, 0xe6da, 0xd8c3,
+    0xd7ce, 0xd0a2, 0xc3cf, 0xe6df, 0xbcbe, 0xb9c2, 0xe6db, 0xd1a7, 0xbaa2, 0xc2cf, 0xd8ab, 0xcaeb, 0xe5ee, 0xe6dc,
+    0xb7f5, 0xc8e6, 0xc4f5, 0xe5b2, 0xc4fe, 0xcbfc, 0xe5b3, 0xd5ac, 0xd3ee, 0xcad8, 0xb0b2, 0xcbce, 0xcdea, 0xbaea,
+    0xe5b5, 0xe5b4, 0xd7da, 0xb9d9, 0xd6e6, 0xb6a8, 0xcdf0, 0xd2cb, 0xb1a6, 0xcab5, 0xb3e8, 0xc9f3, 0xbfcd, 0xd0fb,
+    0xcad2, 0xe5b6, 0xbbc2, 0xcfdc, 0xb9ac, 0xd4d7, 0xbaa6, 0xd1e7, 0xcffc, 0xbcd2, 0xe5b7, 0xc8dd, 0xbfed, 0xb1f6,
+    0xcbde, 0xbcc5, 0xbcc4, 0xd2fa, 0xc3dc, 0xbfdc, 0xb8bb, 0xc3c2, 0xbaae, 0xd4a2, 0xc7de, 0xc4af, 0xb2ec, 0xb9d1,
+    0xe5bb, 0xc1c8, 0xd5af, 0xe5bc, 0xe5be, 0xb4e7, 0xb6d4, 0xcbc2, 0xd1b0, 0xb5bc, 0xcad9, 0xb7e2, 0xc9e4, 0xbdab,
+    0xcebe, 0xd7f0, 0xd0a1, 0xc9d9, 0xb6fb, 0xe6d8, 0xbce2, 0xb3be, 0xc9d0, 0xe6d9, 0xb3a2, 0xdecc, 0xd3c8, 0xdecd,
+    0xd2a2, 0xdece, 0xbecd, 0xdecf, 0xcaac, 0xd2fc, 0xb3df, 0xe5ea, 0xc4e1, 0xbea1, 0xceb2, 0xc4f2, 0xbed6, 0xc6a8,
+    0xb2e3, 0xbed3, 0xc7fc, 0xcceb, 0xbdec, 0xcedd, 0xcaba, 0xc6c1, 0xe5ec, 0xd0bc, 0xd5b9, 0xe5ed, 0xcaf4, 0xcdc0,
+    0xc2c5, 0xe5ef, 0xc2c4, 0xe5f0, 0xe5f8, 0xcdcd, 0xc9bd, 0xd2d9, 0xe1a8, 0xd3ec, 0xcbea, 0xc6f1, 0xe1ac, 0xe1a7,
+    0xe1a9, 0xe1aa, 0xe1af, 0xb2ed, 0xe1ab, 0xb8da, 0xe1ad, 0xe1ae, 0xe1b0, 0xb5ba, 0xe1b1, 0xe1b3, 0xe1b8, 0xd1d2,
+    0xe1b6, 0xe1b5, 0xc1eb, 0xe1b7, 0xd4c0, 0xe1b2, 0xe1ba, 0xb0b6, 0xe1b4, 0xbff9, 0xe1b9, 0xe1bb, 0xe1be, 0xe1bc,
+    0xd6c5, 0xcfbf, 0xe1bd, 0xe1bf, 0xc2cd, 0xb6eb, 0xd3f8, 0xc7cd, 0xb7e5, 0xbefe, 0xe1c0, 0xe1c1, 0xe1c7, 0xb3e7,
+    0xc6e9, 0xb4de, 0xd1c2, 0xe1c8, 0xe1c6, 0xe1c5, 0xe1c3, 0xe1c2, 0xb1c0, 0xd5b8, 0xe1c4, 0xe1cb, 0xe1cc, 0xe1ca,
+    0xeffa, 0xe1d3, 0xe1d2, 0xc7b6, 0xe1c9, 0xe1ce, 0xe1d0, 0xe1d4, 0xe1d1, 0xe1cd, 0xe1cf, 0xe1d5, 0xe1d6, 0xe1d7,
+    0xe1d8, 0xe1da, 0xe1db, 0xcea1, 0xe7dd, 0xb4a8, 0xd6dd, 0xd1b2, 0xb3b2, 0xb9a4, 0xd7f3, 0xc7c9, 0xbede, 0xb9ae,
+    0xced7, 0xb2ee, 0xdbcf, 0xbcba, 0xd2d1, 0xcbc8, 0xb0cd, 0xcfef, 0xd9e3, 0xbded, 0xb1d2, 0xcad0, 0xb2bc, 0xcba7,
+    0xb7ab, 0xcaa6, 0xcfa3, 0xe0f8, 0xd5ca, 0xe0fb, 0xe0fa, 0xc5c1, 0xccfb, 0xc1b1, 0xe0f9, 0xd6e3, 0xb2af, 0xd6c4,
+    0xb5db, 0xb4f8, 0xd6a1, 0xcfaf, 0xb0ef, 0xe0fc, 0xe1a1, 0xb3a3, 0xe0fd, 0xe0fe, 0xc3b1, 0xc3dd, 0xe1a2, 0xb7f9,
+    0xbbcf, 0xe1a3, 0xc4bb, 0xe1a4, 0xe1a5, 0xe1a6, 0xb4b1, 0xb8c9, 0xc6bd, 0xc4ea, 0xb2a2, 0xd0d2, 0xe7db, 0xbbc3,
+    0xd3d7, 0xd3c4, 0xb9e3, 0xe2cf, 0xd7af, 0xc7ec, 0xb1d3, 0xb4b2, 0xe2d1, 0xd0f2, 0xc2ae, 0xe2d0, 0xbfe2, 0xd3a6,
+    0xb5d7, 0xe2d2, 0xb5ea, 0xc3ed, 0xb8fd, 0xb8ae, 0xc5d3, 0xb7cf, 0xe2d4, 0xe2d3, 0xb6c8, 0xd7f9, 0xcda5, 0xe2d8,
+    0xe2d6, 0xcafc, 0xbfb5, 0xd3b9, 0xe2d5, 0xe2d7, 0xc1ae, 0xc0c8, 0xe2db, 0xe2da, 0xc0aa, 0xc1ce, 0xe2dc, 0xe2dd,
+    0xe2de, 0xdbc8, 0xd1d3, 0xcda2, 0xbda8, 0xdec3, 0xd8a5, 0xbfaa, 0xdbcd, 0xd2ec, 0xc6fa, 0xc5aa, 0xdec4, 0xb1d7,
+    0xdfae, 0xcabd, 0xdfb1, 0xb9ad, 0xd2fd, 0xb8a5, 0xbaeb, 0xb3da, 0xb5dc, 0xd5c5, 0xc3d6, 0xcfd2, 0xbba1, 0xe5f3,
+    0xe5f2, 0xe5f4, 0xcde4, 0xc8f5, 0xb5af, 0xc7bf, 0xe5f6, 0xecb0, 0xe5e6, 0xb9e9, 0xb5b1, 0xc2bc, 0xe5e8, 0xe5e7,
+    0xe5e9, 0xd2cd, 0xe1ea, 0xd0ce, 0xcdae, 0xd1e5, 0xb2ca, 0xb1eb, 0xb1f2, 0xc5ed, 0xd5c3, 0xd3b0, 0xe1dc, 0xe1dd,
+    0xd2db, 0xb3b9, 0xb1cb, 0xcdf9, 0xd5f7, 0xe1de, 0xbeb6, 0xb4fd, 0xe1df, 0xbadc, 0xe1e0, 0xbbb2, 0xc2c9, 0xe1e1,
+    0xd0ec, 0xcdbd, 0xe1e2, 0xb5c3, 0xc5c7, 0xe1e3, 0xe1e4, 0xd3f9, 0xe1e5, 0xd1ad, 0xe1e6, 0xcea2, 0xe1e7, 0xb5c2,
+    0xe1e8, 0xbbd5, 0xd0c4, 0xe2e0, 0xb1d8, 0xd2e4, 0xe2e1, 0xbcc9, 0xc8cc, 0xe2e3, 0xecfe, 0xecfd, 0xdfaf, 0xe2e2,
+    0xd6be, 0xcdfc, 0xc3a6, 0xe3c3, 0xd6d2, 0xe2e7, 0xe2e8, 0xd3c7, 0xe2ec, 0xbfec, 0xe2ed, 0xe2e5, 0xb3c0, 0xc4ee,
+    0xe2ee, 0xd0c3, 0xbaf6, 0xe2e9, 0xb7de, 0xbbb3, 0xccac, 0xcbcb, 0xe2e4, 0xe2e6, 0xe2ea, 0xe2eb, 0xe2f7, 0xe2f4,
+    0xd4f5, 0xe2f3, 0xc5ad, 0xd5fa, 0xc5c2, 0xb2c0, 0xe2ef, 0xe2f2, 0xc1af, 0xcbbc, 0xb5a1, 0xe2f9, 0xbcb1, 0xe2f1,
+    0xd0d4, 0xd4b9, 0xe2f5, 0xb9d6, 0xe2f6, 0xc7d3, 0xe2f0, 0xd7dc, 0xeda1, 0xe2f8, 0xeda5, 0xe2fe, 0xcad1, 0xc1b5,
+    0xbbd0, 0xbfd6, 0xbae3, 0xcba1, 0xeda6, 0xeda3, 0xeda2, 0xbbd6, 0xeda7, 0xd0f4, 0xeda4, 0xbade, 0xb6f7, 0xe3a1,
+    0xb6b2, 0xccf1, 0xb9a7, 0xcfa2, 0xc7a1, 0xbfd2, 0xb6f1, 0xe2fa, 0xe2fb, 0xe2fd, 0xe2fc, 0xc4d5, 0xe3a2, 0xd3c1,
+    0xe3a7, 0xc7c4, 0xcfa4, 0xe3a9, 0xbab7, 0xe3a8, 0xbbda, 0xe3a3, 0xe3a4, 0xe3aa, 0xe3a6, 0xcef2, 0xd3c6, 0xbbbc,
+    0xd4c3, 0xc4fa, 0xeda8, 0xd0fc, 0xe3a5, 0xc3f5, 0xe3ad, 0xb1af, 0xe3b2, 0xbcc2, 0xe3ac, 0xb5bf, 0xc7e9, 0xe3b0,
+    0xbeaa, 0xcdef, 0xbbf3, 0xcce8, 0xe3af, 0xe3b1, 0xcfa7, 0xe3ae, 0xcea9, 0xbbdd, 0xb5eb, 0xbee5, 0xb2d2, 0xb3cd,
+    0xb1b9, 0xe3ab, 0xb2d1, 0xb5ac, 0xb9df, 0xb6e8, 0xcfeb, 0xe3b7, 0xbbcc, 0xc8c7, 0xd0ca, 0xe3b8, 0xb3ee, 0xeda9,
+    0xd3fa, 0xd3e4, 0xedaa, 0xe3b9, 0xd2e2, 0xe3b5, 0xd3de, 0xb8d0, 0xe3b3, 0xe3b6, 0xb7df, 0xe3b4, 0xc0a2, 0xe3ba,
+    0xd4b8, 0xb4c8, 0xe3bb, 0xbbc5, 0xc9f7, 0xc9e5, 0xc4bd, 0xedab, 0xc2fd, 0xbbdb, 0xbfae, 0xcebf, 0xe3bc, 0xbfb6,
+    0xb1ef, 0xd4f7, 0xe3be, 0xedad, 0xe3bf, 0xbaa9, 0xedac, 0xe3bd, 0xe3c0, 0xbab6, 0xb6ae, 0xd0b8, 0xb0c3, 0xedae,
+    0xedaf, 0xc0c1, 0xe3c1, 0xc5b3, 0xe3c2, 0xdcb2, 0xedb0, 0xb8ea, 0xceec, 0xeaa7, 0xd0e7, 0xcaf9, 0xc8d6, 0xcfb7,
+    0xb3c9, 0xced2, 0xbde4, 0xe3de, 0xbbf2, 0xeaa8, 0xd5bd, 0xc6dd, 0xeaa9, 0xeaaa, 0xeaac, 0xeaab, 0xeaae, 0xeaad,
+    0xbdd8, 0xeaaf, 0xc2be, 0xb4c1, 0xb4f7, 0xbba7, 0xece6, 0xece5, 0xb7bf, 0xcbf9, 0xb1e2, 0xece7, 0xc9c8, 0xece8,
+    0xece9, 0xcad6, 0xded0, 0xb2c5, 0xd4fa, 0xc6cb, 0xb0c7, 0xb4f2, 0xc8d3, 0xcdd0, 0xbfb8, 0xbfdb, 0xc7a4, 0xd6b4,
+    0xc0a9, 0xded1, 0xc9a8, 0xd1ef, 0xc5a4, 0xb0e7, 0xb3b6, 0xc8c5, 0xb0e2, 0xb7f6, 0xc5fa, 0xb6f3, 0xd5d2, 0xb3d0,
+    0xbcbc, 0xb3ad, 0xbef1, 0xb0d1, 0xd2d6, 0xcae3, 0xd7a5, 0xcdb6, 0xb6b6, 0xbfb9, 0xd5db, 0xb8a7, 0xc5d7, 0xded2,
+    0xbfd9, 0xc2d5, 0xc7c0, 0xbba4, 0xb1a8, 0xc5ea, 0xc5fb, 0xcca7, 0xb1a7, 0xb5d6, 0xc4a8, 0xded3, 0xd1ba, 0xb3e9,
+    0xc3f2, 0xb7f7, 0xd6f4, 0xb5a3, 0xb2f0, 0xc4b4, 0xc4e9, 0xc0ad, 0xded4, 0xb0e8, 0xc5c4, 0xc1e0, 0xb9d5, 0xbedc,
+    0xcdd8, 0xb0ce, 0xcdcf, 0xded6, 0xbed0, 0xd7be, 0xded5, 0xd5d0, 0xb0dd, 0xc4e2, 0xc2a3, 0xbcf0, 0xd3b5, 0xc0b9,
+    0xc5a1, 0xb2a6, 0xd4f1, 0xc0a8, 0xcac3, 0xded7, 0xd5fc, 0xb9b0, 0xc8ad, 0xcba9, 0xded9, 0xbfbd, 0xc6b4, 0xd7a7,
+    0xcab0, 0xc4c3, 0xb3d6, 0xb9d2, 0xd6b8, 0xeafc, 0xb0b4, 0xbfe6, 0xccf4, 0xcdda, 0xd6bf, 0xc2ce, 0xcece, 0xcca2,
+    0xd0ae, 0xc4d3, 0xb5b2, 0xded8, 0xd5f5, 0xbcb7, 0xbbd3, 0xb0a4, 0xc5b2, 0xb4ec, 0xd5f1, 0xeafd, 0xdeda, 0xcda6,
+    0xcdec, 0xcee6, 0xdedc, 0xcdb1, 0xc0a6, 0xd7bd, 0xdedb, 0xb0c6, 0xbab4, 0xc9d3, 0xc4f3, 0xbee8, 0xb2b6, 0xc0cc,
+    0xcbf0, 0xbcf1, 0xbbbb, 0xb5b7, 0xc5f5, 0xdee6, 0xdee3, 0xbedd, 0xdedf, 0xb4b7, 0xbddd, 0xdee0, 0xc4ed, 0xcfc6,
+    0xb5e0, 0xb6de, 0xcada, 0xb5f4, 0xdee5, 0xd5c6, 0xdee1, 0xcccd, 0xc6fe, 0xc5c5, 0xd2b4, 0xbef2, 0xc2d3, 0xccbd,
+    0xb3b8, 0xbdd3, 0xbfd8, 0xcdc6, 0xd1da, 0xb4eb, 0xdee4, 0xdedd, 0xdee7, 0xeafe, 0xc2b0, 0xdee2, 0xd6c0, 0xb5a7,
+    0xb2f4, 0xdee8, 0xdef2, 0xdeed, 0xdef1, 0xc8e0, 0xd7e1, 0xdeef, 0xc3e8, 0xcce1, 0xb2e5, 0xd2be, 0xdeee, 0xdeeb,
+    0xced5, 0xb4a7, 0xbfab, 0xbebe, 0xbdd2, 0xdee9, 0xd4ae, 0xdede, 0xdeea, 0xc0bf, 0xdeec, 0xb2f3, 0xb8e9, 0xc2a7,
+    0xbdc1, 0xdef5, 0xdef8, 0xb2ab, 0xb4a4, 0xb4ea, 0xc9a6, 0xdef6, 0xcbd1, 0xb8e3, 0xdef7, 0xdefa, 0xdef9, 0xccc2,
+    0xb0e1, 0xb4ee, 0xe5ba, 0xd0af, 0xb2eb, 0xeba1, 0xdef4, 0xc9e3, 0xdef3, 0xb0da, 0xd2a1, 0xb1f7, 0xccaf, 0xdef0,
+    0xcba4, 0xd5aa, 0xdefb, 0xb4dd, 0xc4a6, 0xdefd, 0xc3fe, 0xc4a1, 0xdfa1, 0xc1cc, 0xdefc, 0xbeef, 0xc6b2, 0xb3c5,
+    0xc8f6, 0xcbba, 0xdefe, 0xdfa4, 0xd7b2, 0xb3b7, 0xc1c3, 0xc7cb, 0xb2a5, 0xb4e9, 0xd7ab, 0xc4ec, 0xdfa2, 0xdfa3,
+    0xdfa5, 0xbab3, 0xdfa6, 0xc0de, 0xc9c3, 0xb2d9, 0xc7e6, 0xdfa7, 0xc7dc, 0xdfa8, 0xeba2, 0xcbd3, 0xdfaa, 0xdfa9,
+    0xb2c1, 0xc5ca, 0xdfab, 0xd4dc, 0xc8c1, 0xdfac, 0xbef0, 0xdfad, 0xd6a7, 0xeab7, 0xebb6, 0xcad5, 0xd8fc, 0xb8c4,
+    0xb9a5, 0xb7c5, 0xd5fe, 0xb9ca, 0xd0a7, 0xf4cd, 0xb5d0, 0xc3f4, 0xbec8, 0xebb7, 0xb0bd, 0xbdcc, 0xc1b2, 0xb1d6,
+    0xb3a8, 0xb8d2, 0xc9a2, 0xb6d8, 0xebb8, 0xbeb4, 0xcafd, 0xc7c3, 0xd5fb, 0xb7f3, 0xcec4, 0xd5ab, 0xb1f3, 0xecb3,
+    0xb0df, 0xecb5, 0xb6b7, 0xc1cf, 0xf5fa, 0xd0b1, 0xd5e5, 0xced3, 0xbdef, 0xb3e2, 0xb8ab, 0xd5b6, 0xedbd, 0xb6cf,
+    0xcbb9, 0xd0c2, 0xb7bd, 0xecb6, 0xcaa9, 0xc5d4, 0xecb9, 0xecb8, 0xc2c3, 0xecb7, 0xd0fd, 0xecba, 0xecbb, 0xd7e5,
+    0xecbc, 0xecbd, 0xc6ec, 0xcede, 0xbcc8, 0xc8d5, 0xb5a9, 0xbec9, 0xd6bc, 0xd4e7, 0xd1ae, 0xd0f1, 0xeab8, 0xeab9,
+    0xeaba, 0xbab5, 0xcab1, 0xbff5, 0xcdfa, 0xeac0, 0xb0ba, 0xeabe, 0xc0a5, 0xeabb, 0xb2fd, 0xc3f7, 0xbbe8, 0xd2d7,
+    0xcef4, 0xeabf, 0xeabc, 0xeac3, 0xd0c7, 0xd3b3, 0xb4ba, 0xc3c1, 0xd7f2, 0xd5d1, 0xcac7, 0xeac5, 0xeac4, 0xeac7,
+    0xeac6, 0xd6e7, 0xcfd4, 0xeacb, 0xbbce, 0xbdfa, 0xc9ce, 0xeacc, 0xc9b9, 0xcffe, 0xeaca, 0xd4ce, 0xeacd, 0xeacf,
+    0xcded, 0xeac9, 0xeace, 0xceee, 0xbbde, 0xb3bf, 0xc6d5, 0xbeb0, 0xcefa, 0xc7e7, 0xbea7, 0xead0, 0xd6c7, 0xc1c0,
+    0xd4dd, 0xead1, 0xcfbe, 0xead2, 0xcaee, 0xc5af, 0xb0b5, 0xead4, 0xead3, 0xf4df, 0xc4ba, 0xb1a9, 0xe5df, 0xead5,
+    0xcaef, 0xead6, 0xead7, 0xc6d8, 0xead8, 0xead9, 0xd4bb, 0xc7fa, 0xd2b7, 0xb8fc, 0xeac2, 0xb2dc, 0xc2fc, 0xd4f8,
+    0xcce6, 0xd7ee, 0xd4c2, 0xd3d0, 0xebc3, 0xc5f3, 0xb7fe, 0xebd4, 0xcbb7, 0xebde, 0xc0ca, 0xcdfb, 0xb3af, 0xc6da,
+    0xebfc, 0xc4be, 0xceb4, 0xc4a9, 0xb1be, 0xd4fd, 0xcaf5, 0xd6ec, 0xc6d3, 0xb6e4, 0xbbfa, 0xd0e0, 0xc9b1, 0xd4d3,
+    0xc8a8, 0xb8cb, 0xe8be, 0xc9bc, 0xe8bb, 0xc0ee, 0xd0d3, 0xb2c4, 0xb4e5, 0xe8bc, 0xd5c8, 0xb6c5, 0xe8bd, 0xcaf8,
+    0xb8dc, 0xccf5, 0xc0b4, 0xd1ee, 0xe8bf, 0xe8c2, 0xbabc, 0xb1ad, 0xbddc, 0xeabd, 0xe8c3, 0xe8c6, 0xe8cb, 0xe8cc,
+    0xcbc9, 0xb0e5, 0xbcab, 0xb9b9, 0xe8c1, 0xcdf7, 0xe8ca, 0xcef6, 0xd5ed, 0xc1d6, 0xe8c4, 0xc3b6, 0xb9fb, 0xd6a6,
+    0xe8c8, 0xcae0, 0xd4e6, 0xe8c0, 0xe8c5, 0xe8c7, 0xc7b9, 0xb7e3, 0xe8c9, 0xbfdd, 0xe8d2, 0xe8d7, 0xe8d5, 0xbcdc,
+    0xbccf, 0xe8db, 0xe8de, 0xe8da, 0xb1fa, 0xb0d8, 0xc4b3, 0xb8cc, 0xc6e2, 0xc8be, 0xc8e1, 0xe8cf, 0xe8d4, 0xe8d6,
+    0xb9f1, 0xe8d8, 0xd7f5, 0xc4fb, 0xe8dc, 0xb2e9, 0xe8d1, 0xbced, 0xbfc2, 0xe8cd, 0xd6f9, 0xc1f8, 0xb2f1, 0xe8df,
+    0xcac1, 0xe8d9, 0xd5a4, 0xb1ea, 0xd5bb, 0xe8ce, 0xe8d0, 0xb6b0, 0xe8d3, 0xe8dd, 0xc0b8, 0xcaf7, 0xcba8, 0xc6dc,
+    0xc0f5, 0xe8e9, 0xd0a3, 0xe8f2, 0xd6ea, 0xe8e0, 0xe8e1, 0xd1f9, 0xbacb, 0xb8f9, 0xb8f1, 0xd4d4, 0xe8ef, 0xe8ee,
+    0xe8ec, 0xb9f0, 0xccd2, 0xe8e6, 0xcea6, 0xbff2, 0xb0b8, 0xe8f1, 0xe8f0, 0xd7c0, 0xe8e4, 0xcda9, 0xc9a3, 0xbbb8,
+    0xbddb, 0xe8ea, 0xe8e2, 0xe8e3, 0xe8e5, 0xb5b5, 0xe8e7, 0xc7c5, 0xe8eb, 0xe8ed, 0xbdb0, 0xd7ae, 0xe8f8, 0xe8f5,
+    0xcdb0, 0xe8f6, 0xc1ba, 0xe8e8, 0xc3b7, 0xb0f0, 0xe8f4, 0xe8f7, 0xb9a3, 0xc9d2, 0xc3ce, 0xcee0, 0xc0e6, 0xcbf3,
+    0xccdd, 0xd0b5, 0xcae1, 0xe8f3, 0xbcec, 0xe8f9, 0xc3de, 0xc6e5, 0xb9f7, 0xb0f4, 0xd7d8, 0xbcac, 0xc5ef, 0xccc4,
+    0xe9a6, 0xc9ad, 0xe9a2, 0xc0e2, 0xbfc3, 0xe8fe, 0xb9d7, 0xe8fb, 0xe9a4, 0xd2ce, 0xe9a3, 0xd6b2, 0xd7b5, 0xe9a7,
+    0xbdb7, 0xe8fc, 0xe8fd, 0xe9a1, 0xcdd6, 0xd2ac, 0xe9b2, 0xe9a9, 0xb4aa, 0xb4bb, 0xe9ab, 0xd0a8, 0xe9a5, 0xb3fe,
+    0xe9ac, 0xc0e3, 0xe9aa, 0xe9b9, 0xe9b8, 0xe9ae, 0xe8fa, 0xe9a8, 0xbfac, 0xe9b1, 0xe9ba, 0xc2a5, 0xe9af, 0xb8c5,
+    0xe9ad, 0xd3dc, 0xe9b4, 0xe9b5, 0xe9b7, 0xe9c7, 0xc0c6, 0xe9c5, 0xe9b0, 0xe9bb, 0xb0f1, 0xe9bc, 0xd5a5, 0xe9be,
+    0xe9bf, 0xe9c1, 0xc1f1, 0xc8b6, 0xe9bd, 0xe9c2, 0xe9c3, 0xe9b3, 0xe9b6, 0xbbb1, 0xe9c0, 0xbcf7, 0xe9c4, 0xe9c6,
+    0xe9ca, 0xe9ce, 0xb2db, 0xe9c8, 0xb7ae, 0xe9cb, 0xe9cc, 0xd5c1, 0xc4a3, 0xe9d8, 0xbae1, 0xe9c9, 0xd3a3, 0xe9d4,
+    0xe9d7, 0xe9d0, 0xe9cf, 0xc7c1, 0xe9d2, 0xe9d9, 0xb3c8, 0xe9d3, 0xcff0, 0xe9cd, 0xb3f7, 0xe9d6, 0xe9da, 0xccb4,
+    0xcfad, 0xe9d5, 0xe9dc, 0xe9db, 0xe9de, 0xe9d1, 0xe9dd, 0xe9df, 0xc3ca, 0xc7b7, 0xb4ce, 0xbbb6, 0xd0c0, 0xeca3,
+    0xc5b7, 0xd3fb, 0xeca4, 0xeca5, 0xc6db, 0xbfee, 0xeca6, 0xeca7, 0xd0aa, 0xc7b8, 0xb8e8, 0xeca8, 0xd6b9, 0xd5fd,
+    0xb4cb, 0xb2bd, 0xcee4, 0xc6e7, 0xcde1, 0xb4f5, 0xcbc0, 0xbcdf, 0xe9e2, 0xe9e3, 0xd1ea, 0xe9e5, 0xb4f9, 0xe9e4,
+    0xd1b3, 0xcae2, 0xb2d0, 0xe9e8, 0xe9e6, 0xe9e7, 0xd6b3, 0xe9e9, 0xe9ea, 0xe9eb, 0xe9ec, 0xecaf, 0xc5b9, 0xb6ce,
+    0xd2f3, 0xb5ee, 0xbbd9, 0xecb1, 0xd2e3, 0xcee3, 0xc4b8, 0xc3bf, 0xb6be, 0xd8b9, 0xb1c8, 0xb1cf, 0xb1d1, 0xc5fe,
+    0xb1d0, 0xc3ab, 0xd5b1, 0xeba4, 0xbac1, 0xccba, 0xeba5, 0xeba7, 0xeba8, 0xeba6, 0xeba9, 0xebab, 0xebaa, 0xebac,
+    0xcacf, 0xd8b5, 0xc3f1, 0xc3a5, 0xc6f8, 0xebad, 0xc4ca, 0xebae, 0xebaf, 0xebb0, 0xb7d5, 0xb7fa, 0xebb1, 0xc7e2,
+    0xebb3, 0xbaa4, 0xd1f5, 0xb0b1, 0xebb2, 0xebb4, 0xb5aa, 0xc2c8, 0xc7e8, 0xebb5, 0xcbae, 0xe3df, 0xd3c0, 0xd9db,
+    0xcda1, 0xd6ad, 0xc7f3, 0xd9e0, 0xbbe3, 0xbaba, 0xe3e2, 0xcfab, 0xe3e0, 0xc9c7, 0xbab9, 0xd1b4, 0xe3e1, 0xc8ea,
+    0xb9af, 0xbdad, 0xb3d8, 0xcedb, 0xccc0, 0xe3e8, 0xe3e9, 0xcdf4, 0xccad, 0xbcb3, 0xe3ea, 0xe3eb, 0xd0da, 0xc6fb,
+    0xb7da, 0xc7df, 0xd2ca, 0xced6, 0xe3e4, 0xe3ec, 0xc9f2, 0xb3c1, 0xe3e7, 0xc6e3, 0xe3e5, 0xedb3, 0xe3e6, 0xc9b3,
+    0xc5e6, 0xb9b5, 0xc3bb, 0xe3e3, 0xc5bd, 0xc1a4, 0xc2d9, 0xb2d7, 0xe3ed, 0xbba6, 0xc4ad, 0xe3f0, 0xbeda, 0xe3fb,
+    0xe3f5, 0xbad3, 0xb7d0, 0xd3cd, 0xd6ce, 0xd5d3, 0xb9c1, 0xd5b4, 0xd1d8, 0xd0b9, 0xc7f6, 0xc8aa, 0xb2b4, 0xc3da,
+    0xe3ee, 0xe3fc, 0xe3ef, 0xb7a8, 0xe3f7, 0xe3f4, 0xb7ba, 0xc5a2, 0xe3f6, 0xc5dd, 0xb2a8, 0xc6fc, 0xc4e0, 0xd7a2,
+    0xc0e1, 0xe3f9, 0xe3fa, 0xe3fd, 0xcca9, 0xe3f3, 0xd3be, 0xb1c3, 0xedb4, 0xe3f1, 0xe3f2, 0xe3f8, 0xd0ba, 0xc6c3,
+    0xd4f3, 0xe3fe, 0xbde0, 0xe4a7, 0xe4a6, 0xd1f3, 0xe4a3, 0xe4a9, 0xc8f7, 0xcfb4, 0xe4a8, 0xe4ae, 0xc2e5, 0xb6b4,
+    0xbdf2, 0xe4a2, 0xbae9, 0xe4aa, 0xe4ac, 0xb6fd, 0xd6de, 0xe4b2, 0xe4ad, 0xe4a1, 0xbbee, 0xcddd, 0xc7a2, 0xc5c9,
+    0xc1f7, 0xe4a4, 0xc7b3, 0xbdac, 0xbdbd, 0xe4a5, 0xd7c7, 0xb2e2, 0xe4ab, 0xbcc3, 0xe4af, 0xbbeb, 0xe4b0, 0xc5a8,
+    0xe4b1, 0xd5e3, 0xbfa3, 0xe4ba, 0xe4b7, 0xe4bb, 0xe4bd, 0xc6d6, 0xbac6, 0xc0cb, 0xb8a1, 0xe4b4, 0xd4a1, 0xbaa3,
+    0xbdfe, 0xe4bc, 0xcdbf, 0xc4f9, 0xcffb, 0xc9e6, 0xd3bf, 0xcfd1, 0xe4b3, 0xe4b8, 0xe4b9, 0xcce9, 0xccce, 0xc0d4,
+    0xe4b5, 0xc1b0, 0xe4b6, 0xced0, 0xbbc1, 0xb5d3, 0xc8f3, 0xbda7, 0xd5c7, 0xc9ac, 0xb8a2, 0xe4ca, 0xe4cc, 0xd1c4,
+    0xd2ba, 0xbaad, 0xbad4, 0xe4c3, 0xb5ed, 0xd7cd, 0xe4c0, 0xcffd, 0xe4bf, 0xc1dc, 0xccca, 0xcae7, 0xc4d7, 0xccd4,
+    0xe4c8, 0xe4c7, 0xe4c1, 0xe4c4, 0xb5ad, 0xd3d9, 0xe4c6, 0xd2f9, 0xb4e3, 0xbbb4, 0xc9ee, 0xb4be, 0xbbec, 0xd1cd,
+    0xcced, 0xedb5, 0xc7e5, 0xd4a8, 0xe4cb, 0xd7d5, 0xe4c2, 0xbda5, 0xe4c5, 0xd3e6, 0xe4c9, 0xc9f8, 0xe4be, 0xd3e5,
+    0xc7fe, 0xb6c9, 0xd4fc, 0xb2b3, 0xe4d7, 0xcec2, 0xe4cd, 0xcebc, 0xb8db, 0xe4d6, 0xbfca, 0xd3ce, 0xc3ec, 0xc5c8,
+    0xe4d8, 0xcdc4, 0xe4cf, 0xe4d4, 0xe4d5, 0xbafe, 0xcfe6, 0xd5bf, 0xe4d2, 0xe4d0, 0xe4ce, 0xcde5, 0xcaaa, 0xc0a3,
+    0xbda6, 0xe4d3, 0xb8c8, 0xe4e7, 0xd4b4, 0xe4db, 0xc1ef, 0xe4e9, 0xd2e7, 0xe4df, 0xe4e0, 0xcfaa, 0xcbdd, 0xe4da,
+    0xe4d1, 0xe4e5, 0xc8dc, 0xe4e3, 0xc4e7, 0xe4e2, 0xe4e1, 0xb3fc, 0xe4e8, 0xb5e1, 0xd7cc, 0xe4e6, 0xbbac, 0xd7d2,
+    0xcccf, 0xebf8, 0xe4e4, 0xb9f6, 0xd6cd, 0xe4d9, 0xe4dc, 0xc2fa, 0xe4de, 0xc2cb, 0xc0c4, 0xc2d0, 0xb1f5, 0xccb2,
+    0xb5ce, 0xe4ef, 0xc6af, 0xc6e1, 0xe4f5, 0xc2a9, 0xc0ec, 0xd1dd, 0xe4ee, 0xc4ae, 0xe4ed, 0xe4f6, 0xe4f4, 0xc2fe,
+    0xe4dd, 0xe4f0, 0xcafe, 0xd5c4, 0xe4f1, 0xd1fa, 0xe4eb, 0xe4ec, 0xe4f2, 0xceab, 0xc5cb, 0xc7b1, 0xc2ba, 0xe4ea,
+    0xc1ca, 0xccb6, 0xb3b1, 0xe4fb, 0xe4f3, 0xe4fa, 0xe4fd, 0xe4fc, 0xb3ce, 0xb3ba, 0xe4f7, 0xe4f9, 0xe4f8, 0xc5ec,
+    0xc0bd, 0xd4e8, 0xe5a2, 0xb0c4, 0xe5a4, 0xe5a3, 0xbca4, 0xe5a5, 0xe5a1, 0xe4fe, 0xb1f4, 0xe5a8, 0xe5a9, 0xe5a6,
+    0xe5a7, 0xe5aa, 0xc6d9, 0xe5ab, 0xe5ad, 0xe5ac, 0xe5af, 0xe5ae, 0xb9e0, 0xe5b0, 0xe5b1, 0xbbf0, 0xece1, 0xc3f0,
+    0xb5c6, 0xbbd2, 0xc1e9, 0xd4ee, 0xbec4, 0xd7c6, 0xd4d6, 0xb2d3, 0xecbe, 0xeac1, 0xc2af, 0xb4b6, 0xd1d7, 0xb3b4,
+    0xc8b2, 0xbfbb, 0xecc0, 0xd6cb, 0xecbf, 0xecc1, 0xecc5, 0xbee6, 0xccbf, 0xc5da, 0xbebc, 0xecc6, 0xb1fe, 0xecc4,
+    0xd5a8, 0xb5e3, 0xecc2, 0xc1b6, 0xb3e3, 0xecc3, 0xcbb8, 0xc0c3, 0xccfe, 0xc1d2, 0xecc8, 0xbae6, 0xc0d3, 0xd6f2,
+    0xd1cc, 0xbfbe, 0xb7b3, 0xc9d5, 0xecc7, 0xbbe2, 0xcccc, 0xbdfd, 0xc8c8, 0xcfa9, 0xcde9, 0xc5eb, 0xb7e9, 0xd1c9,
+    0xbab8, 0xecc9, 0xecca, 0xbbc0, 0xeccb, 0xece2, 0xb1ba, 0xb7d9, 0xbdb9, 0xeccc, 0xd1e6, 0xeccd, 0xc8bb, 0xecd1,
+    0xecd3, 0xbbcd, 0xbce5, 0xeccf, 0xc9b7, 0xc3ba, 0xece3, 0xd5d5, 0xecd0, 0xd6f3, 0xecd2, 0xecce, 0xecd4, 0xecd5,
+    0xc9bf, 0xcfa8, 0xd0dc, 0xd1ac, 0xc8db, 0xecd6, 0xcef5, 0xcaec, 0xecda, 0xecd9, 0xb0be, 0xecd7, 0xecd8, 0xece4,
+    0xc8bc, 0xc1c7, 0xecdc, 0xd1e0, 0xecdb, 0xd4ef, 0xecdd, 0xdbc6, 0xecde, 0xb1ac, 0xecdf, 0xece0, 0xd7a6, 0xc5c0,
+    0xebbc, 0xb0ae, 0xbef4, 0xb8b8, 0xd2af, 0xb0d6, 0xb5f9, 0xd8b3, 0xcbac, 0xe3dd, 0xc6ac, 0xb0e6, 0xc5c6, 0xebb9,
+    0xebba, 0xebbb, 0xd1c0, 0xc5a3, 0xeaf2, 0xc4b2, 0xc4b5, 0xc0ce, 0xeaf3, 0xc4c1, 0xceef, 0xeaf0, 0xeaf4, 0xc9fc,
+    0xc7a3, 0xccd8, 0xcefe, 0xeaf5, 0xeaf6, 0xcfac, 0xc0e7, 0xeaf7, 0xb6bf, 0xeaf8, 0xeaf9, 0xeafa, 0xeafb, 0xeaf1,
+    0xc8ae, 0xe1eb, 0xb7b8, 0xe1ec, 0xe1ed, 0xd7b4, 0xe1ee, 0xe1ef, 0xd3cc, 0xe1f1, 0xbff1, 0xe1f0, 0xb5d2, 0xb1b7,
+    0xe1f3, 0xe1f2, 0xbafc, 0xe1f4, 0xb9b7, 0xbed1, 0xc4fc, 0xbadd, 0xbdc6, 0xe1f5, 0xe1f7, 0xb6c0, 0xcfc1, 0xcaa8,
+    0xe1f6, 0xd5f8, 0xd3fc, 0xe1f8, 0xe1fc, 0xe1f9, 0xe1fa, 0xc0ea, 0xe1fe, 0xe2a1, 0xc0c7, 0xe1fb, 0xe1fd, 0xe2a5,
+    0xc1d4, 0xe2a3, 0xe2a8, 0xb2fe, 0xe2a2, 0xc3cd, 0xb2c2, 0xe2a7, 0xe2a6, 0xe2a4, 0xe2a9, 0xe2ab, 0xd0c9, 0xd6ed,
+    0xc3a8, 0xe2ac, 0xcfd7, 0xe2ae, 0xbaef, 0xe9e0, 0xe2ad, 0xe2aa, 0xbbab, 0xd4b3, 0xe2b0, 0xe2af, 0xe9e1, 0xe2b1,
+    0xe2b2, 0xe2b3, 0xcca1, 0xe2b4, 0xe2b5, 0xd0fe, 0xc2ca, 0xd3f1, 0xcdf5, 0xe7e0, 0xe7e1, 0xbec1, 0xc2ea, 0xe7e4,
+    0xe7e3, 0xcde6, 0xc3b5, 0xe7e2, 0xbbb7, 0xcfd6, 0xc1e1, 0xe7e9, 0xe7e8, 0xe7f4, 0xb2a3, 0xe7ea, 0xe7e6, 0xe7ec,
+    0xe7eb, 0xc9ba, 0xd5e4, 0xe7e5, 0xb7a9, 0xe7e7, 0xe7ee, 0xe7f3, 0xd6e9, 0xe7ed, 0xe7f2, 0xe7f1, 0xb0e0, 0xe7f5,
+    0xc7f2, 0xc0c5, 0xc0ed, 0xc1f0, 0xe7f0, 0xe7f6, 0xcbf6, 0xe8a2, 0xe8a1, 0xd7c1, 0xe7fa, 0xe7f9, 0xe7fb, 0xe7f7,
+    0xe7fe, 0xe7fd, 0xe7fc, 0xc1d5, 0xc7d9, 0xc5fd, 0xc5c3, 0xc7ed, 0xe8a3, 0xe8a6, 0xe8a5, 0xe8a7, 0xbaf7, 0xe7f8,
+    0xe8a4, 0xc8f0, 0xc9aa, 0xe8a9, 0xb9e5, 0xd1fe, 0xe8a8, 0xe8aa, 0xe8ad, 0xe8ae, 0xc1a7, 0xe8af, 0xe8b0, 0xe8ac,
+    0xe8b4, 0xe8ab, 0xe8b1, 0xe8b5, 0xe8b2, 0xe8b3, 0xe8b7, 0xe8b6, 0xb9cf, 0xf0ac, 0xf0ad, 0xc6b0, 0xb0ea, 0xc8bf,
+    0xcddf, 0xcecd, 0xeab1, 0xeab2, 0xc6bf, 0xb4c9, 0xeab3, 0xd5e7, 0xddf9, 0xeab4, 0xeab5, 0xeab6, 0xb8ca, 0xdfb0,
+    0xc9f5, 0xccf0, 0xc9fa, 0xc9fb, 0xd3c3, 0xcba6, 0xb8a6, 0xf0ae, 0xb1c2, 0xe5b8, 0xccef, 0xd3c9, 0xbcd7, 0xc9ea,
+    0xb5e7, 0xc4d0, 0xb5e9, 0xeeae, 0xbbad, 0xe7de, 0xeeaf, 0xb3a9, 0xeeb2, 0xeeb1, 0xbde7, 0xeeb0, 0xceb7, 0xc5cf,
+    0xc1f4, 0xdbce, 0xeeb3, 0xd0f3, 0xc2d4, 0xc6e8, 0xb7ac, 0xeeb4, 0xb3eb, 0xbbfb, 0xeeb5, 0xe7dc, 0xeeb6, 0xbdae,
+    0xf1e2, 0xcae8, 0xd2c9, 0xf0da, 0xf0db, 0xf0dc, 0xc1c6, 0xb8ed, 0xbece, 0xf0de, 0xc5b1, 0xf0dd, 0xd1f1, 0xf0e0,
+    0xb0cc, 0xbdea, 0xd2df, 0xf0df, 0xb4af, 0xb7e8, 0xf0e6, 0xf0e5, 0xc6a3, 0xf0e1, 0xf0e2, 0xb4c3, 0xf0e3, 0xd5ee,
+    0xccdb, 0xbed2, 0xbcb2, 0xf0e8, 0xf0e7, 0xf0e4, 0xb2a1, 0xd6a2, 0xd3b8, 0xbeb7, 0xc8ac, 0xf0ea, 0xd1f7, 0xd6cc,
+    0xbadb, 0xf0e9, 0xb6bb, 0xcdb4, 0xc6a6, 0xc1a1, 0xf0eb, 0xf0ee, 0xf0ed, 0xf0f0, 0xf0ec, 0xbbbe, 0xf0ef, 0xccb5,
+    0xf0f2, 0xb3d5, 0xb1d4, 0xf0f3, 0xf0f4, 0xf0f6, 0xb4e1, 0xf0f1, 0xf0f7, 0xf0fa, 0xf0f8, 0xf0f5, 0xf0fd, 0xf0f9,
+    0xf0fc, 0xf0fe, 0xf1a1, 0xcec1, 0xf1a4, 0xf1a3, 0xc1f6, 0xf0fb, 0xcadd, 0xb4f1, 0xb1f1, 0xccb1, 0xf1a6, 0xf1a7,
+    0xf1ac, 0xd5ce, 0xf1a9, 0xc8b3, 0xf1a2, 0xf1ab, 0xf1a8, 0xf1a5, 0xf1aa, 0xb0a9, 0xf1ad, 0xf1af, 0xf1b1, 0xf1b0,
+    0xf1ae, 0xd1a2, 0xf1b2, 0xf1b3, 0xb9ef, 0xb5c7, 0xb0d7, 0xb0d9, 0xd4ed, 0xb5c4, 0xbdd4, 0xbbca, 0xf0a7, 0xb8de,
+    0xf0a8, 0xb0a8, 0xf0a9, 0xcdee, 0xf0aa, 0xf0ab, 0xc6a4, 0xd6e5, 0xf1e4, 0xf1e5, 0xc3f3, 0xd3db, 0xd6d1, 0xc5e8,
+    0xd3af, 0xd2e6, 0xeec1, 0xb0bb, 0xd5b5, 0xd1ce, 0xbce0, 0xbad0, 0xbff8, 0xb8c7, 0xb5c1, 0xc5cc, 0xcaa2, 0xc3cb,
+    0xeec2, 0xc4bf, 0xb6a2, 0xedec, 0xc3a4, 0xd6b1, 0xcfe0, 0xedef, 0xc5ce, 0xb6dc, 0xcaa1, 0xeded, 0xedf0, 0xedf1,
+    0xc3bc, 0xbfb4, 0xedee, 0xedf4, 0xedf2, 0xd5e6, 0xc3df, 0xedf3, 0xedf6, 0xd5a3, 0xd1a3, 0xedf5, 0xc3d0, 0xedf7,
+    0xbff4, 0xbeec, 0xedf8, 0xccf7, 0xd1db, 0xd7c5, 0xd5f6, 0xedfc, 0xedfb, 0xedf9, 0xedfa, 0xedfd, 0xbea6, 0xcbaf,
+    0xeea1, 0xb6bd, 0xeea2, 0xc4c0, 0xedfe, 0xbdde, 0xb2c7, 0xb6c3, 0xeea5, 0xd8ba, 0xeea3, 0xeea6, 0xc3e9, 0xb3f2,
+    0xeea7, 0xeea4, 0xcfb9, 0xeea8, 0xc2f7, 0xeea9, 0xeeaa, 0xdeab, 0xc6b3, 0xc7c6, 0xd6f5, 0xb5c9, 0xcbb2, 0xeeab,
+    0xcdab, 0xeeac, 0xd5b0, 0xeead, 0xf6c4, 0xdbc7, 0xb4a3, 0xc3ac, 0xf1e6, 0xcab8, 0xd2d3, 0xd6aa, 0xeff2, 0xbed8,
+    0xbdc3, 0xeff3, 0xb6cc, 0xb0ab, 0xcaaf, 0xedb6, 0xedb7, 0xcef9, 0xb7af, 0xbff3, 0xedb8, 0xc2eb, 0xc9b0, 0xedb9,
+    0xc6f6, 0xbfb3, 0xedbc, 0xc5f8, 0xd1d0, 0xd7a9, 0xedba, 0xedbb, 0xd1e2, 0xedbf, 0xedc0, 0xedc4, 0xedc8, 0xedc6,
+    0xedce, 0xd5e8, 0xedc9, 0xedc7, 0xedbe, 0xc5e9, 0xc6c6, 0xc9e9, 0xd4d2, 0xedc1, 0xedc2, 0xedc3, 0xedc5, 0xc0f9,
+    0xb4a1, 0xb9e8, 0xedd0, 0xedd1, 0xedca, 0xedcf, 0xcef8, 0xcbb6, 0xedcc, 0xedcd, 0xcff5, 0xedd2, 0xc1f2, 0xd3b2,
+    0xedcb, 0xc8b7, 0xbcef, 0xc5f0, 0xedd6, 0xb5ef, 0xc2b5, 0xb0ad, 0xcbe9, 0xb1ae, 0xedd4, 0xcdeb, 0xb5e2, 0xedd5,
+    0xedd3, 0xedd7, 0xb5fa, 0xedd8, 0xedd9, 0xeddc, 0xb1cc, 0xc5f6, 0xbcee, 0xedda, 0xccbc, 0xb2ea, 0xeddb, 0xc4eb,
+    0xb4c5, 0xb0f5, 0xeddf, 0xc0da, 0xb4e8, 0xc5cd, 0xeddd, 0xbfc4, 0xedde, 0xc4a5, 0xede0, 0xede1, 0xede3, 0xc1d7,
+    0xbbc7, 0xbdb8, 0xede2, 0xede4, 0xede6, 0xede5, 0xede7, 0xcabe, 0xecea, 0xc0f1, 0xc9e7, 0xeceb, 0xc6ee, 0xecec,
+    0xc6ed, 0xeced, 0xecf0, 0xd7e6, 0xecf3, 0xecf1, 0xecee, 0xecef, 0xd7a3, 0xc9f1, 0xcbee, 0xecf4, 0xecf2, 0xcfe9,
+    0xecf6, 0xc6b1, 0xbcc0, 0xecf5, 0xb5bb, 0xbbf6, 0xecf7, 0xd9f7, 0xbdfb, 0xc2bb, 0xecf8, 0xecf9, 0xb8a3, 0xecfa,
+    0xecfb, 0xecfc, 0xd3ed, 0xd8ae, 0xc0eb, 0xc7dd, 0xbacc, 0xd0e3, 0xcbbd, 0xcdba, 0xb8d1, 0xb1fc, 0xc7ef, 0xd6d6,
+    0xbfc6, 0xc3eb, 0xeff5, 0xc3d8, 0xd7e2, 0xeff7, 0xb3d3, 0xc7d8, 0xd1ed, 0xd6c8, 0xeff8, 0xeff6, 0xbbfd, 0xb3c6,
+    0xbdd5, 0xd2c6, 0xbbe0, 0xcfa1, 0xeffc, 0xeffb, 0xeff9, 0xb3cc, 0xc9d4, 0xcbb0, 0xeffe, 0xb0de, 0xd6c9, 0xeffd,
+    0xb3ed, 0xf6d5, 0xcec8, 0xf0a2, 0xf0a1, 0xb5be, 0xbcda, 0xbbfc, 0xb8e5, 0xc4c2, 0xf0a3, 0xcbeb, 0xf0a6, 0xd1a8,
+    0xbebf, 0xc7ee, 0xf1b6, 0xf1b7, 0xbfd5, 0xb4a9, 0xf1b8, 0xcdbb, 0xc7d4, 0xd5ad, 0xf1b9, 0xf1ba, 0xc7cf, 0xd2a4,
+    0xd6cf, 0xf1bb, 0xbdd1, 0xb4b0, 0xbebd, 0xb4dc, 0xced1, 0xbfdf, 0xf1bd, 0xbffa, 0xf1bc, 0xf1bf, 0xf1be, 0xf1c0,
+    0xf1c1, 0xc1fe, 0xc1a2, 0xcafa, 0xd5be, 0xbeba, 0xbeb9, 0xd5c2, 0xbfa2, 0xcdaf, 0xf1b5, 0xbddf, 0xb6cb, 0xd6f1,
+    0xf3c3, 0xf3c4, 0xb8cd, 0xf3c6, 0xf3c7, 0xb0ca, 0xf3c5, 0xf3c9, 0xcbf1, 0xf3cb, 0xd0a6, 0xb1ca, 0xf3c8, 0xf3cf,
+    0xb5d1, 0xf3d7, 0xf3d2, 0xf3d4, 0xf3d3, 0xb7fb, 0xb1bf, 0xf3ce, 0xf3ca, 0xb5da, 0xf3d0, 0xf3d1, 0xf3d5, 0xf3cd,
+    0xbce3, 0xc1fd, 0xf3d6, 0xf3da, 0xf3cc, 0xb5c8, 0xbdee, 0xf3dc, 0xb7a4, 0xbff0, 0xd6fe, 0xcdb2, 0xb4f0, 0xb2df,
+    0xf3d8, 0xf3d9, 0xc9b8, 0xf3dd, 0xf3de, 0xf3e1, 0xf3df, 0xf3e3, 0xf3e2, 0xf3db, 0xbfea, 0xb3ef, 0xf3e0, 0xc7a9,
+    0xbcf2, 0xf3eb, 0xb9bf, 0xf3e4, 0xb2ad, 0xbbfe, 0xcbe3, 0xf3ed, 0xf3e9, 0xb9dc, 0xf3ee, 0xf3e5, 0xf3e6, 0xf3ea,
+    0xc2e1, 0xf3ec, 0xf3ef, 0xf3e8, 0xbcfd, 0xcfe4, 0xf3f0, 0xf3e7, 0xf3f2, 0xd7ad, 0xc6aa, 0xf3f3, 0xf3f1, 0xc2a8,
+    0xb8dd, 0xf3f5, 0xf3f4, 0xb4db, 0xf3f6, 0xf3f7, 0xf3f8, 0xc0ba, 0xc0e9, 0xc5f1, 0xf3fb, 0xf3fa, 0xb4d8, 0xf3fe,
+    0xf3f9, 0xf3fc, 0xf3fd, 0xf4a1, 0xf4a3, 0xbbc9, 0xf4a2, 0xf4a4, 0xb2be, 0xf4a6, 0xf4a5, 0xbcae, 0xc3d7, 0xd9e1,
+    0xc0e0, 0xf4cc, 0xd7d1, 0xb7db, 0xf4ce, 0xc1a3, 0xc6c9, 0xb4d6, 0xd5b3, 0xf4d0, 0xf4cf, 0xf4d1, 0xcbda, 0xf4d2,
+    0xd4c1, 0xd6e0, 0xb7e0, 0xc1b8, 0xc1bb, 0xf4d3, 0xbeac, 0xb4e2, 0xf4d4, 0xf4d5, 0xbeab, 0xf4d6, 0xf4db, 0xf4d7,
+    0xf4da, 0xbafd, 0xf4d8, 0xf4d9, 0xb8e2, 0xccc7, 0xf4dc, 0xb2da, 0xc3d3, 0xd4e3, 0xbfb7, 0xf4dd, 0xc5b4, 0xf4e9,
+    0xcfb5, 0xcec9, 0xcbd8, 0xcbf7, 0xbdf4, 0xd7cf, 0xc0db, 0xd0f5, 0xf4ea, 0xf4eb, 0xf4ec, 0xf7e3, 0xb7b1, 0xf4ed,
+    0xd7eb, 0xf4ee, 0xe6f9, 0xbec0, 0xe6fa, 0xbaec, 0xe6fb, 0xcfcb, 0xe6fc, 0xd4bc, 0xbcb6, 0xe6fd, 0xe6fe, 0xbccd,
+    0xc8d2, 0xceb3, 0xe7a1, 0xb4bf, 0xe7a2, 0xc9b4, 0xb8d9, 0xc4c9, 0xd7dd, 0xc2da, 0xb7d7, 0xd6bd, 0xcec6, 0xb7c4,
+    0xc5a6, 0xe7a3, 0xcfdf, 0xe7a4, 0xe7a5, 0xe7a6, 0xc1b7, 0xd7e9, 0xc9f0, 0xcfb8, 0xd6af, 0xd6d5, 0xe7a7, 0xb0ed,
+    0xe7a8, 0xe7a9, 0xc9dc, 0xd2ef, 0xbead, 0xe7aa, 0xb0f3, 0xc8de, 0xbde1, 0xe7ab, 0xc8c6, 0xe7ac, 0xbbe6, 0xb8f8,
+    0xd1a4, 0xe7ad, 0xc2e7, 0xbef8, 0xbdca, 0xcdb3, 0xe7ae, 0xe7af, 0xbeee, 0xd0e5, 0xcbe7, 0xccd0, 0xbccc, 0xe7b0,
+    0xbca8, 0xd0f7, 0xe7b1, 0xd0f8, 0xe7b2, 0xe7b3, 0xb4c2, 0xe7b4, 0xe7b5, 0xc9fe, 0xceac, 0xc3e0, 0xe7b7, 0xb1c1,
+    0xb3f1, 0xe7b8, 0xe7b9, 0xd7db, 0xd5c0, 0xe7ba, 0xc2cc, 0xd7ba, 0xe7bb, 0xe7bc, 0xe7bd, 0xbcea, 0xc3e5, 0xc0c2,
+    0xe7be, 0xe7bf, 0xbca9, 0xe7c0, 0xe7c1, 0xe7b6, 0xb6d0, 0xe7c2, 0xe7c3, 0xe7c4, 0xbbba, 0xb5de, 0xc2c6, 0xb1e0,
+    0xe7c5, 0xd4b5, 0xe7c6, 0xb8bf, 0xe7c8, 0xe7c7, 0xb7ec, 0xe7c9, 0xb2f8, 0xe7ca, 0xe7cb, 0xe7cc, 0xe7cd, 0xe7ce,
+    0xe7cf, 0xe7d0, 0xd3a7, 0xcbf5, 0xe7d1, 0xe7d2, 0xe7d3, 0xe7d4, 0xc9c9, 0xe7d5, 0xe7d6, 0xe7d7, 0xe7d8, 0xe7d9,
+    0xbdc9, 0xe7da, 0xf3be, 0xb8d7, 0xc8b1, 0xf3bf, 0xf3c0, 0xf3c1, 0xb9de, 0xcdf8, 0xd8e8, 0xbab1, 0xc2de, 0xeeb7,
+    0xb7a3, 0xeeb9, 0xeeb8, 0xb0d5, 0xeebb, 0xd5d6, 0xd7ef, 0xd6c3, 0xeebd, 0xcaf0, 0xeebc, 0xeebe, 0xeec0, 0xeebf,
+    0xd1f2, 0xc7bc, 0xc3c0, 0xb8e1, 0xc1e7, 0xf4c6, 0xd0df, 0xf4c7, 0xcfdb, 0xc8ba, 0xf4c8, 0xf4c9, 0xf4ca, 0xf4cb,
+    0xd9fa, 0xb8fe, 0xe5f1, 0xd3f0, 0xf4e0, 0xcecc, 0xb3e1, 0xf1b4, 0xd2ee, 0xf4e1, 0xcfe8, 0xf4e2, 0xc7cc, 0xb5d4,
+    0xb4e4, 0xf4e4, 0xf4e3, 0xf4e5, 0xf4e6, 0xf4e7, 0xbab2, 0xb0bf, 0xf4e8, 0xb7ad, 0xd2ed, 0xd2ab, 0xc0cf, 0xbfbc,
+    0xeba3, 0xd5df, 0xeac8, 0xf1f3, 0xb6f8, 0xcba3, 0xc4cd, 0xf1e7, 0xf1e8, 0xb8fb, 0xf1e9, 0xbac4, 0xd4c5, 0xb0d2,
+    0xf1ea, 0xf1eb, 0xf1ec, 0xf1ed, 0xf1ee, 0xf1ef, 0xf1f1, 0xf1f0, 0xc5d5, 0xf1f2, 0xb6fa, 0xf1f4, 0xd2ae, 0xdec7,
+    0xcbca, 0xb3dc, 0xb5a2, 0xb9a2, 0xc4f4, 0xf1f5, 0xf1f6, 0xc1c4, 0xc1fb, 0xd6b0, 0xf1f7, 0xf1f8, 0xc1aa, 0xc6b8,
+    0xbedb, 0xf1f9, 0xb4cf, 0xf1fa, 0xedb2, 0xedb1, 0xcbe0, 0xd2de, 0xcbc1, 0xd5d8, 0xc8e2, 0xc0df, 0xbca1, 0xebc1,
+    0xd0a4, 0xd6e2, 0xb6c7, 0xb8d8, 0xebc0, 0xb8ce, 0xebbf, 0xb3a6, 0xb9c9, 0xd6ab, 0xb7f4, 0xb7ca, 0xbce7, 0xb7be,
+    0xebc6, 0xebc7, 0xb0b9, 0xbfcf, 0xebc5, 0xd3fd, 0xebc8, 0xebc9, 0xb7ce, 0xebc2, 0xebc4, 0xc9f6, 0xd6d7, 0xd5cd,
+    0xd0b2, 0xebcf, 0xceb8, 0xebd0, 0xb5a8, 0xb1b3, 0xebd2, 0xcca5, 0xc5d6, 0xebd3, 0xebd1, 0xc5df, 0xebce, 0xcaa4,
+    0xebd5, 0xb0fb, 0xbafa, 0xd8b7, 0xf1e3, 0xebca, 0xebcb, 0xebcc, 0xebcd, 0xebd6, 0xe6c0, 0xebd9, 0xbfe8, 0xd2c8,
+    0xebd7, 0xebdc, 0xb8ec, 0xebd8, 0xbdba, 0xd0d8, 0xb0b7, 0xebdd, 0xc4dc, 0xd6ac, 0xb4e0, 0xc2f6, 0xbcb9, 0xebda,
+    0xebdb, 0xd4e0, 0xc6ea, 0xc4d4, 0xebdf, 0xc5a7, 0xd9f5, 0xb2b1, 0xebe4, 0xbdc5, 0xebe2, 0xebe3, 0xb8ac, 0xcdd1,
+    0xebe5, 0xebe1, 0xc1b3, 0xc6a2, 0xccf3, 0xebe6, 0xc0b0, 0xd2b8, 0xebe7, 0xb8af, 0xb8ad, 0xebe8, 0xc7bb, 0xcdf3,
+    0xebea, 0xebeb, 0xebed, 0xd0c8, 0xebf2, 0xebee, 0xebf1, 0xc8f9, 0xd1fc, 0xebec, 0xebe9, 0xb8b9, 0xcfd9, 0xc4e5,
+    0xebef, 0xebf0, 0xccda, 0xcdc8, 0xb0f2, 0xebf6, 0xebf5, 0xb2b2, 0xb8e0, 0xebf7, 0xb1ec, 0xccc5, 0xc4a4, 0xcfa5,
+    0xebf9, 0xeca2, 0xc5f2, 0xebfa, 0xc9c5, 0xe2df, 0xebfe, 0xcdce, 0xeca1, 0xb1db, 0xd3b7, 0xd2dc, 0xebfd, 0xebfb,
+    0xb3bc, 0xeab0, 0xd7d4, 0xf4ab, 0xb3f4, 0xd6c1, 0xd6c2, 0xd5e9, 0xbeca, 0xf4a7, 0xd2a8, 0xf4a8, 0xf4a9, 0xf4aa,
+    0xbecb, 0xd3df, 0xc9e0, 0xc9e1, 0xf3c2, 0xcae6, 0xccf2, 0xe2b6, 0xcbb4, 0xcee8, 0xd6db, 0xf4ad, 0xf4ae, 0xf4af,
+    0xf4b2, 0xbabd, 0xf4b3, 0xb0e3, 0xf4b0, 0xf4b1, 0xbda2, 0xb2d5, 0xf4b6, 0xf4b7, 0xb6e6, 0xb2b0, 0xcfcf, 0xf4b4,
+    0xb4ac, 0xf4b5, 0xf4b8, 0xf4b9, 0xcda7, 0xf4ba, 0xf4bb, 0xf4bc, 0xcbd2, 0xf4bd, 0xf4be, 0xf4bf, 0xf4de, 0xc1bc,
+    0xbce8, 0xc9ab, 0xd1de, 0xe5f5, 0xdcb3, 0xd2d5, 0xdcb4, 0xb0ac, 0xdcb5, 0xbdda, 0xdcb9, 0xd8c2, 0xdcb7, 0xd3f3,
+    0xc9d6, 0xdcba, 0xdcb6, 0xdcbb, 0xc3a2, 0xdcbc, 0xdcc5, 0xdcbd, 0xcedf, 0xd6a5, 0xdccf, 0xdccd, 0xdcd2, 0xbde6,
+    0xc2ab, 0xdcb8, 0xdccb, 0xdcce, 0xdcbe, 0xb7d2, 0xb0c5, 0xdcc7, 0xd0be, 0xdcc1, 0xbba8, 0xb7bc, 0xdccc, 0xdcc6,
+    0xdcbf, 0xc7db, 0xd1bf, 0xdcc0, 0xdcca, 0xdcd0, 0xcead, 0xdcc2, 0xdcc3, 0xdcc8, 0xdcc9, 0xb2d4, 0xdcd1, 0xcbd5,
+    0xd4b7, 0xdcdb, 0xdcdf, 0xcca6, 0xdce6, 0xc3e7, 0xdcdc, 0xbfc1, 0xdcd9, 0xb0fa, 0xb9b6, 0xdce5, 0xdcd3, 0xdcc4,
+    0xdcd6, 0xc8f4, 0xbfe0, 0xc9bb, 0xb1bd, 0xd3a2, 0xdcda, 0xdcd5, 0xc6bb, 0xdcde, 0xd7c2, 0xc3af, 0xb7b6, 0xc7d1,
+    0xc3a9, 0xdce2, 0xdcd8, 0xdceb, 0xdcd4, 0xdcdd, 0xbea5, 0xdcd7, 0xdce0, 0xdce3, 0xdce4, 0xdcf8, 0xdce1, 0xdda2,
+    0xdce7, 0xbceb, 0xb4c4, 0xc3a3, 0xb2e7, 0xdcfa, 0xdcf2, 0xdcef, 0xdcfc, 0xdcee, 0xd2f0, 0xb2e8, 0xc8d7, 0xc8e3,
+    0xdcfb, 0xdced, 0xdcf7, 0xdcf5, 0xbea3, 0xdcf4, 0xb2dd, 0xdcf3, 0xbcf6, 0xdce8, 0xbbc4, 0xc0f3, 0xbcd4, 0xdce9,
+    0xdcea, 0xdcf1, 0xdcf6, 0xdcf9, 0xb5b4, 0xc8d9, 0xbbe7, 0xdcfe, 0xdcfd, 0xd3ab, 0xdda1, 0xdda3, 0xdda5, 0xd2f1,
+    0xdda4, 0xdda6, 0xdda7, 0xd2a9, 0xbac9, 0xdda9, 0xddb6, 0xddb1, 0xddb4, 0xddb0, 0xc6ce, 0xc0f2, 0xc9af, 0xdcec,
+    0xddae, 0xddb7, 0xdcf0, 0xddaf, 0xddb8, 0xddac, 0xddb9, 0xddb3, 0xddad, 0xc4aa, 0xdda8, 0xc0b3, 0xc1ab, 0xddaa,
+    0xddab, 0xddb2, 0xbbf1, 0xddb5, 0xd3a8, 0xddba, 0xddbb, 0xc3a7, 0xddd2, 0xddbc, 0xddd1, 0xb9bd, 0xbed5, 0xbefa,
+    0xbaca, 0xddca, 0xddc5, 0xddbf, 0xb2cb, 0xddc3, 0xddcb, 0xb2a4, 0xddd5, 0xddbe, 0xc6d0, 0xddd0, 0xddd4, 0xc1e2,
+    0xb7c6, 0xddce, 0xddcf, 0xddc4, 0xddbd, 0xddcd, 0xccd1, 0xddc9, 0xddc2, 0xc3c8, 0xc6bc, 0xceae, 0xddcc, 0xddc8,
+    0xddc1, 0xddc6, 0xc2dc, 0xd3a9, 0xd3aa, 0xddd3, 0xcff4, 0xc8f8, 0xdde6, 0xddc7, 0xdde0, 0xc2e4, 0xdde1, 0xddd7,
+    0xd6f8, 0xddd9, 0xddd8, 0xb8f0, 0xddd6, 0xc6cf, 0xb6ad, 0xdde2, 0xbaf9, 0xd4e1, 0xdde7, 0xb4d0, 0xddda, 0xbffb,
+    0xdde3, 0xdddf, 0xdddd, 0xb5d9, 0xdddb, 0xdddc, 0xddde, 0xbdaf, 0xdde4, 0xdde5, 0xddf5, 0xc3c9, 0xcbe2, 0xddf2,
+    0xd8e1, 0xc6d1, 0xddf4, 0xd5f4, 0xddf3, 0xddf0, 0xddec, 0xddef, 0xdde8, 0xd0ee, 0xc8d8, 0xddee, 0xdde9, 0xddea,
+    0xcbf2, 0xdded, 0xb1cd, 0xc0b6, 0xbcbb, 0xddf1, 0xddf7, 0xddf6, 0xddeb, 0xc5ee, 0xddfb, 0xdea4, 0xdea3, 0xddf8,
+    0xc3ef, 0xc2fb, 0xd5e1, 0xceb5, 0xddfd, 0xb2cc, 0xc4e8, 0xcadf, 0xc7be, 0xddfa, 0xddfc, 0xddfe, 0xdea2, 0xb0aa,
+    0xb1ce, 0xdeac, 0xdea6, 0xbdb6, 0xc8ef, 0xdea1, 0xdea5, 0xdea9, 0xdea8, 0xdea7, 0xdead, 0xd4cc, 0xdeb3, 0xdeaa,
+    0xdeae, 0xc0d9, 0xb1a1, 0xdeb6, 0xdeb1, 0xdeb2, 0xd1a6, 0xdeb5, 0xdeaf, 0xdeb0, 0xd0bd, 0xdeb4, 0xcaed, 0xdeb9,
+    0xdeb8, 0xdeb7, 0xdebb, 0xbde5, 0xb2d8, 0xc3ea, 0xdeba, 0xc5ba, 0xdebc, 0xccd9, 0xb7aa, 0xd4e5, 0xdebd, 0xdebf,
+    0xc4a2, 0xdec1, 0xdebe, 0xdec0, 0xd5ba, 0xdec2, 0xf2ae, 0xbba2, 0xc2b2, 0xc5b0, 0xc2c7, 0xf2af, 0xd0e9, 0xd3dd,
+    0xebbd, 0xb3e6, 0xf2b0, 0xf2b1, 0xcaad, 0xbae7, 0xf2b3, 0xf2b5, 0xf2b4, 0xcbe4, 0xcfba, 0xf2b2, 0xcab4, 0xd2cf,
+    0xc2ec, 0xcec3, 0xf2b8, 0xb0f6, 0xf2b7, 0xf2be, 0xb2cf, 0xd1c1, 0xf2ba, 0xf2bc, 0xd4e9, 0xf2bb, 0xf2b6, 0xf2bf,
+    0xf2bd, 0xf2b9, 0xf2c7, 0xf2c4, 0xf2c6, 0xf2ca, 0xf2c2, 0xf2c0, 0xf2c5, 0xd6fb, 0xf2c1, 0xc7f9, 0xc9df, 0xf2c8,
+    0xb9c6, 0xb5b0, 0xf2c3, 0xf2c9, 0xf2d0, 0xf2d6, 0xbbd7, 0xf2d5, 0xcddc, 0xd6eb, 0xf2d2, 0xf2d4, 0xb8f2, 0xf2cb,
+    0xf2ce, 0xc2f9, 0xd5dd, 0xf2cc, 0xf2cd, 0xf2cf, 0xf2d3, 0xf2d9, 0xd3bc, 0xb6ea, 0xcaf1, 0xb7e4, 0xf2d7, 0xf2d8,
+    0xf2da, 0xf2dd, 0xf2db, 0xf2dc, 0xd1d1, 0xf2d1, 0xcdc9, 0xcecf, 0xd6a9, 0xf2e3, 0xc3db, 0xf2e0, 0xc0af, 0xf2ec,
+    0xf2de, 0xf2e1, 0xf2e8, 0xf2e2, 0xf2e7, 0xf2e6, 0xf2e9, 0xf2df, 0xf2e4, 0xf2ea, 0xd3ac, 0xf2e5, 0xb2f5, 0xf2f2,
+    0xd0ab, 0xf2f5, 0xbbc8, 0xf2f9, 0xf2f0, 0xf2f6, 0xf2f8, 0xf2fa, 0xf2f3, 0xf2f1, 0xbafb, 0xb5fb, 0xf2ef, 0xf2f7,
+    0xf2ed, 0xf2ee, 0xf2eb, 0xf3a6, 0xf3a3, 0xf3a2, 0xf2f4, 0xc8da, 0xf2fb, 0xf3a5, 0xc3f8, 0xf2fd, 0xf3a7, 0xf3a9,
+    0xf3a4, 0xf2fc, 0xf3ab, 0xf3aa, 0xc2dd, 0xf3ae, 0xf3b0, 0xf3a1, 0xf3b1, 0xf3ac, 0xf3af, 0xf2fe, 0xf3ad, 0xf3b2,
+    0xf3b4, 0xf3a8, 0xf3b3, 0xf3b5, 0xd0b7, 0xf3b8, 0xd9f9, 0xf3b9, 0xf3b7, 0xc8e4, 0xf3b6, 0xf3ba, 0xf3bb, 0xb4c0,
+    0xeec3, 0xf3bc, 0xf3bd, 0xd1aa, 0xf4ac, 0xd0c6, 0xd0d0, 0xd1dc, 0xcfce, 0xbdd6, 0xd1c3, 0xbae2, 0xe1e9, 0xd2c2,
+    0xf1c2, 0xb2b9, 0xb1ed, 0xf1c3, 0xc9c0, 0xb3c4, 0xd9f2, 0xcba5, 0xf1c4, 0xd6d4, 0xf1c5, 0xf4c0, 0xf1c6, 0xd4ac,
+    0xf1c7, 0xb0c0, 0xf4c1, 0xf4c2, 0xb4fc, 0xc5db, 0xccbb, 0xd0e4, 0xcde0, 0xf1c8, 0xd9f3, 0xb1bb, 0xcfae, 0xb8a4,
+    0xf1ca, 0xf1cb, 0xb2c3, 0xc1d1, 0xd7b0, 0xf1c9, 0xf1cc, 0xf1ce, 0xd9f6, 0xd2e1, 0xd4a3, 0xf4c3, 0xc8b9, 0xf4c4,
+    0xf1cd, 0xf1cf, 0xbfe3, 0xf1d0, 0xf1d4, 0xf1d6, 0xf1d1, 0xc9d1, 0xc5e1, 0xc2e3, 0xb9fc, 0xf1d3, 0xf1d5, 0xb9d3,
+    0xf1db, 0xbad6, 0xb0fd, 0xf1d9, 0xf1d8, 0xf1d2, 0xf1da, 0xf1d7, 0xc8ec, 0xcdca, 0xf1dd, 0xe5bd, 0xf1dc, 0xf1de,
+    0xf1df, 0xcfe5, 0xf4c5, 0xbdf3, 0xf1e0, 0xf1e1, 0xcef7, 0xd2aa, 0xf1fb, 0xb8b2, 0xbcfb, 0xb9db, 0xb9e6, 0xc3d9,
+    0xcad3, 0xeae8, 0xc0c0, 0xbef5, 0xeae9, 0xeaea, 0xeaeb, 0xeaec, 0xeaed, 0xeaee, 0xeaef, 0xbdc7, 0xf5fb, 0xf5fd,
+    0xf5fe, 0xf5fc, 0xbde2, 0xf6a1, 0xb4a5, 0xf6a2, 0xf6a3, 0xecb2, 0xd1d4, 0xd9ea, 0xf6a4, 0xeeba, 0xd5b2, 0xd3fe,
+    0xccdc, 0xcac4, 0xe5c0, 0xf6a5, 0xbeaf, 0xc6a9, 0xdaa5, 0xbcc6, 0xb6a9, 0xb8bc, 0xc8cf, 0xbca5, 0xdaa6, 0xdaa7,
+    0xccd6, 0xc8c3, 0xdaa8, 0xc6fd, 0xd1b5, 0xd2e9, 0xd1b6, 0xbcc7, 0xbdb2, 0xbbe4, 0xdaa9, 0xdaaa, 0xd1c8, 0xdaab,
+    0xd0ed, 0xb6ef, 0xc2db, 0xcbcf, 0xb7ed, 0xc9e8, 0xb7c3, 0xbef7, 0xd6a4, 0xdaac, 0xdaad, 0xc6c0, 0xd7e7, 0xcab6,
+    0xd5a9, 0xcbdf, 0xd5ef, 0xdaae, 0xd6df, 0xb4ca, 0xdab0, 0xdaaf, 0xd2eb, 0xdab1, 0xdab2, 0xdab3, 0xcad4, 0xdab4,
+    0xcaab, 0xdab5, 0xdab6, 0xb3cf, 0xd6ef, 0xdab7, 0xbbb0, 0xb5ae, 0xdab8, 0xdab9, 0xb9ee, 0xd1af, 0xd2e8, 0xdaba,
+    0xb8c3, 0xcfea, 0xb2ef, 0xdabb, 0xdabc, 0xbdeb, 0xcedc, 0xd3ef, 0xdabd, 0xcef3, 0xdabe, 0xd3d5, 0xbbe5, 0xdabf,
+    0xcbb5, 0xcbd0, 0xdac0, 0xc7eb, 0xd6ee, 0xdac1, 0xc5b5, 0xb6c1, 0xdac2, 0xb7cc, 0xbfce, 0xdac3, 0xdac4, 0xcbad,
+    0xdac5, 0xb5f7, 0xdac6, 0xc1c2, 0xd7bb, 0xdac7, 0xccb8, 0xd2ea, 0xc4b1, 0xdac8, 0xb5fd, 0xbbd1, 0xdac9, 0xd0b3,
+    0xdaca, 0xdacb, 0xcebd, 0xdacc, 0xdacd, 0xdace, 0xb2f7, 0xdad1, 0xdacf, 0xd1e8, 0xdad0, 0xc3d5, 0xdad2, 0xdad3,
+    0xdad4, 0xdad5, 0xd0bb, 0xd2a5, 0xb0f9, 0xdad6, 0xc7ab, 0xdad7, 0xbdf7, 0xc3a1, 0xdad8, 0xdad9, 0xc3fd, 0xccb7,
+    0xdada, 0xdadb, 0xc0be, 0xc6d7, 0xdadc, 0xdadd, 0xc7b4, 0xdade, 0xdadf, 0xb9c8, 0xbbed, 0xb6b9, 0xf4f8, 0xf4f9,
+    0xcde3, 0xf5b9, 0xebe0, 0xcff3, 0xbbbf, 0xbac0, 0xd4a5, 0xe1d9, 0xf5f4, 0xb1aa, 0xb2f2, 0xf5f5, 0xf5f7, 0xbad1,
+    0xf5f6, 0xc3b2, 0xf5f9, 0xf5f8, 0xb1b4, 0xd5ea, 0xb8ba, 0xb9b1, 0xb2c6, 0xd4f0, 0xcfcd, 0xb0dc, 0xd5cb, 0xbbf5,
+    0xd6ca, 0xb7b7, 0xccb0, 0xc6b6, 0xb1e1, 0xb9ba, 0xd6fc, 0xb9e1, 0xb7a1, 0xbcfa, 0xeada, 0xeadb, 0xccf9, 0xb9f3,
+    0xeadc, 0xb4fb, 0xc3b3, 0xb7d1, 0xbad8, 0xeadd, 0xd4f4, 0xeade, 0xbcd6, 0xbbdf, 0xeadf, 0xc1de, 0xc2b8, 0xd4df,
+    0xd7ca, 0xeae0, 0xeae1, 0xeae4, 0xeae2, 0xeae3, 0xc9de, 0xb8b3, 0xb6c4, 0xeae5, 0xcaea, 0xc9cd, 0xb4cd, 0xe2d9,
+    0xc5e2, 0xeae6, 0xc0b5, 0xd7b8, 0xeae7, 0xd7ac, 0xc8fc, 0xd8d3, 0xd8cd, 0xd4de, 0xd4f9, 0xc9c4, 0xd3ae, 0xb8d3,
+    0xb3e0, 0xc9e2, 0xf4f6, 0xbad5, 0xf4f7, 0xd7df, 0xf4f1, 0xb8b0, 0xd5d4, 0xb8cf, 0xc6f0, 0xb3c3, 0xf4f2, 0xb3ac,
+    0xd4bd, 0xc7f7, 0xf4f4, 0xf4f3, 0xcccb, 0xc8a4, 0xf4f5, 0xd7e3, 0xc5bf, 0xf5c0, 0xf5bb, 0xf5c3, 0xf5c2, 0xd6ba,
+    0xf5c1, 0xd4be, 0xf5c4, 0xf5cc, 0xb0cf, 0xb5f8, 0xf5c9, 0xf5ca, 0xc5dc, 0xf5c5, 0xf5c6, 0xf5c7, 0xf5cb, 0xbee0,
+    0xf5c8, 0xb8fa, 0xf5d0, 0xf5d3, 0xbfe7, 0xb9f2, 0xf5bc, 0xf5cd, 0xc2b7, 0xccf8, 0xbcf9, 0xf5ce, 0xf5cf, 0xf5d1,
+    0xb6e5, 0xf5d2, 0xf5d5, 0xf5bd, 0xf5d4, 0xd3bb, 0xb3ec, 0xcca4, 0xf5d6, 0xf5d7, 0xbee1, 0xf5d8, 0xccdf, 0xf5db,
+    0xb2c8, 0xd7d9, 0xf5d9, 0xf5da, 0xf5dc, 0xf5e2, 0xf5e0, 0xf5df, 0xf5dd, 0xf5e1, 0xf5de, 0xf5e4, 0xf5e5, 0xcce3,
+    0xe5bf, 0xb5b8, 0xf5e3, 0xf5e8, 0xcca3, 0xf5e6, 0xf5e7, 0xf5be, 0xb1c4, 0xf5bf, 0xb5c5, 0xb2e4, 0xf5ec, 0xf5e9,
+    0xb6d7, 0xf5ed, 0xf5ea, 0xf5eb, 0xb4da, 0xd4ea, 0xf5ee, 0xb3f9, 0xf5ef, 0xf5f1, 0xf5f0, 0xf5f2, 0xf5f3, 0xc9ed,
+    0xb9aa, 0xc7fb, 0xb6e3, 0xccc9, 0xeaa6, 0xb3b5, 0xd4fe, 0xb9ec, 0xd0f9, 0xe9ed, 0xd7aa, 0xe9ee, 0xc2d6, 0xc8ed,
+    0xbae4, 0xe9ef, 0xe9f0, 0xe9f1, 0xd6e1, 0xe9f2, 0xe9f3, 0xe9f5, 0xe9f4, 0xe9f6, 0xe9f7, 0xc7e1, 0xe9f8, 0xd4d8,
+    0xe9f9, 0xbdce, 0xe9fa, 0xe9fb, 0xbdcf, 0xe9fc, 0xb8a8, 0xc1be, 0xe9fd, 0xb1b2, 0xbbd4, 0xb9f5, 0xe9fe, 0xeaa1,
+    0xeaa2, 0xeaa3, 0xb7f8, 0xbcad, 0xcae4, 0xe0ce, 0xd4af, 0xcfbd, 0xd5b7, 0xeaa4, 0xd5de, 0xeaa5, 0xd0c1, 0xb9bc,
+    0xb4c7, 0xb1d9, 0xc0b1, 0xb1e6, 0xb1e7, 0xb1e8, 0xb3bd, 0xc8e8, 0xe5c1, 0xb1df, 0xc1c9, 0xb4ef, 0xc7a8, 0xd3d8,
+    0xc6f9, 0xd1b8, 0xb9fd, 0xc2f5, 0xd3ad, 0xd4cb, 0xbdfc, 0xe5c2, 0xb7b5, 0xe5c3, 0xbbb9, 0xd5e2, 0xbdf8, 0xd4b6,
+    0xcea5, 0xc1ac, 0xb3d9, 0xccf6, 0xe5c6, 0xe5c4, 0xe5c8, 0xe5ca, 0xe5c7, 0xb5cf, 0xc6c8, 0xb5fc, 0xe5c5, 0xcaf6,
+    0xe5c9, 0xc3d4, 0xb1c5, 0xbca3, 0xd7b7, 0xcdcb, 0xcbcd, 0xcaca, 0xccd3, 0xe5cc, 0xe5cb, 0xc4e6, 0xd1a1, 0xd1b7,
+    0xe5cd, 0xe5d0, 0xcdb8, 0xd6f0, 0xe5cf, 0xb5dd, 0xcdbe, 0xe5d1, 0xb6ba, 0xcda8, 0xb9e4, 0xcac5, 0xb3d1, 0xcbd9,
+    0xd4ec, 0xe5d2, 0xb7ea, 0xe5ce, 0xe5d5, 0xb4fe, 0xe5d6, 0xe5d3, 0xe5d4, 0xd2dd, 0xc2df, 0xb1c6, 0xd3e2, 0xb6dd,
+    0xcbec, 0xe5d7, 0xd3f6, 0xb1e9, 0xb6f4, 0xe5da, 0xe5d8, 0xe5d9, 0xb5c0, 0xd2c5, 0xe5dc, 0xe5de, 0xe5dd, 0xc7b2,
+    0xd2a3, 0xe5db, 0xd4e2, 0xd5da, 0xe5e0, 0xd7f1, 0xe5e1, 0xb1dc, 0xd1fb, 0xe5e2, 0xe5e4, 0xe5e3, 0xe5e5, 0xd2d8,
+    0xb5cb, 0xe7df, 0xdaf5, 0xdaf8, 0xdaf6, 0xdaf7, 0xdafa, 0xd0cf, 0xc4c7, 0xb0ee, 0xd0b0, 0xdaf9, 0xd3ca, 0xbaaa,
+    0xdba2, 0xc7f1, 0xdafc, 0xdafb, 0xc9db, 0xdafd, 0xdba1, 0xd7de, 0xdafe, 0xc1da, 0xdba5, 0xd3f4, 0xdba7, 0xdba4,
+    0xdba8, 0xbdbc, 0xc0c9, 0xdba3, 0xdba6, 0xd6a3, 0xdba9, 0xdbad, 0xdbae, 0xdbac, 0xbac2, 0xbfa4, 0xdbab, 0xdbaa,
+    0xd4c7, 0xb2bf, 0xdbaf, 0xb9f9, 0xdbb0, 0xb3bb, 0xb5a6, 0xb6bc, 0xdbb1, 0xb6f5, 0xdbb2, 0xb1c9, 0xdbb4, 0xdbb3,
+    0xdbb5, 0xdbb7, 0xdbb6, 0xdbb8, 0xdbb9, 0xdbba, 0xd3cf, 0xf4fa, 0xc7f5, 0xd7c3, 0xc5e4, 0xf4fc, 0xf4fd, 0xf4fb,
+    0xbec6, 0xd0ef, 0xb7d3, 0xd4cd, 0xccaa, 0xf5a2, 0xf5a1, 0xbaa8, 0xf4fe, 0xcbd6, 0xf5a4, 0xc0d2, 0xb3ea, 0xcdaa,
+    0xf5a5, 0xf5a3, 0xbdb4, 0xf5a8, 0xf5a9, 0xbdcd, 0xc3b8, 0xbfe1, 0xcbe1, 0xf5aa, 0xf5a6, 0xf5a7, 0xc4f0, 0xf5ac,
+    0xb4bc, 0xd7ed, 0xb4d7, 0xf5ab, 0xf5ae, 0xf5ad, 0xf5af, 0xd0d1, 0xc3d1, 0xc8a9, 0xf5b0, 0xf5b1, 0xf5b2, 0xf5b3,
+    0xf5b4, 0xf5b5, 0xf5b7, 0xf5b6, 0xf5b8, 0xb2c9, 0xd3d4, 0xcacd, 0xc0ef, 0xd6d8, 0xd2b0, 0xc1bf, 0xbdf0, 0xb8aa,
+    0xbcf8, 0xf6c6, 0xf6c7, 0xf6c8, 0xf6c9, 0xf6ca, 0xf6cc, 0xf6cb, 0xf7e9, 0xf6cd, 0xf6ce, 0xeec4, 0xeec5, 0xeec6,
+    0xd5eb, 0xb6a4, 0xeec8, 0xeec7, 0xeec9, 0xeeca, 0xc7a5, 0xeecb, 0xeecc, 0xb7b0, 0xb5f6, 0xeecd, 0xeecf, 0xeece,
+    0xb8c6, 0xeed0, 0xeed1, 0xeed2, 0xb6db, 0xb3ae, 0xd6d3, 0xc4c6, 0xb1b5, 0xb8d6, 0xeed3, 0xeed4, 0xd4bf, 0xc7d5,
+    0xbefb, 0xced9, 0xb9b3, 0xeed6, 0xeed5, 0xeed8, 0xeed7, 0xc5a5, 0xeed9, 0xeeda, 0xc7ae, 0xeedb, 0xc7af, 0xeedc,
+    0xb2a7, 0xeedd, 0xeede, 0xeedf, 0xeee0, 0xeee1, 0xd7ea, 0xeee2, 0xeee3, 0xbcd8, 0xeee4, 0xd3cb, 0xccfa, 0xb2ac,
+    0xc1e5, 0xeee5, 0xc7a6, 0xc3ad, 0xeee6, 0xeee7, 0xeee8, 0xeee9, 0xeeea, 0xeeeb, 0xeeec, 0xeeed, 0xeeee, 0xeeef,
+    0xeef0, 0xeef1, 0xeef2, 0xeef4, 0xeef3, 0xeef5, 0xcdad, 0xc2c1, 0xeef6, 0xeef7, 0xeef8, 0xd5a1, 0xeef9, 0xcfb3,
+    0xeefa, 0xeefb, 0xeefc, 0xeefd, 0xefa1, 0xeefe, 0xefa2, 0xb8f5, 0xc3fa, 0xefa3, 0xefa4, 0xbdc2, 0xd2bf, 0xb2f9,
+    0xefa5, 0xefa6, 0xefa7, 0xd2f8, 0xefa8, 0xd6fd, 0xefa9, 0xc6cc, 0xefaa, 0xefab, 0xc1b4, 0xefac, 0xcffa, 0xcbf8,
+    0xefae, 0xefad, 0xb3fa, 0xb9f8, 0xefaf, 0xefb0, 0xd0e2, 0xefb1, 0xefb2, 0xb7e6, 0xd0bf, 0xefb3, 0xefb4, 0xefb5,
+    0xc8f1, 0xcce0, 0xefb6, 0xefb7, 0xefb8, 0xefb9, 0xefba, 0xd5e0, 0xefbb, 0xb4ed, 0xc3aa, 0xefbc, 0xefbd, 0xefbe,
+    0xefbf, 0xcefd, 0xefc0, 0xc2e0, 0xb4b8, 0xd7b6, 0xbdf5, 0xcfc7, 0xefc3, 0xefc1, 0xefc2, 0xefc4, 0xb6a7, 0xbcfc,
+    0xbee2, 0xc3cc, 0xefc5, 0xefc6, 0xefc7, 0xefcf, 0xefc8, 0xefc9, 0xefca, 0xc7c2, 0xeff1, 0xb6cd, 0xefcb, 0xefcc,
+    0xefcd, 0xb6c6, 0xc3be, 0xefce, 0xefd0, 0xefd1, 0xefd2, 0xd5f2, 0xefd3, 0xc4f7, 0xefd4, 0xc4f8, 0xefd5, 0xefd6,
+    0xb8e4, 0xb0f7, 0xefd7, 0xefd8, 0xefd9, 0xefda, 0xefdb, 0xefdc, 0xefdd, 0xefde, 0xbeb5, 0xefe1, 0xefdf, 0xefe0,
+    0xefe2, 0xefe3, 0xc1cd, 0xefe4, 0xefe5, 0xefe6, 0xefe7, 0xefe8, 0xefe9, 0xefea, 0xefeb, 0xefec, 0xc0d8, 0xefed,
+    0xc1ad, 0xefee, 0xefef, 0xeff0, 0xcfe2, 0xb3a4, 0xc3c5, 0xe3c5, 0xc9c1, 0xe3c6, 0xb1d5, 0xceca, 0xb4b3, 0xc8f2,
+    0xe3c7, 0xcfd0, 0xe3c8, 0xbce4, 0xe3c9, 0xe3ca, 0xc3c6, 0xd5a2, 0xc4d6, 0xb9eb, 0xcec5, 0xe3cb, 0xc3f6, 0xe3cc,
+    0xb7a7, 0xb8f3, 0xbad2, 0xe3cd, 0xe3ce, 0xd4c4, 0xe3cf, 0xe3d0, 0xd1cb, 0xe3d1, 0xe3d2, 0xe3d3, 0xe3d4, 0xd1d6,
+    0xe3d5, 0xb2fb, 0xc0bb, 0xe3d6, 0xc0ab, 0xe3d7, 0xe3d8, 0xe3d9, 0xe3da, 0xe3db, 0xb8b7, 0xdae2, 0xb6d3, 0xdae4,
+    0xdae3, 0xdae6, 0xc8ee, 0xdae5, 0xb7c0, 0xd1f4, 0xd2f5, 0xd5f3, 0xbdd7, 0xd7e8, 0xdae8, 0xdae7, 0xb0a2, 0xcdd3,
+    0xdae9, 0xb8bd, 0xbcca, 0xc2bd, 0xc2a4, 0xb3c2, 0xdaea, 0xc2aa, 0xc4b0, 0xbdb5, 0xcfde, 0xdaeb, 0xc9c2, 0xb1dd,
+    0xdaec, 0xb6b8, 0xd4ba, 0xb3fd, 0xdaed, 0xd4c9, 0xcfd5, 0xc5e3, 0xdaee, 0xdaef, 0xdaf0, 0xc1ea, 0xccd5, 0xcfdd,
+    0xd3e7, 0xc2a1, 0xdaf1, 0xcbe5, 0xdaf2, 0xcbe6, 0xd2fe, 0xb8f4, 0xdaf3, 0xb0af, 0xcfb6, 0xd5cf, 0xcbed, 0xdaf4,
+    0xe3c4, 0xc1a5, 0xf6bf, 0xf6c0, 0xf6c1, 0xc4d1, 0xc8b8, 0xd1e3, 0xd0db, 0xd1c5, 0xbcaf, 0xb9cd, 0xeff4, 0xb4c6,
+    0xd3ba, 0xf6c2, 0xb3fb, 0xf6c3, 0xb5f1, 0xf6c5, 0xd3ea, 0xf6a7, 0xd1a9, 0xf6a9, 0xf6a8, 0xc1e3, 0xc0d7, 0xb1a2,
+    0xceed, 0xd0e8, 0xf6ab, 0xcff6, 0xf6aa, 0xd5f0, 0xf6ac, 0xc3b9, 0xbbf4, 0xf6ae, 0xf6ad, 0xc4de, 0xc1d8, 0xcbaa,
+    0xcfbc, 0xf6af, 0xf6b0, 0xf6b1, 0xc2b6, 0xb0d4, 0xc5f9, 0xf6b2, 0xc7e0, 0xf6a6, 0xbeb8, 0xbeb2, 0xb5e5, 0xb7c7,
+    0xbfbf, 0xc3d2, 0xc3e6, 0xd8cc, 0xb8ef, 0xbdf9, 0xd1a5, 0xb0d0, 0xf7b0, 0xf7b1, 0xd0ac, 0xb0b0, 0xf7b2, 0xf7b3,
+    0xf7b4, 0xc7ca, 0xbecf, 0xf7b7, 0xf7b6, 0xb1de, 0xf7b5, 0xf7b8, 0xf7b9, 0xcea4, 0xc8cd, 0xbaab, 0xe8b8, 0xe8b9,
+    0xe8ba, 0xbec2, 0xd2f4, 0xd4cf, 0xc9d8, 0xd2b3, 0xb6a5, 0xc7ea, 0xf1fc, 0xcfee, 0xcbb3, 0xd0eb, 0xe7ef, 0xcde7,
+    0xb9cb, 0xb6d9, 0xf1fd, 0xb0e4, 0xcbcc, 0xf1fe, 0xd4a4, 0xc2ad, 0xc1ec, 0xc6c4, 0xbeb1, 0xf2a1, 0xbcd5, 0xf2a2,
+    0xf2a3, 0xf2a4, 0xd2c3, 0xc6b5, 0xcdc7, 0xf2a5, 0xd3b1, 0xbfc5, 0xcce2, 0xf2a6, 0xf2a7, 0xd1d5, 0xb6ee, 0xf2a8,
+    0xf2a9, 0xb5df, 0xf2aa, 0xf2ab, 0xb2fc, 0xf2ac, 0xf2ad, 0xc8a7, 0xb7e7, 0xeca9, 0xecaa, 0xecab, 0xecac, 0xc6ae,
+    0xecad, 0xecae, 0xb7c9, 0xcab3, 0xe2b8, 0xf7cf, 0xf7d0, 0xb2cd, 0xf7d1, 0xf7d3, 0xf7d2, 0xe2bb, 0xbca2, 0xe2bc,
+    0xe2bd, 0xe2be, 0xe2bf, 0xe2c0, 0xe2c1, 0xb7b9, 0xd2fb, 0xbda4, 0xcace, 0xb1a5, 0xcbc7, 0xe2c2, 0xb6fc, 0xc8c4,
+    0xe2c3, 0xbdc8, 0xb1fd, 0xe2c4, 0xb6f6, 0xe2c5, 0xc4d9, 0xe2c6, 0xcfda, 0xb9dd, 0xe2c7, 0xc0a1, 0xe2c8, 0xb2f6,
+    0xe2c9, 0xc1f3, 0xe2ca, 0xe2cb, 0xc2f8, 0xe2cc, 0xe2cd, 0xe2ce, 0xcad7, 0xd8b8, 0xd9e5, 0xcfe3, 0xf0a5, 0xdcb0,
+    0xc2ed, 0xd4a6, 0xcdd4, 0xd1b1, 0xb3db, 0xc7fd, 0xb2b5, 0xc2bf, 0xe6e0, 0xcabb, 0xe6e1, 0xe6e2, 0xbed4, 0xe6e3,
+    0xd7a4, 0xcdd5, 0xe6e5, 0xbcdd, 0xe6e4, 0xe6e6, 0xe6e7, 0xc2ee, 0xbdbe, 0xe6e8, 0xc2e6, 0xbaa7, 0xe6e9, 0xe6ea,
+    0xb3d2, 0xd1e9, 0xbfa5, 0xe6eb, 0xc6ef, 0xe6ec, 0xe6ed, 0xe6ee, 0xc6ad, 0xe6ef, 0xc9a7, 0xe6f0, 0xe6f1, 0xe6f2,
+    0xe5b9, 0xe6f3, 0xe6f4, 0xc2e2, 0xe6f5, 0xe6f6, 0xd6e8, 0xe6f7, 0xe6f8, 0xb9c7, 0xf7bb, 0xf7ba, 0xf7be, 0xf7bc,
+    0xbaa1, 0xf7bf, 0xf7c0, 0xf7c2, 0xf7c1, 0xf7c4, 0xf7c3, 0xf7c5, 0xf7c6, 0xf7c7, 0xcbe8, 0xb8df, 0xf7d4, 0xf7d5,
+    0xf7d6, 0xf7d8, 0xf7da, 0xf7d7, 0xf7db, 0xf7d9, 0xd7d7, 0xf7dc, 0xf7dd, 0xf7de, 0xf7df, 0xf7e0, 0xdbcb, 0xd8aa,
+    0xe5f7, 0xb9ed, 0xbffd, 0xbbea, 0xf7c9, 0xc6c7, 0xf7c8, 0xf7ca, 0xf7cc, 0xf7cb, 0xf7cd, 0xceba, 0xf7ce, 0xc4a7,
+    0xd3e3, 0xf6cf, 0xc2b3, 0xf6d0, 0xf6d1, 0xf6d2, 0xf6d3, 0xf6d4, 0xf6d6, 0xb1ab, 0xf6d7, 0xf6d8, 0xf6d9, 0xf6da,
+    0xf6db, 0xf6dc, 0xf6dd, 0xf6de, 0xcfca, 0xf6df, 0xf6e0, 0xf6e1, 0xf6e2, 0xf6e3, 0xf6e4, 0xc0f0, 0xf6e5, 0xf6e6,
+    0xf6e7, 0xf6e8, 0xf6e9, 0xf6ea, 0xf6eb, 0xf6ec, 0xf6ed, 0xf6ee, 0xf6ef, 0xf6f0, 0xf6f1, 0xf6f2, 0xf6f3, 0xf6f4,
+    0xbea8, 0xf6f5, 0xf6f6, 0xf6f7, 0xf6f8, 0xc8fa, 0xf6f9, 0xf6fa, 0xf6fb, 0xf6fc, 0xf6fd, 0xf6fe, 0xf7a1, 0xf7a2,
+    0xf7a3, 0xf7a4, 0xf7a5, 0xf7a6, 0xf7a7, 0xf7a8, 0xb1ee, 0xf7a9, 0xf7aa, 0xf7ab, 0xf7ac, 0xf7ad, 0xc1db, 0xf7ae,
+    0xf7af, 0xc4f1, 0xf0af, 0xbca6, 0xf0b0, 0xc3f9, 0xc5b8, 0xd1bb, 0xf0b1, 0xf0b2, 0xf0b3, 0xf0b4, 0xf0b5, 0xd1bc,
+    0xd1ec, 0xf0b7, 0xf0b6, 0xd4a7, 0xcdd2, 0xf0b8, 0xf0ba, 0xf0b9, 0xf0bb, 0xf0bc, 0xb8eb, 0xf0bd, 0xbae8, 0xf0be,
+    0xf0bf, 0xbee9, 0xf0c0, 0xb6ec, 0xf0c1, 0xf0c2, 0xf0c3, 0xf0c4, 0xc8b5, 0xf0c5, 0xf0c6, 0xf0c7, 0xc5f4, 0xf0c8,
+    0xf0c9, 0xf0ca, 0xf7bd, 0xf0cb, 0xf0cc, 0xf0cd, 0xf0ce, 0xf0cf, 0xbad7, 0xf0d0, 0xf0d1, 0xf0d2, 0xf0d3, 0xf0d4,
+    0xf0d5, 0xf0d6, 0xf0d8, 0xd3a5, 0xf0d7, 0xf0d9, 0xf5ba, 0xc2b9, 0xf7e4, 0xf7e5, 0xf7e6, 0xf7e7, 0xf7e8, 0xc2b4,
+    0xf7ea, 0xf7eb, 0xc2f3, 0xf4f0, 0xf4ef, 0xc2e9, 0xf7e1, 0xf7e2, 0xbbc6, 0xd9e4, 0xcaf2, 0xc0e8, 0xf0a4, 0xbada,
+    0xc7ad, 0xc4ac, 0xf7ec, 0xf7ed, 0xf7ee, 0xf7f0, 0xf7ef, 0xf7f1, 0xf7f4, 0xf7f3, 0xf7f2, 0xf7f5, 0xf7f6, 0xede9,
+    0xedea, 0xedeb, 0xf6bc, 0xf6bd, 0xf6be, 0xb6a6, 0xd8be, 0xb9c4, 0xd8bb, 0xdcb1, 0xcaf3, 0xf7f7, 0xf7f8, 0xf7f9,
+    0xf7fb, 0xf7fa, 0xb1c7, 0xf7fc, 0xf7fd, 0xf7fe, 0xc6eb, 0xecb4, 0xb3dd, 0xf6b3, 0xf6b4, 0xc1e4, 0xf6b5, 0xf6b6,
+    0xf6b7, 0xf6b8, 0xf6b9, 0xf6ba, 0xc8a3, 0xf6bb, 0xc1fa, 0xb9a8, 0xede8, 0xb9ea, 0xd9df, 0xa3a1, 0xa3a2, 0xa3a3,
+    0xa1e7, 0xa3a5, 0xa3a6, 0xa3a7, 0xa3a8, 0xa3a9, 0xa3aa, 0xa3ab, 0xa3ac, 0xa3ad, 0xa3ae, 0xa3af, 0xa3b0, 0xa3b1,
+    0xa3b2, 0xa3b3, 0xa3b4, 0xa3b5, 0xa3b6, 0xa3b7, 0xa3b8, 0xa3b9, 0xa3ba, 0xa3bb, 0xa3bc, 0xa3bd, 0xa3be, 0xa3bf,
+    0xa3c0, 0xa3c1, 0xa3c2, 0xa3c3, 0xa3c4, 0xa3c5, 0xa3c6, 0xa3c7, 0xa3c8, 0xa3c9, 0xa3ca, 0xa3cb, 0xa3cc, 0xa3cd,
+    0xa3ce, 0xa3cf, 0xa3d0, 0xa3d1, 0xa3d2, 0xa3d3, 0xa3d4, 0xa3d5, 0xa3d6, 0xa3d7, 0xa3d8, 0xa3d9, 0xa3da, 0xa3db,
+    0xa3dc, 0xa3dd, 0xa3de, 0xa3df, 0xa3e0, 0xa3e1, 0xa3e2, 0xa3e3, 0xa3e4, 0xa3e5, 0xa3e6, 0xa3e7, 0xa3e8, 0xa3e9,
+    0xa3ea, 0xa3eb, 0xa3ec, 0xa3ed, 0xa3ee, 0xa3ef, 0xa3f0, 0xa3f1, 0xa3f2, 0xa3f3, 0xa3f4, 0xa3f5, 0xa3f6, 0xa3f7,
+    0xa3f8, 0xa3f9, 0xa3fa, 0xa3fb, 0xa3fc, 0xa3fd, 0xa1ab, 0xa1e9, 0xa1ea, 0xa3fe, 0xa3a4,
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::hanzi::{encode_gb2312, to_gb2312, GB2312_CODES, UNICODE_CODE_POINTS};
+    use alloc::vec;
+
+    #[test]
+    fn test_table_sorted() {
+        assert_eq!(UNICODE_CODE_POINTS.len(), GB2312_CODES.len());
+        assert!(UNICODE_CODE_POINTS.windows(2).all(|w| w[0] < w[1]));
+        assert!(GB2312_CODES.iter().all(|&c| (0xa1a1..=0xaafe).contains(&c) || (0xb0a1..=0xfafe).contains(&c)));
+    }
+
+    #[test]
+    fn test_to_gb2312() {
+        assert_eq!(to_gb2312('\u{3000}'), Some(0xa1a1)); // ideographic space
+        assert_eq!(to_gb2312('\u{554a}'), Some(0xb0a1)); // the first Hanzi "啊"
+        assert_eq!(to_gb2312('\u{9f44}'), Some(0xf7fe)); // the last Hanzi "齄"
+        assert_eq!(to_gb2312('\u{ff01}'), Some(0xa3a1)); // fullwidth "！"
+        assert_eq!(to_gb2312('\u{9ad4}'), None); // traditional "體"
+        assert_eq!(to_gb2312('\u{1f600}'), None);
+        assert_eq!(to_gb2312('!'), None);
+    }
+
+    #[test]
+    fn test_encode_gb2312() {
+        assert_eq!(encode_gb2312(""), Some(vec![]));
+        assert_eq!(encode_gb2312("\u{554a}\u{3001}"), Some(vec![0xb0, 0xa1, 0xa1, 0xa2]));
+        assert_eq!(encode_gb2312("\u{554a}\u{e9}"), Some(vec![0xb0, 0xa1, 0xa8, 0xa6])); // pinyin "é"
+        assert_eq!(encode_gb2312("\u{554a}A"), None);
+    }
+}
@@ -43,17 +43,27 @@ use alloc::vec::Vec;
 use core::ops::Index;
 
 pub mod bits;
+pub mod builder;
 pub mod canvas;
 mod cast;
+pub mod decode;
+pub mod detect;
 pub mod ec;
+pub mod gs1;
+pub mod hanzi;
+pub mod kanji;
 pub mod optimize;
+pub mod payload;
 pub mod render;
 pub mod types;
 
+pub use crate::builder::QrCodeBuilder;
 pub use crate::types::{Color, EcLevel, QrResult, Version};
 
+use crate::canvas::MaskPattern;
 use crate::cast::As;
 use crate::render::{Pixel, Renderer};
+use crate::types::QrError;
 
 /// The encoded QR code symbol.
 #[derive(Clone)]
@@ -62,6 +72,9 @@ pub struct QrCode {
     version: Version,
     ec_level: EcLevel,
     width: usize,
+    segments: Vec<bits::EncodedSegment>,
+    terminator_bits_count: usize,
+    padding_bits_count: usize,
 }
 
 impl QrCode {
@@ -104,6 +117,130 @@ impl QrCode {
         Self::with_bits(bits, ec_level)
     }
 
+    /// Constructs a new QR code which automatically encodes the given data at a
+    /// specific error correction level, allowing Micro QR code.
+    ///
+    /// This method chooses the smallest Micro QR code if the data fits in any
+    /// of them with the error correction level, and otherwise the smallest QR
+    /// code.
+    ///
+    /// ```
+    /// use qrcode::{EcLevel, QrCode, Version};
+    ///
+    /// let code = QrCode::with_micro_allowed(b"12345", EcLevel::L).unwrap();
+    /// assert_eq!(code.version(), Version::Micro(1));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the QR code cannot be constructed, e.g. when the data
+    /// is too long.
+    pub fn with_micro_allowed<D: AsRef<[u8]>>(data: D, ec_level: EcLevel) -> QrResult<Self> {
+        let bits = bits::encode_auto_micro(data.as_ref(), ec_level)?;
+        Self::with_bits(bits, ec_level)
+    }
+
+    /// Constructs a new QR code which automatically encodes the given text at
+    /// a specific error correction level, using the Kanji mode for Japanese
+    /// characters.
+    ///
+    /// Characters in JIS X 0208 are encoded in the Kanji mode as Shift JIS,
+    /// which takes 13 bits per character instead of 24 bits in UTF-8. Other
+    /// characters are encoded in UTF-8, with the UTF-8 ECI designator if any
+    /// of them is not ASCII.
+    ///
+    /// ```
+    /// use qrcode::{EcLevel, QrCode};
+    ///
+    /// let code = QrCode::with_kanji("\u{70b9}\u{8317}", EcLevel::M).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the QR code cannot be constructed, e.g. when the data
+    /// is too long.
+    pub fn with_kanji(data: &str, ec_level: EcLevel) -> QrResult<Self> {
+        let bits = bits::encode_auto_str(data, ec_level)?;
+        Self::with_bits(bits, ec_level)
+    }
+
+    /// Constructs a new QR code which automatically encodes the given text at
+    /// a specific error correction level, using the Hanzi mode for Chinese
+    /// characters.
+    ///
+    /// Characters in GB 2312 are encoded in the Hanzi mode, which takes 13
+    /// bits per character instead of 24 bits in UTF-8. Other characters are
+    /// encoded in UTF-8, with the UTF-8 ECI designator if any of them is not
+    /// ASCII.
+    ///
+    /// ```
+    /// use qrcode::{EcLevel, QrCode};
+    ///
+    /// let code = QrCode::with_hanzi("\u{4e2d}\u{6587}", EcLevel::M).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the QR code cannot be constructed, e.g. when the data
+    /// is too long.
+    pub fn with_hanzi(data: &str, ec_level: EcLevel) -> QrResult<Self> {
+        let bits = bits::encode_auto_hanzi_str(data, ec_level)?;
+        Self::with_bits(bits, ec_level)
+    }
+
+    /// Constructs a new GS1 QR code from the Application Identifiers and their
+    /// values, at a specific error correction level.
+    ///
+    /// The values are validated against their Application Identifiers, and
+    /// the element string is encoded after FNC1 in the first position.
+    ///
+    /// ```
+    /// use qrcode::{EcLevel, QrCode};
+    ///
+    /// let code = QrCode::with_gs1(&[("01", "09506000134352"), ("10", "ABC123")], EcLevel::M).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the QR code cannot be constructed, e.g. when an
+    /// element is invalid, or the data is too long.
+    pub fn with_gs1(elements: &[(&str, &str)], ec_level: EcLevel) -> QrResult<Self> {
+        let bits = gs1::encode(elements, ec_level)?;
+        Self::with_bits(bits, ec_level)
+    }
+
+    /// Constructs a new QR code which encodes the payload, at the error
+    /// correction level recommended by the payload.
+    ///
+    /// The ECI designator declared by the payload, e.g. the UTF-8 designator
+    /// for UTF-8 text which is not ASCII, is added in front.
+    ///
+    /// The payload may also be a trait object, e.g. `&dyn Payload`, to accept
+    /// any kind of payload uniformly.
+    ///
+    /// ```
+    /// use qrcode::payload::{Wifi, WifiAuthentication};
+    /// use qrcode::QrCode;
+    ///
+    /// let wifi = Wifi {
+    ///     ssid: "Home".into(),
+    ///     password: "correct horse".into(),
+    ///     authentication: WifiAuthentication::Wpa,
+    ///     hidden: false,
+    /// };
+    /// let code = QrCode::from_payload(&wifi).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the payload is invalid, or the QR code cannot be
+    /// constructed, e.g. when the data is too long.
+    pub fn from_payload<P: payload::Payload + ?Sized>(payload: &P) -> QrResult<Self> {
+        let ec_level = payload.ec_level();
+        let bits = bits::encode_auto_with_eci(&payload.to_payload_bytes()?, ec_level, payload.eci_designator())?;
+        Self::with_bits(bits, ec_level)
+    }
+
     /// Constructs a new QR code for the given version and error correction
     /// level.
     ///
@@ -133,6 +270,32 @@ impl QrCode {
         Self::with_bits(bits, ec_level)
     }
 
+    /// Constructs a structured append sequence of QR codes, which splits the
+    /// data across up to 16 linked symbols.
+    ///
+    /// Each symbol is no larger than `max_version`, and the symbols should be
+    /// scanned in the returned order to recover the data.
+    ///
+    /// ```
+    /// use qrcode::{EcLevel, QrCode, Version};
+    ///
+    /// let data = "Some long text. ".repeat(40);
+    /// let codes = QrCode::structured_append(&data, EcLevel::M, Version::Normal(10)).unwrap();
+    /// assert!(codes.len() > 1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the QR codes cannot be constructed, e.g. when the data
+    /// does not fit into 16 symbols of `max_version`, or when `max_version` is
+    /// not a normal QR code version.
+    pub fn structured_append<D: AsRef<[u8]>>(data: D, ec_level: EcLevel, max_version: Version) -> QrResult<Vec<Self>> {
+        bits::encode_structured_append(data.as_ref(), ec_level, max_version)?
+            .into_iter()
+            .map(|bits| Self::with_bits(bits, ec_level))
+            .collect()
+    }
+
     /// Constructs a new QR code with encoded bits.
     ///
     /// Use this method only if there are very special need to manipulate the
@@ -163,14 +326,71 @@ impl QrCode {
     /// are too long, or when the version and error correction level are
     /// incompatible.
     pub fn with_bits(bits: bits::Bits, ec_level: EcLevel) -> QrResult<Self> {
+        Self::with_bits_and_optional_mask(bits, ec_level, None)
+    }
+
+    /// Constructs a new QR code with encoded bits, using the given mask pattern
+    /// instead of the one with the lowest penalty score.
+    ///
+    /// ```
+    /// use qrcode::bits::Bits;
+    /// use qrcode::canvas::MaskPattern;
+    /// use qrcode::{EcLevel, QrCode, Version};
+    ///
+    /// let mut bits = Bits::new(Version::Normal(1));
+    /// bits.push_optimal_data(b"Some data").unwrap();
+    /// bits.push_terminator(EcLevel::M).unwrap();
+    /// let code = QrCode::with_bits_and_mask(bits, EcLevel::M, MaskPattern::Diamonds).unwrap();
+    /// assert_eq!(code.mask_pattern(), MaskPattern::Diamonds);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the QR code cannot be constructed, e.g. when the bits
+    /// are too long, or when the version and error correction level are
+    /// incompatible.
+    ///
+    /// Returns `Err(QrError::UnsupportedMaskPattern)` if the mask pattern
+    /// cannot be used in the version of the bits.
+    pub fn with_bits_and_mask(bits: bits::Bits, ec_level: EcLevel, mask_pattern: MaskPattern) -> QrResult<Self> {
+        Self::with_bits_and_optional_mask(bits, ec_level, Some(mask_pattern))
+    }
+
+    /// Constructs a new QR code with encoded bits, using the given mask
+    /// pattern, or the one with the lowest penalty score if `None`.
+    pub(crate) fn with_bits_and_optional_mask(
+        bits: bits::Bits,
+        ec_level: EcLevel,
+        mask_pattern: Option<MaskPattern>,
+    ) -> QrResult<Self> {
         let version = bits.version();
+        if mask_pattern.is_some_and(|p| !canvas::supported_mask_patterns(version).contains(&p)) {
+            return Err(QrError::UnsupportedMaskPattern);
+        }
+        let segments = bits.segments().to_vec();
+        let terminator_bits_count = bits.terminator_bits_count();
+        let padding_bits_count = bits.padding_bits_count();
         let data = bits.into_bytes();
         let (encoded_data, ec_data) = ec::construct_codewords(&data, version, ec_level)?;
         let mut canvas = canvas::Canvas::new(version, ec_level);
         canvas.draw_all_functional_patterns();
         canvas.draw_data(&encoded_data, &ec_data);
-        let canvas = canvas.apply_best_mask();
-        Ok(Self { content: canvas.into_colors(), version, ec_level, width: version.width().as_usize() })
+        let canvas = match mask_pattern {
+            Some(mask_pattern) => {
+                canvas.apply_mask(mask_pattern);
+                canvas
+            }
+            None => canvas.apply_best_mask(),
+        };
+        Ok(Self {
+            content: canvas.into_colors(),
+            version,
+            ec_level,
+            width: version.width().as_usize(),
+            segments,
+            terminator_bits_count,
+            padding_bits_count,
+        })
     }
 
     /// Gets the version of this QR code.
@@ -190,6 +410,76 @@ impl QrCode {
         self.width
     }
 
+    /// Gets the number of modules per column, i.e. the height of this QR code.
+    ///
+    /// This is the same as the width except for rMQR codes. The height here
+    /// does not contain the quiet zone paddings.
+    pub fn height(&self) -> usize {
+        self.version.height().as_usize()
+    }
+
+    /// Gets the segments encoded in this QR code, in order, including the ECI,
+    /// FNC1 and structured append headers. This tells how the data was split
+    /// into modes, and how many bits each part takes.
+    ///
+    /// ```
+    /// use qrcode::bits::SegmentKind;
+    /// use qrcode::types::Mode;
+    /// use qrcode::QrCode;
+    ///
+    /// let code = QrCode::new(b"0123456789 HELLO").unwrap();
+    /// let segments = code
+    ///     .segments()
+    ///     .iter()
+    ///     .map(|s| match s.kind {
+    ///         SegmentKind::Data(segment) => (segment.mode, segment.begin..segment.end, s.bits_count),
+    ///         _ => unreachable!(),
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(segments, [(Mode::Numeric, 0..10, 48), (Mode::Alphanumeric, 10..16, 46)]);
+    /// assert_eq!((code.terminator_bits_count(), code.padding_bits_count()), (4, 30));
+    /// ```
+    pub fn segments(&self) -> &[bits::EncodedSegment] {
+        &self.segments
+    }
+
+    /// Gets the number of bits of the terminator after the segments.
+    pub const fn terminator_bits_count(&self) -> usize {
+        self.terminator_bits_count
+    }
+
+    /// Gets the number of bits of padding after the terminator, filling the
+    /// rest of the data capacity.
+    pub const fn padding_bits_count(&self) -> usize {
+        self.padding_bits_count
+    }
+
+    /// Gets the mask pattern applied to this QR code.
+    #[allow(clippy::missing_panics_doc)] // the format info is always readable since it is drawn by us.
+    pub fn mask_pattern(&self) -> MaskPattern {
+        canvas::read_format_info(self.version, &self.content).expect("valid format info").1
+    }
+
+    /// Computes the penalty scores of this QR code when each of the supported
+    /// mask patterns is applied, broken down by the rules. The mask pattern
+    /// actually applied is not necessarily the one with the lowest score, if
+    /// it was forced when constructing the QR code.
+    ///
+    /// ```
+    /// use qrcode::QrCode;
+    ///
+    /// let code = QrCode::new(b"Some data").unwrap();
+    /// let scores = code.penalty_scores();
+    /// assert_eq!(scores.len(), 8);
+    /// let best = scores.iter().min_by_key(|(_, s)| s.total()).unwrap();
+    /// assert_eq!(best.0, code.mask_pattern());
+    /// ```
+    pub fn penalty_scores(&self) -> Vec<(MaskPattern, canvas::PenaltyScores)> {
+        canvas::Canvas::from_colors(self.version, self.ec_level, &self.content)
+            .unmask(self.mask_pattern())
+            .compute_penalty_scores_of_all_masks()
+    }
+
     /// Gets the maximum number of allowed erratic modules can be introduced
     /// before the data becomes corrupted. Note that errors should not be
     /// introduced to functional modules.
@@ -210,6 +500,24 @@ impl QrCode {
         canvas::is_functional(self.version, self.version.width(), x, y)
     }
 
+    /// Decodes the QR code back into its data. This is mainly for verifying
+    /// that the encoded symbol actually reads back.
+    ///
+    /// ```
+    /// use qrcode::QrCode;
+    ///
+    /// let code = QrCode::new(b"Some data").unwrap();
+    /// assert_eq!(code.decode().unwrap().data, b"Some data");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the symbol cannot be decoded. See `decode::decode()`
+    /// for detail.
+    pub fn decode(&self) -> QrResult<decode::Decoded> {
+        decode::decode(&self.content, self.width)
+    }
+
     /// Converts the QR code into a human-readable string. This is mainly for
     /// debugging only.
     pub fn to_debug_str(&self, on_char: char, off_char: char) -> String {
@@ -266,8 +574,8 @@ impl QrCode {
     /// Note: the `image` crate itself also provides method to rotate the image,
     /// or overlay a logo on top of the QR code.
     pub fn render<P: Pixel>(&self) -> Renderer<'_, P> {
-        let quiet_zone = if self.version.is_micro() { 2 } else { 4 };
-        Renderer::new(&self.content, self.width, quiet_zone)
+        let quiet_zone = if self.version.is_micro() || self.version.is_rect_micro() { 2 } else { 4 };
+        Renderer::new_rectangular(&self.content, self.width, self.height(), quiet_zone)
     }
 }
 
@@ -282,7 +590,12 @@ impl Index<(usize, usize)> for QrCode {
 
 #[cfg(test)]
 mod tests {
-    use crate::{EcLevel, QrCode, Version};
+    use crate::bits::{Bits, SegmentKind};
+    use crate::canvas::MaskPattern;
+    use crate::payload::{EpcCharacterSet, EpcTransfer, Wifi, WifiAuthentication};
+    use crate::types::{Overflow, QrError};
+    use crate::{gs1, EcLevel, QrCode, Version};
+    use alloc::vec::Vec;
 
     #[test]
     fn test_annex_i_qr() {
@@ -336,6 +649,134 @@ mod tests {
              ###.#..##.###"
         );
     }
+
+    #[test]
+    fn test_structured_append() {
+        let data = (0..3000_u32).map(|i| (i * 31 + i / 7).to_le_bytes()[0]).collect::<Vec<_>>();
+        let codes = QrCode::structured_append(&data, EcLevel::Q, Version::Normal(15)).unwrap();
+        assert_eq!(codes.len(), 11);
+        assert!(codes.iter().all(|code| matches!(code.version(), Version::Normal(1..=15))));
+        let parity = data.iter().fold(0, |a, b| a ^ b);
+        for (code, index) in codes.iter().zip(0..) {
+            assert_eq!(code.segments()[0].kind, SegmentKind::StructuredAppend { index, total: 11, parity });
+            assert_eq!(code.segments()[0].bits_count, 20);
+        }
+        let decoded = codes.iter().flat_map(|code| code.decode().unwrap().data).collect::<Vec<_>>();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_structured_append_too_long() {
+        let data = [0_u8; 3000];
+        assert_eq!(
+            QrCode::structured_append(data, EcLevel::H, Version::Normal(5)).err(),
+            Some(QrError::DataTooLong(Overflow { bits_needed: 24544, bits_available: 5888, segment: None }))
+        );
+        assert_eq!(QrCode::structured_append(b"1", EcLevel::L, Version::Micro(4)).err(), Some(QrError::InvalidVersion));
+    }
+
+    #[test]
+    fn test_kanji() {
+        // "QRコード"
+        let code = QrCode::with_kanji("QR\u{30b3}\u{30fc}\u{30c9}", EcLevel::M).unwrap();
+        let decoded = code.decode().unwrap();
+        assert_eq!(decoded.data, b"QR\x83R\x81[\x83h");
+        assert_eq!(decoded.eci, None);
+    }
+
+    #[test]
+    fn test_hanzi() {
+        // "二维码QR"
+        let code = QrCode::with_hanzi("\u{4e8c}\u{7ef4}\u{7801}QR", EcLevel::M).unwrap();
+        let decoded = code.decode().unwrap();
+        assert_eq!(decoded.data, b"\xb6\xfe\xce\xac\xc2\xebQR");
+        assert_eq!(decoded.eci, None);
+    }
+
+    #[test]
+    fn test_gs1() {
+        let elements = [("01", "09506000134352"), ("10", "AB%1"), ("3103", "000525"), ("21", "12345678")];
+        let code = QrCode::with_gs1(&elements, EcLevel::M).unwrap();
+        assert_eq!(code.segments()[0].kind, SegmentKind::Fnc1First);
+
+        // The segments, terminator and padding fill the data capacity.
+        let bits_count = code.segments().iter().map(|s| s.bits_count).sum::<usize>();
+        let capacity = Bits::new(code.version()).max_len(EcLevel::M).unwrap();
+        assert_eq!(bits_count + code.terminator_bits_count() + code.padding_bits_count(), capacity);
+
+        let decoded = code.decode().unwrap();
+        assert_eq!(decoded.data, b"010950600013435210AB%1\x1d31030005252112345678");
+        assert_eq!(gs1::parse(&decoded.data).unwrap(), elements.map(|(ai, value)| (ai.into(), value.into())));
+    }
+
+    #[test]
+    fn test_from_payload() {
+        let wifi = Wifi {
+            ssid: "Caf\u{e9}".into(),
+            password: "correct horse".into(),
+            authentication: WifiAuthentication::Wpa,
+            hidden: false,
+        };
+        let code = QrCode::from_payload(&wifi).unwrap();
+        assert_eq!(code.error_correction_level(), EcLevel::M);
+        let decoded = code.decode().unwrap();
+        assert_eq!(decoded.data, "WIFI:T:WPA;S:Caf\u{e9};P:correct horse;;".as_bytes());
+        assert_eq!(decoded.eci, Some(26));
+
+        let transfer = EpcTransfer {
+            character_set: EpcCharacterSet::Iso8859_1,
+            name: "Caf\u{e9}".into(),
+            iban: "DE89370400440532013000".into(),
+            ..EpcTransfer::default()
+        };
+        let decoded = QrCode::from_payload(&transfer).unwrap().decode().unwrap();
+        assert_eq!(decoded.data, b"BCD\n002\n2\nSCT\n\nCaf\xe9\nDE89370400440532013000");
+        assert_eq!(decoded.eci, None);
+
+        // "Ã©" in ISO-8859-1 happens to be valid UTF-8, but is still Latin-1.
+        let transfer = EpcTransfer { name: "\u{c3}\u{a9}".into(), ..transfer };
+        let decoded = QrCode::from_payload(&transfer).unwrap().decode().unwrap();
+        assert_eq!(decoded.data, b"BCD\n002\n2\nSCT\n\n\xc3\xa9\nDE89370400440532013000");
+        assert_eq!(decoded.eci, None);
+
+        let transfer = EpcTransfer { character_set: EpcCharacterSet::Utf8, ..transfer };
+        let decoded = QrCode::from_payload(&transfer).unwrap().decode().unwrap();
+        assert_eq!(decoded.data, "BCD\n002\n1\nSCT\n\n\u{c3}\u{a9}\nDE89370400440532013000".as_bytes());
+        assert_eq!(decoded.eci, Some(26));
+    }
+
+    #[test]
+    fn test_forced_mask_pattern() {
+        let code = QrCode::with_version(b"01234567", Version::Micro(2), EcLevel::L).unwrap();
+        let scores = code.penalty_scores();
+        assert_eq!(scores.len(), 4);
+        assert!(scores.iter().all(|(_, s)| s.total() == s.light_side));
+
+        for (mask_pattern, expected_scores) in scores {
+            let mut bits = Bits::new(Version::Micro(2));
+            bits.push_optimal_data(b"01234567").unwrap();
+            bits.push_terminator(EcLevel::L).unwrap();
+            let forced = QrCode::with_bits_and_mask(bits, EcLevel::L, mask_pattern).unwrap();
+            assert_eq!(forced.mask_pattern(), mask_pattern);
+            assert_eq!(forced.decode().unwrap().data, b"01234567");
+            assert!(forced.penalty_scores().contains(&(mask_pattern, expected_scores)));
+        }
+
+        let bits = Bits::new(Version::Micro(2));
+        let res = QrCode::with_bits_and_mask(bits, EcLevel::L, MaskPattern::Checkerboard);
+        assert_eq!(res.err(), Some(QrError::UnsupportedMaskPattern));
+    }
+
+    #[test]
+    fn test_rect_micro_qr() {
+        let code = QrCode::with_version(b"01234567", Version::RectMicro(7, 43), EcLevel::M).unwrap();
+        assert_eq!((code.width(), code.height()), (43, 7));
+        assert_eq!(code.decode().unwrap().data, b"01234567");
+
+        let rendered = code.render::<char>().module_dimensions(1, 1).build();
+        assert_eq!(rendered.lines().count(), 7 + 2 * 2);
+        assert!(rendered.lines().all(|line| line.chars().count() == 43 + 2 * 2));
+    }
 }
 
 #[cfg(all(test, feature = "image"))]
@@ -370,6 +811,8 @@ mod image_tests {
 #[cfg(all(test, feature = "svg"))]
 mod svg_tests {
     use crate::render::svg::Color as SvgColor;
+    use crate::render::Renderer;
+    use crate::types::Color;
     use crate::{EcLevel, QrCode, Version};
 
     #[test]
@@ -392,6 +835,29 @@ mod svg_tests {
         let expected = include_str!("test_annex_i_micro_qr_as_svg.svg");
         assert_eq!(&image, expected);
     }
+
+    #[test]
+    fn test_swiss_cross_as_svg() {
+        let content = [Color::Dark; 46 * 46];
+        let image = Renderer::<SvgColor>::new(&content, 46, 4).module_dimensions(10, 10).swiss_cross(true).build();
+        assert!(image.contains(r#" width="540" height="540" viewBox="0 0 540 540" "#));
+
+        // The modules are clipped around the logo, which is drawn last with the
+        // light cross left out.
+        assert!(image.contains("M230 300h5v5h-5zM240 305h10v5h-10z"));
+        assert!(image.ends_with(concat!(
+            "M240 240h60v11h-60zM240 251h24v13h-24zM276 251h24v13h-24zM240 264h11v12h-11zM289 264h11v12h-11z",
+            "M240 276h24v13h-24zM276 276h24v13h-24zM240 289h60v11h-60z\"/></svg>",
+        )));
+    }
+
+    #[test]
+    fn test_swiss_qr_bill_as_svg() {
+        let content = [Color::Dark; 46 * 46];
+        let image = Renderer::<SvgColor>::new(&content, 46, 4).module_dimensions(10, 10).swiss_qr_bill().build();
+        assert!(image.contains(r#" width="46mm" height="46mm" viewBox="0 0 460 460" "#));
+        assert!(image.contains("M190 260h5v5h-5zM200 265h10v5h-10z"));
+    }
 }
 
 #[cfg(all(test, feature = "pic"))]
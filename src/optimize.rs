@@ -1,5 +1,8 @@
 //! Find the optimal data mode sequence to encode a piece of data.
+use crate::hanzi::to_gb2312;
+use crate::kanji::to_shift_jis;
 use crate::types::{Mode, Version};
+use alloc::vec::Vec;
 use core::slice::Iter;
 
 #[cfg(feature = "bench")]
@@ -26,16 +29,25 @@ impl Segment {
     /// length bits) when this segment is encoded.
     pub fn encoded_len(&self, version: Version) -> usize {
         let byte_size = self.end - self.begin;
-        let chars_count = if self.mode == Mode::Kanji { byte_size / 2 } else { byte_size };
-
-        let mode_bits_count = version.mode_bits_count();
-        let length_bits_count = self.mode.length_bits_count(version);
+        let chars_count = if is_double_byte(self.mode) { byte_size / 2 } else { byte_size };
         let data_bits_count = self.mode.data_bits_count(chars_count);
 
-        mode_bits_count + length_bits_count + data_bits_count
+        header_bits_count(self.mode, version) + data_bits_count
     }
 }
 
+/// Checks whether each character takes two bytes in the mode.
+fn is_double_byte(mode: Mode) -> bool {
+    matches!(mode, Mode::Kanji | Mode::Hanzi)
+}
+
+/// Computes the number of bits of the mode indicator, the subset indicator
+/// (Hanzi mode only) and the length bits of a segment.
+fn header_bits_count(mode: Mode, version: Version) -> usize {
+    let subset_bits_count = if mode == Mode::Hanzi { 4 } else { 0 };
+    version.mode_bits_count() + subset_bits_count + mode.length_bits_count(version)
+}
+
 //}}}
 //------------------------------------------------------------------------------
 //{{{ Parser
@@ -264,9 +276,10 @@ pub struct Optimizer<I> {
 impl<I: Iterator<Item = Segment>> Optimizer<I> {
     /// Optimize the segments by combining adjacent segments when possible.
     ///
-    /// Currently this method uses a greedy algorithm by combining segments from
-    /// left to right until the new segment is longer than before. This method
-    /// does *not* use Annex J from the ISO standard.
+    /// This method uses a greedy algorithm by combining segments from left to
+    /// right until the new segment is longer than before. This method does
+    /// *not* use Annex J from the ISO standard. Use `optimize_segments()` to
+    /// find the segmentation with the shortest encoded length instead.
     pub fn new(mut segments: I, version: Version) -> Self {
         match segments.next() {
             None => Self {
@@ -452,6 +465,241 @@ mod optimize_tests {
     }
 }
 
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Exact optimizer
+
+/// The states of a segment while computing the optimal segmentation. Each state
+/// is the mode of the segment, and the number of characters in the segment
+/// modulo the group size of the mode (3 for Numeric, 2 for Alphanumeric, and 1
+/// otherwise).
+static EXACT_STATES: [(Mode, usize); 8] = [
+    (Mode::Numeric, 0),
+    (Mode::Numeric, 1),
+    (Mode::Numeric, 2),
+    (Mode::Alphanumeric, 0),
+    (Mode::Alphanumeric, 1),
+    (Mode::Byte, 0),
+    (Mode::Kanji, 0),
+    (Mode::Hanzi, 0),
+];
+
+/// Checks whether a character exclusively belonging to `exclusive_mode` can
+/// be encoded with `mode` in the given version.
+fn can_encode(exclusive_mode: Mode, mode: Mode, version: Version) -> bool {
+    if mode == exclusive_mode {
+        return true;
+    }
+    let is_supported = match version {
+        Version::Micro(1) => mode == Mode::Numeric,
+        Version::Micro(2) => mode <= Mode::Alphanumeric,
+        Version::Micro(_) | Version::RectMicro(..) => mode != Mode::Hanzi,
+        Version::Normal(_) => true,
+    };
+    is_supported && exclusive_mode <= mode
+}
+
+/// Computes the number of data bits added by appending one character to a
+/// segment in the state `(mode, phase)`. A Kanji or Hanzi character takes two
+/// bytes when encoded in the Byte mode.
+fn character_bits_count(mode: Mode, phase: usize, exclusive_mode: Mode) -> usize {
+    match (mode, phase) {
+        (Mode::Numeric, 0) => 4,
+        (Mode::Numeric, _) => 3,
+        (Mode::Alphanumeric, 0) => 6,
+        (Mode::Alphanumeric, _) => 5,
+        (Mode::Byte, _) if is_double_byte(exclusive_mode) => 16,
+        (Mode::Byte, _) => 8,
+        (Mode::Kanji | Mode::Hanzi, _) => 13,
+    }
+}
+
+/// Finds the segmentation of the data with the shortest encoded length, as
+/// described in ISO/IEC 18004:2015 Annex J.
+///
+/// The input `segments` should be the output of `Parser`, i.e. each segment
+/// is in the mode exclusively supporting its characters. The characters are
+/// then reassigned to modes using dynamic programming, considering the cost of
+/// the mode indicator and character count indicator when starting a segment,
+/// and the cost of each character within a segment.
+///
+/// ```
+/// use qrcode::optimize::{optimize_segments, Parser, Segment};
+/// use qrcode::types::Mode::{Alphanumeric, Numeric};
+/// use qrcode::types::Version;
+///
+/// let segments = Parser::new(b"ABC123").collect::<Vec<Segment>>();
+/// assert_eq!(
+///     optimize_segments(&segments, Version::Normal(1)),
+///     &[Segment { mode: Alphanumeric, begin: 0, end: 6 }]
+/// );
+/// ```
+pub fn optimize_segments(segments: &[Segment], version: Version) -> Vec<Segment> {
+    optimize_segments_impl(segments, version, true)
+}
+
+/// Finds the segmentation with the shortest encoded length like
+/// `optimize_segments()`, but keeping every Kanji or Hanzi character in its
+/// own mode.
+///
+/// This should be used with the output of `parse_str()` or
+/// `parse_hanzi_str()`, where the Kanji or Hanzi segments are converted from
+/// UTF-8 and thus cannot be encoded as bytes.
+pub fn optimize_str_segments(segments: &[Segment], version: Version) -> Vec<Segment> {
+    optimize_segments_impl(segments, version, false)
+}
+
+fn optimize_segments_impl(segments: &[Segment], version: Version, double_byte_as_byte: bool) -> Vec<Segment> {
+    // Each character is represented by its byte range and exclusive mode.
+    let chars = segments
+        .iter()
+        .flat_map(|seg| {
+            let step = if is_double_byte(seg.mode) { 2 } else { 1 };
+            (seg.begin..seg.end).step_by(step).map(move |i| (i, i + step, seg.mode))
+        })
+        .collect::<Vec<_>>();
+
+    // `costs[s]` is the minimum number of bits to encode the characters so far
+    // and end in the state `EXACT_STATES[s]`. `links[i][s]` records the
+    // previous state leading to this minimum, and whether the i-th character
+    // starts a new segment.
+    let mut costs = [usize::MAX; 8];
+    let mut links = Vec::with_capacity(chars.len());
+
+    for (i, &(_, _, exclusive_mode)) in chars.iter().enumerate() {
+        let (best_state, best_cost) =
+            costs.iter().copied().enumerate().min_by_key(|(_, cost)| *cost).filter(|_| i > 0).unwrap_or((0, 0));
+
+        let mut new_costs = [usize::MAX; 8];
+        let mut new_links = [(0, false); 8];
+        for (s, &(mode, phase)) in EXACT_STATES.iter().enumerate() {
+            if !can_encode(exclusive_mode, mode, version)
+                || (!double_byte_as_byte && is_double_byte(exclusive_mode) && mode != exclusive_mode)
+            {
+                continue;
+            }
+            let group_size = match mode {
+                Mode::Numeric => 3,
+                Mode::Alphanumeric => 2,
+                Mode::Byte | Mode::Kanji | Mode::Hanzi => 1,
+            };
+            let prev_phase = (phase + group_size - 1) % group_size;
+            let char_bits = character_bits_count(mode, prev_phase, exclusive_mode);
+
+            // Starts a new segment with this character.
+            if phase == 1 % group_size {
+                let header_bits = header_bits_count(mode, version);
+                new_costs[s] = best_cost + header_bits + char_bits;
+                new_links[s] = (best_state, true);
+            }
+
+            // Continues the segment from the previous character.
+            if i > 0 {
+                let prev_state = s + prev_phase - phase;
+                if costs[prev_state] != usize::MAX && costs[prev_state] + char_bits < new_costs[s] {
+                    new_costs[s] = costs[prev_state] + char_bits;
+                    new_links[s] = (prev_state, false);
+                }
+            }
+        }
+
+        costs = new_costs;
+        links.push(new_links);
+    }
+
+    // Trace back the optimal choices, and merge the characters into segments.
+    let Some((mut state, _)) = costs.iter().enumerate().min_by_key(|(_, cost)| **cost) else {
+        return Vec::new();
+    };
+    let mut choices = Vec::with_capacity(chars.len());
+    for link in links.iter().rev() {
+        let (prev_state, is_new_segment) = link[state];
+        choices.push((EXACT_STATES[state].0, is_new_segment));
+        state = prev_state;
+    }
+
+    let mut result: Vec<Segment> = Vec::new();
+    for (&(begin, end, _), (mode, is_new_segment)) in chars.iter().zip(choices.into_iter().rev()) {
+        match result.last_mut() {
+            Some(last) if !is_new_segment => last.end = end,
+            _ => result.push(Segment { mode, begin, end }),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod exact_optimize_tests {
+    use crate::bits::Bits;
+    use crate::optimize::{optimize_segments, total_encoded_len, Optimizer, Parser, Segment};
+    use crate::types::{EcLevel, Mode, QrError, Version};
+    use crate::QrCode;
+    use alloc::vec::Vec;
+
+    fn test_exact_result(data: &[u8], expected: &[Segment], version: Version) {
+        let segments = Parser::new(data).collect::<Vec<_>>();
+        let greedy = Optimizer::new(segments.iter().copied(), version).collect::<Vec<_>>();
+        let exact = optimize_segments(&segments, version);
+        assert_eq!(exact, expected);
+        assert!(total_encoded_len(&exact, version) <= total_encoded_len(&greedy, version));
+    }
+
+    #[test]
+    fn test_empty() {
+        test_exact_result(b"", &[], Version::Normal(1));
+    }
+
+    #[test]
+    fn test_annex_j_guidelines() {
+        test_exact_result(
+            b"123A",
+            &[
+                Segment { mode: Mode::Numeric, begin: 0, end: 3 },
+                Segment { mode: Mode::Alphanumeric, begin: 3, end: 4 },
+            ],
+            Version::Micro(2),
+        );
+        test_exact_result(b"12AB", &[Segment { mode: Mode::Alphanumeric, begin: 0, end: 4 }], Version::Micro(2));
+        test_exact_result(b"123A", &[Segment { mode: Mode::Alphanumeric, begin: 0, end: 4 }], Version::Micro(3));
+    }
+
+    #[test]
+    fn test_kanji_as_byte() {
+        test_exact_result(
+            b"\x93\x5f\x93\x5fA\xff\x93\x5f",
+            &[Segment { mode: Mode::Byte, begin: 0, end: 8 }],
+            Version::Normal(1),
+        );
+        test_exact_result(
+            b"\x93\x5f\x93\x5f\x93\x5f\x93\x5f\x93\x5f\xff",
+            &[Segment { mode: Mode::Kanji, begin: 0, end: 10 }, Segment { mode: Mode::Byte, begin: 10, end: 11 }],
+            Version::Normal(1),
+        );
+    }
+
+    #[test]
+    fn test_better_than_greedy() {
+        test_exact_result(
+            b"1111Aa",
+            &[Segment { mode: Mode::Numeric, begin: 0, end: 4 }, Segment { mode: Mode::Byte, begin: 4, end: 6 }],
+            Version::Normal(1),
+        );
+    }
+
+    #[test]
+    fn test_unsupported_modes_in_micro_qr() {
+        // M1 only supports the numeric mode, so the data cannot be encoded
+        // however it is segmented.
+        let segments = optimize_segments(&Parser::new(b"1a").collect::<Vec<_>>(), Version::Micro(1));
+        let mut bits = Bits::new(Version::Micro(1));
+        assert_eq!(bits.push_segments(b"1a", segments.into_iter()), Err(QrError::UnsupportedCharacterSet));
+        assert_eq!(
+            QrCode::with_version(b"1a", Version::Micro(1), EcLevel::L).err(),
+            Some(QrError::UnsupportedCharacterSet)
+        );
+    }
+}
+
 #[cfg(feature = "bench")]
 #[bench]
 fn bench_optimize(bencher: &mut test::Bencher) {
@@ -478,6 +726,171 @@ fn bench_optimize(bencher: &mut test::Bencher) {
     bencher.iter(|| Parser::new(data).optimize(Version::Normal(15)));
 }
 
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Unicode text
+
+/// Converts the text to the bytes to be encoded, and classifies the bytes into
+/// segments like `Parser`.
+///
+/// Characters in JIS X 0208 are converted to Shift JIS and put into Kanji
+/// segments, while all other characters are kept as UTF-8. Since the Kanji
+/// segments are no longer UTF-8, the segments should be optimized using
+/// `optimize_str_segments()`.
+///
+/// ```
+/// use qrcode::optimize::{parse_str, Segment};
+/// use qrcode::types::Mode::{Alphanumeric, Byte, Kanji};
+///
+/// let (bytes, segments) = parse_str("\u{70b9}\u{8317}AB\u{e9}");
+/// assert_eq!(bytes, b"\x93\x5f\xe4\xaaAB\xc3\xa9");
+/// assert_eq!(
+///     segments,
+///     &[
+///         Segment { mode: Kanji, begin: 0, end: 4 },
+///         Segment { mode: Alphanumeric, begin: 4, end: 6 },
+///         Segment { mode: Byte, begin: 6, end: 8 }
+///     ]
+/// );
+/// ```
+pub fn parse_str(data: &str) -> (Vec<u8>, Vec<Segment>) {
+    parse_str_with(data, Mode::Kanji, to_shift_jis)
+}
+
+/// Converts the text to the bytes to be encoded, and classifies the bytes into
+/// segments like `Parser`.
+///
+/// Characters in GB 2312 are converted to EUC-CN and put into Hanzi segments,
+/// while all other characters are kept as UTF-8. Since the Hanzi segments are
+/// no longer UTF-8, the segments should be optimized using
+/// `optimize_str_segments()`.
+///
+/// ```
+/// use qrcode::optimize::{parse_hanzi_str, Segment};
+/// use qrcode::types::Mode::{Hanzi, Numeric};
+///
+/// let (bytes, segments) = parse_hanzi_str("\u{4e2d}\u{6587}123");
+/// assert_eq!(bytes, b"\xd6\xd0\xce\xc4123");
+/// assert_eq!(segments, &[Segment { mode: Hanzi, begin: 0, end: 4 }, Segment { mode: Numeric, begin: 4, end: 7 }]);
+/// ```
+pub fn parse_hanzi_str(data: &str) -> (Vec<u8>, Vec<Segment>) {
+    parse_str_with(data, Mode::Hanzi, to_gb2312)
+}
+
+/// Converts the runs of characters supported by `convert` into double-byte
+/// segments of `mode`, and the rest into UTF-8.
+fn parse_str_with(data: &str, mode: Mode, convert: fn(char) -> Option<u16>) -> (Vec<u8>, Vec<Segment>) {
+    let mut bytes = Vec::with_capacity(data.len());
+    let mut segments = Vec::new();
+    let mut rest = data;
+    while let Some(first) = rest.chars().next() {
+        let is_converted = convert(first).is_some();
+        let run_len = rest.find(|c| convert(c).is_some() != is_converted).unwrap_or(rest.len());
+        let (run, next) = rest.split_at(run_len);
+        let begin = bytes.len();
+        if is_converted {
+            bytes.extend(run.chars().filter_map(convert).flat_map(u16::to_be_bytes));
+            segments.push(Segment { mode, begin, end: bytes.len() });
+        } else {
+            // UTF-8 sequences may look like Shift JIS, so they are never
+            // classified as Kanji.
+            bytes.extend_from_slice(run.as_bytes());
+            push_non_kanji_segments(&mut segments, run.as_bytes(), begin);
+        }
+        rest = next;
+    }
+    (bytes, segments)
+}
+
+/// Classifies the bytes into segments like `Parser`, but never in the Kanji
+/// mode, for data which is not Shift JIS (e.g. UTF-8 text).
+pub(crate) fn parse_non_kanji(data: &[u8]) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    push_non_kanji_segments(&mut segments, data, 0);
+    segments
+}
+
+/// Appends the segments of the bytes starting at `begin`, classifying the
+/// would-be Kanji characters as bytes.
+fn push_non_kanji_segments(segments: &mut Vec<Segment>, data: &[u8], begin: usize) {
+    for seg in Parser::new(data) {
+        let mode = if seg.mode == Mode::Kanji { Mode::Byte } else { seg.mode };
+        match segments.last_mut() {
+            Some(last) if last.mode == mode && last.end == begin + seg.begin => last.end = begin + seg.end,
+            _ => segments.push(Segment { mode, begin: begin + seg.begin, end: begin + seg.end }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod str_tests {
+    use crate::optimize::{
+        optimize_segments, optimize_str_segments, parse_hanzi_str, parse_str, total_encoded_len, Segment,
+    };
+    use crate::types::{Mode, Version};
+
+    #[test]
+    fn test_parse_str() {
+        // "QRコード1994"
+        let (bytes, segments) = parse_str("QR\u{30b3}\u{30fc}\u{30c9}1994");
+        assert_eq!(bytes, b"QR\x83R\x81[\x83h1994");
+        assert_eq!(
+            segments,
+            &[
+                Segment { mode: Mode::Alphanumeric, begin: 0, end: 2 },
+                Segment { mode: Mode::Kanji, begin: 2, end: 8 },
+                Segment { mode: Mode::Numeric, begin: 8, end: 12 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_str_utf_8_not_kanji() {
+        // "\u{e3}\u{81}" in UTF-8 looks like a Shift JIS character.
+        let (bytes, segments) = parse_str("\u{e3}\u{81}\u{1f600}");
+        assert_eq!(bytes, "\u{e3}\u{81}\u{1f600}".as_bytes());
+        assert_eq!(segments, &[Segment { mode: Mode::Byte, begin: 0, end: 8 }]);
+    }
+
+    #[test]
+    fn test_optimize_str_segments() {
+        // "点a点", where the Kanji would be cheaper as bytes if it were Shift JIS.
+        let (_, segments) = parse_str("\u{70b9}a\u{70b9}");
+        assert_eq!(optimize_segments(&segments, Version::Normal(1)), &[Segment { mode: Mode::Byte, begin: 0, end: 5 }]);
+        assert_eq!(
+            optimize_str_segments(&segments, Version::Normal(1)),
+            &[
+                Segment { mode: Mode::Kanji, begin: 0, end: 2 },
+                Segment { mode: Mode::Byte, begin: 2, end: 3 },
+                Segment { mode: Mode::Kanji, begin: 3, end: 5 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_hanzi_str() {
+        // "二维码QR"
+        let (bytes, segments) = parse_hanzi_str("\u{4e8c}\u{7ef4}\u{7801}QR");
+        assert_eq!(bytes, b"\xb6\xfe\xce\xac\xc2\xebQR");
+        assert_eq!(
+            segments,
+            &[Segment { mode: Mode::Hanzi, begin: 0, end: 6 }, Segment { mode: Mode::Alphanumeric, begin: 6, end: 8 }]
+        );
+        assert_eq!(total_encoded_len(&segments, Version::Normal(1)), (4 + 4 + 8 + 39) + (4 + 9 + 11));
+    }
+
+    #[test]
+    fn test_optimize_hanzi() {
+        // "二a", where the Hanzi would be cheaper as bytes if it were EUC-CN.
+        let (_, segments) = parse_hanzi_str("\u{4e8c}a");
+        assert_eq!(optimize_segments(&segments, Version::Normal(1)), &[Segment { mode: Mode::Byte, begin: 0, end: 3 }]);
+        assert_eq!(
+            optimize_str_segments(&segments, Version::Normal(1)),
+            &[Segment { mode: Mode::Hanzi, begin: 0, end: 2 }, Segment { mode: Mode::Byte, begin: 2, end: 3 }]
+        );
+    }
+}
+
 //}}}
 //------------------------------------------------------------------------------
 //{{{ Internal types and data for parsing
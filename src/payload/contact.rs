@@ -0,0 +1,319 @@
+//! Contact information, in the vCard 3.0 (RFC 2426), vCard 4.0 (RFC 6350) or
+//! MECARD format.
+
+use crate::bits::encode_auto_utf8;
+use crate::payload::{escape_text, push_backslash_escaped, push_folded_line, Payload};
+use crate::types::{QrError, QrResult};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The text format of a contact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContactFormat {
+    /// vCard 3.0, the most widely supported format.
+    #[default]
+    VCard3,
+
+    /// vCard 4.0.
+    VCard4,
+
+    /// MECARD, a compact format introduced by NTT Docomo.
+    MeCard,
+
+    /// Whichever of the above formats yields the smallest QR code. vCard 3.0
+    /// is preferred if several formats yield the same version.
+    Smallest,
+}
+
+/// A postal address of a contact.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Address {
+    /// The street address.
+    pub street: String,
+
+    /// The locality, e.g. the city.
+    pub locality: String,
+
+    /// The region, e.g. the state or province.
+    pub region: String,
+
+    /// The postal code.
+    pub postal_code: String,
+
+    /// The country name.
+    pub country: String,
+}
+
+/// The contact information of a person or organization, e.g. for a business
+/// card.
+///
+/// Empty fields are omitted from the payload. At least one of the names or
+/// the organization must be given.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Contact {
+    /// The given (first) name.
+    pub given_name: String,
+
+    /// The family (last) name.
+    pub family_name: String,
+
+    /// The organization name.
+    pub organization: String,
+
+    /// The telephone numbers.
+    pub phones: Vec<String>,
+
+    /// The email addresses.
+    pub emails: Vec<String>,
+
+    /// The postal address.
+    pub address: Option<Address>,
+
+    /// The website.
+    pub url: String,
+
+    /// A free-form note.
+    pub note: String,
+
+    /// The text format of the payload.
+    pub format: ContactFormat,
+}
+
+impl Contact {
+    /// The formatted name of the contact.
+    fn formatted_name(&self) -> String {
+        let name = [self.given_name.as_str(), self.family_name.as_str()]
+            .iter()
+            .filter(|n| !n.is_empty())
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ");
+        if name.is_empty() {
+            self.organization.clone()
+        } else {
+            name
+        }
+    }
+
+    /// Renders the contact as vCard of the given version.
+    fn to_vcard(&self, version: &str) -> String {
+        let mut output = String::new();
+        output.push_str("BEGIN:VCARD\r\nVERSION:");
+        output.push_str(version);
+        output.push_str("\r\n");
+
+        let name = [escape_text(&self.family_name), escape_text(&self.given_name)].join(";");
+        push_folded_line(&mut output, "N", &[name.as_str(), ";;;"].concat());
+        push_folded_line(&mut output, "FN", &escape_text(&self.formatted_name()));
+        if !self.organization.is_empty() {
+            push_folded_line(&mut output, "ORG", &escape_text(&self.organization));
+        }
+        for phone in &self.phones {
+            push_folded_line(&mut output, "TEL", &escape_text(phone));
+        }
+        for email in &self.emails {
+            push_folded_line(&mut output, "EMAIL", &escape_text(email));
+        }
+        if let Some(address) = &self.address {
+            let components =
+                [&address.street, &address.locality, &address.region, &address.postal_code, &address.country];
+            let value = components.iter().map(|c| escape_text(c)).collect::<Vec<_>>().join(";");
+            push_folded_line(&mut output, "ADR", &[";;", value.as_str()].concat());
+        }
+        // URL is a URI value rather than text, so it is not escaped.
+        if !self.url.is_empty() {
+            push_folded_line(&mut output, "URL", &self.url);
+        }
+        if !self.note.is_empty() {
+            push_folded_line(&mut output, "NOTE", &escape_text(&self.note));
+        }
+        output.push_str("END:VCARD");
+        output
+    }
+
+    /// Renders the contact as MECARD.
+    fn to_mecard(&self) -> String {
+        const SPECIAL_CHARS: &str = ":;";
+
+        // The components of the name and the address are separated by commas,
+        // which most readers split before unescaping. So the commas inside the
+        // components are replaced instead of escaped.
+        let mut output = String::from("MECARD:N:");
+        if self.given_name.is_empty() && self.family_name.is_empty() {
+            push_backslash_escaped(&mut output, &self.organization.replace(',', " "), SPECIAL_CHARS);
+        } else {
+            push_backslash_escaped(&mut output, &self.family_name.replace(',', " "), SPECIAL_CHARS);
+            if !self.given_name.is_empty() {
+                output.push(',');
+                push_backslash_escaped(&mut output, &self.given_name.replace(',', " "), SPECIAL_CHARS);
+            }
+        }
+        output.push(';');
+
+        let mut push_field = |name: &str, value: &str| {
+            if !value.is_empty() {
+                output.push_str(name);
+                output.push(':');
+                push_backslash_escaped(&mut output, value, SPECIAL_CHARS);
+                output.push(';');
+            }
+        };
+        push_field("ORG", &self.organization);
+        for phone in &self.phones {
+            push_field("TEL", phone);
+        }
+        for email in &self.emails {
+            push_field("EMAIL", email);
+        }
+        if let Some(address) = &self.address {
+            let components =
+                [&address.street, &address.locality, &address.region, &address.postal_code, &address.country];
+            let mut value = String::from(",,");
+            for (i, component) in components.iter().enumerate() {
+                if i > 0 {
+                    value.push(',');
+                }
+                value.push_str(&component.replace(',', " "));
+            }
+            push_field("ADR", &value);
+        }
+        push_field("URL", &self.url);
+        push_field("NOTE", &self.note);
+        output.push(';');
+        output
+    }
+}
+
+impl Payload for Contact {
+    /// Renders the contact in the selected format.
+    ///
+    /// ```
+    /// use qrcode::payload::{Contact, ContactFormat, Payload};
+    ///
+    /// let contact = Contact {
+    ///     given_name: "Jane".into(),
+    ///     family_name: "Doe".into(),
+    ///     phones: vec!["+1 555 0100".into()],
+    ///     format: ContactFormat::MeCard,
+    ///     ..Contact::default()
+    /// };
+    /// assert_eq!(contact.to_payload_string().unwrap(), "MECARD:N:Doe,Jane;TEL:+1 555 0100;;");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if the names and the organization
+    /// are all empty.
+    fn to_payload_string(&self) -> QrResult<String> {
+        if self.given_name.is_empty() && self.family_name.is_empty() && self.organization.is_empty() {
+            return Err(QrError::InvalidData);
+        }
+        Ok(match self.format {
+            ContactFormat::VCard3 => self.to_vcard("3.0"),
+            ContactFormat::VCard4 => self.to_vcard("4.0"),
+            ContactFormat::MeCard => self.to_mecard(),
+            ContactFormat::Smallest => {
+                let ec_level = self.ec_level();
+                let candidates = [self.to_vcard("3.0"), self.to_vcard("4.0"), self.to_mecard()];
+                let width = |text: &String| match encode_auto_utf8(text.as_bytes(), ec_level) {
+                    Ok(bits) => bits.version().width(),
+                    Err(_) => i16::MAX,
+                };
+                let smallest = candidates.iter().map(width).enumerate().min_by_key(|&(_, w)| w).map_or(0, |(i, _)| i);
+                candidates[smallest].clone()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod contact_tests {
+    use crate::payload::{Address, Contact, ContactFormat, Payload};
+    use crate::types::QrError;
+    use alloc::vec;
+
+    fn contact(format: ContactFormat) -> Contact {
+        Contact {
+            given_name: "Jane".into(),
+            family_name: "Doe".into(),
+            organization: "Example, Inc.".into(),
+            phones: vec!["+1 555 0100".into(), "+1 555 0199".into()],
+            emails: vec!["jane@example.com".into()],
+            address: Some(Address {
+                street: "1 Main St".into(),
+                locality: "Springfield".into(),
+                region: "IL".into(),
+                postal_code: "62701".into(),
+                country: "USA".into(),
+            }),
+            url: "https://example.com".into(),
+            note: "Line 1\nLine 2; more".into(),
+            format,
+        }
+    }
+
+    #[test]
+    fn test_vcard3() {
+        assert_eq!(
+            contact(ContactFormat::VCard3).to_payload_string().unwrap(),
+            "BEGIN:VCARD\r\n\
+             VERSION:3.0\r\n\
+             N:Doe;Jane;;;\r\n\
+             FN:Jane Doe\r\n\
+             ORG:Example\\, Inc.\r\n\
+             TEL:+1 555 0100\r\n\
+             TEL:+1 555 0199\r\n\
+             EMAIL:jane@example.com\r\n\
+             ADR:;;1 Main St;Springfield;IL;62701;USA\r\n\
+             URL:https://example.com\r\n\
+             NOTE:Line 1\\nLine 2\\; more\r\n\
+             END:VCARD"
+        );
+    }
+
+    #[test]
+    fn test_vcard4() {
+        let contact = Contact { organization: "ACME".into(), format: ContactFormat::VCard4, ..Contact::default() };
+        assert_eq!(
+            contact.to_payload_string().unwrap(),
+            "BEGIN:VCARD\r\nVERSION:4.0\r\nN:;;;;\r\nFN:ACME\r\nORG:ACME\r\nEND:VCARD"
+        );
+    }
+
+    #[test]
+    fn test_mecard() {
+        assert_eq!(
+            contact(ContactFormat::MeCard).to_payload_string().unwrap(),
+            "MECARD:N:Doe,Jane;ORG:Example, Inc.;TEL:+1 555 0100;TEL:+1 555 0199;EMAIL:jane@example.com;\
+             ADR:,,1 Main St,Springfield,IL,62701,USA;URL:https\\://example.com;\
+             NOTE:Line 1\nLine 2\\; more;;"
+        );
+    }
+
+    #[test]
+    fn test_vcard_url() {
+        let contact = Contact { url: "https://example.com/map?q=1,2;z=3".into(), ..contact(ContactFormat::VCard4) };
+        let expected = "\r\nURL:https://example.com/map?q=1,2;z=3\r\n";
+        assert!(contact.to_payload_string().unwrap().contains(expected));
+    }
+
+    #[test]
+    fn test_line_folding() {
+        // "NOTE:" + 35 × "é" takes 75 octets, and the rest continues on the
+        // next line after a space.
+        let contact = Contact { note: "\u{e9}".repeat(40), ..contact(ContactFormat::VCard3) };
+        let expected = ["\r\nNOTE:", &"\u{e9}".repeat(35), "\r\n ", &"\u{e9}".repeat(5), "\r\n"].concat();
+        assert!(contact.to_payload_string().unwrap().contains(&expected));
+    }
+
+    #[test]
+    fn test_smallest() {
+        let payload = contact(ContactFormat::Smallest).to_payload_string().unwrap();
+        assert!(payload.starts_with("MECARD:"));
+    }
+
+    #[test]
+    fn test_empty_name() {
+        assert_eq!(Contact::default().to_payload_string(), Err(QrError::InvalidData));
+    }
+}
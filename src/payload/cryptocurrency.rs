@@ -0,0 +1,341 @@
+//! Cryptocurrency payment requests: Bitcoin (`bitcoin:`, BIP 21), Ethereum
+//! (`ethereum:`, EIP-681) and Lightning invoices (`lightning:`, BOLT 11).
+//!
+//! Bech32 strings, e.g. `bc1…` addresses and Lightning invoices, are
+//! case-insensitive, so they are upper-cased together with the URI scheme to be
+//! encoded in the alphanumeric mode, yielding a smaller QR code. Other
+//! addresses are kept verbatim, since their case may be significant, e.g. the
+//! base58 Bitcoin addresses or the EIP-55 checksummed Ethereum addresses.
+
+use crate::payload::{push_percent_encoded, Payload};
+use crate::types::{QrError, QrResult};
+use alloc::string::String;
+use core::fmt::Write;
+
+//------------------------------------------------------------------------------
+//{{{ Bech32
+
+/// The data characters of Bech32, in the order of their values.
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Checks whether the string is a valid Bech32 (BIP 173) or Bech32m (BIP 350)
+/// string, in either all lowercase or all uppercase. The length is not limited
+/// to 90 characters, as Lightning invoices are longer.
+fn is_bech32(s: &str) -> bool {
+    const GENERATORS: [u32; 5] = [0x3b6a_57b2, 0x2650_8e6d, 0x1ea1_19fa, 0x3d42_33dd, 0x2a14_62b3];
+    const BECH32_CONSTANT: u32 = 1;
+    const BECH32M_CONSTANT: u32 = 0x2bc8_30a3;
+
+    let has_lowercase = s.bytes().any(|c| c.is_ascii_lowercase());
+    let has_uppercase = s.bytes().any(|c| c.is_ascii_uppercase());
+    let Some((hrp, data)) = s.rsplit_once('1') else {
+        return false;
+    };
+    if (has_lowercase && has_uppercase)
+        || hrp.is_empty()
+        || data.len() < 6
+        || !hrp.bytes().all(|c| (33..=126).contains(&c))
+    {
+        return false;
+    }
+
+    let mut checksum = 1_u32;
+    let mut push = |value: u8| {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x01ff_ffff) << 5) ^ u32::from(value);
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if (top >> i) & 1 != 0 {
+                checksum ^= generator;
+            }
+        }
+    };
+    let hrp = hrp.bytes().map(|c| c.to_ascii_lowercase());
+    hrp.clone().for_each(|c| push(c >> 5));
+    push(0);
+    hrp.for_each(|c| push(c & 31));
+    for c in data.bytes() {
+        match (0..).zip(BECH32_CHARSET).find(|(_, &d)| d == c.to_ascii_lowercase()) {
+            Some((value, _)) => push(value),
+            None => return false,
+        }
+    }
+    checksum == BECH32_CONSTANT || checksum == BECH32M_CONSTANT
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Bitcoin
+
+/// A Bitcoin payment request.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Bitcoin {
+    /// The address of the recipient.
+    pub address: String,
+
+    /// The amount in satoshis, if requested.
+    pub amount_in_satoshis: Option<u64>,
+
+    /// The label of the recipient. May be empty.
+    pub label: String,
+
+    /// The message describing the payment. May be empty.
+    pub message: String,
+
+    /// A Lightning invoice as a fallback (BIP 21 "unified QR"). May be empty.
+    pub lightning: String,
+}
+
+impl Payload for Bitcoin {
+    /// Renders the request as `bitcoin:<address>?amount=<amount>&…`, with the
+    /// amount in BTC.
+    ///
+    /// ```
+    /// use qrcode::payload::{Bitcoin, Payload};
+    ///
+    /// let bitcoin = Bitcoin {
+    ///     address: "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4".into(),
+    ///     amount_in_satoshis: Some(50_000),
+    ///     label: "Luke-Jr".into(),
+    ///     ..Bitcoin::default()
+    /// };
+    /// assert_eq!(
+    ///     bitcoin.to_payload_string().unwrap(),
+    ///     "BITCOIN:BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4?amount=0.0005&label=Luke-Jr",
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if the address is empty or not
+    /// alphanumeric, the amount exceeds 21 million BTC, or the Lightning
+    /// invoice is not a valid Bech32 string.
+    fn to_payload_string(&self) -> QrResult<String> {
+        let is_valid = !self.address.is_empty()
+            && self.address.bytes().all(|c| c.is_ascii_alphanumeric())
+            && self.amount_in_satoshis.map_or(true, |amount| amount <= 21_000_000 * 100_000_000)
+            && (self.lightning.is_empty() || is_bech32(&self.lightning));
+        if !is_valid {
+            return Err(QrError::InvalidData);
+        }
+
+        let mut output = if is_bech32(&self.address) {
+            ["BITCOIN:", &self.address.to_ascii_uppercase()].concat()
+        } else {
+            ["bitcoin:", &self.address].concat()
+        };
+        let mut separator = '?';
+        let mut push_param = |output: &mut String, name: &str| {
+            output.push(separator);
+            output.push_str(name);
+            output.push('=');
+            separator = '&';
+        };
+        if let Some(amount) = self.amount_in_satoshis {
+            push_param(&mut output, "amount");
+            let _ = write!(output, "{}.{:08}", amount / 100_000_000, amount % 100_000_000);
+            output.truncate(output.trim_end_matches('0').trim_end_matches('.').len());
+        }
+        for (name, value) in [("label", &self.label), ("message", &self.message)] {
+            if !value.is_empty() {
+                push_param(&mut output, name);
+                push_percent_encoded(&mut output, value);
+            }
+        }
+        if !self.lightning.is_empty() {
+            push_param(&mut output, "lightning");
+            output.push_str(&self.lightning.to_ascii_uppercase());
+        }
+        Ok(output)
+    }
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Lightning
+
+/// A Lightning invoice (BOLT 11), or another Bech32-encoded Lightning request
+/// such as LNURL.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Lightning {
+    /// The invoice, e.g. `lnbc1…`.
+    pub invoice: String,
+}
+
+impl Payload for Lightning {
+    /// Renders the invoice as `LIGHTNING:<invoice>`, in uppercase.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if the invoice is not a valid
+    /// Bech32 string starting with `ln`.
+    fn to_payload_string(&self) -> QrResult<String> {
+        let invoice = self.invoice.to_ascii_uppercase();
+        if !invoice.starts_with("LN") || !is_bech32(&self.invoice) {
+            return Err(QrError::InvalidData);
+        }
+        Ok(["LIGHTNING:", &invoice].concat())
+    }
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Ethereum
+
+/// An Ethereum payment request, either of ether or of an ERC-20 token.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Ethereum {
+    /// The address of the recipient, `0x` followed by 40 hex digits. The case
+    /// is kept, as it may carry the EIP-55 checksum.
+    pub address: String,
+
+    /// The chain ID, e.g. 1 for the mainnet. `None` for the chain currently
+    /// selected in the wallet.
+    pub chain_id: Option<u64>,
+
+    /// The amount in the smallest unit: wei for ether, or the base unit of
+    /// the token.
+    pub amount: Option<u128>,
+
+    /// The address of the ERC-20 token contract, or empty for ether.
+    pub token: String,
+}
+
+/// Checks whether the string is an Ethereum address.
+fn is_ethereum_address(address: &str) -> bool {
+    address.len() == 42 && address.starts_with("0x") && address[2..].bytes().all(|c| c.is_ascii_hexdigit())
+}
+
+impl Payload for Ethereum {
+    /// Renders the request as `ethereum:<address>[@<chain id>]?value=<amount>`,
+    /// or for a token, as
+    /// `ethereum:<token>[@<chain id>]/transfer?address=<address>&uint256=<amount>`.
+    ///
+    /// ```
+    /// use qrcode::payload::{Ethereum, Payload};
+    ///
+    /// let ethereum = Ethereum {
+    ///     address: "0xfb6916095ca1df60bB79Ce92cE3Ea74c37c5d359".into(),
+    ///     amount: Some(2_014_000_000_000_000_000),
+    ///     ..Ethereum::default()
+    /// };
+    /// assert_eq!(
+    ///     ethereum.to_payload_string().unwrap(),
+    ///     "ethereum:0xfb6916095ca1df60bB79Ce92cE3Ea74c37c5d359?value=2014000000000000000",
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if the address or the token is not
+    /// a valid Ethereum address, or a token transfer has no amount.
+    fn to_payload_string(&self) -> QrResult<String> {
+        let is_valid = is_ethereum_address(&self.address)
+            && (self.token.is_empty() || (is_ethereum_address(&self.token) && self.amount.is_some()));
+        if !is_valid {
+            return Err(QrError::InvalidData);
+        }
+
+        let mut output = String::from("ethereum:");
+        output.push_str(if self.token.is_empty() { &self.address } else { &self.token });
+        if let Some(chain_id) = self.chain_id {
+            let _ = write!(output, "@{chain_id}");
+        }
+        if self.token.is_empty() {
+            if let Some(amount) = self.amount {
+                let _ = write!(output, "?value={amount}");
+            }
+        } else {
+            output.push_str("/transfer?address=");
+            output.push_str(&self.address);
+            if let Some(amount) = self.amount {
+                let _ = write!(output, "&uint256={amount}");
+            }
+        }
+        Ok(output)
+    }
+}
+
+//}}}
+
+#[cfg(test)]
+mod cryptocurrency_tests {
+    use crate::optimize::{Parser, Segment};
+    use crate::payload::cryptocurrency::is_bech32;
+    use crate::payload::{Bitcoin, Ethereum, Lightning, Payload};
+    use crate::types::{Mode, QrError, Version};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    const INVOICE: &str = "lnbc1pvjluezpp5qqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqypqdpl2pkx2ctnv5sxxmmwwd\
+                           5kgetjypeh2ursdae8g6twvus8g6rfwvs8qun0dfjkxaq8rkx3yf5tcsyz3d73gafnh3cax9rn449d9p5uxz9ezh\
+                           hypd0elx87sjle52x86fux2ypatgddc6k63n7erqz25le42c4u4ecky03ylcqca784w";
+
+    #[test]
+    fn test_bech32() {
+        assert!(is_bech32("BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4"));
+        assert!(is_bech32("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"));
+        assert!(is_bech32("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0"));
+        assert!(is_bech32(INVOICE));
+
+        assert!(!is_bech32("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5"));
+        assert!(!is_bech32("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7KV8F3T4"));
+        assert!(!is_bech32("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2"));
+        assert!(!is_bech32("bc1"));
+    }
+
+    #[test]
+    fn test_bitcoin() {
+        let bitcoin = Bitcoin {
+            address: "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2".into(),
+            amount_in_satoshis: Some(2_100_000_000_000_000),
+            message: "Donation for project xyz".into(),
+            lightning: INVOICE.into(),
+            ..Bitcoin::default()
+        };
+        let expected = "bitcoin:1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2?amount=21000000&\
+                        message=Donation%20for%20project%20xyz&lightning=";
+        let payload = bitcoin.to_payload_string().unwrap();
+        assert_eq!(payload, [expected, &INVOICE.to_ascii_uppercase()].concat());
+
+        let too_much = Bitcoin { amount_in_satoshis: Some(2_100_000_000_000_001), ..bitcoin.clone() };
+        assert_eq!(too_much.to_payload_string(), Err(QrError::InvalidData));
+        let bad_invoice = Bitcoin { lightning: INVOICE.replace('w', "q"), ..bitcoin.clone() };
+        assert_eq!(bad_invoice.to_payload_string(), Err(QrError::InvalidData));
+        let no_address = Bitcoin { address: String::new(), ..bitcoin };
+        assert_eq!(no_address.to_payload_string(), Err(QrError::InvalidData));
+    }
+
+    #[test]
+    fn test_lightning() {
+        let lightning = Lightning { invoice: INVOICE.into() };
+        let payload = lightning.to_payload_string().unwrap();
+        assert_eq!(payload, ["LIGHTNING:", &INVOICE.to_ascii_uppercase()].concat());
+
+        // The whole payload is encoded in the alphanumeric mode.
+        let segments = Parser::new(payload.as_bytes()).optimize(Version::Normal(15)).collect::<Vec<Segment>>();
+        assert_eq!(segments.iter().map(|s| s.mode).collect::<Vec<_>>(), [Mode::Alphanumeric]);
+
+        let not_lightning = Lightning { invoice: "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4".into() };
+        assert_eq!(not_lightning.to_payload_string(), Err(QrError::InvalidData));
+    }
+
+    #[test]
+    fn test_ethereum_token() {
+        let ethereum = Ethereum {
+            address: "0x8e23ee67d1332ad560396262c48ffbb01f93d052".into(),
+            chain_id: Some(137),
+            amount: Some(1_000_000),
+            token: "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174".into(),
+        };
+        assert_eq!(
+            ethereum.to_payload_string().unwrap(),
+            "ethereum:0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174@137/transfer\
+             ?address=0x8e23ee67d1332ad560396262c48ffbb01f93d052&uint256=1000000"
+        );
+
+        let no_amount = Ethereum { amount: None, ..ethereum.clone() };
+        assert_eq!(no_amount.to_payload_string(), Err(QrError::InvalidData));
+        let bad_address = Ethereum { address: "0x8e23".into(), ..ethereum };
+        assert_eq!(bad_address.to_payload_string(), Err(QrError::InvalidData));
+    }
+}
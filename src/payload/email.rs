@@ -0,0 +1,144 @@
+//! Emails, as a `mailto:` URI (RFC 6068) or in the `MATMSG:` format.
+
+use crate::payload::{push_backslash_escaped, push_percent_encoded, Payload};
+use crate::types::{QrError, QrResult};
+use alloc::string::String;
+
+/// The format of an email.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmailFormat {
+    /// `mailto:<address>?subject=<subject>&body=<body>`.
+    #[default]
+    Mailto,
+
+    /// `MATMSG:TO:<address>;SUB:<subject>;BODY:<body>;;`, introduced by NTT
+    /// Docomo.
+    MatMsg,
+}
+
+/// An email to send.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Email {
+    /// The address of the recipient.
+    pub to: String,
+
+    /// The subject. May be empty.
+    pub subject: String,
+
+    /// The body. May be empty.
+    pub body: String,
+
+    /// The format of the payload.
+    pub format: EmailFormat,
+}
+
+impl Email {
+    /// Renders the email as a `mailto:` URI.
+    fn to_mailto(&self, local_part: &str, domain: &str) -> String {
+        let mut output = String::from("mailto:");
+        push_percent_encoded(&mut output, local_part);
+        output.push('@');
+        push_percent_encoded(&mut output, domain);
+        let mut separator = '?';
+        for (name, value) in [("subject=", &self.subject), ("body=", &self.body)] {
+            if !value.is_empty() {
+                output.push(separator);
+                output.push_str(name);
+                // Line breaks must be encoded as CRLF.
+                push_percent_encoded(&mut output, &value.replace("\r\n", "\n").replace('\n', "\r\n"));
+                separator = '&';
+            }
+        }
+        output
+    }
+
+    /// Renders the email in the `MATMSG:` format.
+    fn to_matmsg(&self) -> String {
+        const SPECIAL_CHARS: &str = ":;";
+
+        let mut output = String::from("MATMSG:TO:");
+        push_backslash_escaped(&mut output, &self.to, SPECIAL_CHARS);
+        output.push_str(";SUB:");
+        push_backslash_escaped(&mut output, &self.subject, SPECIAL_CHARS);
+        output.push_str(";BODY:");
+        push_backslash_escaped(&mut output, &self.body, SPECIAL_CHARS);
+        output.push_str(";;");
+        output
+    }
+}
+
+impl Payload for Email {
+    /// Renders the email in the selected format.
+    ///
+    /// ```
+    /// use qrcode::payload::{Email, Payload};
+    ///
+    /// let email = Email {
+    ///     to: "support@example.com".into(),
+    ///     subject: "Order #1234".into(),
+    ///     ..Email::default()
+    /// };
+    /// assert_eq!(email.to_payload_string().unwrap(), "mailto:support@example.com?subject=Order%20%231234");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if the address is not of the form
+    /// `<local part>@<domain>`.
+    fn to_payload_string(&self) -> QrResult<String> {
+        let (local_part, domain) = self.to.rsplit_once('@').ok_or(QrError::InvalidData)?;
+        if local_part.is_empty() || domain.is_empty() || domain.contains(|c: char| c.is_whitespace()) {
+            return Err(QrError::InvalidData);
+        }
+        Ok(match self.format {
+            EmailFormat::Mailto => self.to_mailto(local_part, domain),
+            EmailFormat::MatMsg => self.to_matmsg(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod email_tests {
+    use crate::payload::{Email, EmailFormat, Payload};
+    use crate::types::QrError;
+    use alloc::string::String;
+
+    fn email(format: EmailFormat) -> Email {
+        Email {
+            to: "j.doe+qr@example.com".into(),
+            subject: "Hello & welcome".into(),
+            body: "Line 1\nLine 2; 100%".into(),
+            format,
+        }
+    }
+
+    #[test]
+    fn test_mailto() {
+        assert_eq!(
+            email(EmailFormat::Mailto).to_payload_string().unwrap(),
+            "mailto:j.doe%2Bqr@example.com?subject=Hello%20%26%20welcome&body=Line%201%0D%0ALine%202%3B%20100%25"
+        );
+
+        let body_only = Email { subject: String::new(), ..email(EmailFormat::Mailto) };
+        assert_eq!(
+            body_only.to_payload_string().unwrap(),
+            "mailto:j.doe%2Bqr@example.com?body=Line%201%0D%0ALine%202%3B%20100%25"
+        );
+    }
+
+    #[test]
+    fn test_matmsg() {
+        assert_eq!(
+            email(EmailFormat::MatMsg).to_payload_string().unwrap(),
+            "MATMSG:TO:j.doe+qr@example.com;SUB:Hello & welcome;BODY:Line 1\nLine 2\\; 100%;;"
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        for to in ["", "example.com", "@example.com", "alice@", "alice@exa mple.com"] {
+            let email = Email { to: to.into(), ..Email::default() };
+            assert_eq!(email.to_payload_string(), Err(QrError::InvalidData));
+        }
+    }
+}
@@ -0,0 +1,466 @@
+//! Merchant-presented payment codes of the EMV® QR Code Specification for
+//! Payment Systems, which is used by national payment schemes such as PIX
+//! (Brazil), UPI (India) and SGQR (Singapore).
+//!
+//! The payload is a sequence of data objects, each consisting of a two-digit
+//! ID, a two-digit length and the value. Some values are templates which
+//! contain further data objects. The payload always ends with a CRC-16 checksum
+//! in the data object with ID 63.
+
+use crate::payload::Payload;
+use crate::types::{QrError, QrResult};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+//------------------------------------------------------------------------------
+//{{{ Data objects
+
+/// The value of a data object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataValue {
+    /// A plain string value.
+    Primitive(String),
+
+    /// A template containing nested data objects.
+    Template(Vec<DataObject>),
+}
+
+/// A data object, i.e. an ID-length-value triple.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataObject {
+    /// The ID, from 0 to 99.
+    pub id: u8,
+
+    /// The value.
+    pub value: DataValue,
+}
+
+impl DataObject {
+    /// Creates a data object with a plain string value.
+    pub fn primitive<S: Into<String>>(id: u8, value: S) -> Self {
+        Self { id, value: DataValue::Primitive(value.into()) }
+    }
+
+    /// Creates a template data object.
+    pub fn template(id: u8, objects: Vec<Self>) -> Self {
+        Self { id, value: DataValue::Template(objects) }
+    }
+
+    /// Creates a merchant account information template (ID 26 to 51), which
+    /// starts with the globally unique identifier of the payment network,
+    /// e.g. `br.gov.bcb.pix`.
+    ///
+    /// ```
+    /// use qrcode::payload::DataObject;
+    ///
+    /// let account = DataObject::merchant_account(26, "br.gov.bcb.pix", vec![
+    ///     DataObject::primitive(1, "fulano@example.com"),
+    /// ]);
+    /// assert_eq!(account.get(0).and_then(DataObject::as_str), Some("br.gov.bcb.pix"));
+    /// ```
+    pub fn merchant_account(id: u8, globally_unique_id: &str, mut objects: Vec<Self>) -> Self {
+        objects.insert(0, Self::primitive(0, globally_unique_id));
+        Self::template(id, objects)
+    }
+
+    /// Returns the string value, or `None` if this is a template.
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.value {
+            DataValue::Primitive(value) => Some(value),
+            DataValue::Template(_) => None,
+        }
+    }
+
+    /// Finds the nested data object with the given ID, or `None` if this is
+    /// not a template or no such object exists.
+    pub fn get(&self, id: u8) -> Option<&Self> {
+        match &self.value {
+            DataValue::Primitive(_) => None,
+            DataValue::Template(objects) => objects.iter().find(|o| o.id == id),
+        }
+    }
+
+    /// Appends the encoded data object to the output.
+    fn push_to(&self, output: &mut String) -> QrResult<()> {
+        let value = match &self.value {
+            DataValue::Primitive(value) => value.clone(),
+            DataValue::Template(objects) => {
+                let mut value = String::new();
+                for object in objects {
+                    object.push_to(&mut value)?;
+                }
+                value
+            }
+        };
+        // The length counts characters, not bytes.
+        let len = value.chars().count();
+        if self.id > 99 || len > 99 {
+            return Err(QrError::InvalidData);
+        }
+        let _ = write!(output, "{:02}{len:02}{value}", self.id);
+        Ok(())
+    }
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Merchant-presented mode
+
+/// Whether the code is reused for multiple transactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointOfInitiation {
+    /// The same code is used for all transactions, e.g. printed at the
+    /// counter. The customer usually enters the amount.
+    Static,
+
+    /// A new code is generated for each transaction.
+    Dynamic,
+}
+
+/// A merchant-presented payment code.
+///
+/// The fields with empty values are omitted, except those which are
+/// mandatory. The payload format indicator (ID 00) and the CRC (ID 63) are
+/// added automatically.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MerchantPresented {
+    /// The point of initiation method (ID 01).
+    pub point_of_initiation: Option<PointOfInitiation>,
+
+    /// The merchant account information (ID 02 to 51). At least one is
+    /// required.
+    pub merchant_accounts: Vec<DataObject>,
+
+    /// The ISO 18245 merchant category code (ID 52), e.g. `5812`, or `0000`
+    /// if not applicable.
+    pub merchant_category_code: String,
+
+    /// The ISO 4217 numeric currency code (ID 53), e.g. `986` for BRL.
+    pub transaction_currency: String,
+
+    /// The transaction amount (ID 54), e.g. `12.50`. Empty if the customer
+    /// should enter the amount.
+    pub transaction_amount: String,
+
+    /// The ISO 3166-1 alpha-2 country code (ID 58), e.g. `BR`.
+    pub country_code: String,
+
+    /// The merchant name (ID 59), at most 25 characters.
+    pub merchant_name: String,
+
+    /// The merchant city (ID 60), at most 15 characters.
+    pub merchant_city: String,
+
+    /// The postal code (ID 61), at most 10 characters.
+    pub postal_code: String,
+
+    /// The additional data field template (ID 62), e.g. the bill number or
+    /// the reference label.
+    pub additional_data: Vec<DataObject>,
+
+    /// Other data objects, e.g. the tip indicator (ID 55 to 57), the
+    /// merchant information language template (ID 64) or the unreserved
+    /// templates (ID 80 to 99).
+    pub other: Vec<DataObject>,
+}
+
+/// Whether the value consists of exactly `len` digits.
+fn is_numeric(value: &str, len: usize) -> bool {
+    value.len() == len && value.bytes().all(|c| c.is_ascii_digit())
+}
+
+/// Whether the amount is a decimal number of at most 13 characters.
+fn is_valid_amount(amount: &str) -> bool {
+    let mut parts = amount.splitn(2, '.');
+    let integer = parts.next().unwrap_or_default();
+    let fraction = parts.next().unwrap_or_default();
+    amount.len() <= 13
+        && !integer.is_empty()
+        && integer.bytes().all(|c| c.is_ascii_digit())
+        && fraction.bytes().all(|c| c.is_ascii_digit())
+}
+
+impl MerchantPresented {
+    fn validate(&self) -> QrResult<()> {
+        let is_valid = !self.merchant_accounts.is_empty()
+            && self.merchant_accounts.iter().all(|o| (2..=51).contains(&o.id))
+            && is_numeric(&self.merchant_category_code, 4)
+            && is_numeric(&self.transaction_currency, 3)
+            && (self.transaction_amount.is_empty() || is_valid_amount(&self.transaction_amount))
+            && self.country_code.len() == 2
+            && self.country_code.bytes().all(|c| c.is_ascii_uppercase())
+            && (1..=25).contains(&self.merchant_name.chars().count())
+            && (1..=15).contains(&self.merchant_city.chars().count())
+            && self.postal_code.chars().count() <= 10
+            && self.other.iter().all(|o| matches!(o.id, 55..=57 | 64..=99));
+        if is_valid {
+            Ok(())
+        } else {
+            Err(QrError::InvalidData)
+        }
+    }
+}
+
+impl Payload for MerchantPresented {
+    /// Renders the payment code, ending with the CRC.
+    ///
+    /// ```
+    /// use qrcode::payload::{DataObject, MerchantPresented, Payload, PointOfInitiation};
+    ///
+    /// let payment = MerchantPresented {
+    ///     point_of_initiation: Some(PointOfInitiation::Static),
+    ///     merchant_accounts: vec![DataObject::merchant_account(26, "br.gov.bcb.pix", vec![
+    ///         DataObject::primitive(1, "123e4567-e12b-12d1-a456-426655440000"),
+    ///     ])],
+    ///     merchant_category_code: "0000".into(),
+    ///     transaction_currency: "986".into(),
+    ///     country_code: "BR".into(),
+    ///     merchant_name: "Fulano de Tal".into(),
+    ///     merchant_city: "BRASILIA".into(),
+    ///     additional_data: vec![DataObject::primitive(5, "***")],
+    ///     ..MerchantPresented::default()
+    /// };
+    /// let payload = payment.to_payload_string().unwrap();
+    /// assert!(payload.starts_with("00020101021126580014br.gov.bcb.pix0136123e4567"));
+    /// assert!(payload.ends_with("62070503***630448CD"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if a mandatory field is missing, a
+    /// field is malformed or too long, or the ID of a data object is out of
+    /// its range.
+    fn to_payload_string(&self) -> QrResult<String> {
+        self.validate()?;
+
+        let mut objects = Vec::new();
+        objects.push(DataObject::primitive(0, "01"));
+        if let Some(point_of_initiation) = self.point_of_initiation {
+            let value = match point_of_initiation {
+                PointOfInitiation::Static => "11",
+                PointOfInitiation::Dynamic => "12",
+            };
+            objects.push(DataObject::primitive(1, value));
+        }
+        objects.extend(self.merchant_accounts.iter().cloned());
+        objects.push(DataObject::primitive(52, self.merchant_category_code.clone()));
+        objects.push(DataObject::primitive(53, self.transaction_currency.clone()));
+        if !self.transaction_amount.is_empty() {
+            objects.push(DataObject::primitive(54, self.transaction_amount.clone()));
+        }
+        objects.push(DataObject::primitive(58, self.country_code.clone()));
+        objects.push(DataObject::primitive(59, self.merchant_name.clone()));
+        objects.push(DataObject::primitive(60, self.merchant_city.clone()));
+        if !self.postal_code.is_empty() {
+            objects.push(DataObject::primitive(61, self.postal_code.clone()));
+        }
+        if !self.additional_data.is_empty() {
+            objects.push(DataObject::template(62, self.additional_data.clone()));
+        }
+        objects.extend(self.other.iter().cloned());
+
+        let mut output = String::new();
+        for object in &objects {
+            object.push_to(&mut output)?;
+        }
+        output.push_str("6304");
+        let _ = write!(output, "{:04X}", crc16(output.as_bytes()));
+        Ok(output)
+    }
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Parser
+
+/// Computes the CRC-16/CCITT-FALSE checksum (polynomial 0x1021, initial value
+/// 0xFFFF) of the data.
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc = 0xffff_u16;
+    for &byte in data {
+        crc ^= u16::from(byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 == 0 { crc << 1 } else { (crc << 1) ^ 0x1021 };
+        }
+    }
+    crc
+}
+
+/// Whether the top-level data object with the given ID is a template.
+fn is_template_id(id: u8) -> bool {
+    matches!(id, 26..=51 | 62 | 64 | 80..=99)
+}
+
+/// Parses a sequence of data objects. Templates are parsed recursively if
+/// `is_top_level` is true.
+fn parse_objects(mut data: &str, is_top_level: bool) -> QrResult<Vec<DataObject>> {
+    let mut objects = Vec::new();
+    while !data.is_empty() {
+        let header = data.get(..4).ok_or(QrError::InvalidData)?;
+        if !header.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(QrError::InvalidData);
+        }
+        let id = header[..2].parse::<u8>().map_err(|_| QrError::InvalidData)?;
+        let len = header[2..].parse::<usize>().map_err(|_| QrError::InvalidData)?;
+        let rest = &data[4..];
+        let end = match rest.char_indices().nth(len) {
+            Some((end, _)) => end,
+            None if rest.chars().count() == len => rest.len(),
+            None => return Err(QrError::InvalidData),
+        };
+        let value = &rest[..end];
+        objects.push(if is_top_level && is_template_id(id) {
+            DataObject::template(id, parse_objects(value, false)?)
+        } else {
+            DataObject::primitive(id, value)
+        });
+        data = &rest[end..];
+    }
+    Ok(objects)
+}
+
+/// Parses and validates a merchant-presented payment code.
+///
+/// The templates among the top-level data objects (merchant account
+/// information, additional data field, merchant information language and
+/// unreserved templates) are parsed into their nested data objects. The
+/// returned list includes the payload format indicator and the CRC.
+///
+/// ```
+/// use qrcode::payload::emvco;
+///
+/// let objects = emvco::parse(
+///     "00020101021229300012D156000000000510A93FO3230Q31280012D15600000001030812345678520441115802CN\
+///      5914BEST TRANSPORT6007BEIJING64200002ZH0104最佳运输0202北京540523.7253031565502016233030412340603***\
+///      0708A60086670902ME91320016A0112233449988770708123456786304A13A",
+/// )
+/// .unwrap();
+/// let name = objects.iter().find(|o| o.id == 64).and_then(|o| o.get(1)).and_then(|o| o.as_str());
+/// assert_eq!(name, Some("最佳运输"));
+/// ```
+///
+/// # Errors
+///
+/// Returns `Err(QrError::InvalidData)` if the payload is malformed, the CRC
+/// does not match, a mandatory data object is missing, or a data object
+/// appears more than once.
+pub fn parse(payload: &str) -> QrResult<Vec<DataObject>> {
+    // The CRC covers everything before its value, including its own ID and
+    // length.
+    let crc_start = payload.len().checked_sub(4).ok_or(QrError::InvalidData)?;
+    let checked = payload.get(..crc_start).ok_or(QrError::InvalidData)?;
+    let crc = &payload[crc_start..];
+    if !checked.ends_with("6304") || u16::from_str_radix(crc, 16).ok() != Some(crc16(checked.as_bytes())) {
+        return Err(QrError::InvalidData);
+    }
+
+    let objects = parse_objects(payload, true)?;
+    let has = |id: u8| objects.iter().any(|o| o.id == id);
+    let is_valid = objects.first().and_then(DataObject::as_str) == Some("01")
+        && objects[0].id == 0
+        && objects.iter().any(|o| (2..=51).contains(&o.id))
+        && [52, 53, 58, 59, 60].into_iter().all(has)
+        && objects.iter().enumerate().all(|(i, o)| objects[..i].iter().all(|p| p.id != o.id));
+    if is_valid {
+        Ok(objects)
+    } else {
+        Err(QrError::InvalidData)
+    }
+}
+
+#[cfg(test)]
+mod emvco_tests {
+    use crate::payload::emvco::{crc16, parse};
+    use crate::payload::{DataObject, MerchantPresented, Payload, PointOfInitiation};
+    use crate::types::QrError;
+    use alloc::vec;
+
+    const SAMPLE: &str = "00020101021229300012D156000000000510A93FO3230Q31280012D15600000001030812345678\
+                          520441115802CN5914BEST TRANSPORT6007BEIJING64200002ZH0104最佳运输0202北京\
+                          540523.7253031565502016233030412340603***0708A60086670902ME\
+                          91320016A0112233449988770708123456786304A13A";
+
+    fn payment() -> MerchantPresented {
+        MerchantPresented {
+            point_of_initiation: Some(PointOfInitiation::Dynamic),
+            merchant_accounts: vec![DataObject::merchant_account(
+                29,
+                "D15600000000",
+                vec![DataObject::primitive(5, "A93FO3230Q")],
+            )],
+            merchant_category_code: "4111".into(),
+            transaction_currency: "156".into(),
+            transaction_amount: "23.72".into(),
+            country_code: "CN".into(),
+            merchant_name: "BEST TRANSPORT".into(),
+            merchant_city: "BEIJING".into(),
+            additional_data: vec![DataObject::primitive(3, "1234"), DataObject::primitive(7, "A6008667")],
+            other: vec![DataObject::template(
+                64,
+                vec![DataObject::primitive(0, "ZH"), DataObject::primitive(1, "最佳运输")],
+            )],
+            ..MerchantPresented::default()
+        }
+    }
+
+    #[test]
+    fn test_crc16() {
+        assert_eq!(crc16(b"123456789"), 0x29b1);
+    }
+
+    #[test]
+    fn test_build() {
+        assert_eq!(
+            payment().to_payload_string().unwrap(),
+            "00020101021229300012D156000000000510A93FO3230Q520441115303156540523.725802CN\
+             5914BEST TRANSPORT6007BEIJING6220030412340708A600866764140002ZH0104最佳运输6304E312"
+        );
+    }
+
+    #[test]
+    fn test_build_invalid() {
+        let no_account = MerchantPresented { merchant_accounts: vec![], ..payment() };
+        assert_eq!(no_account.to_payload_string(), Err(QrError::InvalidData));
+
+        let bad_amount = MerchantPresented { transaction_amount: "1,00".into(), ..payment() };
+        assert_eq!(bad_amount.to_payload_string(), Err(QrError::InvalidData));
+
+        let long_name = MerchantPresented { merchant_name: "A".repeat(26), ..payment() };
+        assert_eq!(long_name.to_payload_string(), Err(QrError::InvalidData));
+
+        let reserved = MerchantPresented { other: vec![DataObject::primitive(63, "0000")], ..payment() };
+        assert_eq!(reserved.to_payload_string(), Err(QrError::InvalidData));
+    }
+
+    #[test]
+    fn test_parse() {
+        let objects = parse(SAMPLE).unwrap();
+        let ids = objects.iter().map(|o| o.id).collect::<vec::Vec<_>>();
+        assert_eq!(ids, vec![0, 1, 29, 31, 52, 58, 59, 60, 64, 54, 53, 55, 62, 91, 63]);
+        assert_eq!(objects[2].get(5).and_then(DataObject::as_str), Some("A93FO3230Q"));
+        assert_eq!(objects[12].get(6).and_then(DataObject::as_str), Some("***"));
+        assert_eq!(objects[14].as_str(), Some("A13A"));
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let payload = payment().to_payload_string().unwrap();
+        let objects = parse(&payload).unwrap();
+        assert_eq!(objects[2], payment().merchant_accounts[0]);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let bad_crc = SAMPLE.replace("A13A", "A13B");
+        assert_eq!(parse(&bad_crc), Err(QrError::InvalidData));
+
+        let truncated = "000201520441115303156";
+        assert_eq!(parse(truncated), Err(QrError::InvalidData));
+
+        // Valid CRC but without the merchant name.
+        assert_eq!(parse("0002012608000412345204000053039865802BR6008BRASILIA63048296"), Err(QrError::InvalidData));
+    }
+}
+
+//}}}
@@ -0,0 +1,301 @@
+//! SEPA credit transfers, in the "BCD" format of the European Payments Council
+//! (EPC069-12), also known as Girocode.
+
+use crate::payload::{is_valid_iban, utf8_eci_designator, Payload};
+use crate::types::{EcLevel, QrError, QrResult};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// The maximum size of the payload, in bytes.
+const MAX_PAYLOAD_LEN: usize = 331;
+
+/// The version of the EPC format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EpcVersion {
+    /// Version 001, which requires the BIC.
+    V1,
+
+    /// Version 002, where the BIC is optional within the EEA.
+    #[default]
+    V2,
+}
+
+/// The character set of the payload.
+///
+/// Only the character sets which need no conversion tables are supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EpcCharacterSet {
+    /// UTF-8 (code 1).
+    #[default]
+    Utf8,
+
+    /// ISO 8859-1 (code 2).
+    Iso8859_1,
+}
+
+/// The remittance information of a credit transfer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EpcRemittance {
+    /// A structured creditor reference, e.g. an ISO 11649 `RF` reference, of
+    /// at most 35 characters.
+    Reference(String),
+
+    /// Unstructured text of at most 140 characters.
+    Text(String),
+}
+
+/// A SEPA credit transfer.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct EpcTransfer {
+    /// The version of the format.
+    pub version: EpcVersion,
+
+    /// The character set of the payload.
+    pub character_set: EpcCharacterSet,
+
+    /// The BIC of the beneficiary bank, 8 or 11 characters. May be empty for
+    /// version 002.
+    pub bic: String,
+
+    /// The name of the beneficiary, at most 70 characters.
+    pub name: String,
+
+    /// The IBAN of the beneficiary. Spaces are removed.
+    pub iban: String,
+
+    /// The amount in euro cents, from 1 to 99999999999. `None` if the payer
+    /// should enter the amount.
+    pub amount_in_cents: Option<u64>,
+
+    /// The ISO 20022 purpose code, four letters, e.g. `GDDS`. May be empty.
+    pub purpose: String,
+
+    /// The remittance information.
+    pub remittance: Option<EpcRemittance>,
+
+    /// The beneficiary to originator information, at most 70 characters.
+    pub information: String,
+}
+
+/// Checks whether the BIC has the valid format.
+fn is_valid_bic(bic: &str) -> bool {
+    let bytes = bic.as_bytes();
+    (bytes.len() == 8 || bytes.len() == 11)
+        && bytes[..6].iter().all(u8::is_ascii_uppercase)
+        && bytes[6..].iter().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+}
+
+impl EpcTransfer {
+    /// The structured and unstructured remittance information fields.
+    fn remittance_fields(&self) -> (&str, &str) {
+        match &self.remittance {
+            None => ("", ""),
+            Some(EpcRemittance::Reference(reference)) => (reference, ""),
+            Some(EpcRemittance::Text(text)) => ("", text),
+        }
+    }
+
+    /// Checks the fields against the limits of the EPC guidelines.
+    fn validate(&self, iban: &str) -> QrResult<()> {
+        let (reference, text) = self.remittance_fields();
+        let has_valid_bic = if self.bic.is_empty() { self.version == EpcVersion::V2 } else { is_valid_bic(&self.bic) };
+        let is_valid = has_valid_bic
+            && (1..=70).contains(&self.name.chars().count())
+            && is_valid_iban(iban)
+            && self.amount_in_cents.map_or(true, |amount| (1..=99_999_999_999).contains(&amount))
+            && (self.purpose.is_empty()
+                || (self.purpose.len() == 4 && self.purpose.bytes().all(|c| c.is_ascii_uppercase())))
+            && reference.chars().count() <= 35
+            && text.chars().count() <= 140
+            && self.information.chars().count() <= 70
+            && [&self.name, &self.purpose, reference, text, &self.information]
+                .iter()
+                .all(|field| !field.contains(['\n', '\r']));
+        if is_valid {
+            Ok(())
+        } else {
+            Err(QrError::InvalidData)
+        }
+    }
+
+    /// Encodes the text in the character set, or returns
+    /// `Err(QrError::InvalidData)` if a character cannot be represented.
+    fn to_bytes(&self, text: &str) -> QrResult<Vec<u8>> {
+        match self.character_set {
+            EpcCharacterSet::Utf8 => Ok(text.as_bytes().to_vec()),
+            EpcCharacterSet::Iso8859_1 => {
+                text.chars().map(|c| u8::try_from(u32::from(c)).map_err(|_| QrError::InvalidData)).collect()
+            }
+        }
+    }
+}
+
+impl Payload for EpcTransfer {
+    /// Renders the credit transfer as lines separated by line feeds.
+    ///
+    /// ```
+    /// use qrcode::payload::{EpcRemittance, EpcTransfer, Payload};
+    ///
+    /// let transfer = EpcTransfer {
+    ///     name: "Red Cross".into(),
+    ///     iban: "BE72 0000 0000 1616".into(),
+    ///     amount_in_cents: Some(1000),
+    ///     remittance: Some(EpcRemittance::Text("Donation".into())),
+    ///     ..EpcTransfer::default()
+    /// };
+    /// assert_eq!(
+    ///     transfer.to_payload_string().unwrap(),
+    ///     "BCD\n002\n1\nSCT\n\nRed Cross\nBE72000000001616\nEUR10.00\n\n\nDonation",
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if a field is missing or malformed,
+    /// e.g. the IBAN check digits are wrong, or the payload exceeds 331 bytes.
+    fn to_payload_string(&self) -> QrResult<String> {
+        let iban = self.iban.replace(' ', "");
+        self.validate(&iban)?;
+
+        let mut output = String::from("BCD\n");
+        output.push_str(match self.version {
+            EpcVersion::V1 => "001\n",
+            EpcVersion::V2 => "002\n",
+        });
+        output.push_str(match self.character_set {
+            EpcCharacterSet::Utf8 => "1\n",
+            EpcCharacterSet::Iso8859_1 => "2\n",
+        });
+        output.push_str("SCT\n");
+        for field in [&self.bic, &self.name, &iban] {
+            output.push_str(field);
+            output.push('\n');
+        }
+        if let Some(amount) = self.amount_in_cents {
+            let _ = write!(output, "EUR{}.{:02}", amount / 100, amount % 100);
+        }
+        output.push('\n');
+        output.push_str(&self.purpose);
+        output.push('\n');
+        let (reference, text) = self.remittance_fields();
+        for field in [reference, text, &self.information] {
+            output.push_str(field);
+            output.push('\n');
+        }
+
+        // The trailing empty fields are omitted.
+        output.truncate(output.trim_end_matches('\n').len());
+        if self.to_bytes(&output)?.len() > MAX_PAYLOAD_LEN {
+            return Err(QrError::InvalidData);
+        }
+        Ok(output)
+    }
+
+    /// Renders the credit transfer in its character set.
+    fn to_payload_bytes(&self) -> QrResult<Vec<u8>> {
+        self.to_bytes(&self.to_payload_string()?)
+    }
+
+    /// The UTF-8 ECI designator (26) if the UTF-8 character set is used with
+    /// non-ASCII text. ISO-8859-1 is the default, so it needs no designator.
+    fn eci_designator(&self) -> Option<u32> {
+        match self.character_set {
+            EpcCharacterSet::Utf8 => self.to_payload_string().ok().as_deref().and_then(utf8_eci_designator),
+            EpcCharacterSet::Iso8859_1 => None,
+        }
+    }
+
+    /// The EPC guidelines require error correction level M.
+    fn ec_level(&self) -> EcLevel {
+        EcLevel::M
+    }
+}
+
+#[cfg(test)]
+mod epc_tests {
+    use crate::payload::{is_valid_iban, EpcCharacterSet, EpcRemittance, EpcTransfer, EpcVersion, Payload};
+    use crate::types::{EcLevel, QrError};
+    use alloc::string::String;
+
+    fn transfer() -> EpcTransfer {
+        EpcTransfer {
+            version: EpcVersion::V1,
+            bic: "BHBLDEHHXXX".into(),
+            name: "Franz Musterm\u{e4}nn".into(),
+            iban: "DE71 1102 2033 0123 4567 89".into(),
+            amount_in_cents: Some(1230),
+            purpose: "GDDS".into(),
+            remittance: Some(EpcRemittance::Reference("RF18539007547034".into())),
+            ..EpcTransfer::default()
+        }
+    }
+
+    #[test]
+    fn test_iban() {
+        assert!(is_valid_iban("DE89370400440532013000"));
+        assert!(is_valid_iban("GB82WEST12345698765432"));
+        assert!(!is_valid_iban("DE89370400440532013001"));
+        assert!(!is_valid_iban("de89370400440532013000"));
+        assert!(!is_valid_iban("DE89"));
+    }
+
+    #[test]
+    fn test_transfer() {
+        let transfer = transfer();
+        assert_eq!(
+            transfer.to_payload_string().unwrap(),
+            "BCD\n001\n1\nSCT\nBHBLDEHHXXX\nFranz Musterm\u{e4}nn\nDE71110220330123456789\n\
+             EUR12.30\nGDDS\nRF18539007547034"
+        );
+        assert_eq!(transfer.ec_level(), EcLevel::M);
+    }
+
+    #[test]
+    fn test_iso_8859_1() {
+        let transfer = EpcTransfer { character_set: EpcCharacterSet::Iso8859_1, ..transfer() };
+        let bytes = transfer.to_payload_bytes().unwrap();
+        assert!(bytes.starts_with(b"BCD\n001\n2\nSCT\nBHBLDEHHXXX\nFranz Musterm\xe4nn\n"));
+
+        let transfer = EpcTransfer { name: "Zo\u{eb} \u{20ac}".into(), ..transfer };
+        assert_eq!(transfer.to_payload_bytes(), Err(QrError::InvalidData));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let no_bic = EpcTransfer { bic: String::new(), ..transfer() };
+        assert_eq!(no_bic.to_payload_string(), Err(QrError::InvalidData));
+        let no_bic_v2 = EpcTransfer { version: EpcVersion::V2, ..no_bic };
+        assert!(no_bic_v2.to_payload_string().is_ok());
+
+        let bad_iban = EpcTransfer { iban: "DE71110220330123456788".into(), ..transfer() };
+        assert_eq!(bad_iban.to_payload_string(), Err(QrError::InvalidData));
+
+        let zero_amount = EpcTransfer { amount_in_cents: Some(0), ..transfer() };
+        assert_eq!(zero_amount.to_payload_string(), Err(QrError::InvalidData));
+
+        let multi_line = EpcTransfer { information: "a\nb".into(), ..transfer() };
+        assert_eq!(multi_line.to_payload_string(), Err(QrError::InvalidData));
+    }
+
+    #[test]
+    fn test_payload_limit() {
+        // 70 two-byte characters in each of the name and information, plus 140
+        // characters of text, exceed 331 bytes in UTF-8.
+        let long = EpcTransfer {
+            version: EpcVersion::V2,
+            bic: String::new(),
+            amount_in_cents: None,
+            name: "\u{e4}".repeat(70),
+            remittance: Some(EpcRemittance::Text("x".repeat(140))),
+            information: "\u{e4}".repeat(70),
+            purpose: String::new(),
+            ..transfer()
+        };
+        assert_eq!(long.to_payload_string(), Err(QrError::InvalidData));
+
+        // But not in ISO 8859-1.
+        let long = EpcTransfer { character_set: EpcCharacterSet::Iso8859_1, ..long };
+        assert!(long.to_payload_bytes().is_ok());
+    }
+}
@@ -0,0 +1,242 @@
+//! Calendar events, as an iCalendar (RFC 5545) `VEVENT` component.
+
+use crate::payload::{escape_text, push_folded_line, Payload};
+use crate::types::{QrError, QrResult};
+use alloc::string::String;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+/// The date and time of an event, to the second.
+///
+/// A UTC time is the same instant everywhere, while a floating time is
+/// interpreted in the local time zone of the reader, e.g. 9:00 wherever the
+/// calendar is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventTime {
+    /// The year, from 0 to 9999.
+    pub year: u16,
+
+    /// The month, from 1 to 12.
+    pub month: u8,
+
+    /// The day, from 1 to the number of days of the month.
+    pub day: u8,
+
+    /// The hour, from 0 to 23.
+    pub hour: u8,
+
+    /// The minute, from 0 to 59.
+    pub minute: u8,
+
+    /// The second, from 0 to 60 (for a leap second).
+    pub second: u8,
+
+    /// Whether this is a UTC time, otherwise a floating time.
+    pub is_utc: bool,
+}
+
+impl EventTime {
+    /// Creates a UTC time.
+    pub const fn utc(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Self {
+        Self { year, month, day, hour, minute, second, is_utc: true }
+    }
+
+    /// Creates a floating time.
+    pub const fn floating(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Self {
+        Self { year, month, day, hour, minute, second, is_utc: false }
+    }
+
+    /// Checks whether all fields are in range.
+    fn is_valid(self) -> bool {
+        let is_leap_year = self.year % 4 == 0 && (self.year % 100 != 0 || self.year % 400 == 0);
+        let days_in_month = match self.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year => 29,
+            2 => 28,
+            _ => return false,
+        };
+        self.year <= 9999
+            && (1..=days_in_month).contains(&self.day)
+            && self.hour < 24
+            && self.minute < 60
+            && self.second <= 60
+    }
+}
+
+impl fmt::Display for EventTime {
+    /// Formats the time as `YYYYMMDDTHHMMSS`, followed by `Z` for a UTC time.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:04}{:02}{:02}T{:02}{:02}{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        if self.is_utc {
+            f.write_str("Z")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for EventTime {
+    type Err = QrError;
+
+    /// Parses the time from the iCalendar format `YYYYMMDDTHHMMSS`, with an
+    /// optional `Z` suffix for a UTC time.
+    ///
+    /// ```
+    /// use qrcode::payload::EventTime;
+    ///
+    /// let time = "20240229T093000Z".parse::<EventTime>().unwrap();
+    /// assert_eq!(time, EventTime::utc(2024, 2, 29, 9, 30, 0));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (s, is_utc) = match s.strip_suffix('Z') {
+            Some(s) => (s, true),
+            None => (s, false),
+        };
+        let bytes = s.as_bytes();
+        if bytes.len() != 15 || bytes[8] != b'T' || !bytes[..8].iter().chain(&bytes[9..]).all(u8::is_ascii_digit) {
+            return Err(QrError::InvalidData);
+        }
+        let field = |range: core::ops::Range<usize>| s[range].parse::<u8>().map_err(|_| QrError::InvalidData);
+        let time = Self {
+            year: s[..4].parse().map_err(|_| QrError::InvalidData)?,
+            month: field(4..6)?,
+            day: field(6..8)?,
+            hour: field(9..11)?,
+            minute: field(11..13)?,
+            second: field(13..15)?,
+            is_utc,
+        };
+        if time.is_valid() {
+            Ok(time)
+        } else {
+            Err(QrError::InvalidData)
+        }
+    }
+}
+
+/// A calendar event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEvent {
+    /// The title of the event.
+    pub summary: String,
+
+    /// The start time.
+    pub start: EventTime,
+
+    /// The end time, which must be of the same kind (UTC or floating) as the
+    /// start time and not before it.
+    pub end: Option<EventTime>,
+
+    /// The location. May be empty.
+    pub location: String,
+
+    /// The description. May be empty.
+    pub description: String,
+}
+
+impl Payload for CalendarEvent {
+    /// Renders the event as a `VEVENT` component.
+    ///
+    /// ```
+    /// use qrcode::payload::{CalendarEvent, EventTime, Payload};
+    ///
+    /// let event = CalendarEvent {
+    ///     summary: "Team meeting".into(),
+    ///     start: EventTime::utc(2024, 3, 1, 9, 0, 0),
+    ///     end: Some(EventTime::utc(2024, 3, 1, 10, 0, 0)),
+    ///     location: "Room 1".into(),
+    ///     description: "".into(),
+    /// };
+    /// assert_eq!(
+    ///     event.to_payload_string().unwrap(),
+    ///     "BEGIN:VEVENT\r\nSUMMARY:Team meeting\r\nDTSTART:20240301T090000Z\r\nDTEND:20240301T100000Z\r\n\
+    ///      LOCATION:Room 1\r\nEND:VEVENT",
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if the summary is empty, a time is
+    /// out of range, or the end time is before the start time or of a
+    /// different kind.
+    fn to_payload_string(&self) -> QrResult<String> {
+        let is_valid_end =
+            self.end.map_or(true, |end| end.is_valid() && end.is_utc == self.start.is_utc && end >= self.start);
+        if self.summary.is_empty() || !self.start.is_valid() || !is_valid_end {
+            return Err(QrError::InvalidData);
+        }
+
+        let mut output = String::from("BEGIN:VEVENT\r\n");
+        push_folded_line(&mut output, "SUMMARY", &escape_text(&self.summary));
+        let _ = write!(output, "DTSTART:{}\r\n", self.start);
+        if let Some(end) = self.end {
+            let _ = write!(output, "DTEND:{end}\r\n");
+        }
+        if !self.location.is_empty() {
+            push_folded_line(&mut output, "LOCATION", &escape_text(&self.location));
+        }
+        if !self.description.is_empty() {
+            push_folded_line(&mut output, "DESCRIPTION", &escape_text(&self.description));
+        }
+        output.push_str("END:VEVENT");
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod event_tests {
+    use crate::payload::{CalendarEvent, EventTime, Payload};
+    use crate::types::QrError;
+    use alloc::string::{String, ToString};
+
+    fn event() -> CalendarEvent {
+        CalendarEvent {
+            summary: "Launch; v2, final".into(),
+            start: EventTime::floating(2024, 12, 31, 23, 0, 0),
+            end: None,
+            location: String::new(),
+            description: "Bring\nsnacks".into(),
+        }
+    }
+
+    #[test]
+    fn test_floating_event() {
+        assert_eq!(
+            event().to_payload_string().unwrap(),
+            "BEGIN:VEVENT\r\nSUMMARY:Launch\\; v2\\, final\r\nDTSTART:20241231T230000\r\n\
+             DESCRIPTION:Bring\\nsnacks\r\nEND:VEVENT"
+        );
+    }
+
+    #[test]
+    fn test_invalid_times() {
+        let utc_end = CalendarEvent { end: Some(EventTime::utc(2025, 1, 1, 0, 0, 0)), ..event() };
+        assert_eq!(utc_end.to_payload_string(), Err(QrError::InvalidData));
+
+        let early_end = CalendarEvent { end: Some(EventTime::floating(2024, 12, 31, 22, 0, 0)), ..event() };
+        assert_eq!(early_end.to_payload_string(), Err(QrError::InvalidData));
+
+        let bad_start = CalendarEvent { start: EventTime::floating(2023, 2, 29, 0, 0, 0), ..event() };
+        assert_eq!(bad_start.to_payload_string(), Err(QrError::InvalidData));
+
+        let no_summary = CalendarEvent { summary: String::new(), ..event() };
+        assert_eq!(no_summary.to_payload_string(), Err(QrError::InvalidData));
+    }
+
+    #[test]
+    fn test_parse_time() {
+        let time = "20000229T235960".parse::<EventTime>().unwrap();
+        assert_eq!(time, EventTime::floating(2000, 2, 29, 23, 59, 60));
+        assert_eq!(time.to_string(), "20000229T235960");
+        assert_eq!(EventTime::utc(987, 1, 2, 3, 4, 5).to_string(), "09870102T030405Z");
+
+        assert_eq!("19000229T000000".parse::<EventTime>(), Err(QrError::InvalidData));
+        assert_eq!("20240101 000000".parse::<EventTime>(), Err(QrError::InvalidData));
+        assert_eq!("20240101T0000".parse::<EventTime>(), Err(QrError::InvalidData));
+        assert_eq!("2024010+T000000".parse::<EventTime>(), Err(QrError::InvalidData));
+    }
+}
@@ -0,0 +1,106 @@
+//! Geographic locations, as a `geo:` URI (RFC 5870).
+
+use crate::payload::Payload;
+use crate::types::{QrError, QrResult};
+use alloc::format;
+use alloc::string::String;
+
+/// A geographic location in WGS 84 coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geo {
+    /// The latitude in degrees, from -90 to 90.
+    pub latitude: f64,
+
+    /// The longitude in degrees, from -180 to 180.
+    pub longitude: f64,
+
+    /// The altitude in meters, if known.
+    pub altitude: Option<f64>,
+
+    /// The maximum number of decimal places of the coordinates. Default is 6,
+    /// which is about 0.1 m. Fewer decimal places yield a smaller QR code.
+    pub precision: u8,
+}
+
+impl Default for Geo {
+    fn default() -> Self {
+        Self { latitude: 0.0, longitude: 0.0, altitude: None, precision: 6 }
+    }
+}
+
+/// Formats the number with at most `precision` decimal places, without
+/// trailing zeros.
+fn format_decimal(value: f64, precision: u8) -> String {
+    let mut output = format!("{value:.0$}", usize::from(precision));
+    if output.contains('.') {
+        output.truncate(output.trim_end_matches('0').trim_end_matches('.').len());
+    }
+    if output == "-0" {
+        output.remove(0);
+    }
+    output
+}
+
+impl Payload for Geo {
+    /// Renders the location as `geo:<latitude>,<longitude>[,<altitude>]`.
+    ///
+    /// ```
+    /// use qrcode::payload::{Geo, Payload};
+    ///
+    /// let geo = Geo { latitude: 47.376_887, longitude: 8.541_694, precision: 4, ..Geo::default() };
+    /// assert_eq!(geo.to_payload_string().unwrap(), "geo:47.3769,8.5417");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if a coordinate is out of range or
+    /// not finite.
+    fn to_payload_string(&self) -> QrResult<String> {
+        let is_valid = (-90.0..=90.0).contains(&self.latitude)
+            && (-180.0..=180.0).contains(&self.longitude)
+            && self.altitude.map_or(true, f64::is_finite);
+        if !is_valid {
+            return Err(QrError::InvalidData);
+        }
+
+        let mut output = String::from("geo:");
+        output.push_str(&format_decimal(self.latitude, self.precision));
+        output.push(',');
+        output.push_str(&format_decimal(self.longitude, self.precision));
+        if let Some(altitude) = self.altitude {
+            output.push(',');
+            output.push_str(&format_decimal(altitude, self.precision));
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod geo_tests {
+    use crate::payload::{Geo, Payload};
+    use crate::types::QrError;
+
+    #[test]
+    fn test_geo() {
+        let geo = Geo { latitude: -33.856_784, longitude: 151.215_297, altitude: Some(12.4), ..Geo::default() };
+        assert_eq!(geo.to_payload_string().unwrap(), "geo:-33.856784,151.215297,12.4");
+
+        let geo = Geo { precision: 0, ..geo };
+        assert_eq!(geo.to_payload_string().unwrap(), "geo:-34,151,12");
+
+        let geo = Geo { latitude: -0.000_000_1, longitude: 90.0, altitude: None, precision: 6 };
+        assert_eq!(geo.to_payload_string().unwrap(), "geo:0,90");
+    }
+
+    #[test]
+    fn test_invalid() {
+        let geo = Geo { latitude: 90.5, ..Geo::default() };
+        assert_eq!(geo.to_payload_string(), Err(QrError::InvalidData));
+
+        let geo = Geo { longitude: f64::NAN, ..Geo::default() };
+        assert_eq!(geo.to_payload_string(), Err(QrError::InvalidData));
+
+        let geo = Geo { altitude: Some(f64::INFINITY), ..Geo::default() };
+        assert_eq!(geo.to_payload_string(), Err(QrError::InvalidData));
+    }
+}
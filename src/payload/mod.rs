@@ -0,0 +1,197 @@
+//! The `payload` module builds the text of common QR code applications, such as
+//! Wi-Fi network configurations, contacts, payments, calendar events and
+//! messages. All of them implement the [`Payload`] trait.
+//!
+//! ```
+//! use qrcode::payload::{Wifi, WifiAuthentication};
+//! use qrcode::QrCode;
+//!
+//! let wifi = Wifi {
+//!     ssid: "My Network".into(),
+//!     password: "p@ss;word".into(),
+//!     authentication: WifiAuthentication::Wpa,
+//!     hidden: false,
+//! };
+//! let code = QrCode::from_payload(&wifi).unwrap();
+//! ```
+
+use crate::types::{EcLevel, QrResult};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+pub mod emvco;
+
+mod contact;
+mod cryptocurrency;
+mod email;
+mod epc;
+mod event;
+mod geo;
+mod otp;
+mod phone;
+mod swiss;
+mod wifi;
+
+pub use self::contact::{Address, Contact, ContactFormat};
+pub use self::cryptocurrency::{Bitcoin, Ethereum, Lightning};
+pub use self::email::{Email, EmailFormat};
+pub use self::emvco::{DataObject, DataValue, MerchantPresented, PointOfInitiation};
+pub use self::epc::{EpcCharacterSet, EpcRemittance, EpcTransfer, EpcVersion};
+pub use self::event::{CalendarEvent, EventTime};
+pub use self::geo::Geo;
+pub use self::otp::{Otp, OtpAlgorithm, OtpKind};
+pub use self::phone::{Sms, SmsFormat, Tel};
+pub use self::swiss::{SwissAddress, SwissCurrency, SwissQrBill, SwissReference, SWISS_QR_SIZE_MM};
+pub use self::wifi::{Wifi, WifiAuthentication};
+
+//------------------------------------------------------------------------------
+//{{{ Payload trait
+
+/// The structured content of a QR code, which is rendered into text before
+/// encoding.
+pub trait Payload {
+    /// Renders the payload into the text to be encoded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if the payload is incomplete or
+    /// malformed, e.g. a required field is empty.
+    fn to_payload_string(&self) -> QrResult<String>;
+
+    /// Renders the payload into the bytes to be encoded. Default is the
+    /// UTF-8 encoding of `to_payload_string()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if the payload is incomplete or
+    /// malformed, or cannot be represented in its character set.
+    fn to_payload_bytes(&self) -> QrResult<Vec<u8>> {
+        self.to_payload_string().map(String::into_bytes)
+    }
+
+    /// The error correction level recommended for the payload. Default is M.
+    fn ec_level(&self) -> EcLevel {
+        EcLevel::M
+    }
+
+    /// The ECI designator announcing the character set of
+    /// `to_payload_bytes()`, or `None` to leave it to the reader's default
+    /// (ISO-8859-1). Default is the UTF-8 designator (26) if the payload string
+    /// is not ASCII.
+    fn eci_designator(&self) -> Option<u32> {
+        self.to_payload_string().ok().as_deref().and_then(utf8_eci_designator)
+    }
+}
+
+/// The UTF-8 ECI designator (26) if the text is not ASCII.
+pub(crate) fn utf8_eci_designator(text: &str) -> Option<u32> {
+    (!text.is_ascii()).then_some(26)
+}
+
+//}}}
+//------------------------------------------------------------------------------
+//{{{ Helpers
+
+/// Appends the value to the output, escaping the backslash and all of the
+/// `special_chars` with a backslash.
+fn push_backslash_escaped(output: &mut String, value: &str, special_chars: &str) {
+    for c in value.chars() {
+        if c == '\\' || special_chars.contains(c) {
+            output.push('\\');
+        }
+        output.push(c);
+    }
+}
+
+/// The maximum length of a content line in vCard and iCalendar, in octets,
+/// excluding the line break.
+const MAX_LINE_LEN: usize = 75;
+
+/// Escapes the text value of a vCard or iCalendar property.
+fn escape_text(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' | ',' | ';' => {
+                output.push('\\');
+                output.push(c);
+            }
+            '\n' => output.push_str("\\n"),
+            '\r' => {}
+            _ => output.push(c),
+        }
+    }
+    output
+}
+
+/// Appends a vCard or iCalendar content line, folding it into multiple lines
+/// of at most 75 octets without splitting a UTF-8 sequence.
+fn push_folded_line(output: &mut String, name: &str, value: &str) {
+    let line = [name, ":", value].concat();
+    let mut rest = line.as_str();
+    let mut max_len = MAX_LINE_LEN;
+    loop {
+        let mut len = rest.len().min(max_len);
+        while !rest.is_char_boundary(len) {
+            len -= 1;
+        }
+        let (head, tail) = rest.split_at(len);
+        output.push_str(head);
+        output.push_str("\r\n");
+        if tail.is_empty() {
+            break;
+        }
+        // The continuation line starts with a space, which is not counted as
+        // part of the value but takes one octet.
+        output.push(' ');
+        rest = tail;
+        max_len = MAX_LINE_LEN - 1;
+    }
+}
+
+/// Appends the value to the URI, percent-encoding all characters except the
+/// unreserved ones of RFC 3986.
+pub(crate) fn push_percent_encoded(uri: &mut String, value: &str) {
+    for c in value.bytes() {
+        if c.is_ascii_alphanumeric() || matches!(c, b'-' | b'.' | b'_' | b'~') {
+            uri.push(char::from(c));
+        } else {
+            let _ = write!(uri, "%{c:02X}");
+        }
+    }
+}
+
+/// Checks the ISO 7064 MOD 97-10 check digits of a value which starts with
+/// two letters followed by two check digits, e.g. an IBAN (ISO 13616) or a
+/// creditor reference (ISO 11649). The value must consist of digits and
+/// uppercase letters only.
+fn has_valid_mod97_check_digits(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if bytes.len() < 5
+        || !bytes[..2].iter().all(u8::is_ascii_uppercase)
+        || !bytes[2..4].iter().all(u8::is_ascii_digit)
+        || !bytes[4..].iter().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+    {
+        return false;
+    }
+
+    // Move the first 4 characters to the end, convert the letters to 10 to
+    // 35, and the result should be 1 modulo 97.
+    let remainder = bytes[4..].iter().chain(&bytes[..4]).fold(0_u32, |remainder, &c| {
+        if c.is_ascii_digit() {
+            (remainder * 10 + u32::from(c - b'0')) % 97
+        } else {
+            (remainder * 100 + u32::from(c - b'A' + 10)) % 97
+        }
+    });
+    remainder == 1
+}
+
+/// Checks whether the IBAN (without spaces) has the valid format and check
+/// digits.
+fn is_valid_iban(iban: &str) -> bool {
+    (15..=34).contains(&iban.len()) && has_valid_mod97_check_digits(iban)
+}
+
+//}}}
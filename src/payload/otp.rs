@@ -0,0 +1,242 @@
+//! One-time password provisioning, in the `otpauth://` key URI format
+//! recognized by authenticator apps.
+
+use crate::payload::{push_percent_encoded, Payload};
+use crate::types::{QrError, QrResult};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// The kind of one-time password.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OtpKind {
+    /// Time-based one-time password (RFC 6238), changing every `period`
+    /// seconds. The usual period is 30 seconds.
+    Totp {
+        /// The period in seconds.
+        period: u32,
+    },
+
+    /// HMAC-based one-time password (RFC 4226), starting from the initial
+    /// `counter`.
+    Hotp {
+        /// The initial counter value.
+        counter: u64,
+    },
+}
+
+/// The hash algorithm of the one-time password.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OtpAlgorithm {
+    /// SHA-1, supported by all authenticator apps.
+    #[default]
+    Sha1,
+
+    /// SHA-256.
+    Sha256,
+
+    /// SHA-512.
+    Sha512,
+}
+
+/// The provisioning URI of a one-time password, e.g. for enrolling two-factor
+/// authentication.
+///
+/// The parameters equal to their defaults (SHA-1, 6 digits, 30 seconds) are
+/// omitted from the URI, since some apps reject the others.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Otp {
+    /// The kind of one-time password. Default is TOTP with a period of 30
+    /// seconds.
+    pub kind: OtpKind,
+
+    /// The name of the service provider, e.g. `Example Inc.`. May be empty.
+    pub issuer: String,
+
+    /// The account name, e.g. `alice@example.com`.
+    pub account: String,
+
+    /// The shared secret as raw bytes, which is Base32-encoded in the URI.
+    pub secret: Vec<u8>,
+
+    /// The hash algorithm. Default is SHA-1.
+    pub algorithm: OtpAlgorithm,
+
+    /// The number of digits, 6 to 8. Default is 6.
+    pub digits: u8,
+}
+
+impl Default for Otp {
+    fn default() -> Self {
+        Self {
+            kind: OtpKind::Totp { period: 30 },
+            issuer: String::new(),
+            account: String::new(),
+            secret: Vec::new(),
+            algorithm: OtpAlgorithm::Sha1,
+            digits: 6,
+        }
+    }
+}
+
+/// Encodes the data in Base32 (RFC 4648) without padding.
+fn encode_base32(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+    let mut output = String::with_capacity((data.len() * 8 + 4) / 5);
+    let mut buffer = 0_u32;
+    let mut bits = 0;
+    for &byte in data {
+        buffer = (buffer << 8) | u32::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            output.push(char::from(ALPHABET[(buffer >> bits) as usize & 31]));
+        }
+    }
+    if bits > 0 {
+        output.push(char::from(ALPHABET[(buffer << (5 - bits)) as usize & 31]));
+    }
+    output
+}
+
+impl Payload for Otp {
+    /// Renders the URI as
+    /// `otpauth://totp/<issuer>:<account>?secret=<secret>&issuer=<issuer>`.
+    ///
+    /// ```
+    /// use qrcode::payload::{Otp, Payload};
+    ///
+    /// let otp = Otp {
+    ///     issuer: "Example Inc.".into(),
+    ///     account: "alice@example.com".into(),
+    ///     secret: b"Hello!\xde\xad\xbe\xef".to_vec(),
+    ///     ..Otp::default()
+    /// };
+    /// assert_eq!(
+    ///     otp.to_payload_string().unwrap(),
+    ///     "otpauth://totp/Example%20Inc.:alice%40example.com?secret=JBSWY3DPEHPK3PXP&issuer=Example%20Inc.",
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if the account or the secret is
+    /// empty, the number of digits is not 6 to 8, or the period is zero.
+    fn to_payload_string(&self) -> QrResult<String> {
+        let is_valid = !self.account.is_empty()
+            && !self.secret.is_empty()
+            && (6..=8).contains(&self.digits)
+            && self.kind != OtpKind::Totp { period: 0 };
+        if !is_valid {
+            return Err(QrError::InvalidData);
+        }
+
+        let mut output = String::from("otpauth://");
+        output.push_str(match self.kind {
+            OtpKind::Totp { .. } => "totp/",
+            OtpKind::Hotp { .. } => "hotp/",
+        });
+        // The colon separating the issuer and the account is literal, while
+        // the colons inside them are percent-encoded.
+        if !self.issuer.is_empty() {
+            push_percent_encoded(&mut output, &self.issuer);
+            output.push(':');
+        }
+        push_percent_encoded(&mut output, &self.account);
+
+        output.push_str("?secret=");
+        output.push_str(&encode_base32(&self.secret));
+        if !self.issuer.is_empty() {
+            output.push_str("&issuer=");
+            push_percent_encoded(&mut output, &self.issuer);
+        }
+        match self.algorithm {
+            OtpAlgorithm::Sha1 => {}
+            OtpAlgorithm::Sha256 => output.push_str("&algorithm=SHA256"),
+            OtpAlgorithm::Sha512 => output.push_str("&algorithm=SHA512"),
+        }
+        if self.digits != 6 {
+            let _ = write!(output, "&digits={}", self.digits);
+        }
+        match self.kind {
+            OtpKind::Totp { period: 30 } => {}
+            OtpKind::Totp { period } => {
+                let _ = write!(output, "&period={period}");
+            }
+            OtpKind::Hotp { counter } => {
+                let _ = write!(output, "&counter={counter}");
+            }
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod otp_tests {
+    use crate::payload::otp::encode_base32;
+    use crate::payload::{Otp, OtpAlgorithm, OtpKind, Payload};
+    use crate::types::QrError;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_base32() {
+        // Test vectors from RFC 4648, without padding.
+        let vectors = [
+            ("", ""),
+            ("f", "MY"),
+            ("fo", "MZXQ"),
+            ("foo", "MZXW6"),
+            ("foob", "MZXW6YQ"),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI"),
+        ];
+        for (data, expected) in vectors {
+            assert_eq!(encode_base32(data.as_bytes()), expected);
+        }
+    }
+
+    #[test]
+    fn test_hotp() {
+        let otp = Otp {
+            kind: OtpKind::Hotp { counter: 42 },
+            issuer: "ACME Co: Dev".into(),
+            account: "bob".into(),
+            secret: b"12345678901234567890".to_vec(),
+            algorithm: OtpAlgorithm::Sha256,
+            digits: 8,
+        };
+        assert_eq!(
+            otp.to_payload_string().unwrap(),
+            "otpauth://hotp/ACME%20Co%3A%20Dev:bob?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ\
+             &issuer=ACME%20Co%3A%20Dev&algorithm=SHA256&digits=8&counter=42"
+        );
+    }
+
+    #[test]
+    fn test_totp_without_issuer() {
+        let otp = Otp {
+            kind: OtpKind::Totp { period: 60 },
+            account: "b\u{f6}b".into(),
+            secret: b"\xff".to_vec(),
+            algorithm: OtpAlgorithm::Sha512,
+            ..Otp::default()
+        };
+        assert_eq!(otp.to_payload_string().unwrap(), "otpauth://totp/b%C3%B6b?secret=74&algorithm=SHA512&period=60");
+    }
+
+    #[test]
+    fn test_invalid() {
+        let otp = Otp { account: "alice".into(), secret: b"secret".to_vec(), ..Otp::default() };
+        assert!(otp.to_payload_string().is_ok());
+
+        let no_account = Otp { account: String::new(), ..otp.clone() };
+        assert_eq!(no_account.to_payload_string(), Err(QrError::InvalidData));
+        let no_secret = Otp { secret: Vec::new(), ..otp.clone() };
+        assert_eq!(no_secret.to_payload_string(), Err(QrError::InvalidData));
+        let too_many_digits = Otp { digits: 9, ..otp.clone() };
+        assert_eq!(too_many_digits.to_payload_string(), Err(QrError::InvalidData));
+        let zero_period = Otp { kind: OtpKind::Totp { period: 0 }, ..otp };
+        assert_eq!(zero_period.to_payload_string(), Err(QrError::InvalidData));
+    }
+}
@@ -0,0 +1,145 @@
+//! Telephone calls (`tel:`, RFC 3966) and text messages (`SMSTO:`, `MMSTO:`
+//! or `sms:`, RFC 5724).
+
+use crate::payload::{push_percent_encoded, Payload};
+use crate::types::{QrError, QrResult};
+use alloc::string::String;
+
+/// Removes the spaces from the telephone number, and checks that it consists
+/// of digits, an optional leading `+`, and the visual separators `-.()`.
+fn normalize_number(number: &str) -> QrResult<String> {
+    let number = number.replace(' ', "");
+    let digits = number.strip_prefix('+').unwrap_or(&number);
+    let is_valid = digits.bytes().any(|c| c.is_ascii_digit())
+        && digits.bytes().all(|c| c.is_ascii_digit() || matches!(c, b'-' | b'.' | b'(' | b')'));
+    if is_valid {
+        Ok(number)
+    } else {
+        Err(QrError::InvalidData)
+    }
+}
+
+/// A telephone number to call.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Tel {
+    /// The telephone number, preferably in the international format starting
+    /// with `+`. Spaces are removed.
+    pub number: String,
+}
+
+impl Payload for Tel {
+    /// Renders the number as `tel:<number>`.
+    ///
+    /// ```
+    /// use qrcode::payload::{Payload, Tel};
+    ///
+    /// let tel = Tel { number: "+1 555 0100".into() };
+    /// assert_eq!(tel.to_payload_string().unwrap(), "tel:+15550100");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if the number is empty or contains
+    /// characters other than digits and visual separators.
+    fn to_payload_string(&self) -> QrResult<String> {
+        Ok(["tel:", &normalize_number(&self.number)?].concat())
+    }
+}
+
+/// The format of a text message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SmsFormat {
+    /// `SMSTO:<number>:<message>`, recognized by most phone cameras.
+    #[default]
+    SmsTo,
+
+    /// `MMSTO:<number>:<message>`, for a multimedia message.
+    MmsTo,
+
+    /// `sms:<number>?body=<message>`, the URI of RFC 5724.
+    Uri,
+}
+
+/// A text message to send.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Sms {
+    /// The telephone number of the recipient. Spaces are removed.
+    pub number: String,
+
+    /// The message. May be empty.
+    pub message: String,
+
+    /// The format of the payload.
+    pub format: SmsFormat,
+}
+
+impl Payload for Sms {
+    /// Renders the message in the selected format.
+    ///
+    /// ```
+    /// use qrcode::payload::{Payload, Sms, SmsFormat};
+    ///
+    /// let sms = Sms { number: "+1 555 0100".into(), message: "Hi there!".into(), format: SmsFormat::Uri };
+    /// assert_eq!(sms.to_payload_string().unwrap(), "sms:+15550100?body=Hi%20there%21");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if the number is empty or contains
+    /// characters other than digits and visual separators.
+    fn to_payload_string(&self) -> QrResult<String> {
+        let number = normalize_number(&self.number)?;
+        let mut output = String::from(match self.format {
+            SmsFormat::SmsTo => "SMSTO:",
+            SmsFormat::MmsTo => "MMSTO:",
+            SmsFormat::Uri => "sms:",
+        });
+        output.push_str(&number);
+        if self.format == SmsFormat::Uri {
+            if !self.message.is_empty() {
+                output.push_str("?body=");
+                push_percent_encoded(&mut output, &self.message);
+            }
+        } else {
+            // The message is everything after the second colon, so it needs
+            // no escaping.
+            output.push(':');
+            output.push_str(&self.message);
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod phone_tests {
+    use crate::payload::{Payload, Sms, SmsFormat, Tel};
+    use crate::types::QrError;
+
+    #[test]
+    fn test_tel() {
+        let tel = Tel { number: "(030) 1234-5678".into() };
+        assert_eq!(tel.to_payload_string().unwrap(), "tel:(030)1234-5678");
+
+        for number in ["", "+", "555 CALL NOW", "+1+2"] {
+            let tel = Tel { number: number.into() };
+            assert_eq!(tel.to_payload_string(), Err(QrError::InvalidData));
+        }
+    }
+
+    #[test]
+    fn test_sms() {
+        let sms = Sms { number: "+44 7700 900123".into(), message: "Meet at 5:30?".into(), format: SmsFormat::SmsTo };
+        assert_eq!(sms.to_payload_string().unwrap(), "SMSTO:+447700900123:Meet at 5:30?");
+
+        let mms = Sms { format: SmsFormat::MmsTo, ..sms.clone() };
+        assert_eq!(mms.to_payload_string().unwrap(), "MMSTO:+447700900123:Meet at 5:30?");
+
+        let uri = Sms { format: SmsFormat::Uri, ..sms };
+        assert_eq!(uri.to_payload_string().unwrap(), "sms:+447700900123?body=Meet%20at%205%3A30%3F");
+
+        let empty = Sms { number: "112".into(), ..Sms::default() };
+        assert_eq!(empty.to_payload_string().unwrap(), "SMSTO:112:");
+        let empty = Sms { format: SmsFormat::Uri, ..empty };
+        assert_eq!(empty.to_payload_string().unwrap(), "sms:112");
+    }
+}
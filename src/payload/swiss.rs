@@ -0,0 +1,372 @@
+//! Swiss QR-bills, in the "SPC" format of the Swiss Payment Standards.
+//!
+//! The QR code of a QR-bill must use error correction level M, be printed at
+//! 46×46 mm, and carry the Swiss cross in its center. Both are applied by
+//! [`Renderer::swiss_qr_bill`](crate::render::Renderer::swiss_qr_bill).
+//!
+//! ```
+//! # #[cfg(feature = "svg")] {
+//! use qrcode::payload::{SwissAddress, SwissQrBill, SwissReference};
+//! use qrcode::render::svg;
+//! use qrcode::QrCode;
+//!
+//! let bill = SwissQrBill {
+//!     iban: "CH44 3199 9123 0008 8901 2".into(),
+//!     creditor: SwissAddress {
+//!         name: "Robert Schneider AG".into(),
+//!         street: "Rue du Lac".into(),
+//!         building_number: "1268".into(),
+//!         postal_code: "2501".into(),
+//!         town: "Biel".into(),
+//!         country: "CH".into(),
+//!     },
+//!     amount_in_cents: Some(194_975),
+//!     reference: SwissReference::Qr("210000000003139471430009017".into()),
+//!     ..SwissQrBill::default()
+//! };
+//! let code = QrCode::from_payload(&bill).unwrap();
+//! let svg_xml = code.render::<svg::Color>().swiss_qr_bill().build();
+//! assert!(svg_xml.contains(r#"width="46mm" height="46mm""#));
+//! # }
+//! ```
+
+use crate::payload::{has_valid_mod97_check_digits, is_valid_iban, Payload};
+use crate::types::{EcLevel, QrError, QrResult};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// The maximum length of the payload, in characters.
+const MAX_PAYLOAD_LEN: usize = 997;
+
+/// The size of the printed QR code, in millimeters, excluding the quiet zone.
+pub const SWISS_QR_SIZE_MM: u32 = 46;
+
+/// The currency of a QR-bill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SwissCurrency {
+    /// Swiss franc.
+    #[default]
+    Chf,
+
+    /// Euro.
+    Eur,
+}
+
+/// The reference of a QR-bill.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SwissReference {
+    /// A QR reference (`QRR`) of 27 digits, including the check digit. Requires
+    /// a QR-IBAN.
+    Qr(String),
+
+    /// An ISO 11649 creditor reference (`SCOR`), e.g. `RF18539007547034`.
+    /// Requires a regular IBAN.
+    Creditor(String),
+
+    /// No reference (`NON`). Requires a regular IBAN.
+    #[default]
+    None,
+}
+
+/// A structured address of a creditor or debtor.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SwissAddress {
+    /// The name or company, at most 70 characters.
+    pub name: String,
+
+    /// The street, at most 70 characters. May be empty.
+    pub street: String,
+
+    /// The building number, at most 16 characters. May be empty.
+    pub building_number: String,
+
+    /// The postal code without country prefix, at most 16 characters.
+    pub postal_code: String,
+
+    /// The town, at most 35 characters.
+    pub town: String,
+
+    /// The ISO 3166-1 alpha-2 country code, e.g. `CH`.
+    pub country: String,
+}
+
+/// A Swiss QR-bill.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SwissQrBill {
+    /// The IBAN or QR-IBAN of the creditor, from Switzerland or Liechtenstein.
+    /// Spaces are removed.
+    pub iban: String,
+
+    /// The creditor.
+    pub creditor: SwissAddress,
+
+    /// The amount in cents (Rappen), from 1 to 99999999999. `None` if the
+    /// debtor should enter the amount.
+    pub amount_in_cents: Option<u64>,
+
+    /// The currency.
+    pub currency: SwissCurrency,
+
+    /// The debtor, if known.
+    pub debtor: Option<SwissAddress>,
+
+    /// The payment reference.
+    pub reference: SwissReference,
+
+    /// The unstructured message. Together with the billing information at
+    /// most 140 characters.
+    pub message: String,
+
+    /// The structured billing information, e.g. in the Swico S1 syntax.
+    pub billing_information: String,
+
+    /// The parameters of at most 2 alternative procedures, at most 100
+    /// characters each.
+    pub alternative_schemes: Vec<String>,
+}
+
+/// Checks whether the character is in the character set permitted by the
+/// Swiss Payment Standards, which is a subset of Latin characters.
+fn is_permitted_char(c: char) -> bool {
+    matches!(c, ' '..='~' | '\u{a0}'..='\u{17f}' | '\u{218}'..='\u{21b}' | '\u{20ac}')
+}
+
+/// Checks the modulo 10 recursive check digit of a QR reference.
+fn is_valid_qr_reference(reference: &str) -> bool {
+    const TABLE: [u8; 10] = [0, 9, 4, 6, 8, 2, 7, 1, 3, 5];
+    reference.len() == 27
+        && reference.bytes().all(|c| c.is_ascii_digit())
+        && reference.bytes().fold(0, |carry, c| TABLE[usize::from((carry + c - b'0') % 10)]) == 0
+}
+
+/// Checks whether the IBAN is a QR-IBAN, i.e. its institution identification
+/// is between 30000 and 31999.
+fn is_qr_iban(iban: &str) -> bool {
+    iban.get(4..9).and_then(|iid| iid.parse::<u32>().ok()).is_some_and(|iid| (30000..=31999).contains(&iid))
+}
+
+impl SwissAddress {
+    /// Checks the mandatory fields and the field lengths.
+    fn is_valid(&self) -> bool {
+        let len = |field: &str| field.chars().count();
+        (1..=70).contains(&len(&self.name))
+            && len(&self.street) <= 70
+            && len(&self.building_number) <= 16
+            && (1..=16).contains(&len(&self.postal_code))
+            && (1..=35).contains(&len(&self.town))
+            && self.country.len() == 2
+            && self.country.bytes().all(|c| c.is_ascii_uppercase())
+    }
+
+    /// Appends the address type and the fields, each followed by a line feed.
+    fn push_to(&self, output: &mut String) {
+        output.push_str("S\n");
+        for field in [&self.name, &self.street, &self.building_number, &self.postal_code, &self.town, &self.country] {
+            output.push_str(field);
+            output.push('\n');
+        }
+    }
+}
+
+impl SwissQrBill {
+    /// Checks the fields against the rules of the Swiss Payment Standards.
+    fn validate(&self, iban: &str) -> QrResult<()> {
+        let is_valid_reference = match &self.reference {
+            SwissReference::Qr(reference) => is_qr_iban(iban) && is_valid_qr_reference(reference),
+            SwissReference::Creditor(reference) => {
+                !is_qr_iban(iban) && (5..=25).contains(&reference.len()) && has_valid_mod97_check_digits(reference)
+            }
+            SwissReference::None => !is_qr_iban(iban),
+        };
+        let is_valid = iban.len() == 21
+            && (iban.starts_with("CH") || iban.starts_with("LI"))
+            && is_valid_iban(iban)
+            && is_valid_reference
+            && self.creditor.is_valid()
+            && self.debtor.as_ref().map_or(true, SwissAddress::is_valid)
+            && self.amount_in_cents.map_or(true, |amount| (1..=99_999_999_999).contains(&amount))
+            && self.message.chars().count() + self.billing_information.chars().count() <= 140
+            && self.alternative_schemes.len() <= 2
+            && self.alternative_schemes.iter().all(|scheme| scheme.chars().count() <= 100);
+        if is_valid {
+            Ok(())
+        } else {
+            Err(QrError::InvalidData)
+        }
+    }
+}
+
+impl Payload for SwissQrBill {
+    /// Renders the QR-bill as lines separated by line feeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if a field is missing or malformed,
+    /// the reference does not match the type of the IBAN, a character is not
+    /// permitted, or the payload exceeds 997 characters.
+    fn to_payload_string(&self) -> QrResult<String> {
+        let iban = self.iban.replace(' ', "");
+        self.validate(&iban)?;
+
+        let mut output = String::from("SPC\n0200\n1\n");
+        output.push_str(&iban);
+        output.push('\n');
+        self.creditor.push_to(&mut output);
+        // The ultimate creditor is reserved for future use.
+        output.push_str("\n\n\n\n\n\n\n");
+        if let Some(amount) = self.amount_in_cents {
+            let _ = write!(output, "{}.{:02}", amount / 100, amount % 100);
+        }
+        output.push('\n');
+        output.push_str(match self.currency {
+            SwissCurrency::Chf => "CHF\n",
+            SwissCurrency::Eur => "EUR\n",
+        });
+        match &self.debtor {
+            Some(debtor) => debtor.push_to(&mut output),
+            None => output.push_str("\n\n\n\n\n\n\n"),
+        }
+        let (reference_type, reference) = match &self.reference {
+            SwissReference::Qr(reference) => ("QRR", reference.as_str()),
+            SwissReference::Creditor(reference) => ("SCOR", reference.as_str()),
+            SwissReference::None => ("NON", ""),
+        };
+        for field in [reference_type, reference, &self.message] {
+            output.push_str(field);
+            output.push('\n');
+        }
+        output.push_str("EPD");
+
+        // The trailing optional fields are omitted.
+        let has_optional_fields = !self.billing_information.is_empty() || !self.alternative_schemes.is_empty();
+        if has_optional_fields {
+            output.push('\n');
+            output.push_str(&self.billing_information);
+        }
+        for scheme in &self.alternative_schemes {
+            output.push('\n');
+            output.push_str(scheme);
+        }
+
+        // A line feed inside a field would shift the following fields.
+        let line_count = 31 + if has_optional_fields { 1 + self.alternative_schemes.len() } else { 0 };
+        let is_valid = output.chars().count() <= MAX_PAYLOAD_LEN
+            && output.chars().all(|c| c == '\n' || is_permitted_char(c))
+            && output.split('\n').count() == line_count;
+        if is_valid {
+            Ok(output)
+        } else {
+            Err(QrError::InvalidData)
+        }
+    }
+
+    /// The Swiss Payment Standards require error correction level M.
+    fn ec_level(&self) -> EcLevel {
+        EcLevel::M
+    }
+}
+
+#[cfg(test)]
+mod swiss_tests {
+    use crate::payload::swiss::is_valid_qr_reference;
+    use crate::payload::{Payload, SwissAddress, SwissCurrency, SwissQrBill, SwissReference};
+    use crate::types::{EcLevel, QrError};
+    use alloc::string::String;
+    use alloc::vec;
+
+    fn bill() -> SwissQrBill {
+        SwissQrBill {
+            iban: "CH44 3199 9123 0008 8901 2".into(),
+            creditor: SwissAddress {
+                name: "Robert Schneider AG".into(),
+                street: "Rue du Lac".into(),
+                building_number: "1268".into(),
+                postal_code: "2501".into(),
+                town: "Biel".into(),
+                country: "CH".into(),
+            },
+            amount_in_cents: Some(194_975),
+            debtor: Some(SwissAddress {
+                name: "Pia-Maria Rutschmann-Schnyder".into(),
+                street: "Grosse Marktgasse".into(),
+                building_number: "28".into(),
+                postal_code: "9400".into(),
+                town: "Rorschach".into(),
+                country: "CH".into(),
+            }),
+            reference: SwissReference::Qr("210000000003139471430009017".into()),
+            message: "Order of 15 June 2020".into(),
+            ..SwissQrBill::default()
+        }
+    }
+
+    #[test]
+    fn test_qr_reference() {
+        assert!(is_valid_qr_reference("210000000003139471430009017"));
+        assert!(!is_valid_qr_reference("210000000003139471430009018"));
+        assert!(!is_valid_qr_reference("21000000000313947143000901"));
+    }
+
+    #[test]
+    fn test_bill() {
+        let bill = bill();
+        assert_eq!(
+            bill.to_payload_string().unwrap(),
+            "SPC\n0200\n1\nCH4431999123000889012\n\
+             S\nRobert Schneider AG\nRue du Lac\n1268\n2501\nBiel\nCH\n\
+             \n\n\n\n\n\n\n\
+             1949.75\nCHF\n\
+             S\nPia-Maria Rutschmann-Schnyder\nGrosse Marktgasse\n28\n9400\nRorschach\nCH\n\
+             QRR\n210000000003139471430009017\nOrder of 15 June 2020\nEPD"
+        );
+        assert_eq!(bill.ec_level(), EcLevel::M);
+    }
+
+    #[test]
+    fn test_creditor_reference() {
+        let bill = SwissQrBill {
+            iban: "CH5800791123000889012".into(),
+            amount_in_cents: None,
+            currency: SwissCurrency::Eur,
+            debtor: None,
+            reference: SwissReference::Creditor("RF18539007547034".into()),
+            message: String::new(),
+            billing_information: "//S1/10/10201409/11/200701/20/140.000-53".into(),
+            alternative_schemes: vec!["eBill/B/peter@sample.ch".into()],
+            ..bill()
+        };
+        assert_eq!(
+            bill.to_payload_string().unwrap(),
+            "SPC\n0200\n1\nCH5800791123000889012\n\
+             S\nRobert Schneider AG\nRue du Lac\n1268\n2501\nBiel\nCH\n\
+             \n\n\n\n\n\n\n\
+             \nEUR\n\
+             \n\n\n\n\n\n\n\
+             SCOR\nRF18539007547034\n\nEPD\n\
+             //S1/10/10201409/11/200701/20/140.000-53\neBill/B/peter@sample.ch"
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        // A QR reference requires a QR-IBAN, and vice versa.
+        let regular_iban = SwissQrBill { iban: "CH5800791123000889012".into(), ..bill() };
+        assert_eq!(regular_iban.to_payload_string(), Err(QrError::InvalidData));
+        let no_reference = SwissQrBill { reference: SwissReference::None, ..bill() };
+        assert_eq!(no_reference.to_payload_string(), Err(QrError::InvalidData));
+
+        let foreign_iban = SwissQrBill { iban: "DE89370400440532013000".into(), ..bill() };
+        assert_eq!(foreign_iban.to_payload_string(), Err(QrError::InvalidData));
+
+        let multi_line = SwissQrBill { message: "a\nb".into(), ..bill() };
+        assert_eq!(multi_line.to_payload_string(), Err(QrError::InvalidData));
+
+        let emoji = SwissQrBill { message: "\u{1f600}".into(), ..bill() };
+        assert_eq!(emoji.to_payload_string(), Err(QrError::InvalidData));
+
+        let mut no_town = bill();
+        no_town.creditor.town = String::new();
+        assert_eq!(no_town.to_payload_string(), Err(QrError::InvalidData));
+    }
+}
@@ -0,0 +1,134 @@
+//! Wi-Fi network configuration, in the `WIFI:` format recognized by most
+//! phone cameras.
+
+use crate::payload::{push_backslash_escaped, Payload};
+use crate::types::{QrError, QrResult};
+use alloc::string::String;
+
+/// The authentication type of a Wi-Fi network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WifiAuthentication {
+    /// WPA or WPA2 personal.
+    Wpa,
+
+    /// WPA3 personal, with Simultaneous Authentication of Equals.
+    Wpa3Sae,
+
+    /// WEP.
+    Wep,
+
+    /// An open network without password.
+    NoPass,
+}
+
+/// The configuration to join a Wi-Fi network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wifi {
+    /// The SSID (name) of the network.
+    pub ssid: String,
+
+    /// The password of the network. Ignored if the network is open.
+    pub password: String,
+
+    /// The authentication type of the network.
+    pub authentication: WifiAuthentication,
+
+    /// Whether the network does not broadcast its SSID.
+    pub hidden: bool,
+}
+
+/// Appends a field value, escaping the special characters. A value which
+/// could be mistaken as hex digits is quoted.
+fn push_field(output: &mut String, value: &str) {
+    let is_hex = value.bytes().all(|c| c.is_ascii_hexdigit());
+    if is_hex {
+        output.push('"');
+    }
+    push_backslash_escaped(output, value, ";,:\"");
+    if is_hex {
+        output.push('"');
+    }
+}
+
+impl Payload for Wifi {
+    /// Renders the configuration as `WIFI:T:WPA;S:<ssid>;P:<password>;;`.
+    ///
+    /// ```
+    /// use qrcode::payload::{Payload, Wifi, WifiAuthentication};
+    ///
+    /// let wifi = Wifi {
+    ///     ssid: "Café; Guest".into(),
+    ///     password: "".into(),
+    ///     authentication: WifiAuthentication::NoPass,
+    ///     hidden: true,
+    /// };
+    /// assert_eq!(wifi.to_payload_string().unwrap(), r"WIFI:T:nopass;S:Café\; Guest;H:true;;");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(QrError::InvalidData)` if the SSID is empty, or the
+    /// password is empty for a secured network.
+    fn to_payload_string(&self) -> QrResult<String> {
+        let has_password = self.authentication != WifiAuthentication::NoPass;
+        if self.ssid.is_empty() || (has_password && self.password.is_empty()) {
+            return Err(QrError::InvalidData);
+        }
+
+        let mut output = String::from("WIFI:T:");
+        output.push_str(match self.authentication {
+            WifiAuthentication::Wpa => "WPA",
+            WifiAuthentication::Wpa3Sae => "SAE",
+            WifiAuthentication::Wep => "WEP",
+            WifiAuthentication::NoPass => "nopass",
+        });
+        output.push_str(";S:");
+        push_field(&mut output, &self.ssid);
+        if has_password {
+            output.push_str(";P:");
+            push_field(&mut output, &self.password);
+        }
+        if self.hidden {
+            output.push_str(";H:true");
+        }
+        output.push_str(";;");
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod wifi_tests {
+    use crate::payload::{Payload, Wifi, WifiAuthentication};
+    use crate::types::QrError;
+
+    fn wifi(ssid: &str, password: &str, authentication: WifiAuthentication) -> Wifi {
+        Wifi { ssid: ssid.into(), password: password.into(), authentication, hidden: false }
+    }
+
+    #[test]
+    fn test_wpa() {
+        let payload = wifi("Home", "correct horse", WifiAuthentication::Wpa).to_payload_string();
+        assert_eq!(payload.unwrap(), "WIFI:T:WPA;S:Home;P:correct horse;;");
+        let payload = wifi("Home", "correct horse", WifiAuthentication::Wpa3Sae).to_payload_string();
+        assert_eq!(payload.unwrap(), "WIFI:T:SAE;S:Home;P:correct horse;;");
+    }
+
+    #[test]
+    fn test_escaping() {
+        let payload = wifi(r#"a\b;c,d:e"f"#, "p;w", WifiAuthentication::Wep).to_payload_string();
+        assert_eq!(payload.unwrap(), r#"WIFI:T:WEP;S:a\\b\;c\,d\:e\"f;P:p\;w;;"#);
+    }
+
+    #[test]
+    fn test_hex_quoted() {
+        let payload = wifi("Home", "ABCD1234", WifiAuthentication::Wpa).to_payload_string();
+        assert_eq!(payload.unwrap(), "WIFI:T:WPA;S:Home;P:\"ABCD1234\";;");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(wifi("", "pass", WifiAuthentication::Wpa).to_payload_string(), Err(QrError::InvalidData));
+        assert_eq!(wifi("Home", "", WifiAuthentication::Wpa).to_payload_string(), Err(QrError::InvalidData));
+        assert_eq!(wifi("Home", "", WifiAuthentication::NoPass).to_payload_string().unwrap(), "WIFI:T:nopass;S:Home;;");
+    }
+}
@@ -157,4 +157,26 @@ mod render_tests {
         assert_eq!(image.dimensions(), (8, 4));
         assert_eq!(image.into_raw(), expected);
     }
+
+    #[test]
+    fn test_render_swiss_cross() {
+        let content = [Color::Dark; 46 * 46];
+        let image = Renderer::<Luma<u8>>::new(&content, 46, 0).module_dimensions(10, 10).swiss_cross(true).build();
+
+        // The logo is 70×70 pixels in the center, with a 5-pixel light border.
+        assert_eq!(image.get_pixel(194, 194), &Luma([0]));
+        assert_eq!(image.get_pixel(195, 195), &Luma([255]));
+        assert_eq!(image.get_pixel(199, 264), &Luma([255]));
+        assert_eq!(image.get_pixel(200, 200), &Luma([0]));
+        assert_eq!(image.get_pixel(259, 259), &Luma([0]));
+        assert_eq!(image.get_pixel(265, 265), &Luma([0]));
+
+        // The cross is light.
+        assert_eq!(image.get_pixel(230, 230), &Luma([255]));
+        assert_eq!(image.get_pixel(212, 230), &Luma([255]));
+        assert_eq!(image.get_pixel(210, 230), &Luma([0]));
+        assert_eq!(image.get_pixel(230, 247), &Luma([255]));
+        assert_eq!(image.get_pixel(230, 249), &Luma([0]));
+        assert_eq!(image.get_pixel(220, 220), &Luma([0]));
+    }
 }
@@ -1,8 +1,9 @@
 //! Render a QR code into image.
 
 use crate::cast::As;
+use crate::payload::SWISS_QR_SIZE_MM;
 use crate::types::Color;
-use core::cmp::max;
+use core::cmp::{max, min};
 
 pub mod eps;
 pub mod image;
@@ -43,6 +44,10 @@ pub trait Canvas: Sized {
     /// Constructs a new canvas of the given dimensions.
     fn new(width: u32, height: u32, dark_pixel: Self::Pixel, light_pixel: Self::Pixel) -> Self;
 
+    /// Sets the physical size of the image in millimeters, for the formats
+    /// supporting it. The default implementation ignores the size.
+    fn set_physical_size_mm(&mut self, _width: u32, _height: u32) {}
+
     /// Draws a single dark pixel at the (x, y) coordinate.
     fn draw_dark_pixel(&mut self, x: u32, y: u32);
 
@@ -68,13 +73,15 @@ pub trait Canvas: Sized {
 /// an image.
 pub struct Renderer<'a, P: Pixel> {
     content: &'a [Color],
-    modules_count: u32, // <- we call it `modules_count` here to avoid ambiguity of `width`.
+    modules_count: (u32, u32), // <- we call it `modules_count` here to avoid ambiguity of `width`.
     quiet_zone: u32,
     module_size: (u32, u32),
 
     dark_color: P,
     light_color: P,
     has_quiet_zone: bool,
+    has_swiss_cross: bool,
+    physical_size_mm: Option<(u32, u32)>,
 }
 
 impl<'a, P: Pixel> Renderer<'a, P> {
@@ -84,15 +91,26 @@ impl<'a, P: Pixel> Renderer<'a, P> {
     ///
     /// Panics if the length of `content` is not exactly `modules_count * modules_count`.
     pub fn new(content: &'a [Color], modules_count: usize, quiet_zone: u32) -> Self {
-        assert!(modules_count * modules_count == content.len());
+        Self::new_rectangular(content, modules_count, modules_count, quiet_zone)
+    }
+
+    /// Creates a new renderer for a rectangular symbol, e.g. rMQR code.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `content` is not exactly `modules_width * modules_height`.
+    pub fn new_rectangular(content: &'a [Color], modules_width: usize, modules_height: usize, quiet_zone: u32) -> Self {
+        assert!(modules_width * modules_height == content.len());
         Renderer {
             content,
-            modules_count: modules_count.as_u32(),
+            modules_count: (modules_width.as_u32(), modules_height.as_u32()),
             quiet_zone,
             module_size: P::default_unit_size(),
             dark_color: P::default_color(Color::Dark),
             light_color: P::default_color(Color::Light),
             has_quiet_zone: true,
+            has_swiss_cross: false,
+            physical_size_mm: None,
         }
     }
 
@@ -114,6 +132,55 @@ impl<'a, P: Pixel> Renderer<'a, P> {
         self
     }
 
+    /// Whether to overlay the Swiss cross in the center of the symbol, as
+    /// required by the Swiss QR-bill. Default is false.
+    ///
+    /// The cross covers 7/46 of the symbol, so the QR code should use error
+    /// correction level M or above. The modules should be large enough (e.g.
+    /// the default 8×8 pixels) for the cross to be drawn accurately.
+    ///
+    /// ```
+    /// # #[cfg(feature = "svg")] {
+    /// use qrcode::render::svg;
+    /// use qrcode::{EcLevel, QrCode};
+    ///
+    /// let code = QrCode::with_error_correction_level(b"SPC", EcLevel::M).unwrap();
+    /// let svg_xml = code.render::<svg::Color>().swiss_cross(true).build();
+    /// # }
+    /// ```
+    pub fn swiss_cross(&mut self, has_swiss_cross: bool) -> &mut Self {
+        self.has_swiss_cross = has_swiss_cross;
+        self
+    }
+
+    /// Renders the QR code as required by the Swiss QR-bill: with the Swiss
+    /// cross, without the quiet zone, and with the physical size of 46×46 mm.
+    ///
+    /// ```
+    /// # #[cfg(feature = "svg")] {
+    /// use qrcode::render::svg;
+    /// use qrcode::{EcLevel, QrCode};
+    ///
+    /// let code = QrCode::with_error_correction_level(b"SPC", EcLevel::M).unwrap();
+    /// let svg_xml = code.render::<svg::Color>().swiss_qr_bill().build();
+    /// assert!(svg_xml.contains(r#"width="46mm" height="46mm""#));
+    /// # }
+    /// ```
+    pub fn swiss_qr_bill(&mut self) -> &mut Self {
+        self.swiss_cross(true).quiet_zone(false).physical_dimensions_mm(SWISS_QR_SIZE_MM, SWISS_QR_SIZE_MM)
+    }
+
+    /// Sets the physical size of the whole image in millimeters, including
+    /// the quiet zone if applicable. Default is unset.
+    ///
+    /// Only the SVG renderer supports this, where it sets the `width` and
+    /// `height` attributes while the pixels are kept in the `viewBox`. Other
+    /// renderers ignore it.
+    pub fn physical_dimensions_mm(&mut self, width: u32, height: u32) -> &mut Self {
+        self.physical_size_mm = Some((width, height));
+        self
+    }
+
     /// Sets the size of each module in pixels. Default is 8px.
     #[deprecated(since = "0.4.0", note = "use `.module_dimensions(width, width)` instead")]
     pub fn module_size(&mut self, width: u32) -> &mut Self {
@@ -148,10 +215,9 @@ impl<'a, P: Pixel> Renderer<'a, P> {
     /// quiet zone. If we request an image of size ≥200×200, we get that each
     /// module's size should be 11×11, so the actual image size will be 209×209.
     pub fn min_dimensions(&mut self, width: u32, height: u32) -> &mut Self {
-        let quiet_zone = if self.has_quiet_zone { 2 } else { 0 } * self.quiet_zone;
-        let width_in_modules = self.modules_count + quiet_zone;
+        let (width_in_modules, height_in_modules) = self.total_modules_count();
         let unit_width = (width + width_in_modules - 1) / width_in_modules;
-        let unit_height = (height + width_in_modules - 1) / width_in_modules;
+        let unit_height = (height + height_in_modules - 1) / height_in_modules;
         self.module_dimensions(unit_width, unit_height)
     }
 
@@ -167,13 +233,19 @@ impl<'a, P: Pixel> Renderer<'a, P> {
     /// The module size is at least 1×1, so if the restriction is too small, the
     /// final image *can* be larger than the input.
     pub fn max_dimensions(&mut self, width: u32, height: u32) -> &mut Self {
-        let quiet_zone = if self.has_quiet_zone { 2 } else { 0 } * self.quiet_zone;
-        let width_in_modules = self.modules_count + quiet_zone;
+        let (width_in_modules, height_in_modules) = self.total_modules_count();
         let unit_width = width / width_in_modules;
-        let unit_height = height / width_in_modules;
+        let unit_height = height / height_in_modules;
         self.module_dimensions(unit_width, unit_height)
     }
 
+    /// Obtains the number of modules across and down, including the quiet
+    /// zone if applicable.
+    const fn total_modules_count(&self) -> (u32, u32) {
+        let quiet_zone = if self.has_quiet_zone { 2 } else { 0 } * self.quiet_zone;
+        (self.modules_count.0 + quiet_zone, self.modules_count.1 + quiet_zone)
+    }
+
     /// Renders the QR code into an image.
     #[deprecated(since = "0.4.0", note = "renamed to `.build()` to de-emphasize the image connection")]
     pub fn to_image(&self) -> P::Image {
@@ -182,29 +254,112 @@ impl<'a, P: Pixel> Renderer<'a, P> {
 
     /// Renders the QR code into an image.
     pub fn build(&self) -> P::Image {
-        let w = self.modules_count;
+        let (w, h) = self.modules_count;
         let qz = if self.has_quiet_zone { self.quiet_zone } else { 0 };
-        let width = w + 2 * qz;
+        let (width, height) = self.total_modules_count();
 
         let (mw, mh) = self.module_size;
         let real_width = width * mw;
-        let real_height = width * mh;
+        let real_height = height * mh;
 
         let mut canvas = P::Canvas::new(real_width, real_height, self.dark_color, self.light_color);
+        if let Some((width_mm, height_mm)) = self.physical_size_mm {
+            canvas.set_physical_size_mm(width_mm, height_mm);
+        }
+        let swiss_cross = self.has_swiss_cross.then(|| swiss_cross_area((qz * mw, qz * mh, w * mw, h * mh)));
         let mut i = 0;
-        for y in 0..width {
+        for y in 0..height {
             for x in 0..width {
-                if qz <= x && x < w + qz && qz <= y && y < w + qz {
+                if qz <= x && x < w + qz && qz <= y && y < h + qz {
                     if self.content[i] != Color::Light {
-                        canvas.draw_dark_rect(x * mw, y * mh, mw, mh);
+                        let rect = (x * mw, y * mh, mw, mh);
+                        match swiss_cross {
+                            Some(area) => draw_dark_rect_outside(&mut canvas, rect, area),
+                            None => canvas.draw_dark_rect(rect.0, rect.1, rect.2, rect.3),
+                        }
                     }
                     i += 1;
                 }
             }
         }
+        if let Some(area) = swiss_cross {
+            draw_swiss_cross(&mut canvas, area);
+        }
 
         canvas.into_image()
     }
 }
 
 //}}}
+//------------------------------------------------------------------------------
+//{{{ Swiss cross
+
+/// A rectangle in pixels, as (left, top, width, height).
+type Rect = (u32, u32, u32, u32);
+
+/// Obtains the area of the Swiss cross logo, which is 7 mm wide in a symbol of
+/// 46 mm, in the center of the symbol.
+fn swiss_cross_area(symbol: Rect) -> Rect {
+    let (left, top, width, height) = symbol;
+    let centered = |size: u32| {
+        let logo_size = (size * 7 + 23) / 46;
+        // Round to the same parity as the symbol, so the logo is exactly
+        // centered.
+        logo_size + (size - logo_size) % 2
+    };
+    let (logo_width, logo_height) = (centered(width), centered(height));
+    (left + (width - logo_width) / 2, top + (height - logo_height) / 2, logo_width, logo_height)
+}
+
+/// Draws the part of the dark rectangle outside of the hole.
+fn draw_dark_rect_outside<C: Canvas>(canvas: &mut C, rect: Rect, hole: Rect) {
+    let (left, top, width, height) = rect;
+    let (right, bottom) = (left + width, top + height);
+    let (hole_right, hole_bottom) = (hole.0 + hole.2, hole.1 + hole.3);
+    if right <= hole.0 || hole_right <= left || bottom <= hole.1 || hole_bottom <= top {
+        canvas.draw_dark_rect(left, top, width, height);
+        return;
+    }
+
+    if top < hole.1 {
+        canvas.draw_dark_rect(left, top, width, hole.1 - top);
+    }
+    if hole_bottom < bottom {
+        canvas.draw_dark_rect(left, hole_bottom, width, bottom - hole_bottom);
+    }
+    let (middle_top, middle_bottom) = (max(top, hole.1), min(bottom, hole_bottom));
+    if left < hole.0 {
+        canvas.draw_dark_rect(left, middle_top, hole.0 - left, middle_bottom - middle_top);
+    }
+    if hole_right < right {
+        canvas.draw_dark_rect(hole_right, middle_top, right - hole_right, middle_bottom - middle_top);
+    }
+}
+
+/// Draws the Swiss cross logo into the (light) area: a dark square with a
+/// light border of 0.5 mm, and a light cross following the proportions of the
+/// Swiss flag, i.e. 20/32 of the square long and 6/32 wide.
+fn draw_swiss_cross<C: Canvas>(canvas: &mut C, area: Rect) {
+    let (border_x, border_y) = ((area.2 + 7) / 14, (area.3 + 7) / 14);
+    let (left, top) = (area.0 + border_x, area.1 + border_y);
+    let (width, height) = (area.2 - 2 * border_x, area.3 - 2 * border_y);
+
+    // Distances from the edges of the square to the ends of the cross (`a`)
+    // and to the sides of its arms (`b`).
+    let (ax, bx) = ((width * 6 + 16) / 32, (width * 13 + 16) / 32);
+    let (ay, by) = ((height * 6 + 16) / 32, (height * 13 + 16) / 32);
+
+    // Draw the square row by row, leaving out the cross.
+    let bands =
+        [(0, ay, 0), (ay, by, bx), (by, height - by, ax), (height - by, height - ay, bx), (height - ay, height, 0)];
+    for (band_top, band_bottom, margin) in bands {
+        if margin == 0 {
+            canvas.draw_dark_rect(left, top + band_top, width, band_bottom - band_top);
+        } else {
+            canvas.draw_dark_rect(left, top + band_top, margin, band_bottom - band_top);
+            canvas.draw_dark_rect(left + width - margin, top + band_top, margin, band_bottom - band_top);
+        }
+    }
+}
+
+//}}}
@@ -15,7 +15,6 @@
 use alloc::format;
 use alloc::string::String;
 use core::fmt::Write;
-use core::marker::PhantomData;
 
 use crate::render::{Canvas as RenderCanvas, Pixel};
 use crate::types::Color as ModuleColor;
@@ -35,8 +34,12 @@ impl<'a> Pixel for Color<'a> {
 
 #[doc(hidden)]
 pub struct Canvas<'a> {
-    svg: String,
-    marker: PhantomData<Color<'a>>,
+    width: u32,
+    height: u32,
+    physical_size_mm: Option<(u32, u32)>,
+    dark_pixel: Color<'a>,
+    light_pixel: Color<'a>,
+    path: String,
 }
 
 impl<'a> RenderCanvas for Canvas<'a> {
@@ -44,23 +47,11 @@ impl<'a> RenderCanvas for Canvas<'a> {
     type Image = String;
 
     fn new(width: u32, height: u32, dark_pixel: Color<'a>, light_pixel: Color<'a>) -> Self {
-        Canvas {
-            svg: format!(
-                concat!(
-                    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
-                    r#"<svg xmlns="http://www.w3.org/2000/svg""#,
-                    r#" version="1.1" width="{w}" height="{h}""#,
-                    r#" viewBox="0 0 {w} {h}" shape-rendering="crispEdges">"#,
-                    r#"<path d="M0 0h{w}v{h}H0z" fill="{bg}"/>"#,
-                    r#"<path fill="{fg}" d=""#,
-                ),
-                w = width,
-                h = height,
-                fg = dark_pixel.0,
-                bg = light_pixel.0
-            ),
-            marker: PhantomData,
-        }
+        Canvas { width, height, physical_size_mm: None, dark_pixel, light_pixel, path: String::new() }
+    }
+
+    fn set_physical_size_mm(&mut self, width: u32, height: u32) {
+        self.physical_size_mm = Some((width, height));
     }
 
     fn draw_dark_pixel(&mut self, x: u32, y: u32) {
@@ -68,11 +59,30 @@ impl<'a> RenderCanvas for Canvas<'a> {
     }
 
     fn draw_dark_rect(&mut self, left: u32, top: u32, width: u32, height: u32) {
-        write!(self.svg, "M{left} {top}h{width}v{height}h-{width}z").unwrap();
+        write!(self.path, "M{left} {top}h{width}v{height}h-{width}z").unwrap();
     }
 
-    fn into_image(mut self) -> String {
-        self.svg.push_str(r#""/></svg>"#);
-        self.svg
+    fn into_image(self) -> String {
+        let (w, h) = (self.width, self.height);
+        let size = match self.physical_size_mm {
+            Some((width, height)) => format!(r#"width="{width}mm" height="{height}mm""#),
+            None => format!(r#"width="{w}" height="{h}""#),
+        };
+        format!(
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+                r#"<svg xmlns="http://www.w3.org/2000/svg""#,
+                r#" version="1.1" {size}"#,
+                r#" viewBox="0 0 {w} {h}" shape-rendering="crispEdges">"#,
+                r#"<path d="M0 0h{w}v{h}H0z" fill="{bg}"/>"#,
+                r#"<path fill="{fg}" d="{path}"/></svg>"#,
+            ),
+            size = size,
+            w = w,
+            h = h,
+            fg = self.dark_pixel.0,
+            bg = self.light_pixel.0,
+            path = self.path,
+        )
     }
 }
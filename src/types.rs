@@ -2,6 +2,7 @@
 //! a QR code.
 
 use crate::cast::As;
+use crate::optimize::Segment;
 use core::cmp::{Ordering, PartialOrd};
 use core::default::Default;
 use core::fmt::{Display, Error, Formatter};
@@ -14,11 +15,16 @@ use core::ops::Not;
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum QrError {
     /// The data is too long to encode into a QR code for the given version.
-    DataTooLong,
+    /// The `Overflow` tells how many bits are over the limit.
+    DataTooLong(Overflow),
 
     /// The provided version / error correction level combination is invalid.
     InvalidVersion,
 
+    /// The forced mask pattern cannot be used in the version, e.g. a QR code
+    /// mask pattern in a Micro QR code.
+    UnsupportedMaskPattern,
+
     /// Some characters in the data cannot be supported by the provided QR code
     /// version.
     UnsupportedCharacterSet,
@@ -29,16 +35,42 @@ pub enum QrError {
 
     /// A character not belonging to the character set is found.
     InvalidCharacter,
+
+    /// The data does not follow the format of its application, e.g. a GS1
+    /// element string with an unknown Application Identifier or a wrong check
+    /// digit.
+    InvalidData,
+
+    /// The format information of a symbol being decoded is unreadable.
+    InvalidFormatInfo,
+
+    /// The data of a symbol being decoded is damaged beyond recovery, or does
+    /// not form a valid bit stream.
+    CorruptedData,
+
+    /// No QR code symbol can be located in the image being scanned.
+    SymbolNotFound,
 }
 
 impl Display for QrError {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
         let msg = match *self {
-            Self::DataTooLong => "data too long",
+            Self::DataTooLong(overflow) => {
+                return write!(
+                    fmt,
+                    "data too long: {} bits needed, but only {} bits available",
+                    overflow.bits_needed, overflow.bits_available
+                );
+            }
             Self::InvalidVersion => "invalid version",
+            Self::UnsupportedMaskPattern => "unsupported mask pattern",
             Self::UnsupportedCharacterSet => "unsupported character set",
             Self::InvalidEciDesignator => "invalid ECI designator",
             Self::InvalidCharacter => "invalid character",
+            Self::InvalidData => "invalid data",
+            Self::InvalidFormatInfo => "invalid format information",
+            Self::CorruptedData => "corrupted data",
+            Self::SymbolNotFound => "symbol not found",
         };
         fmt.write_str(msg)
     }
@@ -47,6 +79,63 @@ impl Display for QrError {
 #[cfg(feature = "std")]
 impl ::std::error::Error for QrError {}
 
+/// The details of `QrError::DataTooLong`, telling how far the data is from
+/// fitting.
+///
+/// ```
+/// use qrcode::types::{EcLevel, QrError, Version};
+/// use qrcode::QrCode;
+///
+/// let result = QrCode::with_version(b"0123456789ABCDEFGHIJ", Version::Normal(1), EcLevel::H);
+/// let Err(QrError::DataTooLong(overflow)) = result else {
+///     panic!("the data should not fit");
+/// };
+/// assert_eq!((overflow.bits_needed, overflow.bits_available), (116, 72));
+///
+/// // The letters are the alphanumeric segment crossing the limit, and 8 of
+/// // them need to be removed.
+/// assert_eq!(overflow.segment.map(|s| s.begin..s.end), Some(10..20));
+/// assert_eq!(overflow.excess_chars(), Some(8));
+/// ```
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Overflow {
+    /// The number of bits needed to encode the data, excluding the terminator
+    /// and padding.
+    pub bits_needed: usize,
+
+    /// The number of bits available in the attempted version and error
+    /// correction level. When a segment has more characters than its character
+    /// count indicator can represent, this is instead the number of bits the
+    /// segment could take at most.
+    pub bits_available: usize,
+
+    /// The segment which crosses the limit, if the limit is crossed within a
+    /// data segment. The byte range indexes the encoded data.
+    pub segment: Option<Segment>,
+}
+
+impl Overflow {
+    /// The number of bits exceeding the limit.
+    pub const fn excess_bits(&self) -> usize {
+        self.bits_needed.saturating_sub(self.bits_available)
+    }
+
+    /// Computes the minimum number of characters to remove from the
+    /// overflowing segment so that the data fits.
+    ///
+    /// Returns `None` if the segment is unknown, or removing characters from
+    /// it alone is not enough.
+    pub fn excess_chars(&self) -> Option<usize> {
+        let segment = self.segment?;
+        let mode = segment.mode;
+        let byte_size = segment.end - segment.begin;
+        let chars_count = if matches!(mode, Mode::Kanji | Mode::Hanzi) { byte_size / 2 } else { byte_size };
+        let excess_bits = self.excess_bits();
+        (0..=chars_count)
+            .find(|n| mode.data_bits_count(chars_count) - mode.data_bits_count(chars_count - n) >= excess_bits)
+    }
+}
+
 /// `QrResult` is a convenient alias for a QR code generation result.
 pub type QrResult<T> = Result<T, QrError>;
 
@@ -130,23 +219,88 @@ pub enum Version {
 
     /// A Micro QR code version. The parameter should be between 1 and 4.
     Micro(i16),
+
+    /// A rectangular Micro QR code (rMQR) version, given as the height and
+    /// width in modules, e.g. `RectMicro(7, 43)` for R7x43. The height should
+    /// be one of 7, 9, 11, 13, 15 or 17, and the width one of 27, 43, 59, 77,
+    /// 99 or 139, though not all combinations exist.
+    RectMicro(i16, i16),
 }
 
+/// All valid rMQR versions as (height, width), in the order of their version
+/// indicators.
+pub(crate) static RECT_MICRO_VERSIONS: [(i16, i16); 32] = [
+    (7, 43),
+    (7, 59),
+    (7, 77),
+    (7, 99),
+    (7, 139),
+    (9, 43),
+    (9, 59),
+    (9, 77),
+    (9, 99),
+    (9, 139),
+    (11, 27),
+    (11, 43),
+    (11, 59),
+    (11, 77),
+    (11, 99),
+    (11, 139),
+    (13, 27),
+    (13, 43),
+    (13, 59),
+    (13, 77),
+    (13, 99),
+    (13, 139),
+    (15, 43),
+    (15, 59),
+    (15, 77),
+    (15, 99),
+    (15, 139),
+    (17, 43),
+    (17, 59),
+    (17, 77),
+    (17, 99),
+    (17, 139),
+];
+
 impl Version {
     /// Get the number of "modules" on each size of the QR code, i.e. the width
     /// and height of the code.
+    ///
+    /// For rMQR, this is the number of modules per row.
     pub const fn width(self) -> i16 {
         match self {
             Self::Normal(v) => v * 4 + 17,
             Self::Micro(v) => v * 2 + 9,
+            Self::RectMicro(_, w) => w,
+        }
+    }
+
+    /// Get the number of "modules" on each column of the QR code. This is the
+    /// same as the width except for rMQR.
+    pub const fn height(self) -> i16 {
+        match self {
+            Self::Normal(_) | Self::Micro(_) => self.width(),
+            Self::RectMicro(h, _) => h,
+        }
+    }
+
+    /// The index of an rMQR version, which is also its version indicator.
+    /// Returns `None` for other versions or invalid sizes.
+    pub(crate) fn rect_micro_index(self) -> Option<usize> {
+        match self {
+            Self::RectMicro(h, w) => RECT_MICRO_VERSIONS.iter().position(|v| *v == (h, w)),
+            _ => None,
         }
     }
 
     /// Obtains an object from a hard-coded table.
     ///
-    /// The table must be a 44×4 array. The outer array represents the content
+    /// The table must be a 76×4 array. The outer array represents the content
     /// for each version. The first 40 entry corresponds to QR code versions 1
-    /// to 40, and the last 4 corresponds to Micro QR code version 1 to 4. The
+    /// to 40, the next 4 corresponds to Micro QR code version 1 to 4, and the
+    /// last 32 corresponds to the rMQR versions from R7x43 to R17x139. The
     /// inner array represents the content in each error correction level, in
     /// the order [L, M, Q, H].
     ///
@@ -168,6 +322,14 @@ impl Version {
                     return Ok(obj);
                 }
             }
+            Self::RectMicro(..) => {
+                if let Some(index) = self.rect_micro_index() {
+                    let obj = table[index + 44][ec_level as usize];
+                    if obj != T::default() {
+                        return Ok(obj);
+                    }
+                }
+            }
             _ => {}
         }
         Err(QrError::InvalidVersion)
@@ -175,10 +337,10 @@ impl Version {
 
     /// The number of bits needed to encode the mode indicator.
     pub fn mode_bits_count(self) -> usize {
-        if let Self::Micro(a) = self {
-            (a - 1).as_usize()
-        } else {
-            4
+        match self {
+            Self::Micro(a) => (a - 1).as_usize(),
+            Self::RectMicro(..) => 3,
+            Self::Normal(_) => 4,
         }
     }
 
@@ -186,6 +348,11 @@ impl Version {
     pub const fn is_micro(self) -> bool {
         matches!(self, Self::Micro(_))
     }
+
+    /// Checks whether is version refers to a rectangular Micro QR code.
+    pub const fn is_rect_micro(self) -> bool {
+        matches!(self, Self::RectMicro(..))
+    }
 }
 
 //}}}
@@ -207,6 +374,10 @@ pub enum Mode {
 
     /// The data contains Shift-JIS-encoded double-byte text.
     Kanji,
+
+    /// The data contains GB-2312-encoded double-byte text. This mode is only
+    /// supported in normal QR code.
+    Hanzi,
 }
 
 impl Mode {
@@ -227,26 +398,35 @@ impl Mode {
                 match self {
                     Self::Numeric => 2 + a,
                     Self::Alphanumeric | Self::Byte => 1 + a,
-                    Self::Kanji => a,
+                    Self::Kanji | Self::Hanzi => a,
                 }
             }
             Version::Normal(1..=9) => match self {
                 Self::Numeric => 10,
                 Self::Alphanumeric => 9,
-                Self::Byte | Self::Kanji => 8,
+                Self::Byte | Self::Kanji | Self::Hanzi => 8,
             },
             Version::Normal(10..=26) => match self {
                 Self::Numeric => 12,
                 Self::Alphanumeric => 11,
                 Self::Byte => 16,
-                Self::Kanji => 10,
+                Self::Kanji | Self::Hanzi => 10,
             },
             Version::Normal(_) => match self {
                 Self::Numeric => 14,
                 Self::Alphanumeric => 13,
                 Self::Byte => 16,
-                Self::Kanji => 12,
+                Self::Kanji | Self::Hanzi => 12,
             },
+            Version::RectMicro(..) => {
+                let bits = RECT_MICRO_LENGTH_BITS[version.rect_micro_index().unwrap_or(0)];
+                match self {
+                    Self::Numeric => bits[0],
+                    Self::Alphanumeric => bits[1],
+                    Self::Byte => bits[2],
+                    Self::Kanji | Self::Hanzi => bits[3],
+                }
+            }
         }
     }
 
@@ -258,14 +438,14 @@ impl Mode {
     /// assert_eq!(Mode::Numeric.data_bits_count(7), 24);
     /// ```
     ///
-    /// Note that in Kanji mode, the `raw_data_len` is the number of Kanjis,
-    /// i.e. half the total size of bytes.
+    /// Note that in Kanji and Hanzi modes, the `raw_data_len` is the number of
+    /// characters, i.e. half the total size of bytes.
     pub const fn data_bits_count(self, raw_data_len: usize) -> usize {
         match self {
             Self::Numeric => (raw_data_len * 10 + 2) / 3,
             Self::Alphanumeric => (raw_data_len * 11 + 1) / 2,
             Self::Byte => raw_data_len * 8,
-            Self::Kanji => raw_data_len * 13,
+            Self::Kanji | Self::Hanzi => raw_data_len * 13,
         }
     }
 
@@ -290,6 +470,43 @@ impl Mode {
     }
 }
 
+// The lengths of the character count indicator of rMQR versions, from ISO/IEC
+// 23941, in the order [Numeric, Alphanumeric, Byte, Kanji].
+static RECT_MICRO_LENGTH_BITS: [[usize; 4]; 32] = [
+    [4, 3, 3, 2], // R7x43
+    [5, 5, 4, 3], // R7x59
+    [6, 5, 5, 4], // R7x77
+    [7, 6, 5, 5], // R7x99
+    [7, 6, 6, 5], // R7x139
+    [5, 5, 4, 3], // R9x43
+    [6, 5, 5, 4], // R9x59
+    [7, 6, 5, 5], // R9x77
+    [7, 6, 6, 5], // R9x99
+    [8, 7, 6, 6], // R9x139
+    [4, 4, 3, 2], // R11x27
+    [6, 5, 5, 4], // R11x43
+    [7, 6, 5, 5], // R11x59
+    [7, 6, 6, 5], // R11x77
+    [8, 7, 6, 6], // R11x99
+    [8, 7, 7, 6], // R11x139
+    [5, 5, 4, 3], // R13x27
+    [6, 6, 5, 5], // R13x43
+    [7, 6, 6, 5], // R13x59
+    [7, 7, 6, 5], // R13x77
+    [8, 7, 7, 6], // R13x99
+    [8, 8, 7, 7], // R13x139
+    [7, 6, 6, 5], // R15x43
+    [7, 7, 6, 5], // R15x59
+    [8, 7, 7, 6], // R15x77
+    [8, 7, 7, 6], // R15x99
+    [9, 8, 7, 7], // R15x139
+    [7, 6, 6, 5], // R17x43
+    [8, 7, 6, 6], // R17x59
+    [8, 7, 7, 6], // R17x77
+    [8, 8, 7, 6], // R17x99
+    [9, 8, 8, 7], // R17x139
+];
+
 impl PartialOrd for Mode {
     /// Defines a partial ordering between modes. If `a <= b`, then `b` contains
     /// a superset of all characters supported by `a`.
@@ -305,7 +522,7 @@ impl PartialOrd for Mode {
 
 #[cfg(test)]
 mod mode_tests {
-    use crate::types::Mode::{Alphanumeric, Byte, Kanji, Numeric};
+    use crate::types::Mode::{Alphanumeric, Byte, Hanzi, Kanji, Numeric};
 
     #[test]
     fn test_mode_order() {
@@ -313,6 +530,8 @@ mod mode_tests {
         assert!(Byte > Kanji);
         assert!(!(Numeric < Kanji));
         assert!(!(Numeric >= Kanji));
+        assert!(Byte > Hanzi);
+        assert_eq!(Kanji.partial_cmp(&Hanzi), None);
     }
 
     #[test]
@@ -324,6 +543,7 @@ mod mode_tests {
         assert_eq!(Kanji.max(Numeric), Byte);
         assert_eq!(Alphanumeric.max(Numeric), Alphanumeric);
         assert_eq!(Kanji.max(Kanji), Kanji);
+        assert_eq!(Kanji.max(Hanzi), Byte);
     }
 }
 